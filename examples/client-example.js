@@ -183,9 +183,11 @@ async function registerAgent(agentAddress) {
   console.log('\n=== Registering Agent ===');
   
   // The register_agent function takes:
+  // - caller: Address (admin or AgentManager)
   // - agent: Address
   
   const args = [
+    new StellarSdk.Address(CONFIG.adminKeypair.publicKey()).toScVal(),
     new StellarSdk.Address(agentAddress).toScVal(),
   ];
   
@@ -209,6 +211,7 @@ async function removeAgent(agentAddress) {
   console.log('\n=== Removing Agent ===');
   
   const args = [
+    new StellarSdk.Address(CONFIG.adminKeypair.publicKey()).toScVal(),
     new StellarSdk.Address(agentAddress).toScVal(),
   ];
   
@@ -232,6 +235,7 @@ async function updateFee(feeBps) {
   console.log('\n=== Updating Platform Fee ===');
   
   const args = [
+    new StellarSdk.Address(CONFIG.adminKeypair.publicKey()).toScVal(),
     StellarSdk.xdr.ScVal.scvU32(feeBps),
   ];
   
@@ -266,12 +270,16 @@ async function createRemittance(senderKeypair, agentAddress, amount) {
   // - sender: Address
   // - agent: Address
   // - amount: i128
+  // - currency: String (corridor currency code)
+  // - country: String (corridor country code)
   // - expiry: Option<u64>
   
   const args = [
     new StellarSdk.Address(sender).toScVal(),
     new StellarSdk.Address(agentAddress).toScVal(),
     StellarSdk.xdr.ScVal.scvI128(amountStroops),
+    StellarSdk.xdr.ScVal.scvString('USD'),
+    StellarSdk.xdr.ScVal.scvString('NG'),
     StellarSdk.xdr.ScVal.scvVoid(), // No expiry
   ];
   
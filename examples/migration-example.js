@@ -3,6 +3,12 @@
  * 
  * This example demonstrates how to safely migrate state from an old
  * contract deployment to a new one using cryptographic verification.
+ *
+ * Hashes are never recomputed in JavaScript. Exports are saved as XDR files
+ * and checked with the `swiftremit-migration` tool
+ * (tools/migration-verifier), which uses the contract's own hashing code:
+ *
+ *   cargo run -p swiftremit-migration-verifier -- verify-snapshot snapshot.xdr
  */
 
 const {
@@ -12,6 +18,10 @@ const {
   Networks,
   Keypair,
 } = require('@stellar/stellar-sdk');
+const { execFileSync } = require('child_process');
+const fs = require('fs');
+const os = require('os');
+const path = require('path');
 
 // Configuration
 const config = {
@@ -24,6 +34,34 @@ const config = {
 // Initialize RPC server
 const server = new SorobanRpc.Server(config.rpcUrl);
 
+// Offline verifier binary (build with `cargo build -p swiftremit-migration-verifier`)
+const verifierBin = process.env.SWIFTREMIT_MIGRATION_BIN || 'swiftremit-migration';
+
+/**
+ * Helper: Save an exported value (an ScVal) as base64 XDR in a temp file
+ */
+function saveXdr(name, scVal) {
+  const file = path.join(os.tmpdir(), `swiftremit-${Date.now()}-${name}.xdr`);
+  fs.writeFileSync(file, scVal.toXDR('base64'));
+  return file;
+}
+
+/**
+ * Helper: Run the offline verifier and return its JSON report
+ *
+ * Exit code 1 means the check failed but a report was still printed.
+ */
+function runVerifier(args) {
+  try {
+    return JSON.parse(execFileSync(verifierBin, args, { encoding: 'utf8' }));
+  } catch (error) {
+    if (error.status === 1 && error.stdout) {
+      return JSON.parse(error.stdout);
+    }
+    throw error;
+  }
+}
+
 /**
  * Example 1: Full Migration
  * 
@@ -39,7 +77,7 @@ async function exampleFullMigration() {
   try {
     // Step 1: Pause old contract
     console.log('1. Pausing old contract...');
-    await oldContract.pause({ caller: admin.publicKey() });
+    await oldContract.pause({ caller: admin.publicKey(), reason: 0 });
     console.log('   ✓ Old contract paused');
 
     // Step 2: Export state
@@ -122,7 +160,7 @@ async function exampleBatchMigration() {
 
     // Step 2: Pause old contract
     console.log('\n2. Pausing old contract...');
-    await oldContract.pause({ caller: admin.publicKey() });
+    await oldContract.pause({ caller: admin.publicKey(), reason: 0 });
     console.log('   ✓ Old contract paused');
 
     // Step 3: Export batches
@@ -180,7 +218,7 @@ async function exampleBatchMigration() {
 /**
  * Example 3: Verification Only
  * 
- * Demonstrates how to verify a snapshot without importing.
+ * Demonstrates how to verify a snapshot offline without importing.
  */
 async function exampleVerificationOnly() {
   console.log('\n=== Example 3: Verification Only ===\n');
@@ -195,18 +233,17 @@ async function exampleVerificationOnly() {
     });
     console.log('   ✓ Snapshot exported');
 
-    // Verify integrity
+    // Verify integrity offline, with the contract's hashing code
     console.log('\n2. Verifying integrity...');
-    const verification = await oldContract.verify_migration_snapshot({
-      snapshot: snapshot
-    });
+    const report = runVerifier(['verify-snapshot', saveXdr('snapshot', snapshot)]);
 
-    console.log(`   Valid: ${verification.valid}`);
-    console.log(`   Expected hash: ${verification.expected_hash.toString('hex').substring(0, 32)}...`);
-    console.log(`   Actual hash:   ${verification.actual_hash.toString('hex').substring(0, 32)}...`);
-    console.log(`   Timestamp: ${new Date(verification.timestamp * 1000).toISOString()}`);
+    console.log(`   Valid: ${report.valid}`);
+    console.log(`   Expected hash: ${report.expected_hash.substring(0, 32)}...`);
+    console.log(`   Computed hash: ${report.computed_hash.substring(0, 32)}...`);
+    console.log(`   Merkle root valid: ${report.merkle_root_valid}`);
+    console.log(`   Source contract: ${report.source_contract}`);
 
-    if (verification.valid) {
+    if (report.valid) {
       console.log('\n✅ Snapshot is valid and ready for migration');
     } else {
       console.log('\n❌ Snapshot verification failed - do not use!');
@@ -355,18 +392,21 @@ async function exampleAuditTrail() {
       caller: admin.publicKey()
     });
     
-    const countersMatch = snapshot.instance_data.remittance_counter === 
-                         newSnapshot.instance_data.remittance_counter;
-    const feesMatch = snapshot.instance_data.accumulated_fees === 
-                     newSnapshot.instance_data.accumulated_fees;
+    const diff = runVerifier([
+      'diff',
+      saveXdr('old', snapshot),
+      saveXdr('new', newSnapshot),
+    ]);
     
     auditLog.steps.push({
       step: 'verify_import',
       timestamp: new Date().toISOString(),
-      counters_match: countersMatch,
-      fees_match: feesMatch,
-      new_verification_hash: newSnapshot.verification_hash.toString('hex'),
+      identical: diff.identical,
+      diff: diff,
     });
+    if (!diff.identical) {
+      throw new Error('Imported state differs from the export!');
+    }
     console.log('   ✓ Import verified');
 
     // Save audit log
@@ -393,27 +433,20 @@ async function exampleAuditTrail() {
 
 /**
  * Helper: Verify migration success
+ *
+ * Exports both contracts and diffs the snapshots offline.
  */
-async function verifyMigrationSuccess(oldContract, newContract) {
-  const oldCounter = await oldContract.get_remittance_counter();
-  const newCounter = await newContract.get_remittance_counter();
-  
-  if (oldCounter !== newCounter) {
-    throw new Error(`Counter mismatch: ${oldCounter} != ${newCounter}`);
-  }
-
-  const oldFees = await oldContract.get_accumulated_fees();
-  const newFees = await newContract.get_accumulated_fees();
-  
-  if (oldFees !== newFees) {
-    throw new Error(`Fees mismatch: ${oldFees} != ${newFees}`);
-  }
-
-  const oldFeeBps = await oldContract.get_platform_fee_bps();
-  const newFeeBps = await newContract.get_platform_fee_bps();
-  
-  if (oldFeeBps !== newFeeBps) {
-    throw new Error(`Fee bps mismatch: ${oldFeeBps} != ${newFeeBps}`);
+async function verifyMigrationSuccess(oldContract, newContract, admin) {
+  const oldSnapshot = await oldContract.export_migration_state({ caller: admin });
+  const newSnapshot = await newContract.export_migration_state({ caller: admin });
+
+  const diff = runVerifier([
+    'diff',
+    saveXdr('old', oldSnapshot),
+    saveXdr('new', newSnapshot),
+  ]);
+  if (!diff.identical) {
+    throw new Error(`State mismatch: ${JSON.stringify(diff, null, 2)}`);
   }
 
   return true;
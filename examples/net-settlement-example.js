@@ -341,6 +341,7 @@ async function batchSettleWithNetting(entries) {
   })
     .addOperation(
       contract.call('batch_settle_with_netting', {
+        caller: sourceKeypair.publicKey(),
         entries: entries,
       })
     )
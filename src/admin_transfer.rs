@@ -0,0 +1,226 @@
+//! Two-step admin transfers and emergency admin recovery.
+//!
+//! An admin rotates their key by proposing a transfer to a new address. The
+//! transfer only takes effect when the new address accepts it, which proves
+//! the key is controlled by someone, and it expires after
+//! `ADMIN_TRANSFER_LIFETIME_SECONDS`. On acceptance the new address takes the
+//! proposer's admin seat and any roles granted to the proposer: the admin
+//! count is unchanged and the old key keeps no privileges.
+//!
+//! The recovery key, set at initialization, can restore access when admin
+//! keys are lost. It starts a recovery naming a new admin; after
+//! `RECOVERY_DELAY_SECONDS` it completes the recovery, which grants the admin
+//! role and resets the multisig approval threshold to 1. Any admin can cancel
+//! a recovery during the delay, so a stolen recovery key alone cannot take
+//! over a contract whose admins are still active.
+//!
+//! The threshold reset is a deliberate trade-off. Recovery exists for the case
+//! where too few admin keys remain to reach the threshold, and the contract
+//! cannot tell lost keys from idle ones, so the recovered admin must be able
+//! to act alone to remove the lost admins. The cost is that a recovery key
+//! holder who outlasts the delay unopposed gets sole control, even over a
+//! contract configured for M-of-N approval. The delay and the cancel right of
+//! every admin are what bound that risk: the `("recovery", "started")` event
+//! must be monitored, and admins restore the threshold with a
+//! `SetApprovalThreshold` proposal once the lost keys are removed.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::ttl::{self, TtlClass};
+use crate::{ContractError, Role};
+
+/// How long a proposed admin transfer can be accepted (7 days).
+pub const ADMIN_TRANSFER_LIFETIME_SECONDS: u64 = 7 * 86_400;
+
+/// Delay between starting and completing an admin recovery (72 hours).
+pub const RECOVERY_DELAY_SECONDS: u64 = 72 * 3600;
+
+/// A proposed handover of one admin seat to a new address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransfer {
+    /// Admin handing over their seat
+    pub from: Address,
+    /// Address that must accept the transfer
+    pub to: Address,
+    pub proposed_at: u64,
+    /// Timestamp after which the transfer can no longer be accepted
+    pub expires_at: u64,
+}
+
+/// An admin recovery started by the recovery key.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRecovery {
+    /// Address that becomes an admin when the recovery completes
+    pub new_admin: Address,
+    pub started_at: u64,
+    /// Earliest timestamp the recovery can be completed at
+    pub executable_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum AdminTransferKey {
+    /// Pending transfer indexed by the receiving address (persistent storage)
+    Transfer(Address),
+    /// Recovery key (instance storage)
+    RecoveryKey,
+    /// The recovery in progress, if any (instance storage)
+    Recovery,
+}
+
+/// Records a transfer of `from`'s admin seat to `to`, replacing any pending
+/// transfer to `to`.
+///
+/// # Errors
+/// - AdminAlreadyExists: `to` is already an admin
+pub fn propose_transfer(env: &Env, from: &Address, to: &Address) -> Result<AdminTransfer, ContractError> {
+    if crate::storage::is_admin(env, to) {
+        return Err(ContractError::AdminAlreadyExists);
+    }
+
+    let now = env.ledger().timestamp();
+    let transfer = AdminTransfer {
+        from: from.clone(),
+        to: to.clone(),
+        proposed_at: now,
+        expires_at: now.saturating_add(ADMIN_TRANSFER_LIFETIME_SECONDS),
+    };
+    let key = AdminTransferKey::Transfer(to.clone());
+    env.storage().persistent().set(&key, &transfer);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+
+    Ok(transfer)
+}
+
+/// Completes the pending transfer to `to`, moving the admin seat and the
+/// proposer's roles.
+///
+/// Returns the transfer and the roles moved to `to`.
+///
+/// # Errors
+/// - ProposalNotFound: No transfer to `to` is pending
+/// - ProposalExpired: The transfer's `expires_at` has passed
+/// - AdminNotFound: The proposer is no longer an admin
+/// - AdminAlreadyExists: `to` became an admin in the meantime
+pub fn accept(env: &Env, to: &Address) -> Result<(AdminTransfer, Vec<Role>), ContractError> {
+    let transfer = get_transfer(env, to).ok_or(ContractError::ProposalNotFound)?;
+    if env.ledger().timestamp() > transfer.expires_at {
+        return Err(ContractError::ProposalExpired);
+    }
+    if !crate::storage::is_admin(env, &transfer.from) {
+        return Err(ContractError::AdminNotFound);
+    }
+    if crate::storage::is_admin(env, to) {
+        return Err(ContractError::AdminAlreadyExists);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&AdminTransferKey::Transfer(to.clone()));
+    crate::storage::set_admin_role(env, &transfer.from, false);
+    crate::storage::set_admin_role(env, to, true);
+    let roles = crate::roles::move_roles(env, &transfer.from, to);
+
+    Ok((transfer, roles))
+}
+
+/// Retrieves the pending transfer to an address.
+pub fn get_transfer(env: &Env, to: &Address) -> Option<AdminTransfer> {
+    env.storage()
+        .persistent()
+        .get(&AdminTransferKey::Transfer(to.clone()))
+}
+
+/// Returns the recovery key, if one is configured.
+pub fn get_recovery_key(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&AdminTransferKey::RecoveryKey)
+}
+
+/// Sets or clears the recovery key.
+pub fn set_recovery_key(env: &Env, recovery_key: &Option<Address>) {
+    match recovery_key {
+        Some(key) => env
+            .storage()
+            .instance()
+            .set(&AdminTransferKey::RecoveryKey, key),
+        None => env
+            .storage()
+            .instance()
+            .remove(&AdminTransferKey::RecoveryKey),
+    }
+}
+
+/// Requires authentication from the configured recovery key and returns it.
+///
+/// # Errors
+/// - Unauthorized: No recovery key is configured
+pub fn require_recovery_key(env: &Env) -> Result<Address, ContractError> {
+    let key = get_recovery_key(env).ok_or(ContractError::Unauthorized)?;
+    key.require_auth();
+    Ok(key)
+}
+
+/// Starts a recovery granting the admin role to `new_admin`, replacing any
+/// recovery in progress.
+///
+/// # Errors
+/// - AdminAlreadyExists: `new_admin` is already an admin
+pub fn start_recovery(env: &Env, new_admin: &Address) -> Result<PendingRecovery, ContractError> {
+    if crate::storage::is_admin(env, new_admin) {
+        return Err(ContractError::AdminAlreadyExists);
+    }
+
+    let now = env.ledger().timestamp();
+    let recovery = PendingRecovery {
+        new_admin: new_admin.clone(),
+        started_at: now,
+        executable_at: now.saturating_add(RECOVERY_DELAY_SECONDS),
+    };
+    env.storage()
+        .instance()
+        .set(&AdminTransferKey::Recovery, &recovery);
+
+    Ok(recovery)
+}
+
+/// Cancels the recovery in progress.
+///
+/// # Errors
+/// - InvalidStatus: No recovery is in progress
+pub fn cancel_recovery(env: &Env) -> Result<PendingRecovery, ContractError> {
+    let recovery = get_recovery(env).ok_or(ContractError::InvalidStatus)?;
+    env.storage().instance().remove(&AdminTransferKey::Recovery);
+    Ok(recovery)
+}
+
+/// Completes the recovery in progress once its delay has elapsed.
+///
+/// Grants the admin role to the recovery's new admin and resets the multisig
+/// approval threshold to 1, so the new admin can act alone. See the module
+/// docs for why the reset is unconditional.
+///
+/// # Errors
+/// - InvalidStatus: No recovery is in progress
+/// - TimelockNotElapsed: Called before `executable_at`
+pub fn complete_recovery(env: &Env) -> Result<PendingRecovery, ContractError> {
+    let recovery = get_recovery(env).ok_or(ContractError::InvalidStatus)?;
+    if env.ledger().timestamp() < recovery.executable_at {
+        return Err(ContractError::TimelockNotElapsed);
+    }
+
+    env.storage().instance().remove(&AdminTransferKey::Recovery);
+    if !crate::storage::is_admin(env, &recovery.new_admin) {
+        crate::storage::set_admin_role(env, &recovery.new_admin, true);
+        crate::storage::set_admin_count(env, crate::storage::get_admin_count(env) + 1);
+    }
+    crate::multisig::set_threshold(env, 1)?;
+
+    Ok(recovery)
+}
+
+/// Returns the recovery in progress, if any.
+pub fn get_recovery(env: &Env) -> Option<PendingRecovery> {
+    env.storage().instance().get(&AdminTransferKey::Recovery)
+}
@@ -0,0 +1,331 @@
+//! Archival of historical remittances.
+//!
+//! Completed and cancelled remittances are kept in persistent storage until an
+//! admin archives them. Archiving rolls each record into the summary of the
+//! period it closed in and into the period's totals for its (agent, token)
+//! pair, deletes the record and its settlement flag, and leaves a small marker
+//! so `get_remittance` fails with `RemittanceArchived` and
+//! `get_archived_period` names the summary holding it. Settlement receipts are
+//! kept, so settlement IDs computed off-chain still resolve.
+//!
+//! ## Periods
+//!
+//! A remittance's close time is recorded when it first reaches a terminal
+//! status. Period `p` covers close times in
+//! `[p * ARCHIVE_PERIOD_SECONDS, (p + 1) * ARCHIVE_PERIOD_SECONDS)`.
+//!
+//! ## Commitment (canonical)
+//!
+//! Each summary commits to the records pruned into it, in archival order:
+//!
+//! - Initial commitment: 32 zero bytes
+//! - Next commitment: SHA-256(commitment ‖ `merkle::remittance_leaf(remittance)`)
+//!
+//! Anyone holding the original records (from an indexer or an earlier
+//! migration export) can replay the chain and compare it with the published
+//! commitment.
+
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec};
+
+use crate::ttl::{self, TtlClass};
+use crate::{remittance_leaf, ContractError, Remittance, RemittanceStatus};
+
+/// Length of an archive period, in seconds (30 days).
+pub const ARCHIVE_PERIOD_SECONDS: u64 = 30 * 86_400;
+
+/// Maximum number of remittances archived by one `archive_remittances` call.
+pub const MAX_ARCHIVE_BATCH_SIZE: u32 = 50;
+
+/// Maximum number of (agent, token) totals returned by one `list_totals` call.
+pub const MAX_ARCHIVE_TOTALS_PAGE_SIZE: u32 = 100;
+
+/// Archived totals of one (agent, token) pair within a period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchiveTotals {
+    pub agent: Address,
+    pub token: Address,
+    /// Number of completed remittances
+    pub completed_count: u32,
+    /// Sum of completed remittance amounts
+    pub completed_volume: i128,
+    /// Platform fees collected on completed remittances
+    pub fees: i128,
+    /// Number of cancelled remittances
+    pub cancelled_count: u32,
+    /// Sum of cancelled (refunded) remittance amounts
+    pub cancelled_volume: i128,
+}
+
+/// Summary of the remittances archived into one period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivePeriodSummary {
+    pub period_id: u64,
+    /// First close timestamp covered by the period (inclusive)
+    pub period_start: u64,
+    /// Last close timestamp covered by the period (exclusive)
+    pub period_end: u64,
+    /// Number of remittances archived into the period
+    pub remittance_count: u32,
+    /// Number of (agent, token) pairs with totals in the period
+    pub pair_count: u32,
+    /// Hash chain over the pruned records (see module docs)
+    pub commitment: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum ArchiveKey {
+    /// Summary of a period (persistent storage)
+    Summary(u64),
+    /// Period an archived remittance was rolled into (persistent storage)
+    Archived(u64),
+    /// Totals of an (agent, token) pair within a period (persistent storage)
+    Totals(u64, Address, Address),
+    /// (agent, token) pair at a position of a period's totals, in first-archived order (persistent storage)
+    Pair(u64, u32),
+    /// IDs of every period with a summary, ascending by first use (persistent storage)
+    Periods,
+}
+
+/// Archives the given remittances that closed at least `older_than_days` days ago.
+///
+/// IDs that are unknown, already archived, still pending or too recent are
+/// skipped. Terminal remittances without a recorded close time (closed before
+/// close times were tracked) are stamped with the current time and become
+/// eligible `older_than_days` later.
+///
+/// Returns the number of remittances archived.
+///
+/// # Errors
+/// - InvalidAmount: More than `MAX_ARCHIVE_BATCH_SIZE` IDs
+pub fn archive_remittances(
+    env: &Env,
+    ids: &Vec<u64>,
+    older_than_days: u32,
+) -> Result<u32, ContractError> {
+    if ids.len() > MAX_ARCHIVE_BATCH_SIZE {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let now = env.ledger().timestamp();
+    let min_age = (older_than_days as u64) * 86_400;
+    let mut archived = 0;
+
+    for id in ids.iter() {
+        let remittance = match crate::storage::get_remittance(env, id) {
+            Ok(remittance) => remittance,
+            Err(_) => continue,
+        };
+        if remittance.status == RemittanceStatus::Pending {
+            continue;
+        }
+        let closed_at = match crate::storage::get_remittance_closed_at(env, id) {
+            Some(closed_at) => closed_at,
+            None => {
+                crate::storage::set_remittance_closed_at(env, id, now);
+                continue;
+            }
+        };
+        if now.saturating_sub(closed_at) < min_age {
+            continue;
+        }
+
+        let period_id = closed_at / ARCHIVE_PERIOD_SECONDS;
+        let mut summary = match get_summary(env, period_id) {
+            Some(summary) => summary,
+            None => open_period(env, period_id),
+        };
+        add_to_summary(env, &mut summary, &remittance)?;
+        set_summary(env, &summary);
+
+        restore_archived(env, id, period_id);
+        crate::storage::remove_remittance(env, id);
+
+        archived += 1;
+    }
+
+    Ok(archived)
+}
+
+/// Retrieves the archive summary of a period, if any remittance was archived into it.
+pub fn get_summary(env: &Env, period_id: u64) -> Option<ArchivePeriodSummary> {
+    env.storage()
+        .persistent()
+        .get(&ArchiveKey::Summary(period_id))
+}
+
+/// Returns the period an archived remittance was rolled into.
+pub fn get_archived_period(env: &Env, remittance_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&ArchiveKey::Archived(remittance_id))
+}
+
+/// Retrieves the archived totals of an (agent, token) pair within a period.
+pub fn get_totals(
+    env: &Env,
+    period_id: u64,
+    agent: &Address,
+    token: &Address,
+) -> Option<ArchiveTotals> {
+    env.storage()
+        .persistent()
+        .get(&ArchiveKey::Totals(period_id, agent.clone(), token.clone()))
+}
+
+/// Lists a period's totals among positions `[start, start + limit)`, in
+/// first-archived order. `limit` is capped at `MAX_ARCHIVE_TOTALS_PAGE_SIZE`.
+pub fn list_totals(env: &Env, period_id: u64, start: u32, limit: u32) -> Vec<ArchiveTotals> {
+    let mut totals = Vec::new(env);
+    let pair_count = match get_summary(env, period_id) {
+        Some(summary) => summary.pair_count,
+        None => return totals,
+    };
+    let end = start
+        .saturating_add(limit.min(MAX_ARCHIVE_TOTALS_PAGE_SIZE))
+        .min(pair_count);
+    for position in start..end {
+        if let Some((agent, token)) = get_pair(env, period_id, position) {
+            if let Some(pair_totals) = get_totals(env, period_id, &agent, &token) {
+                totals.push_back(pair_totals);
+            }
+        }
+    }
+    totals
+}
+
+/// Returns the (agent, token) pair at a position of a period's totals.
+pub fn get_pair(env: &Env, period_id: u64, position: u32) -> Option<(Address, Address)> {
+    env.storage()
+        .persistent()
+        .get(&ArchiveKey::Pair(period_id, position))
+}
+
+/// Lists the IDs of every period with an archive summary.
+pub fn list_periods(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&ArchiveKey::Periods)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores a migrated period summary, listing the period if it is new.
+pub fn restore_summary(env: &Env, summary: &ArchivePeriodSummary) {
+    if get_summary(env, summary.period_id).is_none() {
+        add_period(env, summary.period_id);
+    }
+    set_summary(env, summary);
+}
+
+/// Stores migrated totals at their position of a period's totals.
+pub fn restore_totals(env: &Env, period_id: u64, position: u32, totals: &ArchiveTotals) {
+    let pair_key = ArchiveKey::Pair(period_id, position);
+    env.storage()
+        .persistent()
+        .set(&pair_key, &(totals.agent.clone(), totals.token.clone()));
+    ttl::extend_persistent(env, &pair_key, TtlClass::Active);
+    let totals_key = ArchiveKey::Totals(period_id, totals.agent.clone(), totals.token.clone());
+    env.storage().persistent().set(&totals_key, totals);
+    ttl::extend_persistent(env, &totals_key, TtlClass::Active);
+}
+
+/// Stores the marker of a migrated archived remittance.
+pub fn restore_archived(env: &Env, remittance_id: u64, period_id: u64) {
+    let marker = ArchiveKey::Archived(remittance_id);
+    env.storage().persistent().set(&marker, &period_id);
+    ttl::extend_persistent(env, &marker, TtlClass::Active);
+}
+
+/// Extends a commitment with one pruned record.
+pub fn chain_commitment(env: &Env, commitment: &BytesN<32>, remittance: &Remittance) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &commitment.to_array());
+    data.extend_from_array(&remittance_leaf(env, remittance).to_array());
+    env.crypto().sha256(&data).into()
+}
+
+fn add_period(env: &Env, period_id: u64) {
+    let mut periods = list_periods(env);
+    periods.push_back(period_id);
+    env.storage()
+        .persistent()
+        .set(&ArchiveKey::Periods, &periods);
+    ttl::extend_persistent(env, &ArchiveKey::Periods, TtlClass::Active);
+}
+
+fn open_period(env: &Env, period_id: u64) -> ArchivePeriodSummary {
+    add_period(env, period_id);
+
+    let period_start = period_id * ARCHIVE_PERIOD_SECONDS;
+    ArchivePeriodSummary {
+        period_id,
+        period_start,
+        period_end: period_start + ARCHIVE_PERIOD_SECONDS,
+        remittance_count: 0,
+        pair_count: 0,
+        commitment: BytesN::from_array(env, &[0u8; 32]),
+    }
+}
+
+fn add_to_summary(
+    env: &Env,
+    summary: &mut ArchivePeriodSummary,
+    remittance: &Remittance,
+) -> Result<(), ContractError> {
+    let mut totals = match get_totals(env, summary.period_id, &remittance.agent, &remittance.token) {
+        Some(totals) => totals,
+        None => {
+            let pair_key = ArchiveKey::Pair(summary.period_id, summary.pair_count);
+            env.storage()
+                .persistent()
+                .set(&pair_key, &(remittance.agent.clone(), remittance.token.clone()));
+            ttl::extend_persistent(env, &pair_key, TtlClass::Active);
+            summary.pair_count += 1;
+            ArchiveTotals {
+                agent: remittance.agent.clone(),
+                token: remittance.token.clone(),
+                completed_count: 0,
+                completed_volume: 0,
+                fees: 0,
+                cancelled_count: 0,
+                cancelled_volume: 0,
+            }
+        }
+    };
+
+    match remittance.status {
+        RemittanceStatus::Completed => {
+            totals.completed_count += 1;
+            totals.completed_volume = totals
+                .completed_volume
+                .checked_add(remittance.amount)
+                .ok_or(ContractError::Overflow)?;
+            totals.fees = totals
+                .fees
+                .checked_add(remittance.fee)
+                .ok_or(ContractError::Overflow)?;
+        }
+        RemittanceStatus::Cancelled => {
+            totals.cancelled_count += 1;
+            totals.cancelled_volume = totals
+                .cancelled_volume
+                .checked_add(remittance.amount)
+                .ok_or(ContractError::Overflow)?;
+        }
+        RemittanceStatus::Pending => return Err(ContractError::InvalidStatus),
+    }
+
+    let totals_key = ArchiveKey::Totals(summary.period_id, totals.agent.clone(), totals.token.clone());
+    env.storage().persistent().set(&totals_key, &totals);
+    ttl::extend_persistent(env, &totals_key, TtlClass::Active);
+    summary.remittance_count += 1;
+    summary.commitment = chain_commitment(env, &summary.commitment, remittance);
+    Ok(())
+}
+
+fn set_summary(env: &Env, summary: &ArchivePeriodSummary) {
+    let key = ArchiveKey::Summary(summary.period_id);
+    env.storage().persistent().set(&key, summary);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+}
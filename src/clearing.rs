@@ -0,0 +1,335 @@
+//! Clearing cycles (settlement windows) for the SwiftRemit contract.
+//!
+//! Instead of paying agents out one remittance at a time, agents can queue
+//! confirmed remittances into the currently open clearing cycle. An operator
+//! later closes the cycle, at which point every queued remittance is netted per
+//! agent and token, and a single transfer is executed for each agent and token. The cycle totals and
+//! per-agent positions are persisted so the cycle report stays queryable.
+
+use soroban_sdk::{contracttype, token, Address, Env, Map, Vec};
+
+use crate::ttl::{self, TtlClass};
+use crate::{ContractError, RemittanceStatus};
+
+/// Maximum number of remittances that can be queued into one clearing cycle.
+/// Bounds the work done by `close_cycle` so it always fits in one transaction,
+/// even when every remittance is paid to a different agent.
+pub const MAX_CLEARING_CYCLE_SIZE: u32 = 25;
+
+/// Lifecycle of a clearing cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClearingCycleStatus {
+    /// Cycle is accepting queued remittances
+    Open,
+    /// Cycle has been closed and all net transfers executed
+    Settled,
+}
+
+/// Summary of a clearing cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClearingCycle {
+    /// Sequential cycle identifier (starts at 1)
+    pub id: u64,
+    /// Current status of the cycle
+    pub status: ClearingCycleStatus,
+    /// Ledger timestamp when the cycle was opened
+    pub opened_at: u64,
+    /// Ledger timestamp when the cycle was closed (None while open)
+    pub closed_at: Option<u64>,
+    /// Number of remittances queued into the cycle
+    pub remittance_count: u32,
+    /// Sum of the gross remittance amounts in the cycle, across all tokens
+    pub total_amount: i128,
+    /// Sum of the platform fees collected by the cycle
+    pub total_fees: i128,
+    /// Sum of the net transfers paid out to agents
+    pub total_payout: i128,
+}
+
+/// Net position of a single agent in a single token within a settled clearing cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentClearingPosition {
+    /// Agent receiving the net transfer
+    pub agent: Address,
+    /// Token the position is denominated in
+    pub token: Address,
+    /// Number of the agent's remittances settled in the cycle
+    pub remittance_count: u32,
+    /// Sum of the gross remittance amounts
+    pub gross_amount: i128,
+    /// Sum of the platform fees withheld
+    pub fees: i128,
+    /// Amount actually transferred to the agent (gross_amount - fees)
+    pub net_payout: i128,
+}
+
+/// Full report of a clearing cycle for reconciliation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClearingCycleReport {
+    /// Cycle summary and totals
+    pub cycle: ClearingCycle,
+    /// IDs of the remittances queued into the cycle, in queue order
+    pub remittance_ids: Vec<u64>,
+    /// One entry per agent and token paid in the cycle (empty while the cycle is open)
+    pub positions: Vec<AgentClearingPosition>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum ClearingKey {
+    /// ID of the currently open cycle (instance storage)
+    CurrentCycle,
+    /// Cycle summary by ID (persistent storage)
+    Cycle(u64),
+    /// Remittance IDs queued into a cycle (persistent storage)
+    CycleRemittances(u64),
+    /// Per-agent positions of a settled cycle (persistent storage)
+    CyclePositions(u64),
+    /// Cycle a remittance has been queued into (persistent storage)
+    QueuedRemittance(u64),
+}
+
+/// Returns the ID of the currently open cycle, opening cycle 1 on first use.
+pub fn get_current_cycle_id(env: &Env) -> u64 {
+    match env.storage().instance().get(&ClearingKey::CurrentCycle) {
+        Some(cycle_id) => cycle_id,
+        None => open_cycle(env, 1),
+    }
+}
+
+/// Retrieves a clearing cycle summary by ID.
+pub fn get_cycle(env: &Env, cycle_id: u64) -> Result<ClearingCycle, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&ClearingKey::Cycle(cycle_id))
+        .ok_or(ContractError::ClearingCycleNotFound)
+}
+
+/// Retrieves the full report (summary, remittances and agent positions) of a cycle.
+pub fn get_cycle_report(env: &Env, cycle_id: u64) -> Result<ClearingCycleReport, ContractError> {
+    let cycle = get_cycle(env, cycle_id)?;
+
+    Ok(ClearingCycleReport {
+        cycle,
+        remittance_ids: get_cycle_remittances(env, cycle_id),
+        positions: env
+            .storage()
+            .persistent()
+            .get(&ClearingKey::CyclePositions(cycle_id))
+            .unwrap_or(Vec::new(env)),
+    })
+}
+
+/// Returns the cycle a remittance is queued into, if any.
+pub fn get_queued_cycle(env: &Env, remittance_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&ClearingKey::QueuedRemittance(remittance_id))
+}
+
+/// Checks whether a remittance is waiting in (or was settled by) a clearing cycle.
+pub fn is_queued_for_clearing(env: &Env, remittance_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&ClearingKey::QueuedRemittance(remittance_id))
+}
+
+/// Queues a validated remittance into the currently open cycle.
+///
+/// The caller is responsible for running the settlement validation and agent
+/// authorization; this only records the remittance and updates cycle totals.
+///
+/// # Returns
+///
+/// * `Ok(cycle_id)` - Cycle the remittance was queued into
+/// * `Err(ContractError::InvalidStatus)` - Cycle already holds `MAX_CLEARING_CYCLE_SIZE` remittances
+pub fn queue_remittance(
+    env: &Env,
+    remittance: &crate::Remittance,
+) -> Result<u64, ContractError> {
+    let cycle_id = get_current_cycle_id(env);
+    let mut cycle = get_cycle(env, cycle_id)?;
+
+    if cycle.remittance_count >= MAX_CLEARING_CYCLE_SIZE {
+        return Err(ContractError::InvalidStatus);
+    }
+
+    cycle.remittance_count += 1;
+    cycle.total_amount = cycle
+        .total_amount
+        .checked_add(remittance.amount)
+        .ok_or(ContractError::Overflow)?;
+    cycle.total_fees = cycle
+        .total_fees
+        .checked_add(remittance.fee)
+        .ok_or(ContractError::Overflow)?;
+    set_cycle(env, &cycle);
+
+    let mut remittance_ids = get_cycle_remittances(env, cycle_id);
+    remittance_ids.push_back(remittance.id);
+    let remittances_key = ClearingKey::CycleRemittances(cycle_id);
+    env.storage()
+        .persistent()
+        .set(&remittances_key, &remittance_ids);
+    ttl::extend_persistent(env, &remittances_key, TtlClass::Active);
+
+    let queued_key = ClearingKey::QueuedRemittance(remittance.id);
+    env.storage().persistent().set(&queued_key, &cycle_id);
+    ttl::extend_persistent(env, &queued_key, TtlClass::Active);
+
+    Ok(cycle_id)
+}
+
+/// Closes the current cycle, nets it per agent and token and executes one transfer per position.
+///
+/// Every queued remittance is marked completed and its settlement receipt recorded
+/// under a fresh netting batch ID, fees are added to the accumulated platform fees
+/// of their token, the per-agent positions are stored as the cycle report, and
+/// the next cycle is opened.
+///
+/// # Returns
+///
+/// * `Ok(ClearingCycle)` - Summary of the settled cycle
+/// * `Err(ContractError::InvalidStatus)` - No remittances were queued
+pub fn close_cycle(env: &Env) -> Result<ClearingCycle, ContractError> {
+    let cycle_id = get_current_cycle_id(env);
+    let mut cycle = get_cycle(env, cycle_id)?;
+
+    if cycle.remittance_count == 0 {
+        return Err(ContractError::InvalidStatus);
+    }
+
+    let remittance_ids = get_cycle_remittances(env, cycle_id);
+    let batch_id = crate::receipts::next_batch_id(env)?;
+
+    // Net every queued remittance into one position per agent and token
+    let mut positions: Map<(Address, Address), AgentClearingPosition> = Map::new(env);
+    for i in 0..remittance_ids.len() {
+        let remittance_id = remittance_ids.get_unchecked(i);
+        let mut remittance = crate::storage::get_remittance(env, remittance_id)?;
+
+        let position_key = (remittance.agent.clone(), remittance.token.clone());
+        let mut position = positions
+            .get(position_key.clone())
+            .unwrap_or(AgentClearingPosition {
+                agent: remittance.agent.clone(),
+                token: remittance.token.clone(),
+                remittance_count: 0,
+                gross_amount: 0,
+                fees: 0,
+                net_payout: 0,
+            });
+        position.remittance_count += 1;
+        position.gross_amount = position
+            .gross_amount
+            .checked_add(remittance.amount)
+            .ok_or(ContractError::Overflow)?;
+        position.fees = position
+            .fees
+            .checked_add(remittance.fee)
+            .ok_or(ContractError::Overflow)?;
+        position.net_payout = position
+            .gross_amount
+            .checked_sub(position.fees)
+            .ok_or(ContractError::Overflow)?;
+        positions.set(position_key, position);
+
+        remittance.status = RemittanceStatus::Completed;
+        crate::storage::set_remittance(env, remittance_id, &remittance);
+        let payout_amount = remittance
+            .amount
+            .checked_sub(remittance.fee)
+            .ok_or(ContractError::Overflow)?;
+        crate::receipts::record_settlement(env, &remittance, payout_amount, Some(batch_id));
+        // Velocity limits already counted the payout when it was queued
+        crate::rate_limit::record_payout_volume(env, &remittance.agent, &remittance.token, payout_amount)?;
+    }
+
+    // Execute exactly one transfer per agent and token
+    let mut report_positions = Vec::new(env);
+    let mut total_payout: i128 = 0;
+
+    let position_keys = positions.keys();
+    for i in 0..position_keys.len() {
+        let position = positions.get_unchecked(position_keys.get_unchecked(i));
+
+        if position.net_payout > 0 {
+            let token_client = token::Client::new(env, &position.token);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &position.agent,
+                &position.net_payout,
+            );
+        }
+
+        total_payout = total_payout
+            .checked_add(position.net_payout)
+            .ok_or(ContractError::Overflow)?;
+        crate::storage::add_accumulated_fees(env, &position.token, position.fees)?;
+        crate::events::emit_clearing_agent_settled(
+            env,
+            cycle_id,
+            position.agent.clone(),
+            position.token.clone(),
+            position.remittance_count,
+            position.net_payout,
+        );
+        report_positions.push_back(position);
+    }
+
+    cycle.status = ClearingCycleStatus::Settled;
+    cycle.closed_at = Some(env.ledger().timestamp());
+    cycle.total_payout = total_payout;
+    set_cycle(env, &cycle);
+    let positions_key = ClearingKey::CyclePositions(cycle_id);
+    env.storage()
+        .persistent()
+        .set(&positions_key, &report_positions);
+    ttl::extend_persistent(env, &positions_key, TtlClass::Terminal);
+
+    let next_cycle_id = cycle_id.checked_add(1).ok_or(ContractError::Overflow)?;
+    open_cycle(env, next_cycle_id);
+
+    Ok(cycle)
+}
+
+fn open_cycle(env: &Env, cycle_id: u64) -> u64 {
+    set_cycle(
+        env,
+        &ClearingCycle {
+            id: cycle_id,
+            status: ClearingCycleStatus::Open,
+            opened_at: env.ledger().timestamp(),
+            closed_at: None,
+            remittance_count: 0,
+            total_amount: 0,
+            total_fees: 0,
+            total_payout: 0,
+        },
+    );
+    env.storage()
+        .instance()
+        .set(&ClearingKey::CurrentCycle, &cycle_id);
+    cycle_id
+}
+
+fn set_cycle(env: &Env, cycle: &ClearingCycle) {
+    let key = ClearingKey::Cycle(cycle.id);
+    env.storage().persistent().set(&key, cycle);
+    let class = match cycle.status {
+        ClearingCycleStatus::Open => TtlClass::Active,
+        ClearingCycleStatus::Settled => TtlClass::Terminal,
+    };
+    ttl::extend_persistent(env, &key, class);
+}
+
+fn get_cycle_remittances(env: &Env, cycle_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&ClearingKey::CycleRemittances(cycle_id))
+        .unwrap_or(Vec::new(env))
+}
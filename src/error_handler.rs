@@ -1,16 +1,16 @@
+//! Centralized error handling module for the SwiftRemit contract.
+//!
+//! This module provides a single global error handler that:
+//! - Maps contract errors to structured error responses
+//! - Provides consistent error formatting
+//! - Prevents sensitive information leakage
+//! - Logs errors for debugging while keeping client responses clean
+
 #![allow(dead_code)]
 
 use soroban_sdk::{Env, String as SorobanString};
 use crate::ContractError;
 
-/// Centralized error handling module for the SwiftRemit contract.
-/// 
-/// This module provides a single global error handler that:
-/// - Maps contract errors to structured error responses
-/// - Provides consistent error formatting
-/// - Prevents sensitive information leakage
-/// - Logs errors for debugging while keeping client responses clean
-
 /// Error severity levels for logging and monitoring
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ErrorSeverity {
@@ -111,6 +111,42 @@ impl ErrorHandler {
                 ErrorCategory::Validation,
                 ErrorSeverity::Low,
             ),
+            ContractError::InvalidMigrationHash => (
+                20,
+                SorobanString::from_str(env, "Migration data failed verification"),
+                ErrorCategory::Validation,
+                ErrorSeverity::High,
+            ),
+            ContractError::InvalidMigrationBatch => (
+                22,
+                SorobanString::from_str(env, "Migration batch is out of order or invalid"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::MigrationSourceMismatch => (
+                69,
+                SorobanString::from_str(env, "Migration data was exported by a different contract"),
+                ErrorCategory::Validation,
+                ErrorSeverity::High,
+            ),
+            ContractError::InvalidFxRate => (
+                28,
+                SorobanString::from_str(env, "FX rate is invalid"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::InvalidLimitConfig => (
+                60,
+                SorobanString::from_str(env, "Configuration value is out of range"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::DestinationNotAllowed => (
+                41,
+                SorobanString::from_str(env, "Destination is not an allowed treasury destination"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
             
             // Resource Errors
             ContractError::AgentNotRegistered => (
@@ -149,6 +185,48 @@ impl ErrorHandler {
                 ErrorCategory::Resource,
                 ErrorSeverity::Low,
             ),
+            ContractError::ClearingCycleNotFound => (
+                24,
+                SorobanString::from_str(env, "Clearing cycle not found"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::FxRateNotAvailable => (
+                27,
+                SorobanString::from_str(env, "No FX rate is available for this token pair"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::SettlementReceiptNotFound => (
+                29,
+                SorobanString::from_str(env, "Settlement receipt not found"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::WithdrawalNotFound => (
+                38,
+                SorobanString::from_str(env, "Withdrawal not found"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::ProposalNotFound => (
+                42,
+                SorobanString::from_str(env, "Proposal not found"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::RoleAlreadyGranted => (
+                49,
+                SorobanString::from_str(env, "Role is already granted"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::RoleNotGranted => (
+                50,
+                SorobanString::from_str(env, "Role is not granted"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
             
             // State Errors
             ContractError::InvalidStatus => (
@@ -187,6 +265,72 @@ impl ErrorHandler {
                 ErrorCategory::State,
                 ErrorSeverity::Low,
             ),
+            ContractError::MigrationInProgress => (
+                21,
+                SorobanString::from_str(env, "Migration already in progress or completed"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::ContractMigrated => (
+                30,
+                SorobanString::from_str(env, "Contract has been migrated to a successor"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::RemittanceArchived => (
+                36,
+                SorobanString::from_str(env, "Remittance has been archived"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::TimelockNotElapsed => (
+                40,
+                SorobanString::from_str(env, "Timelock has not elapsed"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::ProposalExpired => (
+                43,
+                SorobanString::from_str(env, "Proposal has expired"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::CircuitBreakerTripped => (
+                58,
+                SorobanString::from_str(env, "Payouts are halted by the circuit breaker"),
+                ErrorCategory::State,
+                ErrorSeverity::High,
+            ),
+            ContractError::RateLimitExceeded => (
+                68,
+                SorobanString::from_str(env, "Rate limit exceeded, try again later"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::DailySendLimitExceeded => (
+                23,
+                SorobanString::from_str(env, "Daily send limit exceeded"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::TransactionLimitExceeded => (
+                70,
+                SorobanString::from_str(env, "Amount is outside the transaction limits"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::VelocityLimitExceeded => (
+                62,
+                SorobanString::from_str(env, "Transfer volume limit exceeded"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::KycTierLimitExceeded => (
+                65,
+                SorobanString::from_str(env, "KYC tier limit exceeded"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
             
             // Authorization Errors
             ContractError::Unauthorized => (
@@ -195,6 +339,42 @@ impl ErrorHandler {
                 ErrorCategory::Authorization,
                 ErrorSeverity::Medium,
             ),
+            ContractError::MultisigRequired => (
+                47,
+                SorobanString::from_str(env, "Action requires an approved admin proposal"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::InsufficientApprovals => (
+                46,
+                SorobanString::from_str(env, "Proposal does not have enough approvals"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Low,
+            ),
+            ContractError::AlreadyApproved => (
+                45,
+                SorobanString::from_str(env, "Proposal already approved by this admin"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Low,
+            ),
+            ContractError::KycRequired => (
+                63,
+                SorobanString::from_str(env, "Sender has no KYC verification"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Low,
+            ),
+            ContractError::KycExpired => (
+                64,
+                SorobanString::from_str(env, "Sender's KYC verification has expired"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Low,
+            ),
+            ContractError::AddressSanctioned => (
+                66,
+                SorobanString::from_str(env, "Address is not permitted to transact"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
             
             // System Errors
             ContractError::Overflow => (
@@ -203,6 +383,12 @@ impl ErrorHandler {
                 ErrorCategory::System,
                 ErrorSeverity::High,
             ),
+            ContractError::ScreeningFailed => (
+                67,
+                SorobanString::from_str(env, "Sanctions screening is unavailable"),
+                ErrorCategory::System,
+                ErrorSeverity::High,
+            ),
         }
     }
     
@@ -211,22 +397,12 @@ impl ErrorHandler {
     /// Logs are only available in debug builds and never exposed to clients.
     /// This prevents stack traces and sensitive information from leaking.
     fn log_error(env: &Env, error: ContractError, severity: ErrorSeverity) {
-        #[cfg(any(test, feature = "testutils"))]
-        {
-            use crate::debug::log_error as debug_log;
-            let severity_str = match severity {
-                ErrorSeverity::Low => "LOW",
-                ErrorSeverity::Medium => "MEDIUM",
-                ErrorSeverity::High => "HIGH",
-            };
-            debug_log(env, &format!("[{}] Error: {:?}", severity_str, error));
-        }
-        
-        // In production, errors are not logged to prevent information leakage
-        #[cfg(not(any(test, feature = "testutils")))]
-        {
-            let _ = (env, error, severity); // Suppress unused variable warnings
-        }
+        #[cfg(feature = "debug-log")]
+        soroban_sdk::log!(env, "Error: code={}, severity={}", error as u32, severity as u32);
+
+        // Without debug logging, errors are not logged to prevent information leakage
+        #[cfg(not(feature = "debug-log"))]
+        let _ = (env, error, severity);
     }
     
     /// Get error category for an error
@@ -234,9 +410,22 @@ impl ErrorHandler {
         match error {
             ContractError::InvalidAmount
             | ContractError::InvalidFeeBps
-            | ContractError::InvalidAddress => ErrorCategory::Validation,
+            | ContractError::InvalidAddress
+            | ContractError::InvalidMigrationHash
+            | ContractError::InvalidMigrationBatch
+            | ContractError::MigrationSourceMismatch
+            | ContractError::InvalidFxRate
+            | ContractError::InvalidLimitConfig
+            | ContractError::TransactionLimitExceeded
+            | ContractError::DestinationNotAllowed => ErrorCategory::Validation,
             
-            ContractError::Unauthorized => ErrorCategory::Authorization,
+            ContractError::Unauthorized
+            | ContractError::MultisigRequired
+            | ContractError::InsufficientApprovals
+            | ContractError::AlreadyApproved
+            | ContractError::KycRequired
+            | ContractError::KycExpired
+            | ContractError::AddressSanctioned => ErrorCategory::Authorization,
             
             ContractError::AlreadyInitialized
             | ContractError::NotInitialized
@@ -245,16 +434,34 @@ impl ErrorHandler {
             | ContractError::DuplicateSettlement
             | ContractError::ContractPaused
             | ContractError::NoFeesToWithdraw
-            | ContractError::CannotRemoveLastAdmin => ErrorCategory::State,
+            | ContractError::CannotRemoveLastAdmin
+            | ContractError::MigrationInProgress
+            | ContractError::ContractMigrated
+            | ContractError::RemittanceArchived
+            | ContractError::TimelockNotElapsed
+            | ContractError::ProposalExpired
+            | ContractError::CircuitBreakerTripped
+            | ContractError::RateLimitExceeded
+            | ContractError::DailySendLimitExceeded
+            | ContractError::VelocityLimitExceeded
+            | ContractError::KycTierLimitExceeded => ErrorCategory::State,
             
             ContractError::AgentNotRegistered
             | ContractError::RemittanceNotFound
             | ContractError::AdminNotFound
             | ContractError::AdminAlreadyExists
             | ContractError::TokenNotWhitelisted
-            | ContractError::TokenAlreadyWhitelisted => ErrorCategory::Resource,
+            | ContractError::TokenAlreadyWhitelisted
+            | ContractError::ClearingCycleNotFound
+            | ContractError::FxRateNotAvailable
+            | ContractError::SettlementReceiptNotFound
+            | ContractError::WithdrawalNotFound
+            | ContractError::ProposalNotFound
+            | ContractError::RoleAlreadyGranted
+            | ContractError::RoleNotGranted => ErrorCategory::Resource,
             
-            ContractError::Overflow => ErrorCategory::System,
+            ContractError::Overflow
+            | ContractError::ScreeningFailed => ErrorCategory::System,
         }
     }
     
@@ -276,15 +483,46 @@ impl ErrorHandler {
             | ContractError::CannotRemoveLastAdmin
             | ContractError::TokenNotWhitelisted
             | ContractError::TokenAlreadyWhitelisted
-            | ContractError::AlreadyInitialized => ErrorSeverity::Low,
+            | ContractError::AlreadyInitialized
+            | ContractError::InvalidMigrationBatch
+            | ContractError::InvalidFxRate
+            | ContractError::InvalidLimitConfig
+            | ContractError::TransactionLimitExceeded
+            | ContractError::DestinationNotAllowed
+            | ContractError::InsufficientApprovals
+            | ContractError::AlreadyApproved
+            | ContractError::KycRequired
+            | ContractError::KycExpired
+            | ContractError::MigrationInProgress
+            | ContractError::ContractMigrated
+            | ContractError::RemittanceArchived
+            | ContractError::TimelockNotElapsed
+            | ContractError::ProposalExpired
+            | ContractError::RateLimitExceeded
+            | ContractError::DailySendLimitExceeded
+            | ContractError::VelocityLimitExceeded
+            | ContractError::KycTierLimitExceeded
+            | ContractError::ClearingCycleNotFound
+            | ContractError::FxRateNotAvailable
+            | ContractError::SettlementReceiptNotFound
+            | ContractError::WithdrawalNotFound
+            | ContractError::ProposalNotFound
+            | ContractError::RoleAlreadyGranted
+            | ContractError::RoleNotGranted => ErrorSeverity::Low,
             
             // Medium severity - unexpected but recoverable
             ContractError::NotInitialized
             | ContractError::DuplicateSettlement
-            | ContractError::Unauthorized => ErrorSeverity::Medium,
+            | ContractError::Unauthorized
+            | ContractError::MultisigRequired
+            | ContractError::AddressSanctioned => ErrorSeverity::Medium,
             
             // High severity - critical system errors
-            ContractError::Overflow => ErrorSeverity::High,
+            ContractError::Overflow
+            | ContractError::InvalidMigrationHash
+            | ContractError::MigrationSourceMismatch
+            | ContractError::CircuitBreakerTripped
+            | ContractError::ScreeningFailed => ErrorSeverity::High,
         }
     }
     
@@ -292,7 +530,16 @@ impl ErrorHandler {
     pub fn is_retryable(error: ContractError) -> bool {
         match error {
             // Transient errors that might succeed on retry
-            ContractError::ContractPaused => true,
+            ContractError::ContractPaused
+            | ContractError::InsufficientApprovals
+            | ContractError::TimelockNotElapsed
+            | ContractError::CircuitBreakerTripped
+            | ContractError::RateLimitExceeded
+            | ContractError::DailySendLimitExceeded
+            | ContractError::VelocityLimitExceeded
+            | ContractError::KycTierLimitExceeded
+            | ContractError::FxRateNotAvailable
+            | ContractError::ScreeningFailed => true,
             
             // Permanent errors that won't succeed on retry
             ContractError::AlreadyInitialized
@@ -312,7 +559,29 @@ impl ErrorHandler {
             | ContractError::AdminNotFound
             | ContractError::CannotRemoveLastAdmin
             | ContractError::TokenNotWhitelisted
-            | ContractError::TokenAlreadyWhitelisted => false,
+            | ContractError::TokenAlreadyWhitelisted
+            | ContractError::InvalidMigrationHash
+            | ContractError::InvalidMigrationBatch
+            | ContractError::MigrationSourceMismatch
+            | ContractError::InvalidFxRate
+            | ContractError::InvalidLimitConfig
+            | ContractError::TransactionLimitExceeded
+            | ContractError::DestinationNotAllowed
+            | ContractError::MultisigRequired
+            | ContractError::AlreadyApproved
+            | ContractError::KycRequired
+            | ContractError::KycExpired
+            | ContractError::AddressSanctioned
+            | ContractError::MigrationInProgress
+            | ContractError::ContractMigrated
+            | ContractError::RemittanceArchived
+            | ContractError::ProposalExpired
+            | ContractError::ClearingCycleNotFound
+            | ContractError::SettlementReceiptNotFound
+            | ContractError::WithdrawalNotFound
+            | ContractError::ProposalNotFound
+            | ContractError::RoleAlreadyGranted
+            | ContractError::RoleNotGranted => false,
         }
     }
     
@@ -331,7 +600,7 @@ impl ErrorHandler {
 /// Helper macro for consistent error handling in contract functions
 /// 
 /// Usage:
-/// ```
+/// ```ignore
 /// handle_contract_error!(env, operation_result)
 /// ```
 #[macro_export]
@@ -352,6 +621,8 @@ pub type ContractResult<T> = Result<T, ContractError>;
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
+
     use super::*;
     use soroban_sdk::Env;
 
@@ -447,7 +718,7 @@ mod tests {
     #[test]
     fn test_all_errors_have_unique_codes() {
         let env = Env::default();
-        let errors = vec![
+        let errors = std::vec![
             ContractError::AlreadyInitialized,
             ContractError::NotInitialized,
             ContractError::InvalidAmount,
@@ -467,6 +738,37 @@ mod tests {
             ContractError::CannotRemoveLastAdmin,
             ContractError::TokenNotWhitelisted,
             ContractError::TokenAlreadyWhitelisted,
+            ContractError::InvalidMigrationHash,
+            ContractError::MigrationInProgress,
+            ContractError::InvalidMigrationBatch,
+            ContractError::MigrationSourceMismatch,
+            ContractError::DailySendLimitExceeded,
+            ContractError::ClearingCycleNotFound,
+            ContractError::FxRateNotAvailable,
+            ContractError::InvalidFxRate,
+            ContractError::SettlementReceiptNotFound,
+            ContractError::ContractMigrated,
+            ContractError::RemittanceArchived,
+            ContractError::WithdrawalNotFound,
+            ContractError::TimelockNotElapsed,
+            ContractError::DestinationNotAllowed,
+            ContractError::ProposalNotFound,
+            ContractError::ProposalExpired,
+            ContractError::AlreadyApproved,
+            ContractError::InsufficientApprovals,
+            ContractError::MultisigRequired,
+            ContractError::RoleAlreadyGranted,
+            ContractError::RoleNotGranted,
+            ContractError::CircuitBreakerTripped,
+            ContractError::InvalidLimitConfig,
+            ContractError::VelocityLimitExceeded,
+            ContractError::TransactionLimitExceeded,
+            ContractError::KycRequired,
+            ContractError::KycExpired,
+            ContractError::KycTierLimitExceeded,
+            ContractError::AddressSanctioned,
+            ContractError::ScreeningFailed,
+            ContractError::RateLimitExceeded,
         ];
 
         let mut codes = std::collections::HashSet::new();
@@ -482,7 +784,9 @@ mod tests {
         
         // Messages should not contain technical jargon or stack traces
         let response = ErrorHandler::handle_error(&env, ContractError::InvalidAmount);
-        let message_str = response.message.to_string();
+        let mut buf = std::vec![0u8; response.message.len() as usize];
+        response.message.copy_into_slice(&mut buf);
+        let message_str = std::string::String::from_utf8(buf).unwrap();
         assert!(!message_str.contains("panic"));
         assert!(!message_str.contains("stack"));
         assert!(!message_str.contains("trace"));
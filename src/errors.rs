@@ -5,23 +5,64 @@
 
 use soroban_sdk::contracterror;
 
-
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum ContractError {
+    /// Contract has already been initialized.
+    /// Cause: Calling initialize() more than once, or importing batch 0 into an initialized contract.
+    AlreadyInitialized = 1,
+
+    /// Contract has not been initialized.
+    /// Cause: Calling a function before initialize().
+    NotInitialized = 2,
+
+    /// Amount is invalid.
+    /// Cause: An amount of zero or less, a batch outside its size bounds, or a remittance below the global or corridor min_amount or above its max_amount.
+    InvalidAmount = 3,
+
+    /// Fee basis points are out of range.
+    /// Cause: A fee above 10000 basis points (100%).
+    InvalidFeeBps = 4,
+
+    /// Agent is not registered.
+    /// Cause: Creating a remittance for, or removing, an address that is not a registered agent.
+    AgentNotRegistered = 5,
+
+    /// Remittance does not exist.
+    /// Cause: Querying or acting on an unknown remittance ID.
+    RemittanceNotFound = 6,
+
+    /// Object is not in the state the operation requires.
+    /// Cause: Settling or cancelling a remittance that is not pending, or acting on a withdrawal, proposal, recovery, pause scope, circuit breaker, clearing cycle or migration that is not in the expected state.
+    InvalidStatus = 7,
+
+    /// Arithmetic overflow.
+    /// Cause: An amount, fee or counter calculation exceeded its integer range.
+    Overflow = 8,
+
+    /// No accumulated fees to withdraw.
+    /// Cause: Withdrawing fees while the fee balance is zero.
+    NoFeesToWithdraw = 9,
+
+    /// Address is invalid.
+    /// Cause: An address equal to the contract itself, or sender and agent being the same address.
+    InvalidAddress = 10,
+
+    /// Settlement window has expired.
+    /// Cause: Confirming a payout after the remittance's expiry timestamp.
+    SettlementExpired = 11,
 
+    /// Settlement was already executed.
+    /// Cause: Confirming a payout for a remittance that was already settled.
     DuplicateSettlement = 12,
 
     /// Contract is paused. Settlements are temporarily disabled.
-    /// Cause: Attempting confirm_payout() while contract is in paused state.
+    /// Cause: Attempting confirm_payout() while contract is in paused state, or acting in an operation, agent, token or corridor scope paused with pause_scope(); see get_pause_state().
     ContractPaused = 13,
-    
-    /// Rate limit exceeded. Sender must wait before submitting another settlement.
-    /// Cause: Attempting confirm_payout() before cooldown period has elapsed.
-    RateLimitExceeded = 14,
+
     /// Caller is not authorized to perform admin operations.
-    /// Cause: Non-admin attempting to perform admin-only operations.
+    /// Cause: Non-admin attempting to perform admin-only operations, a caller without the required role, or a missing import admin or recovery key.
     Unauthorized = 14,
 
     /// Admin address already exists in the system.
@@ -45,11 +86,11 @@ pub enum ContractError {
     TokenAlreadyWhitelisted = 19,
     
     /// Migration hash verification failed.
-    /// Cause: Snapshot hash doesn't match computed hash (data tampering or corruption).
+    /// Cause: Snapshot hash doesn't match computed hash (data tampering or corruption), or the data was exported on a different network.
     InvalidMigrationHash = 20,
     
     /// Migration already in progress or completed.
-    /// Cause: Attempting to start migration when one is already active.
+    /// Cause: Attempting to start migration when one is already active, or importing a snapshot that was imported before.
     MigrationInProgress = 21,
     
     /// Migration batch out of order or invalid.
@@ -59,4 +100,112 @@ pub enum ContractError {
     /// Daily send limit exceeded for this user.
     /// Cause: User's total transfers in the last 24 hours exceed the configured limit.
     DailySendLimitExceeded = 23,
+
+    /// Clearing cycle does not exist.
+    /// Cause: Querying a cycle ID that has never been opened.
+    ClearingCycleNotFound = 24,
+
+    /// No FX rate is available for a token pair.
+    /// Cause: No admin-posted rate and no oracle configured, or the oracle call failed.
+    FxRateNotAvailable = 27,
+
+    /// FX rate is invalid.
+    /// Cause: Rate is zero or negative, or base and quote tokens are the same.
+    InvalidFxRate = 28,
+
+    /// No settlement receipt exists for the lookup key.
+    /// Cause: Unknown settlement ID, or the remittance has not been settled.
+    SettlementReceiptNotFound = 29,
+
+    /// Contract has been migrated to a successor and no longer accepts writes.
+    /// Cause: Calling a mutating function after complete_migration(); see get_migration_mode() for the successor.
+    ContractMigrated = 30,
+
+    /// Remittance has been archived into a period summary.
+    /// Cause: Reading or acting on a remittance after archive_remittances(); see get_archived_period() for its summary.
+    RemittanceArchived = 36,
+
+    /// Fee withdrawal proposal does not exist.
+    /// Cause: Vetoing, executing or querying an unknown withdrawal ID.
+    WithdrawalNotFound = 38,
+
+    /// Timelock has not elapsed.
+    /// Cause: Executing a fee withdrawal or completing an admin recovery before its executable_at timestamp.
+    TimelockNotElapsed = 40,
+
+    /// Destination is not on the treasury allow-list.
+    /// Cause: Proposing or executing a fee withdrawal to an address that is not an allowed treasury destination.
+    DestinationNotAllowed = 41,
+
+    /// Admin proposal or admin transfer does not exist.
+    /// Cause: Approving, executing or querying an unknown proposal ID, or accepting an admin transfer that was never proposed or was already accepted.
+    ProposalNotFound = 42,
+
+    /// Admin proposal or admin transfer has expired.
+    /// Cause: Approving or executing a proposal, or accepting an admin transfer, after its expires_at timestamp.
+    ProposalExpired = 43,
+
+    /// Admin has already approved this proposal.
+    /// Cause: The proposer or an approving admin approving the same proposal again.
+    AlreadyApproved = 45,
+
+    /// Admin proposal lacks approvals.
+    /// Cause: Executing a proposal approved by fewer current admins than the approval threshold.
+    InsufficientApprovals = 46,
+
+    /// Action requires an approved admin proposal.
+    /// Cause: Calling update_fee(), pause(), unpause(), unpause_scope(), remove_agent() or the migration mode functions directly while the approval threshold is above 1.
+    MultisigRequired = 47,
+
+    /// Address already holds the role.
+    /// Cause: Granting a role to an address that was already granted it.
+    RoleAlreadyGranted = 49,
+
+    /// Address does not hold the role.
+    /// Cause: Revoking a role that was never granted, or was already revoked.
+    RoleNotGranted = 50,
+
+    /// Payouts are halted by the circuit breaker.
+    /// Cause: Settling after payout volume exceeded a circuit breaker ceiling; an admin must call reset_circuit_breaker().
+    CircuitBreakerTripped = 58,
+
+    /// Limit, threshold or policy configuration is invalid.
+    /// Cause: A negative limit, a minimum above the maximum, transaction limits for a sender or agent scope, an out-of-range withdrawal timelock, approval threshold or storage TTL policy, or an invalid circuit breaker ceiling or window.
+    InvalidLimitConfig = 60,
+
+    /// Velocity limit exceeded.
+    /// Cause: Creating a remittance or settling a payout that would take a sender, corridor, global or agent window volume over its limit; see get_limit_usage().
+    VelocityLimitExceeded = 62,
+
+    /// Sender has no KYC record.
+    /// Cause: Creating a remittance while KYC is required without an assigned tier, or revoking KYC that was never assigned.
+    KycRequired = 63,
+
+    /// Sender's KYC has expired.
+    /// Cause: Creating a remittance after the sender's KYC expires_at, or assigning a tier with an expiry that is not in the future.
+    KycExpired = 64,
+
+    /// KYC tier limit exceeded.
+    /// Cause: A remittance above the tier's max_transfer_amount, or taking the sender's 24-hour volume over its max_daily_volume.
+    KycTierLimitExceeded = 65,
+
+    /// Address is sanctioned.
+    /// Cause: A sender or agent on the denylist, or flagged by the screening contract, creating, settling or being refunded a remittance.
+    AddressSanctioned = 66,
+
+    /// Sanctions screening could not be completed.
+    /// Cause: The configured screening contract could not be called or returned an error; screening fails closed.
+    ScreeningFailed = 67,
+
+    /// Rate limit exceeded. Sender must wait before submitting another settlement.
+    /// Cause: Attempting confirm_payout() before cooldown period has elapsed.
+    RateLimitExceeded = 68,
+
+    /// Migration data was exported by a different contract.
+    /// Cause: Snapshot or config batch source_contract differs from the source set with configure_import_admin().
+    MigrationSourceMismatch = 69,
+
+    /// Remittance amount is outside the transaction limits.
+    /// Cause: Creating a remittance below the minimum or above the maximum amount set with set_transaction_limits() for the global scope or its corridor.
+    TransactionLimitExceeded = 70,
 }
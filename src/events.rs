@@ -4,7 +4,12 @@
 //! contract operations. Events include schema versioning and ledger metadata
 //! for comprehensive audit trails.
 
-use soroban_sdk::{symbol_short, Address, Env};
+use soroban_sdk::{symbol_short, Address, BytesN, Env};
+
+use crate::{
+    AdminAction, AdminTransfer, CircuitBreakerTrip, DenylistEntry, FeeWithdrawal, FxRate, KycRecord,
+    PauseRecord, PauseScope, PendingRecovery, Role, TtlPolicy,
+};
 
 /// Schema version for event structure compatibility
 const SCHEMA_VERSION: u32 = 1;
@@ -50,12 +55,16 @@ pub fn emit_remittance_created(
 ///
 /// * `env` - The contract execution environment
 /// * `remittance_id` - ID of the completed remittance
+/// * `sender` - Address of the sender who created the remittance
 /// * `agent` - Address of the agent who received the payout
+/// * `token` - Token the payout was made in
 /// * `amount` - Payout amount (after fee deduction)
 pub fn emit_remittance_completed(
     env: &Env,
     remittance_id: u64,
+    sender: Address,
     agent: Address,
+    token: Address,
     amount: i128,
 ) {
     env.events().publish(
@@ -65,7 +74,9 @@ pub fn emit_remittance_completed(
             env.ledger().sequence(),
             env.ledger().timestamp(),
             remittance_id,
+            sender,
             agent,
+            token,
             amount,
         ),
     );
@@ -78,11 +89,15 @@ pub fn emit_remittance_completed(
 /// * `env` - The contract execution environment
 /// * `remittance_id` - ID of the cancelled remittance
 /// * `sender` - Address of the sender who received the refund
+/// * `agent` - Address of the agent the remittance was assigned to
+/// * `token` - Token the refund was made in
 /// * `amount` - Refunded amount
 pub fn emit_remittance_cancelled(
     env: &Env,
     remittance_id: u64,
     sender: Address,
+    agent: Address,
+    token: Address,
     amount: i128,
 ) {
     env.events().publish(
@@ -93,11 +108,381 @@ pub fn emit_remittance_cancelled(
             env.ledger().timestamp(),
             remittance_id,
             sender,
+            agent,
+            token,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event for each net transfer executed by a batch settlement.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Party whose obligations the transfer settles
+/// * `recipient` - Party that received the net amount
+/// * `token` - Token the transfer was made in
+/// * `amount` - Net amount transferred (after fee deduction)
+pub fn emit_settlement_completed(
+    env: &Env,
+    sender: Address,
+    recipient: Address,
+    token: Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("complete")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            recipient,
+            token,
             amount,
         ),
     );
 }
 
+// ── Admin Events ───────────────────────────────────────────────────
+
+/// Emits an event when an address is granted the admin role.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `caller` - Admin that added the new admin
+/// * `admin` - Address of the new admin
+pub fn emit_admin_added(env: &Env, caller: Address, admin: Address) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("added")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            caller,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when an address loses the admin role.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `caller` - Admin that removed the admin
+/// * `admin` - Address of the removed admin
+pub fn emit_admin_removed(env: &Env, caller: Address, admin: Address) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            caller,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when an admin proposes handing their seat to a new address.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `transfer` - The proposed transfer
+pub fn emit_admin_transfer_proposed(env: &Env, transfer: AdminTransfer) {
+    env.events().publish(
+        (symbol_short!("transfer"), symbol_short!("proposed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            transfer.from,
+            transfer.to,
+            transfer.expires_at,
+        ),
+    );
+}
+
+/// Emits an event when a new address accepts an admin transfer.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `transfer` - The accepted transfer
+pub fn emit_admin_transfer_accepted(env: &Env, transfer: AdminTransfer) {
+    env.events().publish(
+        (symbol_short!("transfer"), symbol_short!("accepted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            transfer.from,
+            transfer.to,
+        ),
+    );
+}
+
+/// Emits an event when the recovery key is set or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin that changed the key, or the initial admin at initialization
+/// * `recovery_key` - The new recovery key, if any
+pub fn emit_recovery_key_updated(env: &Env, admin: Address, recovery_key: Option<Address>) {
+    env.events().publish(
+        (symbol_short!("recovery"), symbol_short!("key")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            recovery_key,
+        ),
+    );
+}
+
+/// Emits an event when the recovery key starts an admin recovery.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `recovery` - The recovery in progress
+pub fn emit_recovery_started(env: &Env, recovery: PendingRecovery) {
+    env.events().publish(
+        (symbol_short!("recovery"), symbol_short!("started")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            recovery.new_admin,
+            recovery.executable_at,
+        ),
+    );
+}
+
+/// Emits an event when an admin cancels a recovery.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin that cancelled the recovery
+/// * `new_admin` - Address the recovery would have granted the admin role
+pub fn emit_recovery_cancelled(env: &Env, admin: Address, new_admin: Address) {
+    env.events().publish(
+        (symbol_short!("recovery"), symbol_short!("cancelled")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            new_admin,
+        ),
+    );
+}
+
+/// Emits an event when a recovery completes and grants the admin role.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `new_admin` - Address that became an admin
+pub fn emit_recovery_completed(env: &Env, new_admin: Address) {
+    env.events().publish(
+        (symbol_short!("recovery"), symbol_short!("completed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            new_admin,
+        ),
+    );
+}
+
+/// Emits an event when a token is added to the whitelist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `caller` - Admin that whitelisted the token
+/// * `token` - Address of the whitelisted token
+pub fn emit_token_whitelisted(env: &Env, caller: Address, token: Address) {
+    env.events().publish(
+        (symbol_short!("token"), symbol_short!("added")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            caller,
+            token,
+        ),
+    );
+}
+
+/// Emits an event when a token is removed from the whitelist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `caller` - Admin that removed the token
+/// * `token` - Address of the removed token
+pub fn emit_token_removed(env: &Env, caller: Address, token: Address) {
+    env.events().publish(
+        (symbol_short!("token"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            caller,
+            token,
+        ),
+    );
+}
+
+/// Emits an event when the settlement rate limit cooldown is changed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `caller` - Admin that changed the cooldown
+/// * `old_cooldown` - Previous cooldown in seconds
+/// * `new_cooldown` - New cooldown in seconds
+pub fn emit_rate_limit_updated(env: &Env, caller: Address, old_cooldown: u64, new_cooldown: u64) {
+    env.events().publish(
+        (symbol_short!("ratelim"), symbol_short!("updated")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            caller,
+            old_cooldown,
+            new_cooldown,
+        ),
+    );
+}
+
+// ── Pause Events ───────────────────────────────────────────────────
+
+/// Emits an event when the contract is paused.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that paused the contract
+/// * `reason` - Reason code recorded with the pause
+pub fn emit_paused(env: &Env, admin: Address, reason: u32) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("paused")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            reason,
+        ),
+    );
+}
+
+/// Emits an event when the contract is unpaused.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that unpaused the contract
+pub fn emit_unpaused(env: &Env, admin: Address) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("unpaused")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when an operation, agent, token or corridor is paused.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `scope` - The paused scope
+/// * `record` - Reason code, pauser and time of the pause
+pub fn emit_scope_paused(env: &Env, scope: PauseScope, record: PauseRecord) {
+    env.events().publish(
+        (symbol_short!("scope"), symbol_short!("paused")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            scope,
+            record,
+        ),
+    );
+}
+
+/// Emits an event when a scoped pause is lifted.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `scope` - The scope that was unpaused
+/// * `caller` - Address that lifted the pause
+pub fn emit_scope_unpaused(env: &Env, scope: PauseScope, caller: Address) {
+    env.events().publish(
+        (symbol_short!("scope"), symbol_short!("unpaused")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            scope,
+            caller,
+        ),
+    );
+}
+
+/// Emits an event when payout volume trips the circuit breaker.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `trip` - Which ceiling was exceeded, the window volume and the ceiling
+pub fn emit_circuit_breaker_tripped(env: &Env, trip: CircuitBreakerTrip) {
+    env.events().publish(
+        (symbol_short!("breaker"), symbol_short!("tripped")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            trip,
+        ),
+    );
+}
+
+/// Emits an event when an admin resets a tripped circuit breaker.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that reset the breaker
+/// * `trip` - The trip that was cleared
+pub fn emit_circuit_breaker_reset(env: &Env, admin: Address, trip: CircuitBreakerTrip) {
+    env.events().publish(
+        (symbol_short!("breaker"), symbol_short!("reset")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            trip,
+        ),
+    );
+}
+
 // ── Agent Events ───────────────────────────────────────────────────
 
 /// Emits an event when a new agent is registered.
@@ -156,22 +541,665 @@ pub fn emit_fee_updated(env: &Env, fee_bps: u32) {
     );
 }
 
-/// Emits an event when accumulated fees are withdrawn.
+/// Emits an event when a fee withdrawal is proposed.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `to` - Address that received the withdrawn fees
-/// * `amount` - Amount of fees withdrawn
-pub fn emit_fees_withdrawn(env: &Env, to: Address, amount: i128) {
+/// * `withdrawal` - The withdrawal proposal
+pub fn emit_fee_withdrawal_proposed(env: &Env, withdrawal: FeeWithdrawal) {
     env.events().publish(
-        (symbol_short!("fee"), symbol_short!("withdraw")),
+        (symbol_short!("fee"), symbol_short!("proposed")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            to,
-            amount,
+            withdrawal.id,
+            withdrawal.proposer,
+            withdrawal.token,
+            withdrawal.amount,
+            withdrawal.to,
+            withdrawal.executable_at,
+        ),
+    );
+}
+
+/// Emits an event when a fee withdrawal is vetoed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `withdrawal_id` - ID of the vetoed withdrawal
+/// * `admin` - Address that vetoed the withdrawal
+pub fn emit_fee_withdrawal_vetoed(env: &Env, withdrawal_id: u64, admin: Address) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("vetoed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            withdrawal_id,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when accumulated fees are withdrawn.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `withdrawal_id` - ID of the executed withdrawal
+/// * `admin` - Address that executed the withdrawal
+/// * `token` - Token the fees were withdrawn in
+/// * `to` - Address that received the withdrawn fees
+/// * `amount` - Amount of fees withdrawn
+pub fn emit_fees_withdrawn(
+    env: &Env,
+    withdrawal_id: u64,
+    admin: Address,
+    token: Address,
+    to: Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("withdraw")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            withdrawal_id,
+            admin,
+            token,
+            to,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when the fee withdrawal timelock is changed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that changed the timelock
+/// * `seconds` - New timelock in seconds
+pub fn emit_withdrawal_timelock_updated(env: &Env, admin: Address, seconds: u64) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("timelock")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            seconds,
+        ),
+    );
+}
+
+/// Emits an event when an address is added to or removed from the treasury allow-list.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that changed the allow-list
+/// * `destination` - Treasury destination address
+/// * `allowed` - Whether the destination is now allowed
+pub fn emit_treasury_destination_updated(env: &Env, admin: Address, destination: Address, allowed: bool) {
+    env.events().publish(
+        (symbol_short!("treasury"), symbol_short!("dest")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            destination,
+            allowed,
+        ),
+    );
+}
+
+// ── Clearing Events ────────────────────────────────────────────────
+
+/// Emits an event when an agent queues a remittance into a clearing cycle.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `cycle_id` - ID of the open clearing cycle
+/// * `remittance_id` - ID of the queued remittance
+/// * `agent` - Agent who confirmed the remittance
+pub fn emit_remittance_queued(env: &Env, cycle_id: u64, remittance_id: u64, agent: Address) {
+    env.events().publish(
+        (symbol_short!("clearing"), symbol_short!("queued")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            cycle_id,
+            remittance_id,
+            agent,
+        ),
+    );
+}
+
+/// Emits an event for each agent net transfer executed when a cycle closes.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `cycle_id` - ID of the cycle being settled
+/// * `agent` - Agent receiving the net transfer
+/// * `token` - Token the net transfer is paid in
+/// * `remittance_count` - Number of the agent's remittances in the cycle
+/// * `net_payout` - Amount transferred to the agent
+pub fn emit_clearing_agent_settled(
+    env: &Env,
+    cycle_id: u64,
+    agent: Address,
+    token: Address,
+    remittance_count: u32,
+    net_payout: i128,
+) {
+    env.events().publish(
+        (symbol_short!("clearing"), symbol_short!("agent")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            cycle_id,
+            agent,
+            token,
+            remittance_count,
+            net_payout,
+        ),
+    );
+}
+
+/// Emits an event when an operator closes and settles a clearing cycle.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `cycle_id` - ID of the settled cycle
+/// * `operator` - Address that closed the cycle
+/// * `remittance_count` - Number of remittances settled
+/// * `total_payout` - Sum of all agent net transfers
+/// * `total_fees` - Platform fees collected by the cycle
+pub fn emit_clearing_cycle_closed(
+    env: &Env,
+    cycle_id: u64,
+    operator: Address,
+    remittance_count: u32,
+    total_payout: i128,
+    total_fees: i128,
+) {
+    env.events().publish(
+        (symbol_short!("clearing"), symbol_short!("closed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            cycle_id,
+            operator,
+            remittance_count,
+            total_payout,
+            total_fees,
+        ),
+    );
+}
+
+// ── FX Events ──────────────────────────────────────────────────────
+
+/// Emits an event when an admin posts an FX rate.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that posted the rate
+/// * `fx_rate` - The posted rate
+pub fn emit_fx_rate_updated(env: &Env, admin: Address, fx_rate: FxRate) {
+    env.events().publish(
+        (symbol_short!("fx"), symbol_short!("rate_set")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            fx_rate,
+        ),
+    );
+}
+
+/// Emits an event when the FX oracle contract is set or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that changed the oracle
+/// * `oracle` - New oracle address (None when cleared)
+pub fn emit_fx_oracle_updated(env: &Env, admin: Address, oracle: Option<Address>) {
+    env.events().publish(
+        (symbol_short!("fx"), symbol_short!("oracle")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            oracle,
+        ),
+    );
+}
+
+/// Emits an event when the maximum age of admin-posted FX rates changes.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that changed the maximum age
+/// * `max_age` - New maximum age in seconds
+pub fn emit_fx_max_age_updated(env: &Env, admin: Address, max_age: u64) {
+    env.events().publish(
+        (symbol_short!("fx"), symbol_short!("max_age")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            max_age,
+        ),
+    );
+}
+
+/// Emits an event for each FX rate applied by a cross-currency netting batch.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fx_rate` - The rate used to convert the batch's remittances
+pub fn emit_fx_rate_applied(env: &Env, fx_rate: FxRate) {
+    env.events().publish(
+        (symbol_short!("fx"), symbol_short!("applied")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            fx_rate,
+        ),
+    );
+}
+
+// ── Upgrade Events ─────────────────────────────────────────────────
+
+/// Emits an event when the contract WASM is upgraded in place.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that performed the upgrade
+/// * `new_wasm_hash` - Hash of the installed WASM
+/// * `storage_schema_version` - Storage schema version at the time of upgrade
+pub fn emit_contract_upgraded(
+    env: &Env,
+    admin: Address,
+    new_wasm_hash: BytesN<32>,
+    storage_schema_version: u32,
+) {
+    env.events().publish(
+        (symbol_short!("upgrade"), symbol_short!("wasm")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            new_wasm_hash,
+            storage_schema_version,
+        ),
+    );
+}
+
+/// Emits an event when a storage schema migration completes.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `from_version` - Storage schema version before the migration
+/// * `to_version` - Storage schema version after the migration
+pub fn emit_schema_migrated(env: &Env, from_version: u32, to_version: u32) {
+    env.events().publish(
+        (symbol_short!("upgrade"), symbol_short!("schema")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            from_version,
+            to_version,
+        ),
+    );
+}
+
+// ── Migration Events ───────────────────────────────────────────────
+
+/// Emits an event when the contract enters migration mode.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that started the migration
+pub fn emit_migration_started(env: &Env, admin: Address) {
+    env.events().publish(
+        (symbol_short!("migrate"), symbol_short!("started")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when the contract is marked as migrated.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that completed the migration
+/// * `successor` - Contract now holding the migrated state
+pub fn emit_migration_completed(env: &Env, admin: Address, successor: Address) {
+    env.events().publish(
+        (symbol_short!("migrate"), symbol_short!("complete")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            successor,
+        ),
+    );
+}
+
+// ── Storage TTL Events ─────────────────────────────────────────────
+
+/// Emits an event when the storage TTL policy is changed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that changed the policy
+/// * `policy` - The new TTL policy
+pub fn emit_ttl_policy_updated(env: &Env, admin: Address, policy: TtlPolicy) {
+    env.events().publish(
+        (symbol_short!("ttl"), symbol_short!("policy")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            policy,
+        ),
+    );
+}
+
+// ── Archive Events ─────────────────────────────────────────────────
+
+/// Emits an event when remittances are archived into period summaries.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that ran the archival
+/// * `count` - Number of remittances archived
+pub fn emit_remittances_archived(env: &Env, admin: Address, count: u32) {
+    env.events().publish(
+        (symbol_short!("archive"), symbol_short!("pruned")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            count,
+        ),
+    );
+}
+
+// ── Multisig Events ────────────────────────────────────────────────
+
+/// Emits an event when an admin action is proposed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `proposal_id` - ID of the new proposal
+/// * `proposer` - Admin that proposed the action
+/// * `action` - The proposed action
+/// * `expires_at` - Timestamp after which the proposal can no longer be executed
+pub fn emit_admin_action_proposed(
+    env: &Env,
+    proposal_id: u64,
+    proposer: Address,
+    action: AdminAction,
+    expires_at: u64,
+) {
+    env.events().publish(
+        (symbol_short!("multisig"), symbol_short!("proposed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            proposal_id,
+            proposer,
+            action,
+            expires_at,
+        ),
+    );
+}
+
+/// Emits an event when an admin approves a proposal.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `proposal_id` - ID of the approved proposal
+/// * `admin` - Admin that approved
+/// * `approvals` - Number of approvals recorded so far
+pub fn emit_admin_action_approved(env: &Env, proposal_id: u64, admin: Address, approvals: u32) {
+    env.events().publish(
+        (symbol_short!("multisig"), symbol_short!("approved")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            proposal_id,
+            admin,
+            approvals,
+        ),
+    );
+}
+
+/// Emits an event when an approved proposal is executed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `proposal_id` - ID of the executed proposal
+/// * `admin` - Admin that executed it
+pub fn emit_admin_action_executed(env: &Env, proposal_id: u64, admin: Address) {
+    env.events().publish(
+        (symbol_short!("multisig"), symbol_short!("executed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            proposal_id,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when the approval threshold changes.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin that executed the change
+/// * `threshold` - New number of approvals required
+pub fn emit_approval_threshold_updated(env: &Env, admin: Address, threshold: u32) {
+    env.events().publish(
+        (symbol_short!("multisig"), symbol_short!("threshold")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            threshold,
+        ),
+    );
+}
+
+// ── Role Events ────────────────────────────────────────────────────
+
+/// Emits an event when a role is granted to an address.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `role` - The granted role
+/// * `account` - Address receiving the role
+/// * `admin` - Admin that granted the role
+pub fn emit_role_granted(env: &Env, role: Role, account: Address, admin: Address) {
+    env.events().publish(
+        (symbol_short!("role"), symbol_short!("granted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            role,
+            account,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when a role is revoked from an address.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `role` - The revoked role
+/// * `account` - Address losing the role
+/// * `admin` - Admin that revoked the role
+pub fn emit_role_revoked(env: &Env, role: Role, account: Address, admin: Address) {
+    env.events().publish(
+        (symbol_short!("role"), symbol_short!("revoked")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            role,
+            account,
+            admin,
+        ),
+    );
+}
+
+// ── Compliance Events ──────────────────────────────────────────────
+
+/// Emits an event when a KYC tier is assigned to an address.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `account` - Address whose KYC was assigned
+/// * `record` - The tier, expiry and verifying officer
+pub fn emit_kyc_updated(env: &Env, account: Address, record: KycRecord) {
+    env.events().publish(
+        (symbol_short!("kyc"), symbol_short!("updated")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            account,
+            record,
+        ),
+    );
+}
+
+/// Emits an event when an address's KYC is revoked.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `account` - Address whose KYC was revoked
+/// * `officer` - Compliance officer or admin that revoked it
+pub fn emit_kyc_revoked(env: &Env, account: Address, officer: Address) {
+    env.events().publish(
+        (symbol_short!("kyc"), symbol_short!("revoked")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            account,
+            officer,
+        ),
+    );
+}
+
+/// Emits an event when an address is added to the sanctions denylist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `account` - Denylisted address
+/// * `entry` - Reason code, listing admin and time
+pub fn emit_address_denied(env: &Env, account: Address, entry: DenylistEntry) {
+    env.events().publish(
+        (symbol_short!("sanctions"), symbol_short!("denied")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            account,
+            entry,
+        ),
+    );
+}
+
+/// Emits an event when an address is removed from the sanctions denylist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `account` - Address removed from the denylist
+/// * `admin` - Admin that removed it
+pub fn emit_address_allowed(env: &Env, account: Address, admin: Address) {
+    env.events().publish(
+        (symbol_short!("sanctions"), symbol_short!("allowed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            account,
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when the sanctions screening contract is set or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address that changed the screening contract
+/// * `screening` - New screening contract address (None when cleared)
+pub fn emit_screening_contract_updated(env: &Env, admin: Address, screening: Option<Address>) {
+    env.events().publish(
+        (symbol_short!("sanctions"), symbol_short!("screening")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            screening,
         ),
     );
 }
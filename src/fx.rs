@@ -0,0 +1,235 @@
+//! Foreign exchange rates for cross-currency netting.
+//!
+//! Rates are either posted by an admin or supplied by an external oracle
+//! contract. Every rate is expressed as units of the quote token per unit of
+//! the base token, scaled by `FX_RATE_SCALE`, so off-chain reconciliation can
+//! reproduce each conversion exactly from the `FxRate` reported alongside it.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+use crate::ttl::{self, TtlClass};
+use crate::ContractError;
+
+/// Fixed-point scale of FX rates (7 decimals, matching Stellar asset precision).
+/// A rate of `FX_RATE_SCALE` means 1 base token = 1 quote token.
+pub const FX_RATE_SCALE: i128 = 10_000_000;
+
+/// Default maximum age, in seconds, of an admin-posted rate used for netting.
+pub const DEFAULT_MAX_FX_RATE_AGE: u64 = 3_600;
+
+/// Where an FX rate came from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FxRateSource {
+    /// Posted manually by an admin via `set_fx_rate`
+    Admin,
+    /// Returned by the configured FX oracle contract
+    Oracle,
+}
+
+/// An FX rate used to convert amounts from one token into another.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FxRate {
+    /// Token amounts are converted from
+    pub base_token: Address,
+    /// Token amounts are converted into
+    pub quote_token: Address,
+    /// Units of quote token per unit of base token, scaled by `FX_RATE_SCALE`
+    pub rate: i128,
+    /// Whether the rate was admin-posted or oracle-supplied
+    pub source: FxRateSource,
+    /// Ledger timestamp when the rate was posted or fetched
+    pub updated_at: u64,
+}
+
+/// Interface an external FX oracle contract must implement.
+#[contractclient(name = "FxOracleClient")]
+pub trait FxOracle {
+    /// Returns units of `quote_token` per unit of `base_token`, scaled by `FX_RATE_SCALE`.
+    fn fx_rate(env: Env, base_token: Address, quote_token: Address) -> i128;
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum FxKey {
+    /// Admin-posted rate for a (base, quote) token pair (persistent storage)
+    Rate(Address, Address),
+    /// Every (base, quote) pair a rate has been posted for (persistent storage)
+    RatePairs,
+    /// Address of the FX oracle contract (instance storage)
+    Oracle,
+    /// Maximum age of an admin-posted rate in seconds (instance storage)
+    MaxRateAge,
+}
+
+/// Stores an admin-posted FX rate.
+///
+/// # Returns
+///
+/// * `Ok(FxRate)` - The stored rate
+/// * `Err(ContractError::InvalidFxRate)` - Rate is zero or negative, or base equals quote
+pub fn set_admin_rate(
+    env: &Env,
+    base_token: &Address,
+    quote_token: &Address,
+    rate: i128,
+) -> Result<FxRate, ContractError> {
+    if rate <= 0 || base_token == quote_token {
+        return Err(ContractError::InvalidFxRate);
+    }
+
+    let fx_rate = FxRate {
+        base_token: base_token.clone(),
+        quote_token: quote_token.clone(),
+        rate,
+        source: FxRateSource::Admin,
+        updated_at: env.ledger().timestamp(),
+    };
+    restore_rate(env, &fx_rate);
+
+    Ok(fx_rate)
+}
+
+/// Stores an admin-posted rate as is, keeping its timestamp. Used by migration
+/// imports.
+pub fn restore_rate(env: &Env, fx_rate: &FxRate) {
+    let key = FxKey::Rate(fx_rate.base_token.clone(), fx_rate.quote_token.clone());
+    if !env.storage().persistent().has(&key) {
+        let mut pairs = get_rate_pairs(env);
+        pairs.push_back((fx_rate.base_token.clone(), fx_rate.quote_token.clone()));
+        env.storage().persistent().set(&FxKey::RatePairs, &pairs);
+    }
+    ttl::extend_persistent(env, &FxKey::RatePairs, TtlClass::Active);
+    env.storage().persistent().set(&key, fx_rate);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+}
+
+/// Lists every (base, quote) token pair an admin rate has been posted for.
+pub fn get_rate_pairs(env: &Env) -> Vec<(Address, Address)> {
+    env.storage()
+        .persistent()
+        .get(&FxKey::RatePairs)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Retrieves the admin-posted FX rate for a token pair, if any.
+pub fn get_admin_rate(env: &Env, base_token: &Address, quote_token: &Address) -> Option<FxRate> {
+    env.storage()
+        .persistent()
+        .get(&FxKey::Rate(base_token.clone(), quote_token.clone()))
+}
+
+/// Sets (or clears, with `None`) the FX oracle contract address.
+pub fn set_oracle(env: &Env, oracle: &Option<Address>) {
+    match oracle {
+        Some(address) => env.storage().instance().set(&FxKey::Oracle, address),
+        None => env.storage().instance().remove(&FxKey::Oracle),
+    }
+}
+
+/// Retrieves the configured FX oracle contract address.
+pub fn get_oracle(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&FxKey::Oracle)
+}
+
+/// Sets the maximum age of an admin-posted rate that netting will still apply.
+///
+/// # Returns
+///
+/// * `Ok(())` - Maximum age stored
+/// * `Err(ContractError::InvalidFxRate)` - Maximum age is zero
+pub fn set_max_rate_age(env: &Env, max_age: u64) -> Result<(), ContractError> {
+    if max_age == 0 {
+        return Err(ContractError::InvalidFxRate);
+    }
+    env.storage().instance().set(&FxKey::MaxRateAge, &max_age);
+    Ok(())
+}
+
+/// Retrieves the maximum age of an admin-posted rate, in seconds.
+pub fn get_max_rate_age(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&FxKey::MaxRateAge)
+        .unwrap_or(DEFAULT_MAX_FX_RATE_AGE)
+}
+
+/// Resolves the rate to convert `base_token` amounts into `quote_token`.
+///
+/// An admin-posted rate takes precedence so operators can override the oracle,
+/// unless it is older than the maximum rate age; otherwise the configured
+/// oracle is queried.
+///
+/// # Returns
+///
+/// * `Ok(FxRate)` - The rate to apply
+/// * `Err(ContractError::FxRateNotAvailable)` - No fresh admin rate, and no oracle or the oracle failed
+pub fn resolve_rate(
+    env: &Env,
+    base_token: &Address,
+    quote_token: &Address,
+) -> Result<FxRate, ContractError> {
+    if let Some(fx_rate) = get_admin_rate(env, base_token, quote_token) {
+        let age = env.ledger().timestamp().saturating_sub(fx_rate.updated_at);
+        if age <= get_max_rate_age(env) {
+            return Ok(fx_rate);
+        }
+    }
+
+    let oracle = get_oracle(env).ok_or(ContractError::FxRateNotAvailable)?;
+    let client = FxOracleClient::new(env, &oracle);
+    match client.try_fx_rate(base_token, quote_token) {
+        Ok(Ok(rate)) if rate > 0 => Ok(FxRate {
+            base_token: base_token.clone(),
+            quote_token: quote_token.clone(),
+            rate,
+            source: FxRateSource::Oracle,
+            updated_at: env.ledger().timestamp(),
+        }),
+        _ => Err(ContractError::FxRateNotAvailable),
+    }
+}
+
+/// Converts an amount of the rate's base token into its quote token, rounding down.
+pub fn convert(amount: i128, fx_rate: &FxRate) -> Result<i128, ContractError> {
+    amount
+        .checked_mul(fx_rate.rate)
+        .ok_or(ContractError::Overflow)?
+        .checked_div(FX_RATE_SCALE)
+        .ok_or(ContractError::Overflow)
+}
+
+/// Converts an amount of the rate's quote token back into its base token, rounding down.
+pub fn convert_back(amount: i128, fx_rate: &FxRate) -> Result<i128, ContractError> {
+    amount
+        .checked_mul(FX_RATE_SCALE)
+        .ok_or(ContractError::Overflow)?
+        .checked_div(fx_rate.rate)
+        .ok_or(ContractError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_convert_rounds_down() {
+        let env = Env::default();
+        let fx_rate = FxRate {
+            base_token: Address::generate(&env),
+            quote_token: Address::generate(&env),
+            rate: 9_250_000, // 0.925
+            source: FxRateSource::Admin,
+            updated_at: 0,
+        };
+
+        assert_eq!(convert(1000, &fx_rate), Ok(925));
+        assert_eq!(convert(3, &fx_rate), Ok(2));
+        assert_eq!(convert(i128::MAX, &fx_rate), Err(ContractError::Overflow));
+
+        assert_eq!(convert_back(925, &fx_rate), Ok(1000));
+        assert_eq!(convert_back(2, &fx_rate), Ok(2));
+    }
+}
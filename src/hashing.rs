@@ -25,6 +25,8 @@
 //! - No separators between fields — fixed-width encoding eliminates ambiguity
 //! - Hash algorithm: SHA-256 via Soroban env.crypto().sha256()
 
+use core::cmp::Ordering;
+
 use soroban_sdk::{Address, Bytes, BytesN, Env};
 
 /// Canonical field ordering version — increment if ordering ever changes.
@@ -102,7 +104,55 @@ pub fn compute_settlement_id_from_remittance(
 
 /// Serialize an Address to its canonical byte representation.
 /// Uses Soroban's built-in address serialization via to_xdr.
-fn address_to_bytes(env: &Env, address: &Address) -> Bytes {
+pub(crate) fn address_to_bytes(env: &Env, address: &Address) -> Bytes {
     use soroban_sdk::xdr::ToXdr;
     address.to_xdr(env)
 }
+
+/// Canonical total ordering of addresses.
+///
+/// Orders addresses exactly as their XDR encodings (`address_to_bytes`, the same
+/// bytes that feed into settlement IDs) compare byte-wise. The host compares the
+/// underlying `ScAddress` values field by field, and because every XDR field is
+/// fixed-width that order is identical to the byte order, so no serialization is
+/// needed inside the contract. Off-chain systems reproduce the order by sorting
+/// on the `ScAddress` XDR bytes.
+pub fn compare_addresses(a: &Address, b: &Address) -> Ordering {
+    a.cmp(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, Vec};
+
+    #[contract]
+    struct OrderingProbe;
+
+    #[contractimpl]
+    impl OrderingProbe {}
+
+    #[test]
+    fn test_compare_addresses_matches_xdr_byte_order() {
+        let env = Env::default();
+        env.budget().reset_unlimited();
+
+        let mut addresses = Vec::new(&env);
+        for _ in 0..20 {
+            addresses.push_back(Address::generate(&env));
+        }
+        // Mix in a contract address so both ScAddress variants are covered
+        addresses.push_back(env.register_contract(None, OrderingProbe));
+
+        for i in 0..addresses.len() {
+            for j in 0..addresses.len() {
+                let a = addresses.get_unchecked(i);
+                let b = addresses.get_unchecked(j);
+                assert_eq!(
+                    compare_addresses(&a, &b),
+                    address_to_bytes(&env, &a).cmp(&address_to_bytes(&env, &b))
+                );
+            }
+        }
+    }
+}
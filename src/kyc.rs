@@ -0,0 +1,218 @@
+//! KYC tier registry.
+//!
+//! A `ComplianceOfficer` assigns each verified sender a KYC tier and an
+//! expiry. Each tier can cap the size of a single remittance and the
+//! sender's volume over the rolling 24-hour window, across all corridors.
+//!
+//! KYC is enforced in `create_remittance` once it is made required with
+//! `set_kyc_required`. From then on, a sender needs a KYC record that has
+//! not expired, and every remittance must fit within the limits of the
+//! sender's tier. Tiers without configured limits are not capped.
+//!
+//! Addresses with a record are enumerable through the registry
+//! (`RegistryKind::Kyc`) and configured tiers through `get_tiers`, so both
+//! are carried by batched migration exports.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::ttl::{self, TtlClass};
+use crate::{ContractError, RegistryKind};
+
+/// A sender's KYC verification.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycRecord {
+    /// KYC level; each tier's limits are set with `set_kyc_tier_limits`
+    pub tier: u32,
+    /// Timestamp after which the verification is no longer valid
+    pub expires_at: u64,
+    /// Compliance officer or admin that assigned the tier
+    pub verified_by: Address,
+    pub verified_at: u64,
+}
+
+/// Amount limits of a KYC tier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycTierLimits {
+    /// Maximum amount of a single remittance (0 = no maximum)
+    pub max_transfer_amount: i128,
+    /// Maximum volume over the rolling 24-hour window (0 = no maximum)
+    pub max_daily_volume: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum KycKey {
+    /// Whether senders need valid KYC to create remittances (instance storage)
+    Required,
+    /// KYC record of an address (persistent storage)
+    Record(Address),
+    /// Limits of a tier (persistent storage)
+    TierLimits(u32),
+    /// Tiers with configured limits, in the order they were set (instance storage)
+    Tiers,
+}
+
+/// Returns whether senders need valid KYC to create remittances.
+pub fn is_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&KycKey::Required)
+        .unwrap_or(false)
+}
+
+/// Sets whether senders need valid KYC to create remittances.
+pub fn set_required(env: &Env, required: bool) {
+    env.storage().instance().set(&KycKey::Required, &required);
+}
+
+/// Assigns a KYC tier to an address, replacing its previous record.
+///
+/// # Errors
+/// - KycExpired: `expires_at` is not in the future
+pub fn set_record(
+    env: &Env,
+    account: &Address,
+    tier: u32,
+    expires_at: u64,
+    verified_by: &Address,
+) -> Result<KycRecord, ContractError> {
+    let now = env.ledger().timestamp();
+    if expires_at <= now {
+        return Err(ContractError::KycExpired);
+    }
+
+    let record = KycRecord {
+        tier,
+        expires_at,
+        verified_by: verified_by.clone(),
+        verified_at: now,
+    };
+    restore_record(env, account, &record);
+    Ok(record)
+}
+
+/// Stores a KYC record as is (used by migration import).
+pub fn restore_record(env: &Env, account: &Address, record: &KycRecord) {
+    let key = KycKey::Record(account.clone());
+    env.storage().persistent().set(&key, record);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+    crate::registry::track(env, RegistryKind::Kyc, account);
+}
+
+/// Removes an address's KYC record.
+///
+/// # Errors
+/// - KycRequired: The address has no KYC record
+pub fn revoke_record(env: &Env, account: &Address) -> Result<KycRecord, ContractError> {
+    let record = get_record(env, account).ok_or(ContractError::KycRequired)?;
+    env.storage()
+        .persistent()
+        .remove(&KycKey::Record(account.clone()));
+    Ok(record)
+}
+
+/// Returns an address's KYC record, including expired ones.
+pub fn get_record(env: &Env, account: &Address) -> Option<KycRecord> {
+    env.storage()
+        .persistent()
+        .get(&KycKey::Record(account.clone()))
+}
+
+/// Sets or clears the limits of a tier.
+///
+/// # Errors
+/// - InvalidLimitConfig: A limit is negative
+pub fn set_tier_limits(
+    env: &Env,
+    tier: u32,
+    limits: &Option<KycTierLimits>,
+) -> Result<(), ContractError> {
+    let key = KycKey::TierLimits(tier);
+    let mut tiers = get_tiers(env);
+    match limits {
+        Some(limits) => {
+            if limits.max_transfer_amount < 0 || limits.max_daily_volume < 0 {
+                return Err(ContractError::InvalidLimitConfig);
+            }
+            env.storage().persistent().set(&key, limits);
+            ttl::extend_persistent(env, &key, TtlClass::Active);
+            if !tiers.contains(tier) {
+                tiers.push_back(tier);
+            }
+        }
+        None => {
+            env.storage().persistent().remove(&key);
+            if let Some(index) = tiers.first_index_of(tier) {
+                tiers.remove(index);
+            }
+        }
+    }
+    env.storage().instance().set(&KycKey::Tiers, &tiers);
+    Ok(())
+}
+
+/// Returns every tier with configured limits.
+pub fn get_tiers(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&KycKey::Tiers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the limits of a tier, if configured.
+pub fn get_tier_limits(env: &Env, tier: u32) -> Option<KycTierLimits> {
+    env.storage().persistent().get(&KycKey::TierLimits(tier))
+}
+
+/// Checks whether a sender's tier caps their daily volume, so their transfers
+/// must be recorded for `require_sender_within_tier`.
+pub fn caps_daily_volume(env: &Env, sender: &Address) -> bool {
+    if !is_required(env) {
+        return false;
+    }
+    get_record(env, sender)
+        .and_then(|record| get_tier_limits(env, record.tier))
+        .is_some_and(|limits| limits.max_daily_volume > 0)
+}
+
+/// Checks that a sender may create a remittance of `amount` under their KYC
+/// tier. Does nothing while KYC is not required.
+///
+/// # Errors
+/// - KycRequired: The sender has no KYC record
+/// - KycExpired: The sender's KYC record has expired
+/// - KycTierLimitExceeded: The amount, or the sender's volume over the last
+///   24 hours including it, exceeds the tier's limits
+pub fn require_sender_within_tier(
+    env: &Env,
+    sender: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    if !is_required(env) {
+        return Ok(());
+    }
+
+    let record = get_record(env, sender).ok_or(ContractError::KycRequired)?;
+    if env.ledger().timestamp() >= record.expires_at {
+        return Err(ContractError::KycExpired);
+    }
+
+    let limits = match get_tier_limits(env, record.tier) {
+        Some(limits) => limits,
+        None => return Ok(()),
+    };
+    if limits.max_transfer_amount > 0 && amount > limits.max_transfer_amount {
+        return Err(ContractError::KycTierLimitExceeded);
+    }
+    if limits.max_daily_volume > 0 {
+        let volume = crate::storage::get_daily_total(env, sender)?
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        if volume > limits.max_daily_volume {
+            return Err(ContractError::KycTierLimitExceeded);
+        }
+    }
+    Ok(())
+}
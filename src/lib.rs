@@ -4,35 +4,67 @@
 //! with built-in duplicate settlement protection and expiry mechanisms.
 
 #![no_std]
+// Contract entrypoints take their arguments individually so they map
+// directly onto the contract interface.
+#![allow(clippy::too_many_arguments)]
+mod admin_transfer;
+mod archive;
+mod clearing;
 mod debug;
 mod error_handler;
 mod errors;
 mod events;
+mod fx;
 mod hashing;
+mod kyc;
+mod limits;
+mod merkle;
 mod migration;
+mod multisig;
 mod netting;
+mod pause;
 mod rate_limit;
+mod receipts;
+mod registry;
+mod roles;
+mod sanctions;
 mod storage;
+mod treasury;
+mod ttl;
 mod types;
+mod upgrade;
 mod validation;
 #[cfg(test)]
 mod test;
 
-#[cfg(test)]
-mod test; 
-
-use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
+use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, Map, String, Vec};
 
+pub use admin_transfer::*;
+pub use archive::*;
+pub use clearing::*;
 pub use debug::*;
 pub use error_handler::*;
 pub use errors::ContractError;
 pub use events::*;
+pub use fx::*;
 pub use hashing::*;
+pub use kyc::*;
+pub use limits::*;
+pub use merkle::*;
 pub use migration::*;
+pub use multisig::*;
 pub use netting::*;
+pub use pause::*;
 pub use rate_limit::*;
+pub use receipts::*;
+pub use registry::*;
+pub use roles::*;
+pub use sanctions::*;
 pub use storage::*;
+pub use treasury::*;
+pub use ttl::*;
 pub use types::*;
+pub use upgrade::*;
 pub use validation::*;
 
 /// The main SwiftRemit contract for managing cross-border remittances.
@@ -57,8 +89,12 @@ impl SwiftRemitContract {
     ///
     /// * `env` - The contract execution environment
     /// * `admin` - Address that will have administrative privileges
-    /// * `usdc_token` - Address of the USDC token contract used for transactions
+    /// * `usdc_token` - Address of the USDC token contract used for transactions; it is
+    ///   whitelisted as part of initialization
     /// * `fee_bps` - Platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    /// * `rate_limit_cooldown` - Settlement cooldown per sender in seconds (0 = disabled)
+    /// * `recovery_key` - Key allowed to start an emergency admin recovery, kept
+    ///   separate from the admin keys (see `start_admin_recovery`)
     ///
     /// # Returns
     ///
@@ -69,7 +105,7 @@ impl SwiftRemitContract {
     /// # Examples
     ///
     /// ```ignore
-    /// contract.initialize(env, admin_addr, usdc_addr, 250); // 2.5% fee
+    /// contract.initialize(env, admin_addr, usdc_addr, 250, 0, Some(recovery_addr)); // 2.5% fee
     /// ```
     pub fn initialize(
         env: Env,
@@ -77,18 +113,27 @@ impl SwiftRemitContract {
         usdc_token: Address,
         fee_bps: u32,
         rate_limit_cooldown: u64,
+        recovery_key: Option<Address>,
     ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
         // Centralized validation before business logic
         validate_initialize_request(&env, &admin, &usdc_token, fee_bps)?;
+        if recovery_key.as_ref() == Some(&admin) {
+            return Err(ContractError::InvalidAddress);
+        }
 
-        // Set legacy admin for backward compatibility
-        set_admin(&env, &admin);
-        
-        // Initialize new admin role system
+        // Initialize admin role system
         set_admin_role(&env, &admin, true);
         set_admin_count(&env, 1);
+        if recovery_key.is_some() {
+            admin_transfer::set_recovery_key(&env, &recovery_key);
+            emit_recovery_key_updated(&env, admin.clone(), recovery_key);
+        }
         
+        // The primary token is always accepted; whitelisting needs an admin,
+        // so it cannot be done before initialization.
         set_usdc_token(&env, &usdc_token);
+        set_token_whitelisted(&env, &usdc_token, true);
         set_platform_fee_bps(&env, fee_bps);
         set_remittance_counter(&env, 0);
         set_accumulated_fees(&env, 0);
@@ -97,6 +142,9 @@ impl SwiftRemitContract {
         // Initialize rate limiting with default configuration
         init_rate_limit(&env);
 
+        // Fresh deployments start at the current storage layout
+        upgrade::set_schema_version(&env, STORAGE_SCHEMA_VERSION);
+
         log_initialize(&env, &admin, &usdc_token, fee_bps);
 
         Ok(())
@@ -104,12 +152,13 @@ impl SwiftRemitContract {
 
     /// Registers a new agent authorized to receive remittance payouts.
     ///
-    /// Only the contract admin can register agents. Registered agents can confirm
-    /// payouts for remittances assigned to them.
+    /// Only admins and agent managers can register agents. Registered agents can
+    /// confirm payouts for remittances assigned to them.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
+    /// * `caller` - Admin or `AgentManager` registering the agent
     /// * `agent` - Address to register as an authorized agent
     ///
     /// # Returns
@@ -119,67 +168,61 @@ impl SwiftRemitContract {
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the contract admin.
-    pub fn register_agent(env: Env, agent: Address) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    /// Requires authentication from an admin or an `AgentManager`.
+    pub fn register_agent(env: Env, caller: Address, agent: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
 
-        set_agent_registered(&env, &agent, true);
+        require_role(&env, &caller, Role::AgentManager)?;
 
-        emit_agent_registered(&env, agent.clone(), caller.clone());
+        set_agent_registered(&env, &agent, true);
 
-        
         // Event: Agent registered - Fires when admin adds a new agent to the approved list
         // Used by off-chain systems to track which addresses can confirm payouts
-        emit_agent_registered(&env, agent, caller.clone());
-
+        emit_agent_registered(&env, agent);
 
         Ok(())
     }
 
     /// Removes an agent's authorization to receive remittance payouts.
     ///
-    /// Only the contract admin can remove agents. Removed agents cannot confirm
-    /// new payouts, but existing remittances assigned to them remain valid.
+    /// Only admins and agent managers can remove agents. Removed agents cannot
+    /// confirm new payouts, but existing remittances assigned to them remain valid.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
+    /// * `caller` - Admin or `AgentManager` removing the agent
     /// * `agent` - Address of the agent to remove
     ///
     /// # Returns
     ///
     /// * `Ok(())` - Agent successfully removed
     /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::AgentNotRegistered)` - Address is not a registered agent
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the contract admin.
-    pub fn remove_agent(env: Env, agent: Address) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
-
-        set_agent_registered(&env, &agent, false);
-
-        emit_agent_removed(&env, agent.clone(), caller.clone());
-
-        
-        // Event: Agent removed - Fires when admin removes an agent from the approved list
-        // Used by off-chain systems to revoke payout confirmation privileges
-        emit_agent_removed(&env, agent, caller.clone());
+    /// Requires authentication from an admin or an `AgentManager`.
+    pub fn remove_agent(env: Env, caller: Address, agent: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
 
+        require_role(&env, &caller, Role::AgentManager)?;
 
-        Ok(())
+        apply_admin_action(&env, &caller, AdminAction::RemoveAgent(agent))
     }
 
     /// Updates the platform fee rate.
     ///
-    /// Only the contract admin can update the fee. The new fee applies to all
+    /// Only admins and fee managers can update the fee. The new fee applies to all
     /// remittances created after the update.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
+    /// * `caller` - Admin or `FeeManager` updating the fee
     /// * `fee_bps` - New platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
     ///
     /// # Returns
@@ -187,24 +230,22 @@ impl SwiftRemitContract {
     /// * `Ok(())` - Fee successfully updated
     /// * `Err(ContractError::NotInitialized)` - Contract not initialized
     /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
+    /// * `Err(ContractError::MultisigRequired)` - Fee changes require an approved proposal
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the contract admin.
-    pub fn update_fee(env: Env, fee_bps: u32) -> Result<(), ContractError> {
+    /// Requires authentication from an admin or a `FeeManager`.
+    pub fn update_fee(env: Env, caller: Address, fee_bps: u32) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
+
         // Centralized validation
         validate_update_fee_request(fee_bps)?;
         
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
-
-        let old_fee = get_platform_fee_bps(&env)?;
-        set_platform_fee_bps(&env, fee_bps);
-        emit_fee_updated(&env, caller.clone(), old_fee, fee_bps);
-
-        log_update_fee(&env, fee_bps);
+        require_role(&env, &caller, Role::FeeManager)?;
 
-        Ok(())
+        apply_admin_action(&env, &caller, AdminAction::UpdateFee(fee_bps))
     }
 
     /// Creates a new remittance transaction.
@@ -219,13 +260,31 @@ impl SwiftRemitContract {
     /// * `sender` - Address initiating the remittance
     /// * `agent` - Address of the registered agent who will receive the payout
     /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `currency` - Corridor currency code (e.g., "USD")
+    /// * `country` - Corridor country code (e.g., "US")
     /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
     ///
+    /// The remittance is tagged with its (currency, country) corridor, so
+    /// corridor-level controls apply to it through its lifecycle.
+    ///
     /// # Returns
     ///
     /// * `Ok(remittance_id)` - Unique ID of the created remittance
     /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
     /// * `Err(ContractError::AgentNotRegistered)` - Specified agent is not registered
+    /// * `Err(ContractError::ContractPaused)` - Creation, or the agent, token or corridor, is paused
+    /// * `Err(ContractError::DailySendLimitExceeded)` - The sender's transfers in the corridor over
+    ///   the last 24 hours would exceed its daily limit
+    /// * `Err(ContractError::TransactionLimitExceeded)` - Amount is outside the global or
+    ///   corridor transaction limits
+    /// * `Err(ContractError::AddressSanctioned)` - The sender or agent is sanctioned
+    /// * `Err(ContractError::ScreeningFailed)` - The sanctions screening contract could not be called
+    /// * `Err(ContractError::KycRequired)` / `Err(ContractError::KycExpired)` - KYC is required and
+    ///   the sender has no KYC record, or it has expired
+    /// * `Err(ContractError::KycTierLimitExceeded)` - The amount, or the sender's volume over the
+    ///   last 24 hours, exceeds the limits of the sender's KYC tier
+    /// * `Err(ContractError::VelocityLimitExceeded)` - A sender, corridor or global velocity limit
+    ///   would be exceeded
     /// * `Err(ContractError::Overflow)` - Arithmetic overflow in fee calculation
     /// * `Err(ContractError::NotInitialized)` - Contract not initialized
     ///
@@ -237,46 +296,57 @@ impl SwiftRemitContract {
         sender: Address,
         agent: Address,
         amount: i128,
+        currency: String,
+        country: String,
         expiry: Option<u64>,
     ) -> Result<u64, ContractError> {
-        // Centralized validation before business logic
-        validate_create_remittance_request(&env, &sender, &agent, amount)?;
-        
-        sender.require_auth();
-
-        let fee_bps = get_platform_fee_bps(&env)?;
-        let fee = amount
-            .checked_mul(fee_bps as i128)
-            .ok_or(ContractError::Overflow)?
-            .checked_div(10000)
-            .ok_or(ContractError::Overflow)?;
-
-            let fee_bps = get_platform_fee_bps(&env)?;
-            let fee = amount
-                .checked_mul(fee_bps as i128)
-                .ok_or(ContractError::Overflow)?
-                .checked_div(10000)
-                .ok_or(ContractError::Overflow)?;
-
-            let usdc_token = get_usdc_token(&env)?;
-            let token_client = token::Client::new(&env, &usdc_token);
-            token_client.transfer(&sender, &env.current_contract_address(), &amount);
-
-            let counter = get_remittance_counter(&env)?;
-            let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
-
-            let remittance = Remittance {
-                id: remittance_id,
-                sender: sender.clone(),
-                agent: agent.clone(),
-                amount,
-                fee,
-                status: RemittanceStatus::Pending,
-                expiry,
-            };
+        ttl::bump_instance(&env);
+        let usdc_token = get_usdc_token(&env)?;
+        create_remittance_in_token(&env, sender, agent, amount, usdc_token, currency, country, expiry)
+    }
 
-            set_remittance(&env, remittance_id, &remittance);
-            set_remittance_counter(&env, remittance_id);
+    /// Creates a new remittance denominated in a specific whitelisted token.
+    ///
+    /// Behaves exactly like `create_remittance`, but escrows `token` instead of the
+    /// contract's primary USDC token. The payout, refund and collected fee are all
+    /// paid in the same token.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Address of the registered agent who will receive the payout
+    /// * `amount` - Amount to remit in `token` (must be positive)
+    /// * `token` - Whitelisted token contract the remittance is denominated in
+    /// * `currency` - Corridor currency code (e.g., "USD")
+    /// * `country` - Corridor country code (e.g., "US")
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    /// * Any error `create_remittance` can return
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_remittance_with_token(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        token: Address,
+        currency: String,
+        country: String,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        ttl::bump_instance(&env);
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+        create_remittance_in_token(&env, sender, agent, amount, token, currency, country, expiry)
+    }
 
     /// Confirms a remittance payout to the agent.
     ///
@@ -296,65 +366,44 @@ impl SwiftRemitContract {
     /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
     /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
     /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
+    /// * `Err(ContractError::CircuitBreakerTripped)` - Payouts are halted by the circuit breaker, or the payout would exceed one of its ceilings
+    /// * `Err(ContractError::ContractPaused)` - Payouts, or the remittance's agent, token or corridor, are paused
+    /// * `Err(ContractError::VelocityLimitExceeded)` - The payout would exceed an agent velocity limit
+    /// * `Err(ContractError::AddressSanctioned)` - The sender or agent is sanctioned
     /// * `Err(ContractError::InvalidAddress)` - Agent address validation failed
     /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
+    /// * `Err(ContractError::MigrationInProgress)` - The contract is being migrated
     ///
     /// # Authorization
     ///
     /// Requires authentication from the agent address assigned to the remittance.
     pub fn confirm_payout(env: Env, remittance_id: u64) -> Result<(), ContractError> {
-        // Centralized validation before business logic
-        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
-
-        remittance.agent.require_auth();
-
-        if remittance.status != RemittanceStatus::Pending {
-            return Err(ContractError::InvalidStatus);
-        }
-
-        // Check for duplicate settlement execution
-        if has_settlement_hash(&env, remittance_id) {
-            return Err(ContractError::DuplicateSettlement);
-        }
-
-        // Check if settlement has expired
-        if let Some(expiry_time) = remittance.expiry {
-            let current_time = env.ledger().timestamp();
-            if current_time > expiry_time {
-                return Err(ContractError::SettlementExpired);
-            }
-        }
+        ttl::bump_instance(&env);
 
-        // Check rate limit for sender
-        check_rate_limit(&env, &remittance.sender)?;
+        // Centralized validation before business logic (shared with simulate_settlement)
+        let (mut remittance, payout_amount) = validate_settlement(&env, remittance_id)?;
 
-        // Validate the agent address before transfer
-        validate_address(&remittance.agent)?;
+        remittance.agent.require_auth();
 
-        let payout_amount = remittance
-            .amount
-            .checked_sub(remittance.fee)
-            .ok_or(ContractError::Overflow)?;
+        // Count the payout against velocity limits and circuit breaker
+        // ceilings before any tokens move
+        limits::record_payout(&env, &remittance.agent, payout_amount)?;
+        record_payout_volume(&env, &remittance.agent, &remittance.token, payout_amount)?;
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
+        let token_client = token::Client::new(&env, &remittance.token);
         token_client.transfer(
             &env.current_contract_address(),
             &remittance.agent,
             &payout_amount,
         );
 
-        let current_fees = get_accumulated_fees(&env)?;
-        let new_fees = current_fees
-            .checked_add(remittance.fee)
-            .ok_or(ContractError::Overflow)?;
-        set_accumulated_fees(&env, new_fees);
+        add_accumulated_fees(&env, &remittance.token, remittance.fee)?;
 
-        remittance.status = RemittanceStatus::Settled;
+        remittance.status = RemittanceStatus::Completed;
         set_remittance(&env, remittance_id, &remittance);
 
-        // Mark settlement as executed to prevent duplicates
-        set_settlement_hash(&env, remittance_id);
+        // Mark settlement as executed to prevent duplicates and persist its receipt
+        record_settlement(&env, &remittance, payout_amount, None);
         
         // Update last settlement time for rate limiting
         let current_time = env.ledger().timestamp();
@@ -362,29 +411,64 @@ impl SwiftRemitContract {
 
         // Event: Remittance completed - Fires when agent confirms fiat payout and USDC is released
         // Used by off-chain systems to track successful settlements and update transaction status
-        emit_remittance_completed(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), payout_amount);
+        emit_remittance_completed(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), remittance.token.clone(), payout_amount);
         
         // Event: Settlement completed - Fires with final executed settlement values
         // Used by off-chain systems for reconciliation and audit trails of completed transactions
-        emit_settlement_completed(&env, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), payout_amount);
+        emit_settlement_completed(&env, remittance.sender.clone(), remittance.agent.clone(), remittance.token.clone(), payout_amount);
 
         log_confirm_payout(&env, remittance_id, payout_amount);
 
-        Ok(remittance_id)
+        Ok(())
     }
 
-    pub fn finalize_remittance(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
-        let mut remittance = get_remittance(&env, remittance_id)?;
-
-        if !remittance.status.can_transition_to(&RemittanceStatus::Finalized) {
-            return Err(ContractError::InvalidStateTransition);
+    /// Simulates a remittance payout without executing it.
+    ///
+    /// Runs exactly the checks `confirm_payout` performs (migration mode, pause
+    /// state, remittance status, duplicate settlement, expiry, rate limit) but never transfers tokens
+    /// or writes to storage. Requires no authorization, so wallets and frontends can
+    /// call it before prompting an agent to sign.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to simulate
+    ///
+    /// # Returns
+    ///
+    /// A `SettlementSimulation` with:
+    /// * `would_succeed` - Whether `confirm_payout` would succeed right now
+    /// * `payout_amount` - Amount the agent would receive (amount - fee)
+    /// * `fee` - Platform fee that would be collected
+    /// * `error_message` - The `ContractError` code `confirm_payout` would return, if any
+    pub fn simulate_settlement(env: Env, remittance_id: u64) -> SettlementSimulation {
+        ttl::bump_instance(&env);
+        match validate_settlement(&env, remittance_id) {
+            Ok((remittance, payout_amount)) => SettlementSimulation {
+                would_succeed: true,
+                payout_amount,
+                fee: remittance.fee,
+                error_message: None,
+            },
+            Err(error) => {
+                // Still report the amounts when the remittance exists so callers
+                // can show what the payout would have been.
+                let (payout_amount, fee) = match get_remittance(&env, remittance_id) {
+                    Ok(remittance) => (
+                        remittance.amount.checked_sub(remittance.fee).unwrap_or(0),
+                        remittance.fee,
+                    ),
+                    Err(_) => (0, 0),
+                };
+
+                SettlementSimulation {
+                    would_succeed: false,
+                    payout_amount,
+                    fee,
+                    error_message: Some(error as u32),
+                }
+            }
         }
-
-        remittance.status = RemittanceStatus::Finalized;
-        set_remittance(&env, remittance_id, &remittance);
-
-        Ok(())
     }
 
     /// Cancels a pending remittance and refunds the sender.
@@ -402,90 +486,361 @@ impl SwiftRemitContract {
     /// * `Ok(())` - Remittance successfully cancelled and refunded
     /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
     /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::ContractPaused)` - Cancellations or the remittance's token are paused
+    /// * `Err(ContractError::AddressSanctioned)` - The sender is sanctioned; the funds stay in escrow
     ///
     /// # Authorization
     ///
     /// Requires authentication from the sender address who created the remittance.
     pub fn cancel_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_refundable(&env)?;
+
         // Centralized validation before business logic
         let mut remittance = validate_cancel_remittance_request(&env, remittance_id)?;
 
         remittance.sender.require_auth();
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
+        let token_client = token::Client::new(&env, &remittance.token);
         token_client.transfer(
             &env.current_contract_address(),
             &remittance.sender,
             &remittance.amount,
         );
 
-        remittance.status = RemittanceStatus::Failed;
+        remittance.status = RemittanceStatus::Cancelled;
         set_remittance(&env, remittance_id, &remittance);
 
+        // The refunded amount no longer counts against the sender's limits
+        limits::release_creation(
+            &env,
+            remittance_id,
+            &remittance.sender,
+            remittance.amount,
+            get_remittance_corridor(&env, remittance_id),
+        );
+
         // Event: Remittance cancelled - Fires when sender cancels a pending remittance and receives full refund
         // Used by off-chain systems to track cancellations and update transaction status
-        emit_remittance_cancelled(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), remittance.amount);
+        emit_remittance_cancelled(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), remittance.token.clone(), remittance.amount);
 
         log_cancel_remittance(&env, remittance_id);
 
         Ok(())
     }
 
-    /// Withdraws accumulated platform fees to a specified address.
+    /// Proposes withdrawing `amount` of accumulated platform fees in `token` to `to`.
     ///
-    /// Transfers all accumulated fees to the recipient address and resets the
-    /// fee counter to zero. Only the contract admin can withdraw fees.
+    /// The withdrawal can be executed with `execute_fee_withdrawal` once the
+    /// withdrawal timelock has elapsed, unless another admin vetoes it first.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `env` - The contract execution environment
-    /// * `to` - Address to receive the withdrawn fees
+    /// The withdrawal proposal, including its ID and `executable_at`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `Treasurer`.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidAmount` - Amount is zero or negative
+    /// * `DestinationNotAllowed` - `to` is not an allowed treasury destination
+    /// * `NoFeesToWithdraw` - Accumulated fees in `token` are below `amount`
+    pub fn propose_fee_withdrawal(
+        env: Env,
+        caller: Address,
+        token: Address,
+        amount: i128,
+        to: Address,
+    ) -> Result<FeeWithdrawal, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::Treasurer)?;
+        let withdrawal = treasury::propose_withdrawal(&env, &caller, &token, amount, &to)?;
+
+        emit_fee_withdrawal_proposed(&env, withdrawal.clone());
+
+        Ok(withdrawal)
+    }
+
+    /// Vetoes a pending fee withdrawal proposed by someone else.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `Treasurer` other than the proposer.
+    pub fn veto_fee_withdrawal(env: Env, caller: Address, withdrawal_id: u64) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::Treasurer)?;
+        treasury::veto(&env, &caller, withdrawal_id)?;
+
+        emit_fee_withdrawal_vetoed(&env, withdrawal_id, caller);
+
+        Ok(())
+    }
+
+    /// Executes a pending fee withdrawal once its timelock has elapsed.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `Treasurer` other than the proposer.
+    ///
+    /// # Errors
+    ///
+    /// * `Unauthorized` - Caller proposed the withdrawal
+    /// * `TimelockNotElapsed` - Called before the withdrawal's `executable_at`
+    /// * `ContractPaused` - Withdrawals or the withdrawal's token are paused
+    /// * `DestinationNotAllowed` - Destination was removed from the allow-list
+    /// * `NoFeesToWithdraw` - Accumulated fees fell below the amount
+    pub fn execute_fee_withdrawal(env: Env, caller: Address, withdrawal_id: u64) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::Treasurer)?;
+        pause::require_operation_not_paused(&env, PausableOperation::Withdrawal)?;
+        let pending = treasury::get_withdrawal(&env, withdrawal_id)?;
+        pause::require_not_paused(&env, PauseScope::Token(pending.token))?;
+        let withdrawal = treasury::execute(&env, &caller, withdrawal_id)?;
+
+        // Event: Fees withdrawn - Fires when a timelocked fee withdrawal is executed
+        // Used by off-chain systems to track revenue collection and maintain financial records
+        emit_fees_withdrawn(&env, withdrawal_id, caller, withdrawal.token, withdrawal.to.clone(), withdrawal.amount);
+
+        log_withdraw_fees(&env, &withdrawal.to, withdrawal.amount);
+
+        Ok(())
+    }
+
+    /// Retrieves a fee withdrawal proposal by ID.
+    pub fn get_fee_withdrawal(env: Env, withdrawal_id: u64) -> Result<FeeWithdrawal, ContractError> {
+        ttl::bump_instance(&env);
+        treasury::get_withdrawal(&env, withdrawal_id)
+    }
+
+    /// Sets the delay, in seconds, between proposing and executing a fee withdrawal.
+    ///
+    /// Applies to withdrawals proposed afterwards.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidLimitConfig` - Outside `MIN_WITHDRAWAL_TIMELOCK..=MAX_WITHDRAWAL_TIMELOCK`
+    /// * `MultisigRequired` - Timelock changes require an approved proposal
+    pub fn set_withdrawal_timelock(env: Env, caller: Address, seconds: u64) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        apply_admin_action(&env, &caller, AdminAction::SetWithdrawalTimelock(seconds))
+    }
+
+    /// Returns the fee withdrawal timelock in seconds.
+    pub fn get_withdrawal_timelock(env: Env) -> u64 {
+        ttl::bump_instance(&env);
+        treasury::get_timelock(&env)
+    }
+
+    /// Adds an address to the treasury allow-list of fee withdrawal destinations.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `MultisigRequired` - Allow-list additions require an approved proposal
+    pub fn add_treasury_destination(env: Env, caller: Address, destination: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        apply_admin_action(&env, &caller, AdminAction::AddTreasuryDestination(destination))
+    }
+
+    /// Removes an address from the treasury allow-list.
+    ///
+    /// Pending withdrawals to the address can no longer be executed.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn remove_treasury_destination(env: Env, caller: Address, destination: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+        treasury::set_destination(&env, &destination, false);
+
+        emit_treasury_destination_updated(&env, caller, destination, false);
+
+        Ok(())
+    }
+
+    /// Checks if an address is an allowed treasury destination.
+    pub fn is_treasury_destination(env: Env, destination: Address) -> bool {
+        ttl::bump_instance(&env);
+        treasury::is_destination(&env, &destination)
+    }
+
+    /// Lists allowed treasury destinations among registry positions `[start, start + limit)`.
+    pub fn list_treasury_destinations(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        ttl::bump_instance(&env);
+        registry::list(&env, RegistryKind::Treasury, start, limit)
+    }
+
+    /// Proposes an admin action that requires M-of-N admin approval.
+    ///
+    /// The proposal counts as the proposer's approval and expires after
+    /// `PROPOSAL_LIFETIME_SECONDS`.
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Fees successfully withdrawn
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees available (balance is zero or negative)
-    /// * `Err(ContractError::InvalidAddress)` - Recipient address validation failed
+    /// The proposal ID.
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the contract admin.
-    pub fn withdraw_fees(env: Env, to: Address) -> Result<(), ContractError> {
-        let admin = get_admin(&env)?;
-        admin.require_auth();
+    /// Requires authentication from an admin.
+    pub fn propose_admin_action(env: Env, caller: Address, action: AdminAction) -> Result<u64, ContractError> {
+        ttl::bump_instance(&env);
+        require_admin(&env, &caller)?;
 
-        remittance.status = RemittanceStatus::Failed;
-        set_remittance(&env, remittance_id, &remittance);
+        let proposal = multisig::propose(&env, &caller, action)?;
 
-        log_cancel_remittance(&env, remittance_id);
+        emit_admin_action_proposed(&env, proposal.id, caller, proposal.action.clone(), proposal.expires_at);
+
+        Ok(proposal.id)
+    }
+
+    /// Approves a pending admin proposal.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin that has not approved it yet.
+    pub fn approve_admin_action(env: Env, caller: Address, proposal_id: u64) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        require_admin(&env, &caller)?;
+
+        let proposal = multisig::approve(&env, &caller, proposal_id)?;
+
+        emit_admin_action_approved(&env, proposal_id, caller, proposal.approvals.len());
 
         Ok(())
     }
 
-    pub fn withdraw_fees(env: Env, to: Address) -> Result<(), ContractError> {
-        // Centralized validation before business logic
-        let fees = validate_withdraw_fees_request(&env, &to)?;
-        
-        let caller = get_admin(&env)?;
+    /// Executes an approved admin proposal by replaying its stored action.
+    ///
+    /// Approvals only count while the approving address is still an admin.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `InsufficientApprovals` - Fewer approvals than the approval threshold
+    /// * `ProposalExpired` - The proposal's `expires_at` has passed
+    /// * `InvalidStatus` - The proposal was already executed
+    pub fn execute_admin_action(env: Env, caller: Address, proposal_id: u64) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
         require_admin(&env, &caller)?;
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&env.current_contract_address(), &to, &fees);
+        let action = multisig::take_for_execution(&env, proposal_id)?;
+        apply_admin_action(&env, &caller, action)?;
 
-        set_accumulated_fees(&env, 0);
+        emit_admin_action_executed(&env, proposal_id, caller);
 
-        // Event: Fees withdrawn - Fires when admin withdraws accumulated platform fees
-        // Used by off-chain systems to track revenue collection and maintain financial records
-        emit_fees_withdrawn(&env, caller.clone(), to.clone(), usdc_token.clone(), fees);
+        Ok(())
+    }
+
+    /// Retrieves an admin proposal by ID.
+    pub fn get_admin_proposal(env: Env, proposal_id: u64) -> Result<AdminProposal, ContractError> {
+        ttl::bump_instance(&env);
+        multisig::get_proposal(&env, proposal_id)
+    }
 
-        log_withdraw_fees(&env, &to, fees);
+    /// Returns the number of admin approvals required to execute a proposal.
+    pub fn get_approval_threshold(env: Env) -> u32 {
+        ttl::bump_instance(&env);
+        multisig::get_threshold(&env)
+    }
+
+    /// Grants an operational role to an address.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `RoleAlreadyGranted` - The address already holds the role
+    /// * `MultisigRequired` - Role grants require an approved proposal
+    pub fn grant_role(env: Env, caller: Address, role: Role, account: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        apply_admin_action(&env, &caller, AdminAction::GrantRole(role, account))
+    }
+
+    /// Revokes an operational role from an address.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `RoleNotGranted` - The address does not hold the role
+    pub fn revoke_role(env: Env, caller: Address, role: Role, account: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        require_admin(&env, &caller)?;
+
+        if !roles::has_role(&env, role, &account) {
+            return Err(ContractError::RoleNotGranted);
+        }
+        roles::set_role(&env, role, &account, false);
+
+        emit_role_revoked(&env, role, account, caller);
 
         Ok(())
     }
 
+    /// Checks whether an address was granted a role.
+    ///
+    /// Admins implicitly hold every role but are only reported here when the
+    /// role was granted to them explicitly.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        ttl::bump_instance(&env);
+        roles::has_role(&env, role, &account)
+    }
+
+    /// Lists holders of a role among registry positions `[start, start + limit)`.
+    ///
+    /// Advance `start` by `limit` until it reaches `get_role_registry_len`.
+    pub fn list_role_holders(env: Env, role: Role, start: u32, limit: u32) -> Vec<Address> {
+        ttl::bump_instance(&env);
+        registry::list(&env, RegistryKind::Role(role), start, limit)
+    }
+
+    /// Number of positions in a role's holder registry.
+    pub fn get_role_registry_len(env: Env, role: Role) -> u32 {
+        ttl::bump_instance(&env);
+        registry::len(&env, RegistryKind::Role(role))
+    }
+
     /// Retrieves a remittance record by ID.
     ///
     /// # Arguments
@@ -497,12 +852,17 @@ impl SwiftRemitContract {
     ///
     /// * `Ok(Remittance)` - The remittance record
     /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::MigrationInProgress)` - A batched import is not finalized
     pub fn get_remittance(env: Env, remittance_id: u64) -> Result<Remittance, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_import_complete(&env)?;
         get_remittance(&env, remittance_id)
     }
 
 
     pub fn get_accumulated_fees(env: Env) -> Result<i128, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_import_complete(&env)?;
         get_accumulated_fees(&env)
     }
 
@@ -518,6 +878,7 @@ impl SwiftRemitContract {
     /// * `true` - Address is a registered agent
     /// * `false` - Address is not registered
     pub fn is_agent_registered(env: Env, agent: Address) -> bool {
+        ttl::bump_instance(&env);
         is_agent_registered(&env, &agent)
     }
 
@@ -531,61 +892,323 @@ impl SwiftRemitContract {
     ///
     /// * `Ok(u32)` - Platform fee in basis points (1 bps = 0.01%)
     /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::MigrationInProgress)` - A batched import is not finalized
     pub fn get_platform_fee_bps(env: Env) -> Result<u32, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_import_complete(&env)?;
         get_platform_fee_bps(&env)
     }
 
-    pub fn pause(env: Env) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    /// Pauses every payout path (`confirm_payout`, `queue_for_clearing`, batch
+    /// settlement and clearing cycle closes).
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - Admin or `Pauser` pausing the contract
+    /// * `reason` - Operator-defined reason code recorded with the pause
+    ///   (`PAUSE_REASON_UNSPECIFIED` = 0)
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `Pauser`.
+    pub fn pause(env: Env, caller: Address, reason: u32) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
 
-        set_paused(&env, true);
-        
-        // Event: Paused - Fires when admin pauses the contract to prevent new payouts
-        // Used by off-chain systems to halt operations during emergencies or maintenance
-        emit_paused(&env, caller);
+        require_role(&env, &caller, Role::Pauser)?;
 
-        Ok(())
+        apply_admin_action(&env, &caller, AdminAction::Pause(reason))
     }
 
-    pub fn unpause(env: Env) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    pub fn unpause(env: Env, caller: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
 
-        set_paused(&env, false);
-        
-        // Event: Unpaused - Fires when admin resumes contract operations after pause
-        // Used by off-chain systems to resume normal payout processing
-        emit_unpaused(&env, caller);
+        require_role(&env, &caller, Role::Pauser)?;
 
-        Ok(())
+        apply_admin_action(&env, &caller, AdminAction::Unpause)
     }
 
     pub fn is_paused(env: Env) -> bool {
+        ttl::bump_instance(&env);
         crate::storage::is_paused(&env)
     }
+
+    /// Pauses one operation, agent, token or corridor without pausing the
+    /// whole contract. Pausing a scope that is already paused replaces its
+    /// reason and pauser.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - Admin or `Pauser` pausing the scope
+    /// * `scope` - Operation, agent, token or (currency, country) corridor to pause
+    /// * `reason` - Operator-defined reason code recorded with the pause
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `Pauser`. Unlike `pause`,
+    /// scoped pauses do not require M-of-N approval, so one incident can be
+    /// contained without a quorum; lifting them does (see `unpause_scope`).
+    pub fn pause_scope(env: Env, caller: Address, scope: PauseScope, reason: u32) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::Pauser)?;
+
+        let record = PauseRecord {
+            reason,
+            paused_by: caller,
+            paused_at: env.ledger().timestamp(),
+        };
+        pause::pause_scope(&env, &scope, &record);
+
+        emit_scope_paused(&env, scope, record);
+
+        Ok(())
+    }
+
+    /// Lifts a scoped pause.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `Pauser`. Above an approval
+    /// threshold of 1 it must go through an `UnpauseScope` proposal, so a
+    /// single key cannot reopen a scope paused during an incident.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidStatus` - The scope is not paused
+    /// * `MultisigRequired` - The approval threshold is above 1
+    pub fn unpause_scope(env: Env, caller: Address, scope: PauseScope) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
+
+        require_role(&env, &caller, Role::Pauser)?;
+
+        apply_admin_action(&env, &caller, AdminAction::UnpauseScope(scope))
+    }
+
+    /// Checks whether an operation, agent, token or corridor is paused.
+    /// Does not consider the global pause (see `is_paused`).
+    pub fn is_scope_paused(env: Env, scope: PauseScope) -> bool {
+        ttl::bump_instance(&env);
+        pause::get_scope_record(&env, &scope).is_some()
+    }
+
+    /// Returns the global pause and every active scoped pause, with the
+    /// reason code, pauser and time of each.
+    pub fn get_pause_state(env: Env) -> PauseState {
+        ttl::bump_instance(&env);
+        pause::get_state(&env)
+    }
     
-    pub fn update_rate_limit(env: Env, cooldown_seconds: u64) -> Result<(), ContractError> {
-        let admin = get_admin(&env)?;
-        admin.require_auth();
+    /// Sets the per-sender settlement cooldown in seconds.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn update_rate_limit_cooldown(env: Env, caller: Address, cooldown_seconds: u64) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
 
         let old_cooldown = get_rate_limit_cooldown(&env)?;
         set_rate_limit_cooldown(&env, cooldown_seconds);
         
-        emit_rate_limit_updated(&env, admin, old_cooldown, cooldown_seconds);
+        emit_rate_limit_updated(&env, caller, old_cooldown, cooldown_seconds);
 
         Ok(())
     }
     
     pub fn get_rate_limit_cooldown(env: Env) -> Result<u64, ContractError> {
+        ttl::bump_instance(&env);
         get_rate_limit_cooldown(&env)
     }
     
     pub fn get_last_settlement_time(env: Env, sender: Address) -> Option<u64> {
+        ttl::bump_instance(&env);
         get_last_settlement_time(&env, &sender)
+    }
+
+    /// Returns the code version and the storage schema versions.
+    ///
+    /// `storage_schema_version` is below `code_schema_version` after an upgrade
+    /// until `migrate_storage_schema` has rewritten every stored record.
+    pub fn get_version(env: Env) -> ContractVersion {
+        ttl::bump_instance(&env);
+        upgrade::get_version(&env)
+    }
+
+    /// Upgrades the contract WASM in place, keeping its address and state.
+    ///
+    /// The new code takes effect from the next invocation. If it raises the
+    /// storage schema version, stored records are upgraded lazily on read and
+    /// the admin completes the migration with `migrate_storage_schema`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        emit_contract_upgraded(&env, caller, new_wasm_hash.clone(), upgrade::get_schema_version(&env));
+        upgrade::upgrade_wasm(&env, new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Rewrites up to `batch_size` stored remittances in the current storage layout.
+    ///
+    /// Call repeatedly after an upgrade until the returned status is `complete`;
+    /// the stored schema version is then raised to the code's version. A no-op
+    /// when no migration is pending.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `Migrator`.
+    pub fn migrate_storage_schema(
+        env: Env,
+        caller: Address,
+        batch_size: u32,
+    ) -> Result<SchemaMigrationStatus, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::Migrator)?;
+        upgrade::migrate_batch(&env, batch_size)
+    }
+
+    /// Returns the progress of the storage schema migration.
+    pub fn get_schema_migration_status(env: Env) -> Result<SchemaMigrationStatus, ContractError> {
+        ttl::bump_instance(&env);
+        upgrade::get_migration_status(&env)
+    }
+
+    /// Sets the storage TTL policy, in ledgers.
+    ///
+    /// Applies to entries as they are next written or bumped; existing TTLs are
+    /// never shortened.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidLimitConfig` - A threshold exceeds its extension, an extension is
+    ///   zero, or an extension exceeds the network's maximum entry TTL
+    pub fn set_ttl_policy(env: Env, caller: Address, policy: TtlPolicy) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+        ttl::set_policy(&env, &policy)?;
+        ttl::bump_instance(&env);
+
+        emit_ttl_policy_updated(&env, caller, policy);
+
+        Ok(())
+    }
+
+    /// Returns the storage TTL policy.
+    pub fn get_ttl_policy(env: Env) -> TtlPolicy {
+        ttl::bump_instance(&env);
+        ttl::get_policy(&env)
+    }
+
+    /// Extends the TTL of the given remittances, their settlement flags and receipts.
+    ///
+    /// Permissionless: anyone who needs a remittance to stay readable (sender,
+    /// agent, auditor) can keep it alive. Unknown IDs are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The number of remittances extended.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidAmount` - More than `MAX_TTL_BUMP_IDS` IDs
+    pub fn bump_remittance_ttl(env: Env, ids: Vec<u64>) -> Result<u32, ContractError> {
+        ttl::bump_instance(&env);
+        ttl::bump_remittances(&env, &ids)
+    }
 
-    pub fn get_version(env: Env) -> soroban_sdk::String {
-        soroban_sdk::String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    /// Archives completed and cancelled remittances closed at least `older_than_days` ago.
+    ///
+    /// Each record is rolled into the summary of the period it closed in and
+    /// deleted; `get_remittance` then fails with `RemittanceArchived`. IDs that
+    /// are unknown, already archived, pending or too recent are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The number of remittances archived.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidAmount` - More than `MAX_ARCHIVE_BATCH_SIZE` IDs
+    pub fn archive_remittances(
+        env: Env,
+        caller: Address,
+        ids: Vec<u64>,
+        older_than_days: u32,
+    ) -> Result<u32, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+        let archived = archive::archive_remittances(&env, &ids, older_than_days)?;
+
+        emit_remittances_archived(&env, caller, archived);
+
+        Ok(archived)
+    }
+
+    /// Returns the archive summary of a period, if any remittance was archived into it.
+    pub fn get_archive_summary(env: Env, period_id: u64) -> Option<ArchivePeriodSummary> {
+        ttl::bump_instance(&env);
+        archive::get_summary(&env, period_id)
+    }
+
+    /// Returns the archived totals of an (agent, token) pair within a period, if any.
+    pub fn get_archive_totals(
+        env: Env,
+        period_id: u64,
+        agent: Address,
+        token: Address,
+    ) -> Option<ArchiveTotals> {
+        ttl::bump_instance(&env);
+        archive::get_totals(&env, period_id, &agent, &token)
+    }
+
+    /// Lists a period's (agent, token) totals among positions
+    /// `[start, start + limit)`, in first-archived order. The summary's
+    /// `pair_count` is the number of positions.
+    pub fn list_archive_totals(env: Env, period_id: u64, start: u32, limit: u32) -> Vec<ArchiveTotals> {
+        ttl::bump_instance(&env);
+        archive::list_totals(&env, period_id, start, limit)
+    }
+
+    /// Returns the archive period a remittance was rolled into, if it was archived.
+    pub fn get_archived_period(env: Env, remittance_id: u64) -> Option<u64> {
+        ttl::bump_instance(&env);
+        archive::get_archived_period(&env, remittance_id)
+    }
+
+    /// Lists the IDs of every period with an archive summary.
+    pub fn list_archive_periods(env: Env) -> Vec<u64> {
+        ttl::bump_instance(&env);
+        archive::list_periods(&env)
     }
 
     /// Batch settle multiple remittances with net settlement optimization.
@@ -608,176 +1231,756 @@ impl SwiftRemitContract {
     /// Result: Single transfer of 10 USDC from A to B, total fees: 3.8
     /// 
     /// # Parameters
+    /// - `caller`: Operator settling the batch
     /// - `entries`: Vector of BatchSettlementEntry containing remittance IDs to settle
     /// 
     /// # Returns
     /// BatchSettlementResult with list of successfully settled remittance IDs
     /// 
     /// # Errors
+    /// - Unauthorized: Caller is neither admin nor settlement operator
     /// - ContractPaused: Contract is in paused state
+    /// - CircuitBreakerTripped: Payouts are halted by the circuit breaker, or the batch would exceed one of its ceilings
+    /// - ContractPaused: Batch settlement, or a remittance's agent, token or corridor, is paused
     /// - InvalidAmount: Batch size exceeds MAX_BATCH_SIZE or is empty
     /// - RemittanceNotFound: One or more remittance IDs don't exist
     /// - InvalidStatus: One or more remittances are not in Pending status
     /// - DuplicateSettlement: Duplicate remittance IDs in batch
     /// - Overflow: Arithmetic overflow in calculations
+    ///
+    /// # Authorization
+    /// Requires authentication from an admin or a `SettlementOperator`.
     pub fn batch_settle_with_netting(
         env: Env,
+        caller: Address,
         entries: Vec<BatchSettlementEntry>,
     ) -> Result<BatchSettlementResult, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::SettlementOperator)?;
         if is_paused(&env) {
             return Err(ContractError::ContractPaused);
         }
+        require_circuit_closed(&env)?;
+        pause::require_operation_not_paused(&env, PausableOperation::BatchSettlement)?;
 
-        // Validate batch size
-        let batch_size = entries.len();
-        if batch_size == 0 {
-            return Err(ContractError::InvalidAmount);
+        let remittances = load_batch_remittances(&env, &entries)?;
+
+        // Compute net settlements
+        let net_transfers = compute_net_settlements(&env, &remittances);
+
+        // Validate net settlement calculations
+        validate_net_settlement(&remittances, &net_transfers)?;
+
+        record_batch_payouts(&env, &remittances)?;
+        execute_net_transfers(&env, &net_transfers, &remittances)?;
+
+        let batch_id = next_batch_id(&env)?;
+        complete_batch(&env, batch_id, &remittances)
+    }
+
+    /// Batch settle remittances denominated in different tokens with cross-currency netting.
+    ///
+    /// Works like `batch_settle_with_netting`, except every remittance is first
+    /// converted into `settlement_token` using the admin-posted FX rate (or the
+    /// FX oracle when no rate is posted), so opposing flows in different tokens
+    /// can offset each other. Each net transfer is paid out of the escrow of the
+    /// remittances flowing in the net direction, in their own tokens, converted
+    /// back at the rate applied while netting; fees are booked in each
+    /// remittance's own token. The rates applied are reported in each
+    /// `NetTransfer` and emitted with the `fx_rate` event.
+    ///
+    /// # Parameters
+    /// - `caller`: Operator settling the batch
+    /// - `entries`: Vector of BatchSettlementEntry containing remittance IDs to settle
+    /// - `settlement_token`: Whitelisted token amounts are netted in
+    ///
+    /// # Returns
+    /// BatchSettlementResult with list of successfully settled remittance IDs
+    ///
+    /// # Errors
+    /// - TokenNotWhitelisted: Settlement token is not whitelisted
+    /// - FxRateNotAvailable: No fresh rate could be resolved for one of the source tokens
+    /// - Unauthorized: Caller is neither admin nor settlement operator
+    /// - Any error `batch_settle_with_netting` can return
+    ///
+    /// # Authorization
+    /// Requires authentication from an admin or a `SettlementOperator`.
+    pub fn batch_settle_with_fx_netting(
+        env: Env,
+        caller: Address,
+        entries: Vec<BatchSettlementEntry>,
+        settlement_token: Address,
+    ) -> Result<BatchSettlementResult, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::SettlementOperator)?;
+        if is_paused(&env) {
+            return Err(ContractError::ContractPaused);
         }
-        if batch_size > MAX_BATCH_SIZE {
-            return Err(ContractError::InvalidAmount);
+        require_circuit_closed(&env)?;
+        pause::require_operation_not_paused(&env, PausableOperation::BatchSettlement)?;
+        if !is_token_whitelisted(&env, &settlement_token) {
+            return Err(ContractError::TokenNotWhitelisted);
         }
 
-        // Load all remittances and validate
-        let mut remittances = Vec::new(&env);
-        let mut seen_ids = Vec::new(&env);
+        let remittances = load_batch_remittances(&env, &entries)?;
 
-        for i in 0..batch_size {
-            let entry = entries.get_unchecked(i);
-            let remittance_id = entry.remittance_id;
+        // Fees are converted along with amounts, so they are checked against the
+        // converted totals inside the netting engine rather than the originals.
+        let net_transfers =
+            compute_cross_currency_net_settlements(&env, &remittances, &settlement_token)?;
 
-            // Check for duplicate IDs in batch
-            for j in 0..seen_ids.len() {
-                if seen_ids.get_unchecked(j) == remittance_id {
-                    return Err(ContractError::DuplicateSettlement);
-                }
+        for i in 0..net_transfers.len() {
+            let transfer = net_transfers.get_unchecked(i);
+            for j in 0..transfer.fx_rates.len() {
+                emit_fx_rate_applied(&env, transfer.fx_rates.get_unchecked(j));
             }
-            seen_ids.push_back(remittance_id);
+        }
 
-            // Load and validate remittance
-            let remittance = get_remittance(&env, remittance_id)?;
+        record_batch_payouts(&env, &remittances)?;
+        execute_net_transfers(&env, &net_transfers, &remittances)?;
 
-            // Verify remittance is pending
-            if remittance.status != RemittanceStatus::Pending {
-                return Err(ContractError::InvalidStatus);
-            }
+        let batch_id = next_batch_id(&env)?;
+        complete_batch(&env, batch_id, &remittances)
+    }
 
-            // Check for duplicate settlement execution
-            if has_settlement_hash(&env, remittance_id) {
-                return Err(ContractError::DuplicateSettlement);
-            }
+    /// Queues a remittance into the currently open clearing cycle.
+    ///
+    /// The agent confirms the payout as with `confirm_payout`, but instead of an
+    /// immediate transfer the remittance is held until the cycle is closed and
+    /// settled with one net transfer per agent. Queued remittances can no longer
+    /// be cancelled or settled individually. The payout counts against the
+    /// agent's velocity limits when it is queued, so closing the cycle cannot
+    /// fail on a limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to queue
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(cycle_id)` - ID of the cycle the remittance was queued into
+    /// * `Err(ContractError::InvalidStatus)` - Current cycle is at capacity
+    /// * Any error `confirm_payout` would return for this remittance
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance.
+    pub fn queue_for_clearing(env: Env, remittance_id: u64) -> Result<u64, ContractError> {
+        ttl::bump_instance(&env);
 
-            // Check expiry
-            if let Some(expiry_time) = remittance.expiry {
-                let current_time = env.ledger().timestamp();
-                if current_time > expiry_time {
-                    return Err(ContractError::SettlementExpired);
-                }
-            }
+        let (remittance, payout_amount) = validate_settlement(&env, remittance_id)?;
 
-            // Validate addresses
-            validate_address(&remittance.agent)?;
+        remittance.agent.require_auth();
 
-            remittances.push_back(remittance);
-        }
+        let cycle_id = clearing::queue_remittance(&env, &remittance)?;
+        limits::record_payout(&env, &remittance.agent, payout_amount)?;
 
-        // Compute net settlements
-        let net_transfers = compute_net_settlements(&env, &remittances);
+        // The agent's confirmation counts as the settlement for rate limiting
+        set_last_settlement_time(&env, &remittance.sender, env.ledger().timestamp());
 
-        // Validate net settlement calculations
-        validate_net_settlement(&remittances, &net_transfers)?;
+        emit_remittance_queued(&env, cycle_id, remittance_id, remittance.agent.clone());
 
-        // Execute net transfers
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
+        Ok(cycle_id)
+    }
 
-        for i in 0..net_transfers.len() {
-            let transfer = net_transfers.get_unchecked(i);
+    /// Closes the current clearing cycle and settles it.
+    ///
+    /// Nets every remittance queued into the cycle per agent, executes one token
+    /// transfer per agent, marks the remittances completed, accumulates the
+    /// cycle's fees and opens the next cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Operator closing the cycle
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ClearingCycle)` - Summary of the settled cycle
+    /// * `Err(ContractError::ContractPaused)` - Contract is paused
+    /// * `Err(ContractError::CircuitBreakerTripped)` - Payouts are halted by the circuit breaker, or the cycle would exceed one of its ceilings
+    /// * `Err(ContractError::ContractPaused)` - Batch settlement is paused
+    /// * `Err(ContractError::InvalidStatus)` - No remittances were queued
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `SettlementOperator`.
+    pub fn close_clearing_cycle(env: Env, caller: Address) -> Result<ClearingCycle, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::SettlementOperator)?;
+        validate_not_paused(&env)?;
+        require_circuit_closed(&env)?;
+        pause::require_operation_not_paused(&env, PausableOperation::BatchSettlement)?;
+
+        let cycle = clearing::close_cycle(&env)?;
+
+        emit_clearing_cycle_closed(
+            &env,
+            cycle.id,
+            caller,
+            cycle.remittance_count,
+            cycle.total_payout,
+            cycle.total_fees,
+        );
 
-            // Determine actual sender and recipient based on net_amount sign
-            let (from, to, amount) = if transfer.net_amount > 0 {
-                // Positive: party_a -> party_b
-                (transfer.party_a.clone(), transfer.party_b.clone(), transfer.net_amount)
-            } else if transfer.net_amount < 0 {
-                // Negative: party_b -> party_a
-                (transfer.party_b.clone(), transfer.party_a.clone(), -transfer.net_amount)
-            } else {
-                // Zero: complete offset, no transfer needed
-                continue;
-            };
+        Ok(cycle)
+    }
 
-            // Calculate payout amount (net amount minus fees)
-            let payout_amount = amount
-                .checked_sub(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
+    /// Returns the currently open clearing cycle.
+    pub fn get_current_clearing_cycle(env: Env) -> Result<ClearingCycle, ContractError> {
+        ttl::bump_instance(&env);
+        let cycle_id = clearing::get_current_cycle_id(&env);
+        clearing::get_cycle(&env, cycle_id)
+    }
 
-            // Execute the net transfer from contract to recipient
-            // Note: The sender's funds are already in the contract from create_remittance
-            token_client.transfer(
-                &env.current_contract_address(),
-                &to,
-                &payout_amount,
-            );
+    /// Returns a clearing cycle summary by ID.
+    pub fn get_clearing_cycle(env: Env, cycle_id: u64) -> Result<ClearingCycle, ContractError> {
+        ttl::bump_instance(&env);
+        clearing::get_cycle(&env, cycle_id)
+    }
 
-            // Accumulate fees
-            let current_fees = get_accumulated_fees(&env)?;
-            let new_fees = current_fees
-                .checked_add(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
-            set_accumulated_fees(&env, new_fees);
+    /// Returns the report of a clearing cycle: totals, queued remittance IDs and
+    /// the net position paid to each agent.
+    pub fn get_clearing_cycle_report(
+        env: Env,
+        cycle_id: u64,
+    ) -> Result<ClearingCycleReport, ContractError> {
+        ttl::bump_instance(&env);
+        clearing::get_cycle_report(&env, cycle_id)
+    }
 
-            // Emit settlement event
-            emit_settlement_completed(&env, from, to, usdc_token.clone(), payout_amount);
-        }
+    /// Returns the clearing cycle a remittance was queued into, if any.
+    pub fn get_remittance_clearing_cycle(env: Env, remittance_id: u64) -> Option<u64> {
+        ttl::bump_instance(&env);
+        clearing::get_queued_cycle(&env, remittance_id)
+    }
 
-        // Mark all remittances as completed and set settlement hashes
-        let mut settled_ids = Vec::new(&env);
+    /// Lists registered agents among registry positions `[start, start + limit)`.
+    ///
+    /// Pages are over registry positions, so removed agents leave gaps; advance
+    /// `start` by `limit` until it reaches `get_agent_registry_len`. `limit` is
+    /// capped at `MAX_REGISTRY_PAGE_SIZE`.
+    pub fn list_agents(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        ttl::bump_instance(&env);
+        registry::list(&env, RegistryKind::Agent, start, limit)
+    }
 
-        for i in 0..remittances.len() {
-            let mut remittance = remittances.get_unchecked(i);
-            remittance.status = RemittanceStatus::Settled;
-            set_remittance(&env, remittance.id, &remittance);
-            set_settlement_hash(&env, remittance.id);
-            settled_ids.push_back(remittance.id);
+    /// Returns the number of registry positions of `list_agents`.
+    pub fn get_agent_registry_len(env: Env) -> u32 {
+        ttl::bump_instance(&env);
+        registry::len(&env, RegistryKind::Agent)
+    }
 
-            // Emit individual remittance completion event
-            let payout_amount = remittance
-                .amount
-                .checked_sub(remittance.fee)
-                .ok_or(ContractError::Overflow)?;
-            emit_remittance_completed(
-                &env,
-                remittance.id,
-                remittance.sender.clone(),
-                remittance.agent.clone(),
-                usdc_token.clone(),
-                payout_amount,
-            );
-        }
+    /// Grants the admin role to an address.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `AdminAlreadyExists` - `new_admin` is already an admin
+    /// * `MultisigRequired` - Adding admins requires an approved proposal
+    pub fn add_admin(env: Env, caller: Address, new_admin: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
+
+        require_admin(&env, &caller)?;
 
-        Ok(BatchSettlementResult { settled_ids })
+        apply_admin_action(&env, &caller, AdminAction::AddAdmin(new_admin))
     }
 
-    /// Add a token to the whitelist. Only admins can call this.
-    pub fn whitelist_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
-        // Centralized validation
-        validate_admin_operation(&env, &caller, &token)?;
+    /// Revokes the admin role from an address. Admins may remove themselves.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `AdminNotFound` - `admin` is not an admin
+    /// * `CannotRemoveLastAdmin` - `admin` is the only admin
+    /// * `InvalidLimitConfig` - Fewer admins would remain than the multisig
+    ///   approval threshold; lower the threshold first
+    /// * `MultisigRequired` - Removing admins requires an approved proposal
+    pub fn remove_admin(env: Env, caller: Address, admin: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
 
-        if is_token_whitelisted(&env, &token) {
-            return Err(ContractError::TokenAlreadyWhitelisted);
-        }
+        require_admin(&env, &caller)?;
 
-        set_token_whitelisted(&env, &token, true);
-        
-        // Event: Token whitelisted - Fires when admin adds a token to the approved list
-        // Used by off-chain systems to track which tokens can be used for remittances
-        emit_token_whitelisted(&env, caller.clone(), token.clone());
-        log_whitelist_token(&env, &token);
+        apply_admin_action(&env, &caller, AdminAction::RemoveAdmin(admin))
+    }
 
-        Ok(())
+    /// Checks whether an address holds the admin role.
+    pub fn is_admin(env: Env, address: Address) -> bool {
+        ttl::bump_instance(&env);
+        crate::storage::is_admin(&env, &address)
     }
 
-    /// Remove a token from the whitelist. Only admins can call this.
+    /// Proposes handing the caller's admin seat to `new_admin`.
+    ///
+    /// The transfer takes effect only when `new_admin` calls
+    /// `accept_admin_transfer` within `ADMIN_TRANSFER_LIFETIME_SECONDS`.
+    /// Proposing again to the same address replaces the pending transfer.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `AdminAlreadyExists` - `new_admin` is already an admin
+    pub fn propose_admin_transfer(env: Env, caller: Address, new_admin: Address) -> Result<AdminTransfer, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        require_admin(&env, &caller)?;
+
+        let transfer = admin_transfer::propose_transfer(&env, &caller, &new_admin)?;
+
+        emit_admin_transfer_proposed(&env, transfer.clone());
+
+        Ok(transfer)
+    }
+
+    /// Accepts a pending admin transfer: `new_admin` becomes an admin and the
+    /// proposer loses the admin role. Roles granted to the proposer move to
+    /// `new_admin`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `new_admin`.
+    ///
+    /// # Errors
+    ///
+    /// * `ProposalNotFound` - No transfer to `new_admin` is pending
+    /// * `ProposalExpired` - The transfer's `expires_at` has passed
+    /// * `AdminNotFound` - The proposer is no longer an admin
+    pub fn accept_admin_transfer(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        new_admin.require_auth();
+
+        let (transfer, moved_roles) = admin_transfer::accept(&env, &new_admin)?;
+
+        for role in moved_roles.iter() {
+            emit_role_revoked(&env, role, transfer.from.clone(), transfer.from.clone());
+            emit_role_granted(&env, role, new_admin.clone(), transfer.from.clone());
+        }
+        emit_admin_transfer_accepted(&env, transfer);
+
+        Ok(())
+    }
+
+    /// Returns the pending admin transfer to an address, if any.
+    pub fn get_admin_transfer(env: Env, new_admin: Address) -> Option<AdminTransfer> {
+        ttl::bump_instance(&env);
+        admin_transfer::get_transfer(&env, &new_admin)
+    }
+
+    /// Sets or clears the recovery key.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidAddress` - The recovery key is an admin
+    /// * `MultisigRequired` - Recovery key changes require an approved proposal
+    pub fn set_recovery_key(env: Env, caller: Address, recovery_key: Option<Address>) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        multisig::require_direct(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        apply_admin_action(&env, &caller, AdminAction::SetRecoveryKey(recovery_key))
+    }
+
+    /// Returns the recovery key, if one is configured.
+    pub fn get_recovery_key(env: Env) -> Option<Address> {
+        ttl::bump_instance(&env);
+        admin_transfer::get_recovery_key(&env)
+    }
+
+    /// Starts an emergency recovery that grants the admin role to `new_admin`
+    /// once `RECOVERY_DELAY_SECONDS` have passed. Any admin can cancel it
+    /// during the delay. Starting again replaces the recovery in progress.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the recovery key.
+    ///
+    /// # Errors
+    ///
+    /// * `Unauthorized` - No recovery key is configured
+    /// * `AdminAlreadyExists` - `new_admin` is already an admin
+    pub fn start_admin_recovery(env: Env, new_admin: Address) -> Result<PendingRecovery, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        admin_transfer::require_recovery_key(&env)?;
+
+        let recovery = admin_transfer::start_recovery(&env, &new_admin)?;
+
+        emit_recovery_started(&env, recovery.clone());
+
+        Ok(recovery)
+    }
+
+    /// Cancels the admin recovery in progress.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn cancel_admin_recovery(env: Env, caller: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        require_admin(&env, &caller)?;
+
+        let recovery = admin_transfer::cancel_recovery(&env)?;
+
+        emit_recovery_cancelled(&env, caller, recovery.new_admin);
+
+        Ok(())
+    }
+
+    /// Completes the admin recovery in progress once its delay has elapsed.
+    ///
+    /// Grants the admin role to the recovery's new admin and resets the
+    /// multisig approval threshold to 1. The reset is deliberate: recovery is
+    /// for when too few admin keys remain to reach the threshold. The delay and
+    /// the admins' right to cancel are what protect an M-of-N configuration.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the recovery key.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidStatus` - No recovery is in progress
+    /// * `TimelockNotElapsed` - Called before the recovery's `executable_at`
+    pub fn complete_admin_recovery(env: Env) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+        admin_transfer::require_recovery_key(&env)?;
+
+        let recovery = admin_transfer::complete_recovery(&env)?;
+
+        emit_approval_threshold_updated(&env, recovery.new_admin.clone(), 1);
+        emit_recovery_completed(&env, recovery.new_admin);
+
+        Ok(())
+    }
+
+    /// Returns the admin recovery in progress, if any.
+    pub fn get_pending_recovery(env: Env) -> Option<PendingRecovery> {
+        ttl::bump_instance(&env);
+        admin_transfer::get_recovery(&env)
+    }
+
+    /// Lists admins among registry positions `[start, start + limit)`.
+    ///
+    /// Paginates like `list_agents`.
+    pub fn list_admins(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        ttl::bump_instance(&env);
+        registry::list(&env, RegistryKind::Admin, start, limit)
+    }
+
+    /// Returns the number of registry positions of `list_admins`.
+    pub fn get_admin_registry_len(env: Env) -> u32 {
+        ttl::bump_instance(&env);
+        registry::len(&env, RegistryKind::Admin)
+    }
+
+    /// Lists whitelisted tokens among registry positions `[start, start + limit)`.
+    ///
+    /// Paginates like `list_agents`.
+    pub fn list_whitelisted_tokens(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        ttl::bump_instance(&env);
+        registry::list(&env, RegistryKind::Token, start, limit)
+    }
+
+    /// Returns the number of registry positions of `list_whitelisted_tokens`.
+    pub fn get_token_registry_len(env: Env) -> u32 {
+        ttl::bump_instance(&env);
+        registry::len(&env, RegistryKind::Token)
+    }
+
+    /// Looks up a settlement receipt by its canonical settlement ID.
+    ///
+    /// The settlement ID can be reproduced off-chain from the remittance fields
+    /// with `compute_settlement_id`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SettlementReceipt)` - The receipt
+    /// * `Err(ContractError::SettlementReceiptNotFound)` - No settlement with that ID
+    pub fn get_settlement_receipt(
+        env: Env,
+        settlement_id: BytesN<32>,
+    ) -> Result<SettlementReceipt, ContractError> {
+        ttl::bump_instance(&env);
+        receipts::get_receipt(&env, &settlement_id)
+    }
+
+    /// Looks up the settlement receipt of a remittance.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SettlementReceipt)` - The receipt
+    /// * `Err(ContractError::SettlementReceiptNotFound)` - Remittance has not been settled
+    pub fn get_remittance_receipt(
+        env: Env,
+        remittance_id: u64,
+    ) -> Result<SettlementReceipt, ContractError> {
+        ttl::bump_instance(&env);
+        receipts::get_receipt_by_remittance(&env, remittance_id)
+    }
+
+    /// Posts an FX rate used to convert `base_token` amounts into `quote_token`.
+    ///
+    /// Admin-posted rates take precedence over the FX oracle. The rate is the
+    /// number of quote token units per base token unit, scaled by `FX_RATE_SCALE`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FxRate)` - The stored rate
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidFxRate)` - Rate is not positive, or base equals quote
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn set_fx_rate(
+        env: Env,
+        caller: Address,
+        base_token: Address,
+        quote_token: Address,
+        rate: i128,
+    ) -> Result<FxRate, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        let fx_rate = fx::set_admin_rate(&env, &base_token, &quote_token, rate)?;
+
+        emit_fx_rate_updated(&env, caller, fx_rate.clone());
+
+        Ok(fx_rate)
+    }
+
+    /// Returns the admin-posted FX rate for a token pair, if any.
+    pub fn get_fx_rate(env: Env, base_token: Address, quote_token: Address) -> Option<FxRate> {
+        ttl::bump_instance(&env);
+        fx::get_admin_rate(&env, &base_token, &quote_token)
+    }
+
+    /// Sets the maximum age, in seconds, of an admin-posted FX rate that
+    /// cross-currency netting will still apply. Older rates are ignored and the
+    /// FX oracle is consulted instead. Defaults to `DEFAULT_MAX_FX_RATE_AGE`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Maximum age updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidFxRate)` - Maximum age is zero
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn set_fx_rate_max_age(
+        env: Env,
+        caller: Address,
+        max_age: u64,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        fx::set_max_rate_age(&env, max_age)?;
+
+        emit_fx_max_age_updated(&env, caller, max_age);
+
+        Ok(())
+    }
+
+    /// Returns the maximum age, in seconds, of an admin-posted FX rate.
+    pub fn get_fx_rate_max_age(env: Env) -> u64 {
+        ttl::bump_instance(&env);
+        fx::get_max_rate_age(&env)
+    }
+
+    /// Sets (or clears, with `None`) the FX oracle contract consulted when no
+    /// admin rate is posted for a token pair.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn set_fx_oracle(
+        env: Env,
+        caller: Address,
+        oracle: Option<Address>,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        fx::set_oracle(&env, &oracle);
+
+        emit_fx_oracle_updated(&env, caller, oracle);
+
+        Ok(())
+    }
+
+    /// Adds an address to the sanctions denylist, replacing its entry if it
+    /// is already listed. Denylisted senders and agents cannot create, settle
+    /// or be refunded remittances.
+    ///
+    /// # Parameters
+    /// - `caller`: Admin address (must be authorized)
+    /// - `account`: Sanctioned address
+    /// - `reason`: Operator-defined reason code, e.g. the sanctions program
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn deny_address(
+        env: Env,
+        caller: Address,
+        account: Address,
+        reason: u32,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        let entry = sanctions::deny(&env, &account, reason, &caller);
+        emit_address_denied(&env, account, entry);
+
+        Ok(())
+    }
+
+    /// Removes an address from the sanctions denylist. Does nothing if the
+    /// address is not listed.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn allow_address(env: Env, caller: Address, account: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        if sanctions::allow(&env, &account).is_some() {
+            emit_address_allowed(&env, account, caller);
+        }
+
+        Ok(())
+    }
+
+    /// Returns an address's denylist entry, if it is denylisted.
+    pub fn get_denylist_entry(env: Env, account: Address) -> Option<DenylistEntry> {
+        ttl::bump_instance(&env);
+        sanctions::get_entry(&env, &account)
+    }
+
+    /// Checks an address against the denylist and the screening contract.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The address is not sanctioned
+    /// * `Err(ContractError::AddressSanctioned)` - The address is denylisted or flagged
+    /// * `Err(ContractError::ScreeningFailed)` - The screening contract could not be called
+    pub fn screen_address(env: Env, account: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        sanctions::require_not_sanctioned(&env, &account)
+    }
+
+    /// Sets (or clears, with `None`) the external screening contract asked
+    /// about every address the denylist does not block. The contract must
+    /// implement `SanctionsScreening`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn set_screening_contract(
+        env: Env,
+        caller: Address,
+        screening: Option<Address>,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        sanctions::set_screening_contract(&env, &screening);
+
+        emit_screening_contract_updated(&env, caller, screening);
+
+        Ok(())
+    }
+
+    /// Returns the external screening contract, if one is configured.
+    pub fn get_screening_contract(env: Env) -> Option<Address> {
+        ttl::bump_instance(&env);
+        sanctions::get_screening_contract(&env)
+    }
+
+    /// Returns the configured FX oracle contract, if any.
+    pub fn get_fx_oracle(env: Env) -> Option<Address> {
+        ttl::bump_instance(&env);
+        fx::get_oracle(&env)
+    }
+
+    /// Returns the platform fees accumulated in `token` and awaiting withdrawal.
+    pub fn get_token_fees(env: Env, token: Address) -> Result<i128, ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_import_complete(&env)?;
+        get_token_fees(&env, &token)
+    }
+
+    /// Add a token to the whitelist. Only admins can call this.
+    pub fn whitelist_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        // Centralized validation
+        validate_admin_operation(&env, &caller, &token)?;
+
+        if is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenAlreadyWhitelisted);
+        }
+
+        set_token_whitelisted(&env, &token, true);
+        
+        // Event: Token whitelisted - Fires when admin adds a token to the approved list
+        // Used by off-chain systems to track which tokens can be used for remittances
+        emit_token_whitelisted(&env, caller.clone(), token.clone());
+        log_whitelist_token(&env, &token);
+
+        Ok(())
+    }
+
+    /// Remove a token from the whitelist. Only admins can call this.
     pub fn remove_whitelisted_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
         // Centralized validation
         validate_admin_operation(&env, &caller, &token)?;
 
@@ -785,75 +1988,560 @@ impl SwiftRemitContract {
             return Err(ContractError::TokenNotWhitelisted);
         }
 
-        set_token_whitelisted(&env, &token, false);
-        
-        // Event: Token removed - Fires when admin removes a token from the approved list
-        // Used by off-chain systems to track which tokens are no longer accepted for remittances
-        emit_token_removed(&env, caller.clone(), token.clone());
-        log_remove_whitelisted_token(&env, &token);
+        set_token_whitelisted(&env, &token, false);
+        
+        // Event: Token removed - Fires when admin removes a token from the approved list
+        // Used by off-chain systems to track which tokens are no longer accepted for remittances
+        emit_token_removed(&env, caller.clone(), token.clone());
+        log_remove_whitelisted_token(&env, &token);
+
+        Ok(())
+    }
+
+    /// Check if a token is whitelisted.
+    pub fn is_token_whitelisted(env: Env, token: Address) -> bool {
+        ttl::bump_instance(&env);
+        is_token_whitelisted(&env, &token)
+    }
+
+    /// Update rate limit configuration. Only admins can call this.
+    /// 
+    /// # Parameters
+    /// - `caller`: Admin address (must be authorized)
+    /// - `max_requests`: Maximum number of requests allowed per window
+    /// - `window_seconds`: Time window in seconds
+    /// - `enabled`: Whether rate limiting is enabled
+    /// 
+    /// # Example
+    /// ```ignore
+    /// // Set rate limit to 50 requests per 30 seconds
+    /// contract.update_rate_limit(&admin, 50, 30, true)?;
+    /// ```
+    pub fn update_rate_limit(
+        env: Env,
+        caller: Address,
+        max_requests: u32,
+        window_seconds: u64,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        let config = RateLimitConfig {
+            max_requests,
+            window_seconds,
+            enabled,
+        };
+
+        set_rate_limit_config(&env, config);
+
+        log_update_rate_limit(&env, max_requests, window_seconds, enabled);
+
+        Ok(())
+    }
+
+    /// Get current rate limit configuration
+    /// 
+    /// # Returns
+    /// Tuple of (max_requests, window_seconds, enabled)
+    pub fn get_rate_limit_config(env: Env) -> (u32, u64, bool) {
+        ttl::bump_instance(&env);
+        let config = get_rate_limit_config(&env);
+        (config.max_requests, config.window_seconds, config.enabled)
+    }
+
+    /// Get rate limit status for a specific address
+    /// 
+    /// # Parameters
+    /// - `address`: Address to check
+    /// 
+    /// # Returns
+    /// Tuple of (current_requests, max_requests, window_seconds)
+    pub fn get_rate_limit_status(env: Env, address: Address) -> (u32, u32, u64) {
+        ttl::bump_instance(&env);
+        get_rate_limit_status(&env, &address)
+    }
+
+    /// Update circuit breaker configuration. Only admins can call this.
+    ///
+    /// While enabled, every settled payout is added to a total and a
+    /// per-agent volume window. When a window's volume exceeds its ceiling
+    /// the breaker trips and halts all payouts until an admin calls
+    /// `reset_circuit_breaker`.
+    ///
+    /// # Parameters
+    /// - `caller`: Admin address (must be authorized)
+    /// - `config`: Volume ceilings (0 = no ceiling), window length and enabled flag
+    ///
+    /// # Errors
+    /// - InvalidLimitConfig: A ceiling is negative or the window is zero
+    pub fn set_circuit_breaker(
+        env: Env,
+        caller: Address,
+        config: CircuitBreakerConfig,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+
+        set_circuit_breaker_config(&env, &config)
+    }
+
+    /// Get current circuit breaker configuration
+    pub fn get_circuit_breaker_config(env: Env) -> CircuitBreakerConfig {
+        ttl::bump_instance(&env);
+        get_circuit_breaker_config(&env)
+    }
+
+    /// Get the circuit breaker trip, if payouts are halted by the breaker
+    ///
+    /// # Returns
+    /// - `Some(CircuitBreakerTrip)`: Which ceiling was exceeded, by how much and when
+    /// - `None`: The breaker is not tripped
+    pub fn get_circuit_breaker_trip(env: Env) -> Option<CircuitBreakerTrip> {
+        ttl::bump_instance(&env);
+        get_circuit_breaker_trip(&env)
+    }
+
+    /// Reset a tripped circuit breaker and resume payouts. Only admins can call this.
+    ///
+    /// Clears the volume window that tripped the breaker, so payouts are
+    /// counted from zero again.
+    ///
+    /// # Errors
+    /// - InvalidStatus: The breaker is not tripped
+    pub fn reset_circuit_breaker(env: Env, caller: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_admin(&env, &caller)?;
+        let trip = rate_limit::reset_circuit_breaker(&env)?;
+
+        emit_circuit_breaker_reset(&env, caller, trip);
+
+        Ok(())
+    }
+}
+
+/// Performs an admin action, directly or when replaying an approved proposal.
+fn apply_admin_action(env: &Env, executor: &Address, action: AdminAction) -> Result<(), ContractError> {
+    match action {
+        AdminAction::UpdateFee(fee_bps) => {
+            migration::require_writable(env)?;
+            validate_update_fee_request(fee_bps)?;
+            set_platform_fee_bps(env, fee_bps);
+            emit_fee_updated(env, fee_bps);
+            log_update_fee(env, fee_bps);
+        }
+        AdminAction::Pause(reason) => {
+            migration::require_writable(env)?;
+            pause::set_global(
+                env,
+                Some(PauseRecord {
+                    reason,
+                    paused_by: executor.clone(),
+                    paused_at: env.ledger().timestamp(),
+                }),
+            );
+            // Event: Paused - Fires when admin pauses the contract to prevent new payouts
+            // Used by off-chain systems to halt operations during emergencies or maintenance
+            emit_paused(env, executor.clone(), reason);
+        }
+        AdminAction::Unpause => {
+            migration::require_writable(env)?;
+            pause::set_global(env, None);
+            // Event: Unpaused - Fires when admin resumes contract operations after pause
+            // Used by off-chain systems to resume normal payout processing
+            emit_unpaused(env, executor.clone());
+        }
+        AdminAction::UnpauseScope(scope) => {
+            migration::require_writable(env)?;
+            pause::unpause_scope(env, &scope)?;
+            emit_scope_unpaused(env, scope, executor.clone());
+        }
+        AdminAction::RemoveAgent(agent) => {
+            migration::require_writable(env)?;
+            if !is_agent_registered(env, &agent) {
+                return Err(ContractError::AgentNotRegistered);
+            }
+            set_agent_registered(env, &agent, false);
+            // Event: Agent removed - Fires when admin removes an agent from the approved list
+            // Used by off-chain systems to revoke payout confirmation privileges
+            emit_agent_removed(env, agent);
+        }
+        AdminAction::StartMigration => {
+            migration::start_migration(env)?;
+            emit_migration_started(env, executor.clone());
+        }
+        AdminAction::CompleteMigration(successor) => {
+            migration::complete_migration(env, &successor)?;
+            emit_migration_completed(env, executor.clone(), successor);
+        }
+        AdminAction::SetApprovalThreshold(threshold) => {
+            migration::require_writable(env)?;
+            multisig::set_threshold(env, threshold)?;
+            emit_approval_threshold_updated(env, executor.clone(), threshold);
+        }
+        AdminAction::SetWithdrawalTimelock(seconds) => {
+            migration::require_writable(env)?;
+            treasury::set_timelock(env, seconds)?;
+            emit_withdrawal_timelock_updated(env, executor.clone(), seconds);
+        }
+        AdminAction::AddTreasuryDestination(destination) => {
+            migration::require_writable(env)?;
+            treasury::set_destination(env, &destination, true);
+            emit_treasury_destination_updated(env, executor.clone(), destination, true);
+        }
+        AdminAction::AddAdmin(new_admin) => {
+            migration::require_writable(env)?;
+            if is_admin(env, &new_admin) {
+                return Err(ContractError::AdminAlreadyExists);
+            }
+            set_admin_role(env, &new_admin, true);
+            set_admin_count(env, get_admin_count(env) + 1);
+
+            log_add_admin(env, executor, &new_admin);
+            emit_admin_added(env, executor.clone(), new_admin);
+        }
+        AdminAction::RemoveAdmin(admin) => {
+            migration::require_writable(env)?;
+            if !is_admin(env, &admin) {
+                return Err(ContractError::AdminNotFound);
+            }
+            let admin_count = get_admin_count(env);
+            if admin_count <= 1 {
+                return Err(ContractError::CannotRemoveLastAdmin);
+            }
+            if multisig::get_threshold(env) > admin_count - 1 {
+                return Err(ContractError::InvalidLimitConfig);
+            }
+            set_admin_role(env, &admin, false);
+            set_admin_count(env, admin_count - 1);
+
+            log_remove_admin(env, executor, &admin);
+            emit_admin_removed(env, executor.clone(), admin);
+        }
+        AdminAction::GrantRole(role, account) => {
+            migration::require_writable(env)?;
+            if roles::has_role(env, role, &account) {
+                return Err(ContractError::RoleAlreadyGranted);
+            }
+            roles::set_role(env, role, &account, true);
+            emit_role_granted(env, role, account, executor.clone());
+        }
+        AdminAction::SetRecoveryKey(recovery_key) => {
+            migration::require_writable(env)?;
+            if let Some(key) = &recovery_key {
+                if is_admin(env, key) {
+                    return Err(ContractError::InvalidAddress);
+                }
+            }
+            admin_transfer::set_recovery_key(env, &recovery_key);
+            emit_recovery_key_updated(env, executor.clone(), recovery_key);
+        }
+    }
+    Ok(())
+}
+
+/// Shared body of `create_remittance` and `create_remittance_with_token`.
+#[allow(clippy::too_many_arguments)]
+fn create_remittance_in_token(
+    env: &Env,
+    sender: Address,
+    agent: Address,
+    amount: i128,
+    token: Address,
+    currency: String,
+    country: String,
+    expiry: Option<u64>,
+) -> Result<u64, ContractError> {
+    migration::require_writable(env)?;
+
+    // Centralized validation before business logic
+    validate_create_remittance_request(env, &sender, &agent, amount)?;
+    pause::require_operation_not_paused(env, PausableOperation::Creation)?;
+    pause::require_remittance_not_paused(
+        env,
+        &agent,
+        &token,
+        Some((currency.clone(), country.clone())),
+    )?;
+    sanctions::require_parties_not_sanctioned(env, &sender, &agent)?;
+    kyc::require_sender_within_tier(env, &sender, amount)?;
+    limits::check_creation(env, &sender, &currency, &country, amount)?;
+
+    sender.require_auth();
+
+    let fee_bps = get_platform_fee_bps(env)?;
+    let fee = amount
+        .checked_mul(fee_bps as i128)
+        .ok_or(ContractError::Overflow)?
+        .checked_div(10000)
+        .ok_or(ContractError::Overflow)?;
+
+    let token_client = token::Client::new(env, &token);
+    token_client.transfer(&sender, &env.current_contract_address(), &amount);
+
+    let counter = get_remittance_counter(env)?;
+    let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+
+    let remittance = Remittance {
+        id: remittance_id,
+        sender: sender.clone(),
+        agent: agent.clone(),
+        amount,
+        fee,
+        token,
+        status: RemittanceStatus::Pending,
+        expiry,
+    };
+
+    set_remittance(env, remittance_id, &remittance);
+    set_remittance_counter(env, remittance_id);
+    set_remittance_corridor(env, remittance_id, &currency, &country);
+    limits::record_creation(env, remittance_id, &sender, &currency, &country, amount);
+
+    // Event: Remittance created - Fires when a sender escrows funds for an agent payout
+    // Used by off-chain systems to start tracking the remittance lifecycle
+    emit_remittance_created(env, remittance_id, sender.clone(), agent.clone(), amount, fee);
+
+    log_create_remittance(env, remittance_id, &sender, &agent, amount, fee);
+
+    Ok(remittance_id)
+}
+
+/// Loads and validates the remittances of a settlement batch.
+fn load_batch_remittances(
+    env: &Env,
+    entries: &Vec<BatchSettlementEntry>,
+) -> Result<Vec<Remittance>, ContractError> {
+    // Validate batch size
+    let batch_size = entries.len();
+    if batch_size == 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if batch_size > MAX_BATCH_SIZE {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    // Load all remittances and validate
+    let mut remittances = Vec::new(env);
+    let mut seen_ids = Vec::new(env);
+
+    for i in 0..batch_size {
+        let entry = entries.get_unchecked(i);
+        let remittance_id = entry.remittance_id;
+
+        // Check for duplicate IDs in batch
+        for j in 0..seen_ids.len() {
+            if seen_ids.get_unchecked(j) == remittance_id {
+                return Err(ContractError::DuplicateSettlement);
+            }
+        }
+        seen_ids.push_back(remittance_id);
+
+        // Load and validate remittance
+        let remittance = get_remittance(env, remittance_id)?;
+
+        // Verify remittance is pending
+        if remittance.status != RemittanceStatus::Pending {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        // Check for duplicate settlement execution
+        if has_settlement_hash(env, remittance_id) {
+            return Err(ContractError::DuplicateSettlement);
+        }
+
+        // Remittances queued into a clearing cycle are settled by that cycle
+        validate_not_queued_for_clearing(env, remittance_id)?;
+
+        // Check expiry
+        if let Some(expiry_time) = remittance.expiry {
+            let current_time = env.ledger().timestamp();
+            if current_time > expiry_time {
+                return Err(ContractError::SettlementExpired);
+            }
+        }
+
+        // Validate addresses
+        validate_address(&remittance.agent)?;
+
+        // Paused agents, tokens and corridors hold back the whole batch
+        pause::require_stored_remittance_not_paused(env, &remittance)?;
+        sanctions::require_parties_not_sanctioned(env, &remittance.sender, &remittance.agent)?;
+
+        remittances.push_back(remittance);
+    }
+
+    Ok(remittances)
+}
+
+/// Pays out net transfers from the escrow of the remittances behind them.
+///
+/// A net transfer is denominated in the token it was netted in, but the funds
+/// backing it are the escrowed payouts (amount - fee) of the remittances
+/// flowing in the net direction. The recipient is paid from those escrows,
+/// one transfer per source token, converting back at the rate applied while
+/// netting, so a batch never pays out a token it did not escrow. Fees stay in
+/// escrow and are booked in each remittance's own token.
+fn execute_net_transfers(
+    env: &Env,
+    net_transfers: &Vec<NetTransfer>,
+    remittances: &Vec<Remittance>,
+) -> Result<(), ContractError> {
+    for i in 0..net_transfers.len() {
+        let transfer = net_transfers.get_unchecked(i);
+
+        // Determine actual sender and recipient based on net_amount sign
+        let (from, to, amount) = if transfer.net_amount > 0 {
+            // Positive: party_a -> party_b
+            (transfer.party_a.clone(), transfer.party_b.clone(), transfer.net_amount)
+        } else if transfer.net_amount < 0 {
+            // Negative: party_b -> party_a
+            (transfer.party_b.clone(), transfer.party_a.clone(), -transfer.net_amount)
+        } else {
+            // Zero: complete offset, no transfer needed
+            continue;
+        };
+
+        // Calculate payout amount (net amount minus fees)
+        let payout_amount = amount
+            .checked_sub(transfer.total_fees)
+            .ok_or(ContractError::Overflow)?;
+        if payout_amount <= 0 {
+            continue;
+        }
+
+        // Escrowed payouts flowing in the net direction, per source token
+        let mut escrow: Map<Address, i128> = Map::new(env);
+        for j in 0..remittances.len() {
+            let remittance = remittances.get_unchecked(j);
+            if remittance.status != RemittanceStatus::Pending
+                || remittance.sender != from
+                || remittance.agent != to
+                || netting_rate(&transfer, &remittance.token).is_none()
+            {
+                continue;
+            }
+            let escrowed = remittance
+                .amount
+                .checked_sub(remittance.fee)
+                .ok_or(ContractError::Overflow)?
+                .checked_add(escrow.get(remittance.token.clone()).unwrap_or(0))
+                .ok_or(ContractError::Overflow)?;
+            escrow.set(remittance.token, escrowed);
+        }
+
+        // Draw the payout from those escrows until it is covered
+        let mut remaining = payout_amount;
+        for (source_token, escrowed) in escrow.iter() {
+            if remaining == 0 {
+                break;
+            }
+            let fx_rate = netting_rate(&transfer, &source_token).unwrap_or(None);
+            let available = match &fx_rate {
+                Some(fx_rate) => convert(escrowed, fx_rate)?,
+                None => escrowed,
+            };
+            let (drawn, source_amount) = if remaining >= available {
+                (available, escrowed)
+            } else {
+                let source_amount = match &fx_rate {
+                    Some(fx_rate) => convert_back(remaining, fx_rate)?,
+                    None => remaining,
+                };
+                (remaining, source_amount)
+            };
+            remaining -= drawn;
 
-        Ok(())
+            if source_amount > 0 {
+                // The sender's funds are already in the contract from create_remittance
+                let token_client = token::Client::new(env, &source_token);
+                token_client.transfer(&env.current_contract_address(), &to, &source_amount);
+                emit_settlement_completed(env, from.clone(), to.clone(), source_token, source_amount);
+            }
+        }
     }
 
-    /// Check if a token is whitelisted.
-    pub fn is_token_whitelisted(env: Env, token: Address) -> bool {
-        is_token_whitelisted(&env, &token)
+    for i in 0..remittances.len() {
+        let remittance = remittances.get_unchecked(i);
+        if remittance.status == RemittanceStatus::Pending {
+            add_accumulated_fees(env, &remittance.token, remittance.fee)?;
+        }
     }
 
-    /// Update rate limit configuration. Only admins can call this.
-    /// 
-    /// # Parameters
-    /// - `caller`: Admin address (must be authorized)
-    /// - `max_requests`: Maximum number of requests allowed per window
-    /// - `window_seconds`: Time window in seconds
-    /// - `enabled`: Whether rate limiting is enabled
-    /// 
-    /// # Example
-    /// ```ignore
-    /// // Set rate limit to 50 requests per 30 seconds
-    /// contract.update_rate_limit(&admin, 50, 30, true)?;
-    /// ```
-    pub fn update_rate_limit(
-        env: Env,
-        caller: Address,
-        max_requests: u32,
-        window_seconds: u64,
-        enabled: bool,
-    ) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
+    Ok(())
+}
 
-        let config = RateLimitConfig {
-            max_requests,
-            window_seconds,
-            enabled,
-        };
+/// Rate that converted `token` into the transfer's token while netting.
+///
+/// Returns `Some(None)` for the transfer's own token, `Some(Some(rate))` for a
+/// converted source token and `None` when `token` did not feed the transfer.
+fn netting_rate(transfer: &NetTransfer, token: &Address) -> Option<Option<FxRate>> {
+    if *token == transfer.token {
+        return Some(None);
+    }
+    transfer
+        .fx_rates
+        .iter()
+        .find(|fx_rate| fx_rate.base_token == *token)
+        .map(Some)
+}
 
-        set_rate_limit_config(&env, config);
+/// Counts every payout of a batch against velocity limits and circuit breaker
+/// ceilings, before any net transfer moves tokens.
+fn record_batch_payouts(env: &Env, remittances: &Vec<Remittance>) -> Result<(), ContractError> {
+    for i in 0..remittances.len() {
+        let remittance = remittances.get_unchecked(i);
+        let payout_amount = remittance
+            .amount
+            .checked_sub(remittance.fee)
+            .ok_or(ContractError::Overflow)?;
+        limits::record_payout(env, &remittance.agent, payout_amount)?;
+        record_payout_volume(env, &remittance.agent, &remittance.token, payout_amount)?;
+    }
+    Ok(())
+}
 
-        log_update_rate_limit(&env, max_requests, window_seconds, enabled);
+/// Marks every remittance of a settled batch as completed.
+fn complete_batch(
+    env: &Env,
+    batch_id: u64,
+    remittances: &Vec<Remittance>,
+) -> Result<BatchSettlementResult, ContractError> {
+    let mut settled_ids = Vec::new(env);
 
-        Ok(())
-    }
+    for i in 0..remittances.len() {
+        let mut remittance = remittances.get_unchecked(i);
+        let payout_amount = remittance
+            .amount
+            .checked_sub(remittance.fee)
+            .ok_or(ContractError::Overflow)?;
 
-    /// Get current rate limit configuration
-    /// 
-    /// # Returns
-    /// Tuple of (max_requests, window_seconds, enabled)
-    pub fn get_rate_limit_config(env: Env) -> (u32, u64, bool) {
-        let config = get_rate_limit_config(&env);
-        (config.max_requests, config.window_seconds, config.enabled)
+        remittance.status = RemittanceStatus::Completed;
+        set_remittance(env, remittance.id, &remittance);
+        record_settlement(env, &remittance, payout_amount, Some(batch_id));
+        settled_ids.push_back(remittance.id);
+
+        // Emit individual remittance completion event
+        emit_remittance_completed(
+            env,
+            remittance.id,
+            remittance.sender.clone(),
+            remittance.agent.clone(),
+            remittance.token.clone(),
+            payout_amount,
+        );
     }
 
-    /// Get rate limit status for a specific address
-    /// 
-    /// # Parameters
-    /// - `address`: Address to check
-    /// 
-    /// # Returns
-    /// Tuple of (current_requests, max_requests, window_seconds)
-    pub fn get_rate_limit_status(env: Env, address: Address) -> (u32, u32, u64) {
-        get_rate_limit_status(&env, &address)
-    }
+    Ok(BatchSettlementResult {
+        batch_id,
+        settled_ids,
+    })
 }
 
 #[contractimpl]
@@ -865,12 +2553,13 @@ impl SwiftRemitContract {
     /// Export complete contract state for migration
     /// 
     /// Creates a cryptographically verified snapshot of all contract data including:
-    /// - Instance storage (admin, token, fees, counters)
+    /// - Instance storage (token, fees, counters, admin count)
     /// - Persistent storage (remittances, agents, admins, settlement hashes)
     /// - Verification hash for integrity checking
     /// 
     /// # Security
-    /// - Only callable by admin
+    /// - Only callable by an admin or a `Migrator`
+    /// - Only callable in migration mode, so no write can race the export
     /// - Generates deterministic SHA-256 hash
     /// - Includes timestamp and ledger sequence for audit trail
     /// - Prevents tampering through cryptographic verification
@@ -878,8 +2567,14 @@ impl SwiftRemitContract {
     /// # Returns
     /// MigrationSnapshot containing complete contract state
     /// 
+    /// # Errors
+    /// - InvalidStatus: `start_migration` has not been called, or remittances
+    ///   have been archived (use `export_migration_batch`)
+    /// - ContractMigrated: Contract already migrated
+    /// 
     /// # Example
     /// ```ignore
+    /// contract.start_migration(&admin)?;
     /// let snapshot = contract.export_migration_state(&admin)?;
     /// // Verify hash before using
     /// let verification = contract.verify_migration_snapshot(&snapshot)?;
@@ -889,7 +2584,9 @@ impl SwiftRemitContract {
         env: Env,
         caller: Address,
     ) -> Result<MigrationSnapshot, ContractError> {
-        require_admin(&env, &caller)?;
+        ttl::bump_instance(&env);
+        require_role(&env, &caller, Role::Migrator)?;
+        migration::require_migrating(&env)?;
         migration::export_state(&env)
     }
 
@@ -902,14 +2599,17 @@ impl SwiftRemitContract {
     /// - Replay protection
     /// 
     /// # Security
-    /// - Only callable by admin
+    /// - Only callable by the import admin set with `configure_import_admin`
     /// - Verifies cryptographic hash before import
+    /// - Rejects snapshots exported on another network
+    /// - Rejects snapshots exported by a contract other than the configured source
+    /// - Each snapshot hash can be imported once
     /// - Prevents import if contract already initialized
     /// - Atomic operation (all or nothing)
     /// - No trust assumptions (cryptographically verified)
     /// 
     /// # Parameters
-    /// - `caller`: Admin address (must be authorized)
+    /// - `caller`: Import admin address (must be authorized)
     /// - `snapshot`: Complete migration snapshot to import
     /// 
     /// # Returns
@@ -918,7 +2618,11 @@ impl SwiftRemitContract {
     /// # Errors
     /// - AlreadyInitialized: Contract already has data
     /// - InvalidMigrationHash: Hash verification failed
-    /// - Unauthorized: Caller is not admin
+    /// - InvalidMigrationHash: Snapshot exported on another network
+    /// - MigrationSourceMismatch: Snapshot exported by a contract other than the configured source
+    /// - MigrationInProgress: Snapshot hash imported before
+    /// - Unauthorized: No import admin configured
+    /// - Unauthorized: Caller is not the import admin
     /// 
     /// # Example
     /// ```ignore
@@ -931,8 +2635,44 @@ impl SwiftRemitContract {
         caller: Address,
         snapshot: MigrationSnapshot,
     ) -> Result<(), ContractError> {
-        caller.require_auth();
-        migration::import_state(&env, snapshot)
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        migration::import_state(&env, &caller, snapshot)
+    }
+
+    /// Sets the only address allowed to import migrated state, and the only
+    /// contract whose exports it can import.
+    ///
+    /// Call right after deploying a destination contract and before publishing
+    /// its address. Can only be called once, on an uninitialized contract;
+    /// neither address can be changed afterwards.
+    ///
+    /// # Errors
+    /// - AlreadyInitialized: Contract initialized or import admin already set
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `import_admin`.
+    pub fn configure_import_admin(
+        env: Env,
+        import_admin: Address,
+        source_contract: Address,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::configure_import_admin(&env, &import_admin, &source_contract)
+    }
+
+    /// Returns the address allowed to import migrated state, if configured.
+    pub fn get_import_admin(env: Env) -> Option<Address> {
+        ttl::bump_instance(&env);
+        migration::get_import_admin(&env)
+    }
+
+    /// Returns the contract whose exports can be imported, if configured.
+    pub fn get_import_source(env: Env) -> Option<Address> {
+        ttl::bump_instance(&env);
+        migration::get_import_source(&env)
     }
 
     /// Verify migration snapshot integrity without importing
@@ -962,27 +2702,63 @@ impl SwiftRemitContract {
         env: Env,
         snapshot: MigrationSnapshot,
     ) -> MigrationVerification {
+        ttl::bump_instance(&env);
         migration::verify_snapshot(&env, &snapshot)
     }
 
-    /// Export state in batches for large datasets
+    /// Verify a single migrated record against a migration Merkle root
     /// 
-    /// For contracts with many remittances, export in batches to avoid
-    /// resource limits. Each batch includes its own hash for verification.
+    /// Remittances are proven against `MigrationSnapshot::merkle_root` and
+    /// exported batches against `MigrationDigest::merkle_root`, without the
+    /// rest of the export. Proofs list sibling hashes from the leaf upwards.
     /// 
     /// # Parameters
-    /// - `caller`: Admin address (must be authorized)
+    /// - `record`: Remittance or batch to verify
+    /// - `proof`: Sibling hashes from the record's leaf to the root
+    /// - `root`: Published Merkle root
+    /// 
+    /// # Returns
+    /// true if the record is included under `root`
+    pub fn verify_migration_proof(
+        env: Env,
+        record: MigrationRecord,
+        proof: Vec<MerkleProofStep>,
+        root: BytesN<32>,
+    ) -> bool {
+        ttl::bump_instance(&env);
+        merkle::verify_proof(&env, &record, &proof, &root)
+    }
+
+    /// Export one batch of a batched migration
+    /// 
+    /// Streams every storage category (configuration, admins, tokens, agents,
+    /// daily limits, remittances with settlement flags, settlement receipts and
+    /// per-user rate limit and transfer state) as a fixed sequence of hashed
+    /// batches. Batches must be exported in order: batch 0 plans the migration
+    /// and starts a new export session, every later batch must be the next one.
+    /// 
+    /// # Parameters
+    /// - `caller`: Admin or `Migrator` address (must be authorized)
     /// - `batch_number`: Which batch to export (0-indexed)
-    /// - `batch_size`: Number of items per batch (max 100)
+    /// - `batch_size`: Number of items per batch (max 100, fixed for the session)
     /// 
     /// # Returns
-    /// MigrationBatch containing subset of data with verification hash
+    /// MigrationBatch containing one category's records with verification hash
+    /// 
+    /// # Errors
+    /// - InvalidAmount: Batch size is zero or exceeds MAX_MIGRATION_BATCH_SIZE
+    /// - InvalidMigrationBatch: Batch out of order or batch size changed
+    /// - InvalidStatus: `start_migration` has not been called
+    /// - ContractMigrated: Contract already migrated
     /// 
     /// # Example
     /// ```ignore
-    /// // Export in batches of 50
-    /// let batch0 = contract.export_migration_batch(&admin, 0, 50)?;
-    /// let batch1 = contract.export_migration_batch(&admin, 1, 50)?;
+    /// contract.start_migration(&admin)?;
+    /// let first = contract.export_migration_batch(&admin, 0, 50)?;
+    /// for n in 1..first.total_batches {
+    ///     batches.push(contract.export_migration_batch(&admin, n, 50)?);
+    /// }
+    /// let digest = contract.get_migration_export_digest()?;
     /// ```
     pub fn export_migration_batch(
         env: Env,
@@ -990,61 +2766,158 @@ impl SwiftRemitContract {
         batch_number: u32,
         batch_size: u32,
     ) -> Result<MigrationBatch, ContractError> {
-        require_admin(&env, &caller)?;
+        ttl::bump_instance(&env);
+        require_role(&env, &caller, Role::Migrator)?;
+        migration::require_migrating(&env)?;
         migration::export_batch(&env, batch_number, batch_size)
     }
 
+    /// Returns the digest (batch count and rolling hash) of a completed export,
+    /// to be passed to `finalize_migration_import` on the destination contract.
+    /// 
+    /// # Errors
+    /// - InvalidMigrationBatch: Not every batch of the session has been exported
+    pub fn get_migration_export_digest(env: Env) -> Result<MigrationDigest, ContractError> {
+        ttl::bump_instance(&env);
+        migration::get_export_digest(&env)
+    }
+
     /// Import state from batch
     /// 
-    /// Import a single batch of remittances with hash verification.
-    /// Batches should be imported in order (0, 1, 2, ...) for consistency.
+    /// Imports one batch with hash verification. The contract tracks an import
+    /// cursor, so batches must arrive in order (0, 1, 2, ...). Batch 0 carries
+    /// the configuration and initializes the contract, authorized by the
+    /// import admin; later batches require the import admin or an admin.
     /// 
     /// # Parameters
-    /// - `caller`: Admin address (must be authorized)
+    /// - `caller`: Import admin, or an admin for batches after 0 (must be authorized)
     /// - `batch`: Batch to import with verification hash
     /// 
     /// # Returns
     /// Ok(()) if import successful
     /// 
     /// # Errors
+    /// - InvalidMigrationBatch: Batch out of order
     /// - InvalidMigrationHash: Batch hash verification failed
-    /// - Unauthorized: Caller is not admin
+    /// - MigrationSourceMismatch: Batch 0 exported by a contract other than the configured source
+    /// - AlreadyInitialized: Batch 0 imported into an initialized contract
+    /// - MigrationInProgress: Import already finalized
+    /// - Unauthorized: Caller is not allowed to import the batch
     /// 
     /// # Example
     /// ```ignore
-    /// let batch = get_batch_from_old_contract(0);
-    /// contract.import_migration_batch(&admin, batch)?;
+    /// for batch in batches {
+    ///     contract.import_migration_batch(&admin, batch)?;
+    /// }
+    /// contract.finalize_migration_import(&admin, digest)?;
     /// ```
     pub fn import_migration_batch(
         env: Env,
         caller: Address,
         batch: MigrationBatch,
     ) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
-        migration::import_batch(&env, batch)
+        ttl::bump_instance(&env);
+        migration::require_active(&env)?;
+
+        migration::import_batch(&env, &caller, batch)
+    }
+
+    /// Finalize a batched import
+    /// 
+    /// Verifies that every batch was imported and that the rolling hash over
+    /// them matches the digest published by the source contract.
+    /// 
+    /// # Errors
+    /// - InvalidMigrationBatch: Batches missing or batch count mismatch
+    /// - InvalidMigrationHash: Rolling hash mismatch
+    /// - MigrationInProgress: Import already finalized
+    /// - Unauthorized: Caller is not the import admin, an admin or a migrator
+    pub fn finalize_migration_import(
+        env: Env,
+        caller: Address,
+        digest: MigrationDigest,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_active(&env)?;
+
+        if migration::get_import_admin(&env).as_ref() == Some(&caller) {
+            caller.require_auth();
+        } else {
+            require_role(&env, &caller, Role::Migrator)?;
+        }
+        migration::finalize_import(&env, &digest)
+    }
+
+    /// Returns the progress of a batched import (next batch, total, rolling hash).
+    pub fn get_migration_import_status(env: Env) -> MigrationImportStatus {
+        ttl::bump_instance(&env);
+        migration::get_import_status(&env)
+    }
+
+    /// Puts the contract into migration mode.
+    ///
+    /// The contract becomes read-only except for `cancel_remittance`, so
+    /// snapshots exported afterwards cannot go stale. Export the state, import
+    /// it into the successor, then call `complete_migration`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `Migrator`.
+    pub fn start_migration(env: Env, caller: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        multisig::require_direct(&env)?;
+        require_role(&env, &caller, Role::Migrator)?;
+
+        apply_admin_action(&env, &caller, AdminAction::StartMigration)
+    }
+
+    /// Marks the contract as migrated to `successor`.
+    ///
+    /// Every mutating entrypoint, including refunds, then fails with
+    /// `ContractMigrated`; `get_migration_mode` returns the successor address.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `Migrator`.
+    pub fn complete_migration(env: Env, caller: Address, successor: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        multisig::require_direct(&env)?;
+        require_role(&env, &caller, Role::Migrator)?;
+
+        apply_admin_action(&env, &caller, AdminAction::CompleteMigration(successor))
+    }
+
+    /// Returns the migration mode, including the successor once migrated.
+    pub fn get_migration_mode(env: Env) -> MigrationMode {
+        ttl::bump_instance(&env);
+        migration::get_mode(&env)
     }
 
     /// Sets the daily send limit for a specific currency-country pair.
     /// 
     /// # Parameters
+    /// - `caller`: Admin or `ComplianceOfficer` address (must be authorized)
     /// - `currency`: Currency code (e.g., "USD", "EUR")
     /// - `country`: Country code (e.g., "US", "UK")
     /// - `limit`: Maximum amount that can be sent in 24 hours
     /// 
     /// # Authorization
-    /// Requires admin authentication
+    /// Requires admin or `ComplianceOfficer` authentication
     /// 
     /// # Errors
     /// - InvalidAmount: If limit is negative
-    /// - Unauthorized: If caller is not admin
+    /// - Unauthorized: If caller is neither admin nor compliance officer
     pub fn set_daily_limit(
         env: Env,
+        caller: Address,
         currency: String,
         country: String,
         limit: i128,
     ) -> Result<(), ContractError> {
-        let admin = get_admin(&env)?;
-        admin.require_auth();
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::ComplianceOfficer)?;
 
         if limit < 0 {
             return Err(ContractError::InvalidAmount);
@@ -1065,6 +2938,249 @@ impl SwiftRemitContract {
     /// - `Some(DailyLimit)`: If a limit is configured
     /// - `None`: If no limit is configured (unlimited)
     pub fn get_daily_limit(env: Env, currency: String, country: String) -> Option<DailyLimit> {
+        ttl::bump_instance(&env);
         get_daily_limit(&env, &currency, &country)
     }
+
+    /// Gets how much a sender can still send in a corridor before reaching
+    /// its daily send limit.
+    ///
+    /// Counts the sender's remittances in the corridor created within the
+    /// last 24 hours, excluding cancelled ones.
+    ///
+    /// # Parameters
+    /// - `sender`: Sender address
+    /// - `currency`: Currency code (e.g., "USD", "EUR")
+    /// - `country`: Country code (e.g., "US", "UK")
+    ///
+    /// # Returns
+    /// - `Some(amount)`: Remaining allowance, zero once the limit is reached
+    /// - `None`: If no limit is configured (unlimited)
+    pub fn get_remaining_daily_allowance(
+        env: Env,
+        sender: Address,
+        currency: String,
+        country: String,
+    ) -> Result<Option<i128>, ContractError> {
+        ttl::bump_instance(&env);
+        let daily_limit = match get_daily_limit(&env, &currency, &country) {
+            Some(daily_limit) => daily_limit,
+            None => return Ok(None),
+        };
+        let total = get_daily_corridor_total(&env, &sender, &currency, &country)?;
+        Ok(Some(daily_limit.limit.saturating_sub(total).max(0)))
+    }
+
+    /// Sets the maximum volume of a scope per UTC day, ISO week or calendar month.
+    ///
+    /// Sender, corridor and global limits apply to created remittances;
+    /// agent limits apply to payouts. Replaces the limit of the same scope
+    /// and window. Volume counts from when the limit is first set.
+    ///
+    /// # Parameters
+    /// - `caller`: Admin or `ComplianceOfficer` address (must be authorized)
+    /// - `limit`: Scope, window and maximum volume
+    ///
+    /// # Errors
+    /// - InvalidLimitConfig: If the maximum volume is negative
+    /// - Unauthorized: If caller is neither admin nor compliance officer
+    pub fn set_velocity_limit(
+        env: Env,
+        caller: Address,
+        limit: VelocityLimit,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::ComplianceOfficer)?;
+
+        limits::set_velocity_limit(&env, &limit)
+    }
+
+    /// Removes the velocity limit of a scope and window, if one is set.
+    ///
+    /// # Parameters
+    /// - `caller`: Admin or `ComplianceOfficer` address (must be authorized)
+    /// - `scope`: Scope of the limit
+    /// - `window`: Window of the limit
+    ///
+    /// # Errors
+    /// - Unauthorized: If caller is neither admin nor compliance officer
+    pub fn remove_velocity_limit(
+        env: Env,
+        caller: Address,
+        scope: LimitScope,
+        window: LimitWindow,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::ComplianceOfficer)?;
+
+        limits::remove_velocity_limit(&env, &scope, window);
+        Ok(())
+    }
+
+    /// Lists every configured velocity limit.
+    pub fn get_velocity_limits(env: Env) -> Vec<VelocityLimit> {
+        ttl::bump_instance(&env);
+        limits::get_velocity_limits(&env)
+    }
+
+    /// Sets or clears the minimum and maximum remittance amount, globally or
+    /// for a corridor. A remittance must satisfy both the global and its
+    /// corridor's limits.
+    ///
+    /// # Parameters
+    /// - `caller`: Admin or `ComplianceOfficer` address (must be authorized)
+    /// - `scope`: `Global` or `Corridor(currency, country)`
+    /// - `limits`: The limits, or None to clear them
+    ///
+    /// # Errors
+    /// - InvalidLimitConfig: If the scope is `Sender` or `Agent`, a bound is
+    ///   negative, or the minimum exceeds the maximum
+    /// - Unauthorized: If caller is neither admin nor compliance officer
+    pub fn set_transaction_limits(
+        env: Env,
+        caller: Address,
+        scope: LimitScope,
+        limits: Option<TransactionLimits>,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::ComplianceOfficer)?;
+
+        limits::set_transaction_limits(&env, &scope, &limits)
+    }
+
+    /// Gets the transaction limits of the global scope or a corridor.
+    pub fn get_transaction_limits(env: Env, scope: LimitScope) -> Option<TransactionLimits> {
+        ttl::bump_instance(&env);
+        limits::get_transaction_limits(&env, &scope)
+    }
+
+    /// Gets how much of a velocity limit a sender, agent, corridor or the
+    /// whole contract has used in the current window.
+    ///
+    /// # Returns
+    /// - `Some(LimitUsage)`: The limit, the volume used and remaining, and when the window resets
+    /// - `None`: If no limit is set for the subject's scope and the window
+    pub fn get_limit_usage(
+        env: Env,
+        subject: LimitSubject,
+        window: LimitWindow,
+    ) -> Option<LimitUsage> {
+        ttl::bump_instance(&env);
+        limits::get_usage(&env, &subject, window)
+    }
+
+    /// Assigns a KYC tier to an address until `expires_at`, replacing its
+    /// previous tier.
+    ///
+    /// # Parameters
+    /// - `caller`: Admin or `ComplianceOfficer` address (must be authorized)
+    /// - `account`: Verified address
+    /// - `tier`: KYC tier
+    /// - `expires_at`: Timestamp the verification expires at
+    ///
+    /// # Errors
+    /// - KycExpired: If `expires_at` is not in the future
+    /// - Unauthorized: If caller is neither admin nor compliance officer
+    pub fn set_kyc(
+        env: Env,
+        caller: Address,
+        account: Address,
+        tier: u32,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::ComplianceOfficer)?;
+
+        let record = kyc::set_record(&env, &account, tier, expires_at, &caller)?;
+        emit_kyc_updated(&env, account, record);
+        Ok(())
+    }
+
+    /// Removes an address's KYC record.
+    ///
+    /// # Parameters
+    /// - `caller`: Admin or `ComplianceOfficer` address (must be authorized)
+    /// - `account`: Address to revoke
+    ///
+    /// # Errors
+    /// - KycRequired: If the address has no KYC record
+    /// - Unauthorized: If caller is neither admin nor compliance officer
+    pub fn revoke_kyc(env: Env, caller: Address, account: Address) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::ComplianceOfficer)?;
+
+        kyc::revoke_record(&env, &account)?;
+        emit_kyc_revoked(&env, account, caller);
+        Ok(())
+    }
+
+    /// Gets an address's KYC record, including an expired one.
+    pub fn get_kyc(env: Env, account: Address) -> Option<KycRecord> {
+        ttl::bump_instance(&env);
+        kyc::get_record(&env, &account)
+    }
+
+    /// Sets or clears the single-transfer and daily volume limits of a KYC tier.
+    ///
+    /// # Parameters
+    /// - `caller`: Admin or `ComplianceOfficer` address (must be authorized)
+    /// - `tier`: KYC tier
+    /// - `limits`: The limits, or None to leave the tier uncapped
+    ///
+    /// # Errors
+    /// - InvalidLimitConfig: If a limit is negative
+    /// - Unauthorized: If caller is neither admin nor compliance officer
+    pub fn set_kyc_tier_limits(
+        env: Env,
+        caller: Address,
+        tier: u32,
+        limits: Option<KycTierLimits>,
+    ) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::ComplianceOfficer)?;
+
+        kyc::set_tier_limits(&env, tier, &limits)
+    }
+
+    /// Gets the limits of a KYC tier.
+    pub fn get_kyc_tier_limits(env: Env, tier: u32) -> Option<KycTierLimits> {
+        ttl::bump_instance(&env);
+        kyc::get_tier_limits(&env, tier)
+    }
+
+    /// Sets whether senders need valid KYC to create remittances.
+    ///
+    /// # Parameters
+    /// - `caller`: Admin or `ComplianceOfficer` address (must be authorized)
+    /// - `required`: Whether KYC is enforced in `create_remittance`
+    ///
+    /// # Errors
+    /// - Unauthorized: If caller is neither admin nor compliance officer
+    pub fn set_kyc_required(env: Env, caller: Address, required: bool) -> Result<(), ContractError> {
+        ttl::bump_instance(&env);
+        migration::require_writable(&env)?;
+
+        require_role(&env, &caller, Role::ComplianceOfficer)?;
+
+        kyc::set_required(&env, required);
+        Ok(())
+    }
+
+    /// Returns whether senders need valid KYC to create remittances.
+    pub fn is_kyc_required(env: Env) -> bool {
+        ttl::bump_instance(&env);
+        kyc::is_required(&env)
+    }
 }
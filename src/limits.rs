@@ -0,0 +1,547 @@
+//! Transaction and velocity limits.
+//!
+//! The limits engine evaluates every amount limit a remittance is subject to:
+//! - Transaction limits: a minimum and maximum amount per remittance, set
+//!   globally or per (currency, country) corridor
+//! - Velocity limits: a maximum volume per calendar day, week or month, set
+//!   for a scope:
+//!   - `Sender`: each sender's created volume
+//!   - `Corridor`: all senders' created volume in a corridor
+//!   - `Global`: all created volume
+//!   - `Agent`: each agent's payout volume
+//! - The rolling 24-hour daily send limit of each sender per corridor
+//!   (`set_daily_limit`)
+//!
+//! Sender, corridor and global limits are checked in `create_remittance`;
+//! agent limits are checked in every payout path (`confirm_payout`, batch
+//! settlement and clearing cycle closes). Velocity windows are fixed UTC
+//! calendar buckets, not sliding windows: volume resets at midnight UTC, on
+//! Monday at midnight UTC, or on the first of the month. A window only counts
+//! volume while a limit is configured for it. Cancelled remittances release
+//! their created volume from windows that have not ended yet.
+//!
+//! Amounts are compared in token base units across all tokens.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::ttl::{self, TtlClass, DAY_IN_LEDGERS};
+use crate::{ContractError, TransferRecord};
+
+const DAY_SECONDS: u64 = 86_400;
+
+/// Calendar bucket a velocity limit counts volume in, in UTC.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LimitWindow {
+    /// Midnight to midnight
+    UtcDay,
+    /// Monday to Monday
+    IsoWeek,
+    /// First of the month to the first of the next month
+    CalendarMonth,
+}
+
+impl LimitWindow {
+    /// Returns the `[start, end)` bucket containing `timestamp`.
+    pub fn bucket(&self, timestamp: u64) -> (u64, u64) {
+        let day = timestamp / DAY_SECONDS;
+        let (first_day, days) = match self {
+            LimitWindow::UtcDay => (day, 1),
+            // 1970-01-01 was a Thursday
+            LimitWindow::IsoWeek => {
+                let next_monday = day + 7 - (day + 3) % 7;
+                let monday = next_monday.saturating_sub(7);
+                (monday, next_monday - monday)
+            }
+            LimitWindow::CalendarMonth => {
+                let (year, month, day_of_month) = civil_date(day);
+                (day - (day_of_month - 1), days_in_month(year, month))
+            }
+        };
+        (first_day * DAY_SECONDS, (first_day + days) * DAY_SECONDS)
+    }
+
+    fn max_days(&self) -> u32 {
+        match self {
+            LimitWindow::UtcDay => 1,
+            LimitWindow::IsoWeek => 7,
+            LimitWindow::CalendarMonth => 31,
+        }
+    }
+
+    fn all() -> [LimitWindow; 3] {
+        [
+            LimitWindow::UtcDay,
+            LimitWindow::IsoWeek,
+            LimitWindow::CalendarMonth,
+        ]
+    }
+}
+
+/// Converts days since 1970-01-01 to a (year, month, day) civil date.
+fn civil_date(days: u64) -> (u64, u64, u64) {
+    // Days since 0000-03-01, so leap days fall at the end of each year
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn days_in_month(year: u64, month: u64) -> u64 {
+    match month {
+        2 if year.is_multiple_of(4)
+            && (!year.is_multiple_of(100) || year.is_multiple_of(400)) =>
+        {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Whose volume a limit applies to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LimitScope {
+    /// Each sender's created volume, across all corridors
+    Sender,
+    /// Each agent's payout volume
+    Agent,
+    /// Created volume in a (currency, country) corridor, across all senders
+    Corridor(String, String),
+    /// All created volume
+    Global,
+}
+
+/// The volume counted against a limit: a sender, an agent, a corridor or
+/// the whole contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LimitSubject {
+    Sender(Address),
+    Agent(Address),
+    Corridor(String, String),
+    Global,
+}
+
+impl LimitSubject {
+    /// Returns the scope whose limits apply to this subject.
+    pub fn scope(&self) -> LimitScope {
+        match self {
+            LimitSubject::Sender(_) => LimitScope::Sender,
+            LimitSubject::Agent(_) => LimitScope::Agent,
+            LimitSubject::Corridor(currency, country) => {
+                LimitScope::Corridor(currency.clone(), country.clone())
+            }
+            LimitSubject::Global => LimitScope::Global,
+        }
+    }
+}
+
+/// A maximum volume per window for a scope.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VelocityLimit {
+    pub scope: LimitScope,
+    pub window: LimitWindow,
+    /// Maximum volume per window
+    pub max_volume: i128,
+}
+
+/// Bounds on the amount of a single remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionLimits {
+    /// Minimum amount (0 = no minimum)
+    pub min_amount: i128,
+    /// Maximum amount (0 = no maximum)
+    pub max_amount: i128,
+}
+
+/// How much of a velocity limit a subject has used in the current window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LimitUsage {
+    /// The configured maximum volume per window
+    pub max_volume: i128,
+    /// Volume counted in the current window
+    pub used: i128,
+    /// Volume still available, zero once the limit is reached
+    pub remaining: i128,
+    pub window_start: u64,
+    /// Timestamp the current window ends and the volume resets at
+    pub window_end: u64,
+}
+
+/// Volume counted in one window
+#[contracttype]
+#[derive(Clone, Debug)]
+struct WindowVolume {
+    window_start: u64,
+    volume: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum LimitsKey {
+    /// Maximum volume of a velocity limit (persistent storage)
+    Velocity(LimitScope, LimitWindow),
+    /// Every configured velocity limit, in the order they were set (instance storage)
+    VelocityLimits,
+    /// Transaction limits of the global or a corridor scope (persistent storage)
+    Transaction(LimitScope),
+    /// Every scope with transaction limits, in the order they were set (instance storage)
+    TransactionScopes,
+    /// Volume of a subject in the current window (temporary storage)
+    Volume(LimitSubject, LimitWindow),
+    /// When a remittance's created volume was counted (temporary storage)
+    CountedAt(u64),
+}
+
+/// Sets a velocity limit, replacing the limit of the same scope and window.
+///
+/// # Errors
+/// - InvalidLimitConfig: The maximum volume is negative
+pub fn set_velocity_limit(env: &Env, limit: &VelocityLimit) -> Result<(), ContractError> {
+    if limit.max_volume < 0 {
+        return Err(ContractError::InvalidLimitConfig);
+    }
+
+    let key = LimitsKey::Velocity(limit.scope.clone(), limit.window);
+    if !env.storage().persistent().has(&key) {
+        let mut configured = configured_limits(env);
+        configured.push_back((limit.scope.clone(), limit.window));
+        env.storage()
+            .instance()
+            .set(&LimitsKey::VelocityLimits, &configured);
+    }
+    env.storage().persistent().set(&key, &limit.max_volume);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+    Ok(())
+}
+
+/// Removes a velocity limit, if one is set. Volume counted in its window is
+/// no longer tracked.
+pub fn remove_velocity_limit(env: &Env, scope: &LimitScope, window: LimitWindow) {
+    let key = LimitsKey::Velocity(scope.clone(), window);
+    if !env.storage().persistent().has(&key) {
+        return;
+    }
+
+    env.storage().persistent().remove(&key);
+    let mut configured = configured_limits(env);
+    if let Some(index) = configured.first_index_of((scope.clone(), window)) {
+        configured.remove(index);
+    }
+    env.storage()
+        .instance()
+        .set(&LimitsKey::VelocityLimits, &configured);
+}
+
+/// Returns the maximum volume of a velocity limit, if one is set.
+pub fn get_velocity_limit(env: &Env, scope: &LimitScope, window: LimitWindow) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&LimitsKey::Velocity(scope.clone(), window))
+}
+
+fn configured_limits(env: &Env) -> Vec<(LimitScope, LimitWindow)> {
+    env.storage()
+        .instance()
+        .get(&LimitsKey::VelocityLimits)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns every configured velocity limit.
+pub fn get_velocity_limits(env: &Env) -> Vec<VelocityLimit> {
+    let mut limits = Vec::new(env);
+    for (scope, window) in configured_limits(env).iter() {
+        if let Some(max_volume) = get_velocity_limit(env, &scope, window) {
+            limits.push_back(VelocityLimit {
+                scope,
+                window,
+                max_volume,
+            });
+        }
+    }
+    limits
+}
+
+/// Sets or clears the transaction limits of the global scope or a corridor.
+///
+/// # Errors
+/// - InvalidLimitConfig: The scope is `Sender` or `Agent`, a bound is
+///   negative, or the minimum exceeds the maximum
+pub fn set_transaction_limits(
+    env: &Env,
+    scope: &LimitScope,
+    limits: &Option<TransactionLimits>,
+) -> Result<(), ContractError> {
+    if matches!(scope, LimitScope::Sender | LimitScope::Agent) {
+        return Err(ContractError::InvalidLimitConfig);
+    }
+
+    let key = LimitsKey::Transaction(scope.clone());
+    let mut scopes = get_transaction_limit_scopes(env);
+    match limits {
+        Some(limits) => {
+            if limits.min_amount < 0
+                || limits.max_amount < 0
+                || (limits.max_amount > 0 && limits.min_amount > limits.max_amount)
+            {
+                return Err(ContractError::InvalidLimitConfig);
+            }
+            env.storage().persistent().set(&key, limits);
+            ttl::extend_persistent(env, &key, TtlClass::Active);
+            if !scopes.contains(scope) {
+                scopes.push_back(scope.clone());
+            }
+        }
+        None => {
+            env.storage().persistent().remove(&key);
+            if let Some(index) = scopes.first_index_of(scope) {
+                scopes.remove(index);
+            }
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&LimitsKey::TransactionScopes, &scopes);
+    Ok(())
+}
+
+/// Returns every scope with transaction limits.
+pub fn get_transaction_limit_scopes(env: &Env) -> Vec<LimitScope> {
+    env.storage()
+        .instance()
+        .get(&LimitsKey::TransactionScopes)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the transaction limits of the global scope or a corridor.
+pub fn get_transaction_limits(env: &Env, scope: &LimitScope) -> Option<TransactionLimits> {
+    env.storage()
+        .persistent()
+        .get(&LimitsKey::Transaction(scope.clone()))
+}
+
+/// Returns a subject's usage of a velocity limit, if the limit is set.
+pub fn get_usage(env: &Env, subject: &LimitSubject, window: LimitWindow) -> Option<LimitUsage> {
+    let max_volume = get_velocity_limit(env, &subject.scope(), window)?;
+    let used = window_volume(env, subject, window);
+    let (window_start, window_end) = window.bucket(env.ledger().timestamp());
+    Some(LimitUsage {
+        max_volume,
+        used,
+        remaining: max_volume.saturating_sub(used).max(0),
+        window_start,
+        window_end,
+    })
+}
+
+/// Checks every limit a new remittance is subject to.
+///
+/// # Errors
+/// - TransactionLimitExceeded: The amount is outside the global or corridor
+///   transaction limits
+/// - DailySendLimitExceeded: The sender's rolling daily corridor limit would be exceeded
+/// - VelocityLimitExceeded: A sender, corridor or global velocity limit would be exceeded
+pub fn check_creation(
+    env: &Env,
+    sender: &Address,
+    currency: &String,
+    country: &String,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let corridor = LimitScope::Corridor(currency.clone(), country.clone());
+    for scope in [LimitScope::Global, corridor] {
+        if let Some(limits) = get_transaction_limits(env, &scope) {
+            if amount < limits.min_amount || (limits.max_amount > 0 && amount > limits.max_amount)
+            {
+                return Err(ContractError::TransactionLimitExceeded);
+            }
+        }
+    }
+
+    crate::validation::validate_daily_send_limit(env, sender, currency, country, amount)?;
+
+    for subject in creation_subjects(sender, currency, country) {
+        check_velocity(env, &subject, amount)?;
+    }
+    Ok(())
+}
+
+/// Counts a new remittance against the sender's daily send limit and every
+/// configured sender, corridor and global velocity limit.
+///
+/// The transfer is only added to the sender's history when a daily limit
+/// reads it: the corridor has a daily send limit, or the sender's KYC tier
+/// caps their daily volume. Like velocity limits, daily limits therefore
+/// count transfers from when the limit is set.
+pub fn record_creation(
+    env: &Env,
+    remittance_id: u64,
+    sender: &Address,
+    currency: &String,
+    country: &String,
+    amount: i128,
+) {
+    if crate::storage::get_daily_limit(env, currency, country).is_some()
+        || crate::kyc::caps_daily_volume(env, sender)
+    {
+        crate::storage::record_user_transfer(
+            env,
+            sender,
+            &TransferRecord {
+                remittance_id,
+                currency: currency.clone(),
+                country: country.clone(),
+                timestamp: env.ledger().timestamp(),
+                amount,
+            },
+        );
+    }
+
+    let mut counted = false;
+    for subject in creation_subjects(sender, currency, country) {
+        counted |= add_volume(env, &subject, amount);
+    }
+    if counted {
+        let key = LimitsKey::CountedAt(remittance_id);
+        env.storage()
+            .temporary()
+            .set(&key, &env.ledger().timestamp());
+        extend_temporary(env, &key, LimitWindow::CalendarMonth);
+    }
+}
+
+/// Releases a cancelled remittance's amount from the sender's daily send
+/// limit and from the velocity windows it was counted in, if they have not
+/// ended.
+pub fn release_creation(
+    env: &Env,
+    remittance_id: u64,
+    sender: &Address,
+    amount: i128,
+    corridor: Option<(String, String)>,
+) {
+    crate::storage::release_user_transfer(env, sender, remittance_id);
+
+    let key = LimitsKey::CountedAt(remittance_id);
+    let counted_at: u64 = match env.storage().temporary().get(&key) {
+        Some(counted_at) => counted_at,
+        None => return,
+    };
+    env.storage().temporary().remove(&key);
+
+    let mut subjects = Vec::new(env);
+    subjects.push_back(LimitSubject::Sender(sender.clone()));
+    subjects.push_back(LimitSubject::Global);
+    if let Some((currency, country)) = corridor {
+        subjects.push_back(LimitSubject::Corridor(currency, country));
+    }
+    for subject in subjects.iter() {
+        for window in LimitWindow::all() {
+            let key = LimitsKey::Volume(subject.clone(), window);
+            if let Some(mut stored) = env.storage().temporary().get::<_, WindowVolume>(&key) {
+                if stored.window_start == window.bucket(counted_at).0 {
+                    stored.volume = stored.volume.saturating_sub(amount).max(0);
+                    env.storage().temporary().set(&key, &stored);
+                }
+            }
+        }
+    }
+}
+
+/// Checks the agent's payout velocity limits without counting the payout.
+///
+/// # Errors
+/// - VelocityLimitExceeded: The payout would exceed an agent velocity limit
+pub fn check_payout(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    check_velocity(env, &LimitSubject::Agent(agent.clone()), amount)
+}
+
+/// Checks the agent's payout velocity limits and counts the payout.
+///
+/// # Errors
+/// - VelocityLimitExceeded: The payout would exceed an agent velocity limit
+pub fn record_payout(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    check_payout(env, agent, amount)?;
+    add_volume(env, &LimitSubject::Agent(agent.clone()), amount);
+    Ok(())
+}
+
+fn creation_subjects(sender: &Address, currency: &String, country: &String) -> [LimitSubject; 3] {
+    [
+        LimitSubject::Sender(sender.clone()),
+        LimitSubject::Corridor(currency.clone(), country.clone()),
+        LimitSubject::Global,
+    ]
+}
+
+fn check_velocity(env: &Env, subject: &LimitSubject, amount: i128) -> Result<(), ContractError> {
+    let scope = subject.scope();
+    for window in LimitWindow::all() {
+        if let Some(max_volume) = get_velocity_limit(env, &scope, window) {
+            let volume = window_volume(env, subject, window)
+                .checked_add(amount)
+                .ok_or(ContractError::Overflow)?;
+            if volume > max_volume {
+                return Err(ContractError::VelocityLimitExceeded);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Adds an amount to the subject's windows that have a limit configured.
+/// Returns whether any window counted it.
+fn add_volume(env: &Env, subject: &LimitSubject, amount: i128) -> bool {
+    let scope = subject.scope();
+    let mut counted = false;
+    for window in LimitWindow::all() {
+        if get_velocity_limit(env, &scope, window).is_none() {
+            continue;
+        }
+        let key = LimitsKey::Volume(subject.clone(), window);
+        let stored = WindowVolume {
+            window_start: current_window_start(env, window),
+            volume: window_volume(env, subject, window).saturating_add(amount),
+        };
+        env.storage().temporary().set(&key, &stored);
+        extend_temporary(env, &key, window);
+        counted = true;
+    }
+    counted
+}
+
+/// Returns the subject's volume in the current window.
+fn window_volume(env: &Env, subject: &LimitSubject, window: LimitWindow) -> i128 {
+    let stored: Option<WindowVolume> = env
+        .storage()
+        .temporary()
+        .get(&LimitsKey::Volume(subject.clone(), window));
+    match stored {
+        Some(stored) if stored.window_start == current_window_start(env, window) => stored.volume,
+        _ => 0,
+    }
+}
+
+fn current_window_start(env: &Env, window: LimitWindow) -> u64 {
+    window.bucket(env.ledger().timestamp()).0
+}
+
+/// Keeps a temporary entry alive for one window plus a day.
+fn extend_temporary(env: &Env, key: &LimitsKey, window: LimitWindow) {
+    let days = window.max_days() + 1;
+    let ledgers = days * DAY_IN_LEDGERS;
+    env.storage().temporary().extend_ttl(key, ledgers, ledgers);
+}
@@ -0,0 +1,221 @@
+//! Merkle trees over migrated records.
+//!
+//! `compute_snapshot_hash` commits to an entire snapshot, so checking a single
+//! remittance against it needs the full export. Migration snapshots and export
+//! digests therefore also publish a Merkle root, letting auditors and the
+//! destination contract verify one remittance or one batch with a logarithmic
+//! proof.
+//!
+//! ## Tree Construction (canonical)
+//!
+//! - Leaf:  SHA-256(0x00 ‖ leaf data)
+//! - Node:  SHA-256(0x01 ‖ left ‖ right)
+//! - A node without a sibling is promoted to the next level unchanged
+//! - The root of an empty tree is 32 zero bytes
+//!
+//! Leaf data is the XDR of the `Remittance` for remittance records, and the
+//! recomputed `batch_hash` for batch records. The distinct leaf and node
+//! prefixes stop an internal node from being passed off as a leaf.
+
+use soroban_sdk::{contracttype, xdr::ToXdr, Bytes, BytesN, Env, Vec};
+
+use crate::{MigrationBatch, Remittance};
+
+/// A record that can be proven against a migration Merkle root.
+///
+/// Contract types cannot box the batch variant, which carries a whole config batch.
+#[contracttype]
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum MigrationRecord {
+    /// A remittance, proven against `MigrationSnapshot::merkle_root`
+    Remittance(Remittance),
+    /// An exported batch, proven against `MigrationDigest::merkle_root`
+    Batch(MigrationBatch),
+}
+
+/// One level of a Merkle proof, from the leaf upwards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleProofStep {
+    /// Hash of the sibling node at this level
+    pub sibling: BytesN<32>,
+    /// Whether the sibling is the left child
+    pub sibling_is_left: bool,
+}
+
+/// Computes the leaf hash of a record.
+pub fn leaf_hash(env: &Env, record: &MigrationRecord) -> BytesN<32> {
+    match record {
+        MigrationRecord::Remittance(remittance) => remittance_leaf(env, remittance),
+        MigrationRecord::Batch(batch) => batch_leaf(
+            env,
+            &crate::migration::compute_batch_hash(
+                env,
+                batch.batch_number,
+                batch.total_batches,
+                &batch.payload,
+            ),
+        ),
+    }
+}
+
+/// Leaf hash of a remittance: SHA-256(0x00 ‖ XDR(remittance)).
+pub fn remittance_leaf(env: &Env, remittance: &Remittance) -> BytesN<32> {
+    hash_leaf(env, &remittance.clone().to_xdr(env))
+}
+
+/// Leaf hash of an exported batch: SHA-256(0x00 ‖ batch_hash).
+pub fn batch_leaf(env: &Env, batch_hash: &BytesN<32>) -> BytesN<32> {
+    hash_leaf(env, &Bytes::from_array(env, &batch_hash.to_array()))
+}
+
+/// Computes the Merkle root of a list of leaf hashes.
+pub fn compute_root(env: &Env, leaves: &Vec<BytesN<32>>) -> BytesN<32> {
+    if leaves.is_empty() {
+        return BytesN::from_array(env, &[0u8; 32]);
+    }
+
+    let mut level = leaves.clone();
+    while level.len() > 1 {
+        level = next_level(env, &level);
+    }
+    level.get_unchecked(0)
+}
+
+/// Builds the proof for the leaf at `index`.
+///
+/// Proofs are normally built off-chain from an export; this is the reference
+/// implementation. Panics if `index` is out of range.
+pub fn build_proof(env: &Env, leaves: &Vec<BytesN<32>>, index: u32) -> Vec<MerkleProofStep> {
+    assert!(index < leaves.len(), "leaf index out of range");
+
+    let mut proof = Vec::new(env);
+    let mut level = leaves.clone();
+    let mut index = index;
+    while level.len() > 1 {
+        if index % 2 == 1 {
+            proof.push_back(MerkleProofStep {
+                sibling: level.get_unchecked(index - 1),
+                sibling_is_left: true,
+            });
+        } else if index + 1 < level.len() {
+            proof.push_back(MerkleProofStep {
+                sibling: level.get_unchecked(index + 1),
+                sibling_is_left: false,
+            });
+        }
+
+        level = next_level(env, &level);
+        index /= 2;
+    }
+    proof
+}
+
+/// Verifies that `record` is included in the tree with the given `root`.
+pub fn verify_proof(
+    env: &Env,
+    record: &MigrationRecord,
+    proof: &Vec<MerkleProofStep>,
+    root: &BytesN<32>,
+) -> bool {
+    root_from_proof(env, leaf_hash(env, record), proof) == *root
+}
+
+/// Folds a proof into the root it implies for `leaf`.
+fn root_from_proof(env: &Env, leaf: BytesN<32>, proof: &Vec<MerkleProofStep>) -> BytesN<32> {
+    let mut hash = leaf;
+    for step in proof.iter() {
+        hash = if step.sibling_is_left {
+            hash_node(env, &step.sibling, &hash)
+        } else {
+            hash_node(env, &hash, &step.sibling)
+        };
+    }
+    hash
+}
+
+/// Hashes each pair of nodes into the level above, promoting an unpaired last node.
+fn next_level(env: &Env, level: &Vec<BytesN<32>>) -> Vec<BytesN<32>> {
+    let mut next = Vec::new(env);
+    let mut i = 0;
+    while i < level.len() {
+        if i + 1 < level.len() {
+            next.push_back(hash_node(env, &level.get_unchecked(i), &level.get_unchecked(i + 1)));
+        } else {
+            next.push_back(level.get_unchecked(i));
+        }
+        i += 2;
+    }
+    next
+}
+
+fn hash_leaf(env: &Env, data: &Bytes) -> BytesN<32> {
+    let mut buf = Bytes::from_array(env, &[0x00]);
+    buf.append(data);
+    env.crypto().sha256(&buf).into()
+}
+
+fn hash_node(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut buf = Bytes::from_array(env, &[0x01]);
+    buf.extend_from_array(&left.to_array());
+    buf.extend_from_array(&right.to_array());
+    env.crypto().sha256(&buf).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(env: &Env, count: u8) -> Vec<BytesN<32>> {
+        let mut leaves = Vec::new(env);
+        for i in 0..count {
+            leaves.push_back(hash_leaf(env, &Bytes::from_array(env, &[i])));
+        }
+        leaves
+    }
+
+    fn verify_leaf(
+        env: &Env,
+        leaf: &BytesN<32>,
+        proof: &Vec<MerkleProofStep>,
+        root: &BytesN<32>,
+    ) -> bool {
+        root_from_proof(env, leaf.clone(), proof) == *root
+    }
+
+    #[test]
+    fn test_proofs_verify_for_every_leaf() {
+        let env = Env::default();
+        // Odd sizes exercise promotion of unpaired nodes
+        for count in [1u8, 2, 3, 5, 8, 13] {
+            let leaves = leaves(&env, count);
+            let root = compute_root(&env, &leaves);
+            for i in 0..leaves.len() {
+                let proof = build_proof(&env, &leaves, i);
+                assert!(verify_leaf(&env, &leaves.get_unchecked(i), &proof, &root));
+            }
+        }
+    }
+
+    #[test]
+    fn test_proof_rejects_wrong_leaf_and_root() {
+        let env = Env::default();
+        let leaves = leaves(&env, 5);
+        let root = compute_root(&env, &leaves);
+        let proof = build_proof(&env, &leaves, 2);
+
+        assert!(!verify_leaf(&env, &leaves.get_unchecked(3), &proof, &root));
+        let other_root = compute_root(&env, &self::leaves(&env, 6));
+        assert!(!verify_leaf(&env, &leaves.get_unchecked(2), &proof, &other_root));
+    }
+
+    #[test]
+    fn test_empty_tree_root_is_zero() {
+        let env = Env::default();
+        assert_eq!(
+            compute_root(&env, &Vec::new(&env)),
+            BytesN::from_array(&env, &[0u8; 32])
+        );
+    }
+}
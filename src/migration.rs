@@ -1,6 +1,10 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, Vec};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec};
 
-use crate::{ContractError, Remittance, RemittanceStatus};
+use crate::{
+    ArchivePeriodSummary, ArchiveTotals, CircuitBreakerConfig, CircuitBreakerTrip, ContractError, DailyLimit, DenylistEntry, FeeWithdrawal, FxRate, KycRecord, KycTierLimits,
+    GlobalPause, LimitScope, RegistryKind, Remittance, RemittanceStatus, Role, ScopedPause,
+    SettlementReceipt, TransactionLimits, TransferRecord, TtlPolicy, VelocityLimit,
+};
 
 /// Maximum number of items that can be exported/imported in a single batch
 /// to prevent excessive resource consumption
@@ -20,6 +24,12 @@ pub struct MigrationSnapshot {
     /// Ledger sequence when snapshot was created
     pub ledger_sequence: u32,
     
+    /// Contract the snapshot was exported from
+    pub source_contract: Address,
+    
+    /// SHA-256 of the network passphrase the source runs on (the ledger network ID)
+    pub network_passphrase_hash: BytesN<32>,
+    
     /// Instance storage data
     pub instance_data: InstanceData,
     
@@ -28,15 +38,15 @@ pub struct MigrationSnapshot {
     
     /// Cryptographic hash of all data for integrity verification
     pub verification_hash: BytesN<32>,
+    
+    /// Merkle root over the remittances, in ID order, for per-record proofs
+    pub merkle_root: BytesN<32>,
 }
 
 /// Instance storage data (contract-level configuration)
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct InstanceData {
-    /// Legacy admin address
-    pub admin: Address,
-    
     /// USDC token contract address
     pub usdc_token: Address,
     
@@ -74,23 +84,10 @@ pub struct PersistentData {
     
     /// Whitelisted tokens
     pub whitelisted_tokens: Vec<Address>,
-}
-
-/// Migration batch for incremental export/import
-#[contracttype]
-#[derive(Clone, Debug)]
-pub struct MigrationBatch {
-    /// Batch number (0-indexed)
-    pub batch_number: u32,
     
-    /// Total number of batches
-    pub total_batches: u32,
-    
-    /// Remittances in this batch
-    pub remittances: Vec<Remittance>,
-    
-    /// Hash of this batch for verification
-    pub batch_hash: BytesN<32>,
+    /// Accumulated fees per non-USDC token, including tokens removed from the
+    /// whitelist (USDC fees are in the instance data)
+    pub token_fees: Vec<MigratedTokenFees>,
 }
 
 /// Migration verification result
@@ -113,7 +110,7 @@ pub struct MigrationVerification {
 /// Export complete contract state for migration
 /// 
 /// This function creates a complete snapshot of all contract data including:
-/// - Instance storage (admin, token, fees, counters)
+/// - Instance storage (token, fees, counters, admin count)
 /// - Persistent storage (remittances, agents, admins, hashes)
 /// - Cryptographic verification hash
 /// 
@@ -124,10 +121,17 @@ pub struct MigrationVerification {
 /// 
 /// # Returns
 /// MigrationSnapshot containing all contract state
+///
+/// # Errors
+/// - InvalidStatus: Remittances have been archived; the snapshot does not
+///   carry archive state, so such contracts migrate in batches
 pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
+    if !crate::archive::list_periods(env).is_empty() {
+        return Err(ContractError::InvalidStatus);
+    }
+
     // Collect instance data
     let instance_data = InstanceData {
-        admin: crate::storage::get_admin(env)?,
         usdc_token: crate::storage::get_usdc_token(env)?,
         platform_fee_bps: crate::storage::get_platform_fee_bps(env)?,
         remittance_counter: crate::storage::get_remittance_counter(env)?,
@@ -146,13 +150,10 @@ pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
     }
     
     // Collect registered agents
-    // Note: In production, you'd need a way to iterate over all agents
-    // For now, we'll use a placeholder that requires agents to be tracked separately
-    let agents = Vec::new(env);
+    let agents = crate::registry::list_all(env, RegistryKind::Agent);
     
     // Collect admin roles
-    // Note: Similar to agents, would need iteration support
-    let admin_roles = Vec::new(env);
+    let admin_roles = crate::registry::list_all(env, RegistryKind::Admin);
     
     // Collect settlement hashes
     let mut settlement_hashes = Vec::new(env);
@@ -163,7 +164,17 @@ pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
     }
     
     // Collect whitelisted tokens
-    let whitelisted_tokens = Vec::new(env);
+    let whitelisted_tokens = crate::registry::list_all(env, RegistryKind::Token);
+    
+    // Collect fees held in other tokens
+    let mut token_fees = Vec::new(env);
+    let token_positions = crate::registry::len(env, RegistryKind::Token);
+    let mut start = 0;
+    while start < token_positions {
+        let page = crate::registry::MAX_REGISTRY_PAGE_SIZE.min(token_positions - start);
+        token_fees.append(&collect_token_fees(env, start, page)?);
+        start += page;
+    }
     
     let persistent_data = PersistentData {
         remittances,
@@ -171,28 +182,40 @@ pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
         admin_roles,
         settlement_hashes,
         whitelisted_tokens,
+        token_fees,
     };
     
     // Create snapshot
     let timestamp = env.ledger().timestamp();
     let ledger_sequence = env.ledger().sequence();
     
+    let source_contract = env.current_contract_address();
+    let network_passphrase_hash = env.ledger().network_id();
+    
     // Compute verification hash
     let verification_hash = compute_snapshot_hash(
         env,
+        &source_contract,
+        &network_passphrase_hash,
         &instance_data,
         &persistent_data,
         timestamp,
         ledger_sequence,
     );
     
+    // Compute Merkle root over the remittances
+    let merkle_root = compute_remittance_root(env, &persistent_data.remittances);
+    
     Ok(MigrationSnapshot {
         version: 1,
         timestamp,
         ledger_sequence,
+        source_contract,
+        network_passphrase_hash,
         instance_data,
         persistent_data,
         verification_hash,
+        merkle_root,
     })
 }
 
@@ -205,28 +228,37 @@ pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
 /// - Replay protection
 /// 
 /// # Security
-/// - Only callable by admin
+/// - Only callable by the import admin configured at deployment
 /// - Verifies cryptographic hash before import
+/// - Only accepts snapshots exported on the same network
+/// - Only accepts snapshots exported by the configured source contract
+/// - Each snapshot hash can be imported once
 /// - Prevents import if contract is already initialized
 /// - Atomic operation (all or nothing)
 /// 
 /// # Parameters
+/// - `caller`: Address performing the import
 /// - `snapshot`: Complete migration snapshot to import
 /// 
 /// # Returns
 /// Ok(()) if import successful, Err otherwise
 pub fn import_state(
     env: &Env,
+    caller: &Address,
     snapshot: MigrationSnapshot,
 ) -> Result<(), ContractError> {
-    // Verify contract is not already initialized
-    if crate::storage::has_admin(env) {
-        return Err(ContractError::AlreadyInitialized);
+    require_import_admin(env, caller)?;
+    
+    // Verify the snapshot comes from this network
+    if snapshot.network_passphrase_hash != env.ledger().network_id() {
+        return Err(ContractError::InvalidMigrationHash);
     }
     
     // Verify snapshot hash
     let computed_hash = compute_snapshot_hash(
         env,
+        &snapshot.source_contract,
+        &snapshot.network_passphrase_hash,
         &snapshot.instance_data,
         &snapshot.persistent_data,
         snapshot.timestamp,
@@ -237,14 +269,34 @@ pub fn import_state(
         return Err(ContractError::InvalidMigrationHash);
     }
     
+    // Verify the published Merkle root matches the remittances
+    if compute_remittance_root(env, &snapshot.persistent_data.remittances) != snapshot.merkle_root {
+        return Err(ContractError::InvalidMigrationHash);
+    }
+    
+    // Verify the snapshot comes from the configured source contract
+    require_import_source(env, &snapshot.source_contract)?;
+    
+    // Replay protection: each snapshot hash is imported at most once
+    let imported_key = MigrationKey::ImportedSnapshot(snapshot.verification_hash.clone());
+    if env.storage().persistent().has(&imported_key) {
+        return Err(ContractError::MigrationInProgress);
+    }
+    env.storage().persistent().set(&imported_key, &snapshot.source_contract);
+    
+    // Verify contract is not already initialized
+    if crate::storage::has_admin(env) {
+        return Err(ContractError::AlreadyInitialized);
+    }
+    
     // Import instance data
-    crate::storage::set_admin(env, &snapshot.instance_data.admin);
     crate::storage::set_usdc_token(env, &snapshot.instance_data.usdc_token);
     crate::storage::set_platform_fee_bps(env, snapshot.instance_data.platform_fee_bps);
     crate::storage::set_remittance_counter(env, snapshot.instance_data.remittance_counter);
     crate::storage::set_accumulated_fees(env, snapshot.instance_data.accumulated_fees);
     crate::storage::set_paused(env, snapshot.instance_data.paused);
     crate::storage::set_admin_count(env, snapshot.instance_data.admin_count);
+    crate::upgrade::set_schema_version(env, crate::STORAGE_SCHEMA_VERSION);
     
     // Import persistent data
     
@@ -278,6 +330,11 @@ pub fn import_state(
         crate::storage::set_token_whitelisted(env, &token, true);
     }
     
+    // Import fees held in other tokens
+    for migrated in snapshot.persistent_data.token_fees.iter() {
+        crate::storage::add_accumulated_fees(env, &migrated.token, migrated.fees)?;
+    }
+    
     Ok(())
 }
 
@@ -290,14 +347,17 @@ pub fn import_state(
 /// 
 /// # Algorithm
 /// Uses SHA-256 hash of concatenated serialized data:
-/// 1. Instance data (admin, token, fees, counters)
-/// 2. Persistent data (remittances, agents, etc.)
-/// 3. Timestamp and ledger sequence
+/// 1. Source contract address and network passphrase hash
+/// 2. Instance data (admin, token, fees, counters)
+/// 3. Persistent data (remittances, agents, etc.)
+/// 4. Timestamp and ledger sequence
 /// 
 /// # Returns
 /// 32-byte cryptographic hash
-fn compute_snapshot_hash(
+pub fn compute_snapshot_hash(
     env: &Env,
+    source_contract: &Address,
+    network_passphrase_hash: &BytesN<32>,
     instance_data: &InstanceData,
     persistent_data: &PersistentData,
     timestamp: u64,
@@ -305,9 +365,12 @@ fn compute_snapshot_hash(
 ) -> BytesN<32> {
     let mut data = Bytes::new(env);
     
+    // Bind the hash to the source contract and network
+    data.append(&crate::hashing::address_to_bytes(env, source_contract));
+    data.extend_from_array(&network_passphrase_hash.to_array());
+    
     // Serialize instance data
-    data.append(&instance_data.admin.to_string().to_bytes());
-    data.append(&instance_data.usdc_token.to_string().to_bytes());
+    data.append(&crate::hashing::address_to_bytes(env, &instance_data.usdc_token));
     data.append(&Bytes::from_array(env, &instance_data.platform_fee_bps.to_be_bytes()));
     data.append(&Bytes::from_array(env, &instance_data.remittance_counter.to_be_bytes()));
     data.append(&Bytes::from_array(env, &instance_data.accumulated_fees.to_be_bytes()));
@@ -320,10 +383,11 @@ fn compute_snapshot_hash(
     for i in 0..persistent_data.remittances.len() {
         let r = persistent_data.remittances.get_unchecked(i);
         data.append(&Bytes::from_array(env, &r.id.to_be_bytes()));
-        data.append(&r.sender.to_string().to_bytes());
-        data.append(&r.agent.to_string().to_bytes());
+        data.append(&crate::hashing::address_to_bytes(env, &r.sender));
+        data.append(&crate::hashing::address_to_bytes(env, &r.agent));
         data.append(&Bytes::from_array(env, &r.amount.to_be_bytes()));
         data.append(&Bytes::from_array(env, &r.fee.to_be_bytes()));
+        data.append(&crate::hashing::address_to_bytes(env, &r.token));
         
         let status_byte = match r.status {
             RemittanceStatus::Pending => 0u8,
@@ -340,13 +404,13 @@ fn compute_snapshot_hash(
     // Agents
     for i in 0..persistent_data.agents.len() {
         let agent = persistent_data.agents.get_unchecked(i);
-        data.append(&agent.to_string().to_bytes());
+        data.append(&crate::hashing::address_to_bytes(env, &agent));
     }
     
     // Admin roles
     for i in 0..persistent_data.admin_roles.len() {
         let admin = persistent_data.admin_roles.get_unchecked(i);
-        data.append(&admin.to_string().to_bytes());
+        data.append(&crate::hashing::address_to_bytes(env, &admin));
     }
     
     // Settlement hashes
@@ -358,7 +422,13 @@ fn compute_snapshot_hash(
     // Whitelisted tokens
     for i in 0..persistent_data.whitelisted_tokens.len() {
         let token = persistent_data.whitelisted_tokens.get_unchecked(i);
-        data.append(&token.to_string().to_bytes());
+        data.append(&crate::hashing::address_to_bytes(env, &token));
+    }
+    
+    // Fees per non-USDC token
+    for migrated in persistent_data.token_fees.iter() {
+        data.append(&crate::hashing::address_to_bytes(env, &migrated.token));
+        data.append(&Bytes::from_array(env, &migrated.fees.to_be_bytes()));
     }
     
     // Add timestamp and ledger sequence
@@ -366,7 +436,7 @@ fn compute_snapshot_hash(
     data.append(&Bytes::from_array(env, &ledger_sequence.to_be_bytes()));
     
     // Compute SHA-256 hash
-    env.crypto().sha256(&data)
+    env.crypto().sha256(&data).into()
 }
 
 /// Verify migration snapshot integrity
@@ -385,13 +455,17 @@ pub fn verify_snapshot(
 ) -> MigrationVerification {
     let computed_hash = compute_snapshot_hash(
         env,
+        &snapshot.source_contract,
+        &snapshot.network_passphrase_hash,
         &snapshot.instance_data,
         &snapshot.persistent_data,
         snapshot.timestamp,
         snapshot.ledger_sequence,
     );
     
-    let valid = computed_hash == snapshot.verification_hash;
+    let valid = computed_hash == snapshot.verification_hash
+        && compute_remittance_root(env, &snapshot.persistent_data.remittances)
+            == snapshot.merkle_root;
     
     MigrationVerification {
         valid,
@@ -401,17 +475,418 @@ pub fn verify_snapshot(
     }
 }
 
-/// Export state in batches for large datasets
+/// Compute the Merkle root over a list of remittances (see `merkle`)
+pub fn compute_remittance_root(env: &Env, remittances: &Vec<Remittance>) -> BytesN<32> {
+    let mut leaves = Vec::new(env);
+    for remittance in remittances.iter() {
+        leaves.push_back(crate::merkle::remittance_leaf(env, &remittance));
+    }
+    crate::merkle::compute_root(env, &leaves)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Batched Migration Protocol
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// Large states are streamed as a fixed sequence of hashed batches. The
+// sequence is planned once, when batch 0 is exported, and always follows the
+// same category order:
+//
+//   Config → Admins → Roles → Tokens → TokenFees → Agents → DailyLimits →
+//   KycTiers → KycRecords → Denylist → TreasuryDestinations →
+//   FeeWithdrawals → PauseScopes → VelocityLimits → TransactionLimits →
+//   FxRates → Remittances → Receipts → Users → ArchivePeriods →
+//   ArchiveTotals → ArchivedRemittances
+//
+// Config is a single batch; every other category takes
+// ceil(positions / batch_size) batches, where positions are registry positions
+// (admins, role holders, tokens, agents, KYC records, denylisted addresses,
+// treasury destinations, users; TokenFees walks the token registry including
+// removed tokens), list entries (daily limit corridors, KYC tiers, paused
+// scopes, velocity limits, transaction limit scopes, FX rate pairs, archive
+// periods), archive totals positions of every period laid end to end, fee
+// withdrawal IDs or remittance IDs (remittances, receipts, and archive markers
+// once anything is archived). Both sides chain the batch hashes into a rolling
+// hash; the source publishes it once the last batch is exported, and the
+// destination only finalizes the import if its own rolling hash matches.
+
+/// Instance configuration carried by the first batch of a migration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationConfig {
+    /// Contract the batches were exported from
+    pub source_contract: Address,
+    /// SHA-256 of the network passphrase the source runs on (the ledger network ID)
+    pub network_passphrase_hash: BytesN<32>,
+    /// USDC token contract address
+    pub usdc_token: Address,
+    /// Platform fee in basis points
+    pub platform_fee_bps: u32,
+    /// Global remittance counter
+    pub remittance_counter: u64,
+    /// Accumulated platform fees
+    pub accumulated_fees: i128,
+    /// Contract pause status
+    pub paused: bool,
+    /// Global pause with its record
+    pub global_pause: GlobalPause,
+    /// Number of admins
+    pub admin_count: u32,
+    /// Settlement cooldown in seconds per sender
+    pub rate_limit_cooldown: u64,
+    /// Whether senders need valid KYC to create remittances
+    pub kyc_required: bool,
+    /// Sanctions screening contract, if configured
+    pub screening_contract: Option<Address>,
+    /// Fee withdrawal timelock in seconds
+    pub withdrawal_timelock: u64,
+    /// ID of the last proposed fee withdrawal
+    pub withdrawal_counter: u64,
+    /// Circuit breaker ceilings and window
+    pub circuit_breaker: CircuitBreakerConfig,
+    /// Whether the circuit breaker is tripped, and why
+    pub circuit_breaker_state: MigratedBreakerState,
+    /// FX oracle contract, if configured
+    pub fx_oracle: Option<Address>,
+    /// Maximum age of an admin-posted FX rate in seconds
+    pub fx_max_rate_age: u64,
+    /// TTL thresholds and extensions, applied to every imported entry
+    pub ttl_policy: TtlPolicy,
+    /// Number of admin approvals sensitive actions need
+    pub approval_threshold: u32,
+    /// Key allowed to start an admin recovery, if set
+    pub recovery_key: Option<Address>,
+}
+
+/// State of the circuit breaker. Volume windows are not carried: they restart
+/// empty on the destination.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MigratedBreakerState {
+    Closed,
+    /// Tripped and waiting for an admin reset
+    Tripped(CircuitBreakerTrip),
+}
+
+/// An operational role held by an address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGrant {
+    pub role: Role,
+    pub account: Address,
+}
+
+/// The limits of a KYC tier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratedKycTier {
+    pub tier: u32,
+    pub limits: KycTierLimits,
+}
+
+/// An address's KYC record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratedKycRecord {
+    pub account: Address,
+    pub record: KycRecord,
+}
+
+/// A denylisted address with its entry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratedDenylistEntry {
+    pub account: Address,
+    pub entry: DenylistEntry,
+}
+
+/// Platform fees collected in a token other than USDC.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratedTokenFees {
+    pub token: Address,
+    pub fees: i128,
+}
+
+/// Transaction limits of the global scope or a corridor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratedTransactionLimits {
+    pub scope: LimitScope,
+    pub limits: TransactionLimits,
+}
+
+/// A remittance together with its settlement flag.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratedRemittance {
+    /// The remittance record
+    pub remittance: Remittance,
+    /// Whether a settlement has been executed (duplicate-settlement guard)
+    pub settled: bool,
+    /// Corridor the remittance was created in, checked against corridor
+    /// pauses and limits
+    pub corridor: MigratedCorridor,
+    /// Time the remittance reached a terminal status, which decides its
+    /// archive period
+    pub closed_at: Option<u64>,
+}
+
+/// Totals of an (agent, token) pair at a position of an archive period's totals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratedArchiveTotals {
+    pub period_id: u64,
+    /// Position in the period's totals, in first-archived order
+    pub position: u32,
+    pub totals: ArchiveTotals,
+}
+
+/// Marker of an archived remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratedArchiveMarker {
+    pub remittance_id: u64,
+    /// Period the remittance was rolled into
+    pub period_id: u64,
+}
+
+/// The (currency, country) corridor a remittance was tagged with at creation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MigratedCorridor {
+    /// Created before remittances were tagged with a corridor
+    Untagged,
+    Tagged(String, String),
+}
+
+/// Per-user rate limit and transfer history state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratedUser {
+    /// User address
+    pub user: Address,
+    /// Timestamp of the user's last settlement, if any
+    pub last_settlement_time: Option<u64>,
+    /// Transfer history used for daily limit accounting
+    pub transfers: Vec<TransferRecord>,
+}
+
+/// Typed contents of a migration batch; one storage category per batch.
+///
+/// Contract types cannot box the larger config variant, which only batch 0 carries.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum MigrationPayload {
+    /// Instance configuration (always batch 0)
+    Config(MigrationConfig),
+    /// Addresses holding the admin role
+    Admins(Vec<Address>),
+    /// Holders of operational roles
+    Roles(Vec<RoleGrant>),
+    /// Whitelisted tokens
+    Tokens(Vec<Address>),
+    /// Accumulated fees per non-USDC token (USDC fees are in the config)
+    TokenFees(Vec<MigratedTokenFees>),
+    /// Registered agents
+    Agents(Vec<Address>),
+    /// Daily limits per (currency, country) corridor
+    DailyLimits(Vec<DailyLimit>),
+    /// Limits of configured KYC tiers
+    KycTiers(Vec<MigratedKycTier>),
+    /// KYC records, including expired ones
+    KycRecords(Vec<MigratedKycRecord>),
+    /// Sanctions denylist
+    Denylist(Vec<MigratedDenylistEntry>),
+    /// Treasury allow-list
+    TreasuryDestinations(Vec<Address>),
+    /// Fee withdrawal proposals, in every status
+    FeeWithdrawals(Vec<FeeWithdrawal>),
+    /// Active scoped pauses
+    PauseScopes(Vec<ScopedPause>),
+    /// Velocity limits (volume windows restart empty on the destination)
+    VelocityLimits(Vec<VelocityLimit>),
+    /// Transaction limits
+    TransactionLimits(Vec<MigratedTransactionLimits>),
+    /// Admin-posted FX rates, with the time they were posted
+    FxRates(Vec<FxRate>),
+    /// Remittances with their settlement flags
+    Remittances(Vec<MigratedRemittance>),
+    /// Settlement receipts
+    Receipts(Vec<SettlementReceipt>),
+    /// Per-user state
+    Users(Vec<MigratedUser>),
+    /// Archive period summaries
+    ArchivePeriods(Vec<ArchivePeriodSummary>),
+    /// Archived totals per period, agent and token
+    ArchiveTotals(Vec<MigratedArchiveTotals>),
+    /// Markers of archived remittances
+    ArchivedRemittances(Vec<MigratedArchiveMarker>),
+}
+
+/// Migration batch for incremental export/import
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationBatch {
+    /// Batch number (0-indexed)
+    pub batch_number: u32,
+    
+    /// Total number of batches
+    pub total_batches: u32,
+    
+    /// Storage category and records carried by this batch
+    pub payload: MigrationPayload,
+    
+    /// Hash of this batch for verification
+    pub batch_hash: BytesN<32>,
+}
+
+/// Batch layout of a migration, fixed when batch 0 is exported.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationPlan {
+    /// Maximum records per batch
+    pub batch_size: u32,
+    /// Total number of batches, including the config batch
+    pub total_batches: u32,
+    /// Admin registry positions
+    pub admin_positions: u32,
+    /// Registry positions of each role, in `ALL_ROLES` order
+    pub role_positions: Vec<u32>,
+    /// Token registry positions
+    pub token_positions: u32,
+    /// Agent registry positions
+    pub agent_positions: u32,
+    /// Daily limit corridors
+    pub corridor_count: u32,
+    /// KYC tiers with configured limits
+    pub kyc_tier_count: u32,
+    /// KYC registry positions
+    pub kyc_positions: u32,
+    /// Denylist registry positions
+    pub denylist_positions: u32,
+    /// Treasury allow-list registry positions
+    pub treasury_positions: u32,
+    /// Highest fee withdrawal ID
+    pub withdrawal_counter: u64,
+    /// Paused scopes
+    pub pause_scope_count: u32,
+    /// Configured velocity limits
+    pub velocity_limit_count: u32,
+    /// Scopes with transaction limits
+    pub transaction_scope_count: u32,
+    /// Token pairs with an admin-posted FX rate
+    pub fx_pair_count: u32,
+    /// Highest remittance ID
+    pub remittance_counter: u64,
+    /// User registry positions
+    pub user_positions: u32,
+    /// Totals positions of each archive period, in `list_periods` order
+    pub archive_pair_counts: Vec<u32>,
+    /// Remittance IDs scanned for archive markers (0 when nothing is archived)
+    pub archived_id_range: u64,
+}
+
+/// Digest of a complete migration: what `finalize_import` checks against.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationDigest {
+    /// Number of batches in the migration
+    pub total_batches: u32,
+    /// Rolling hash over every batch hash, in batch order
+    pub rolling_hash: BytesN<32>,
+    /// Merkle root over the batch hashes, in batch order, for per-batch proofs
+    pub merkle_root: BytesN<32>,
+}
+
+/// Progress of a batched import.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationImportStatus {
+    /// Next batch number the contract will accept
+    pub next_batch: u32,
+    /// Total batches announced by batch 0 (0 before any batch is imported)
+    pub total_batches: u32,
+    /// Rolling hash over the batches imported so far
+    pub rolling_hash: BytesN<32>,
+    /// Whether the import has been finalized
+    pub finalized: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum MigrationKey {
+    /// Plan of the export in progress (instance storage)
+    ExportPlan,
+    /// Next batch number to export (instance storage)
+    ExportCursor,
+    /// Rolling hash over exported batches (instance storage)
+    ExportRollingHash,
+    /// Hashes of the exported batches, in order (persistent storage)
+    ExportBatchHashes,
+    /// Next batch number to import (instance storage)
+    ImportCursor,
+    /// Total batches announced by the imported batch 0 (instance storage)
+    ImportTotal,
+    /// Rolling hash over imported batches (instance storage)
+    ImportRollingHash,
+    /// Set once the import is finalized (instance storage)
+    ImportFinalized,
+    /// Lifecycle of the source contract during a migration (instance storage)
+    Mode,
+    /// Only address allowed to import state, set at deployment (instance storage)
+    ImportAdmin,
+    /// Only contract whose exports can be imported, set with the import admin (instance storage)
+    ImportSource,
+    /// Source contract of an imported snapshot, by snapshot hash (persistent storage)
+    ImportedSnapshot(BytesN<32>),
+}
+
+/// Batch categories, in export order.
+#[derive(Clone, Copy)]
+enum Category {
+    Config,
+    Admins,
+    Roles,
+    Tokens,
+    TokenFees,
+    Agents,
+    DailyLimits,
+    KycTiers,
+    KycRecords,
+    Denylist,
+    TreasuryDestinations,
+    FeeWithdrawals,
+    PauseScopes,
+    VelocityLimits,
+    TransactionLimits,
+    FxRates,
+    Remittances,
+    Receipts,
+    Users,
+    ArchivePeriods,
+    ArchiveTotals,
+    ArchivedRemittances,
+}
+
+/// Export one batch of the migration
 /// 
-/// For contracts with many remittances, export in batches to avoid
-/// resource limits. Each batch includes its own hash for verification.
+/// Batches must be exported in order. Exporting batch 0 plans the migration
+/// from the current state and (re)starts the export session; every later batch
+/// must be the next one and use the same batch size. After the last batch the
+/// digest is available from `get_export_digest`.
 /// 
 /// # Parameters
 /// - `batch_number`: Which batch to export (0-indexed)
 /// - `batch_size`: Number of items per batch
 /// 
 /// # Returns
-/// MigrationBatch containing subset of data
+/// MigrationBatch containing one category's records and the batch hash
+/// 
+/// # Errors
+/// - InvalidAmount: batch_size is zero or exceeds MAX_MIGRATION_BATCH_SIZE
+/// - InvalidMigrationBatch: batch is out of order, or batch_size changed mid-export
 pub fn export_batch(
     env: &Env,
     batch_number: u32,
@@ -420,98 +895,1000 @@ pub fn export_batch(
     if batch_size == 0 || batch_size > MAX_MIGRATION_BATCH_SIZE {
         return Err(ContractError::InvalidAmount);
     }
-    
-    let counter = crate::storage::get_remittance_counter(env)?;
-    let total_batches = (counter as u32 + batch_size - 1) / batch_size;
-    
-    if batch_number >= total_batches {
-        return Err(ContractError::InvalidAmount);
-    }
-    
-    let start_id = (batch_number * batch_size) as u64 + 1;
-    let end_id = ((batch_number + 1) * batch_size).min(counter as u32) as u64;
-    
-    let mut remittances = Vec::new(env);
-    for id in start_id..=end_id {
-        if let Ok(remittance) = crate::storage::get_remittance(env, id) {
-            remittances.push_back(remittance);
+
+    let storage = env.storage().instance();
+    let plan = if batch_number == 0 {
+        let plan = plan_migration(env, batch_size)?;
+        storage.set(&MigrationKey::ExportPlan, &plan);
+        storage.set(&MigrationKey::ExportRollingHash, &BytesN::from_array(env, &[0u8; 32]));
+        env.storage()
+            .persistent()
+            .set(&MigrationKey::ExportBatchHashes, &Vec::<BytesN<32>>::new(env));
+        plan
+    } else {
+        let plan: MigrationPlan = storage
+            .get(&MigrationKey::ExportPlan)
+            .ok_or(ContractError::InvalidMigrationBatch)?;
+        let cursor: u32 = storage.get(&MigrationKey::ExportCursor).unwrap_or(0);
+        if batch_number != cursor || batch_size != plan.batch_size {
+            return Err(ContractError::InvalidMigrationBatch);
         }
+        plan
+    };
+
+    if batch_number >= plan.total_batches {
+        return Err(ContractError::InvalidMigrationBatch);
     }
-    
-    // Compute batch hash
-    let batch_hash = compute_batch_hash(env, &remittances, batch_number);
-    
+
+    let payload = export_payload(env, &plan, batch_number)?;
+    let batch_hash = compute_batch_hash(env, batch_number, plan.total_batches, &payload);
+
+    let rolling_hash: BytesN<32> = storage
+        .get(&MigrationKey::ExportRollingHash)
+        .unwrap_or(BytesN::from_array(env, &[0u8; 32]));
+    storage.set(
+        &MigrationKey::ExportRollingHash,
+        &chain_hash(env, &rolling_hash, &batch_hash),
+    );
+    storage.set(&MigrationKey::ExportCursor, &(batch_number + 1));
+
+    let mut batch_hashes: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&MigrationKey::ExportBatchHashes)
+        .unwrap_or(Vec::new(env));
+    batch_hashes.push_back(batch_hash.clone());
+    env.storage()
+        .persistent()
+        .set(&MigrationKey::ExportBatchHashes, &batch_hashes);
+
     Ok(MigrationBatch {
         batch_number,
-        total_batches,
-        remittances,
+        total_batches: plan.total_batches,
+        payload,
         batch_hash,
     })
 }
 
+/// Returns the digest of a completed export
+/// 
+/// # Errors
+/// - InvalidMigrationBatch: No export started, or not every batch exported yet
+pub fn get_export_digest(env: &Env) -> Result<MigrationDigest, ContractError> {
+    let storage = env.storage().instance();
+    let plan: MigrationPlan = storage
+        .get(&MigrationKey::ExportPlan)
+        .ok_or(ContractError::InvalidMigrationBatch)?;
+    let cursor: u32 = storage.get(&MigrationKey::ExportCursor).unwrap_or(0);
+    if cursor != plan.total_batches {
+        return Err(ContractError::InvalidMigrationBatch);
+    }
+
+    let batch_hashes: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&MigrationKey::ExportBatchHashes)
+        .unwrap_or(Vec::new(env));
+    let mut leaves = Vec::new(env);
+    for batch_hash in batch_hashes.iter() {
+        leaves.push_back(crate::merkle::batch_leaf(env, &batch_hash));
+    }
+
+    Ok(MigrationDigest {
+        total_batches: plan.total_batches,
+        rolling_hash: storage
+            .get(&MigrationKey::ExportRollingHash)
+            .ok_or(ContractError::InvalidMigrationBatch)?,
+        merkle_root: crate::merkle::compute_root(env, &leaves),
+    })
+}
+
 /// Import state from batch
 /// 
-/// Import a single batch of remittances. Must be called in order
-/// (batch 0, then 1, then 2, etc.) to ensure consistency.
+/// Batches must arrive in order (0, 1, 2, ...); the on-chain cursor rejects
+/// anything else. Batch 0 carries the configuration and can only be imported
+/// into an uninitialized contract, authorized by the import admin; later
+/// batches are authorized by the import admin or an admin of the (partially
+/// imported) contract.
 /// 
 /// # Parameters
+/// - `caller`: Address importing the batch
 /// - `batch`: Batch to import
 /// 
 /// # Returns
 /// Ok(()) if import successful
+/// 
+/// # Errors
+/// - MigrationInProgress: Import already finalized
+/// - InvalidMigrationBatch: Batch out of order, total changed, or batch 0 is not a config batch
+/// - InvalidMigrationHash: Batch hash verification failed
+/// - AlreadyInitialized: Batch 0 imported into an initialized contract
+/// - InvalidMigrationHash: Batch 0 was exported on another network
+/// - MigrationSourceMismatch: Batch 0 was exported by a contract other than the configured source
+/// - Unauthorized: No import admin configured (batch 0)
+/// - Unauthorized: Caller is not the import admin (batch 0) or an admin
 pub fn import_batch(
     env: &Env,
+    caller: &Address,
     batch: MigrationBatch,
 ) -> Result<(), ContractError> {
+    let status = get_import_status(env);
+    if status.finalized {
+        return Err(ContractError::MigrationInProgress);
+    }
+    if batch.batch_number != status.next_batch
+        || (batch.batch_number > 0 && batch.total_batches != status.total_batches)
+    {
+        return Err(ContractError::InvalidMigrationBatch);
+    }
+
     // Verify batch hash
-    let computed_hash = compute_batch_hash(env, &batch.remittances, batch.batch_number);
-    
+    let computed_hash =
+        compute_batch_hash(env, batch.batch_number, batch.total_batches, &batch.payload);
     if computed_hash != batch.batch_hash {
         return Err(ContractError::InvalidMigrationHash);
     }
-    
-    // Import remittances
-    for i in 0..batch.remittances.len() {
-        let remittance = batch.remittances.get_unchecked(i);
-        crate::storage::set_remittance(env, remittance.id, &remittance);
+
+    match &batch.payload {
+        MigrationPayload::Config(config) => {
+            if batch.batch_number != 0 {
+                return Err(ContractError::InvalidMigrationBatch);
+            }
+            require_import_admin(env, caller)?;
+            if crate::storage::has_admin(env) {
+                return Err(ContractError::AlreadyInitialized);
+            }
+            if config.network_passphrase_hash != env.ledger().network_id() {
+                return Err(ContractError::InvalidMigrationHash);
+            }
+            require_import_source(env, &config.source_contract)?;
+            import_config(env, config)?;
+        }
+        payload => {
+            if batch.batch_number == 0 {
+                return Err(ContractError::InvalidMigrationBatch);
+            }
+            if get_import_admin(env).as_ref() == Some(caller) {
+                caller.require_auth();
+            } else {
+                crate::storage::require_admin(env, caller)?;
+            }
+            import_payload(env, payload)?;
+        }
     }
-    
+
+    let storage = env.storage().instance();
+    storage.set(
+        &MigrationKey::ImportRollingHash,
+        &chain_hash(env, &status.rolling_hash, &batch.batch_hash),
+    );
+    storage.set(&MigrationKey::ImportCursor, &(batch.batch_number + 1));
+    storage.set(&MigrationKey::ImportTotal, &batch.total_batches);
+
+    Ok(())
+}
+
+/// Finalize a batched import
+/// 
+/// Succeeds only if every announced batch was imported and the rolling hash
+/// over them equals the digest published by the source contract.
+/// 
+/// # Errors
+/// - MigrationInProgress: Import already finalized
+/// - InvalidMigrationBatch: Batches missing, or batch count differs from the digest
+/// - InvalidMigrationHash: Rolling hash differs from the digest
+pub fn finalize_import(env: &Env, digest: &MigrationDigest) -> Result<(), ContractError> {
+    let status = get_import_status(env);
+    if status.finalized {
+        return Err(ContractError::MigrationInProgress);
+    }
+    if status.total_batches == 0
+        || status.next_batch != status.total_batches
+        || status.total_batches != digest.total_batches
+    {
+        return Err(ContractError::InvalidMigrationBatch);
+    }
+    if status.rolling_hash != digest.rolling_hash {
+        return Err(ContractError::InvalidMigrationHash);
+    }
+
+    env.storage()
+        .instance()
+        .set(&MigrationKey::ImportFinalized, &true);
+
     Ok(())
 }
 
+/// Returns the progress of the batched import
+pub fn get_import_status(env: &Env) -> MigrationImportStatus {
+    let storage = env.storage().instance();
+    MigrationImportStatus {
+        next_batch: storage.get(&MigrationKey::ImportCursor).unwrap_or(0),
+        total_batches: storage.get(&MigrationKey::ImportTotal).unwrap_or(0),
+        rolling_hash: storage
+            .get(&MigrationKey::ImportRollingHash)
+            .unwrap_or(BytesN::from_array(env, &[0u8; 32])),
+        finalized: storage.get(&MigrationKey::ImportFinalized).unwrap_or(false),
+    }
+}
+
 /// Compute hash of a batch for verification
-fn compute_batch_hash(
+/// 
+/// SHA-256 over `batch_number` (u32 BE) || `total_batches` (u32 BE) || XDR of
+/// the payload, so off-chain tools can recompute it from the batch alone.
+pub fn compute_batch_hash(
     env: &Env,
-    remittances: &Vec<Remittance>,
     batch_number: u32,
+    total_batches: u32,
+    payload: &MigrationPayload,
 ) -> BytesN<32> {
     let mut data = Bytes::new(env);
-    
-    // Add batch number
     data.append(&Bytes::from_array(env, &batch_number.to_be_bytes()));
-    
-    // Add all remittances
-    for i in 0..remittances.len() {
-        let r = remittances.get_unchecked(i);
-        data.append(&Bytes::from_array(env, &r.id.to_be_bytes()));
-        data.append(&r.sender.to_string().to_bytes());
-        data.append(&r.agent.to_string().to_bytes());
-        data.append(&Bytes::from_array(env, &r.amount.to_be_bytes()));
-        data.append(&Bytes::from_array(env, &r.fee.to_be_bytes()));
-        
-        let status_byte = match r.status {
-            RemittanceStatus::Pending => 0u8,
-            RemittanceStatus::Completed => 1u8,
-            RemittanceStatus::Cancelled => 2u8,
-        };
-        data.append(&Bytes::from_array(env, &[status_byte]));
-        
-        if let Some(expiry) = r.expiry {
-            data.append(&Bytes::from_array(env, &expiry.to_be_bytes()));
+    data.append(&Bytes::from_array(env, &total_batches.to_be_bytes()));
+    data.append(&payload.clone().to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+/// Extend a rolling hash with the next batch hash: SHA-256(rolling || batch_hash)
+pub fn chain_hash(env: &Env, rolling_hash: &BytesN<32>, batch_hash: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &rolling_hash.to_array());
+    data.append(&Bytes::from_array(env, &batch_hash.to_array()));
+    env.crypto().sha256(&data).into()
+}
+
+fn plan_migration(env: &Env, batch_size: u32) -> Result<MigrationPlan, ContractError> {
+    let remittance_counter = crate::storage::get_remittance_counter(env)?;
+    let archive_pair_counts = archive_pair_counts(env);
+    let mut plan = MigrationPlan {
+        batch_size,
+        total_batches: 0,
+        admin_positions: crate::registry::len(env, RegistryKind::Admin),
+        role_positions: role_positions(env),
+        token_positions: crate::registry::len(env, RegistryKind::Token),
+        agent_positions: crate::registry::len(env, RegistryKind::Agent),
+        corridor_count: crate::storage::get_daily_limit_corridors(env).len(),
+        kyc_tier_count: crate::kyc::get_tiers(env).len(),
+        kyc_positions: crate::registry::len(env, RegistryKind::Kyc),
+        denylist_positions: crate::registry::len(env, RegistryKind::Denylist),
+        treasury_positions: crate::registry::len(env, RegistryKind::Treasury),
+        withdrawal_counter: crate::treasury::get_withdrawal_counter(env),
+        pause_scope_count: crate::pause::active_scopes(env).len(),
+        velocity_limit_count: crate::limits::get_velocity_limits(env).len(),
+        transaction_scope_count: crate::limits::get_transaction_limit_scopes(env).len(),
+        fx_pair_count: crate::fx::get_rate_pairs(env).len(),
+        remittance_counter,
+        user_positions: crate::registry::len(env, RegistryKind::User),
+        archived_id_range: if archive_pair_counts.is_empty() {
+            0
+        } else {
+            remittance_counter
+        },
+        archive_pair_counts,
+    };
+    plan.total_batches = CATEGORIES
+        .iter()
+        .map(|category| category_batches(&plan, *category))
+        .sum();
+    Ok(plan)
+}
+
+const CATEGORIES: [Category; 22] = [
+    Category::Config,
+    Category::Admins,
+    Category::Roles,
+    Category::Tokens,
+    Category::TokenFees,
+    Category::Agents,
+    Category::DailyLimits,
+    Category::KycTiers,
+    Category::KycRecords,
+    Category::Denylist,
+    Category::TreasuryDestinations,
+    Category::FeeWithdrawals,
+    Category::PauseScopes,
+    Category::VelocityLimits,
+    Category::TransactionLimits,
+    Category::FxRates,
+    Category::Remittances,
+    Category::Receipts,
+    Category::Users,
+    Category::ArchivePeriods,
+    Category::ArchiveTotals,
+    Category::ArchivedRemittances,
+];
+
+fn category_positions(plan: &MigrationPlan, category: Category) -> u64 {
+    match category {
+        Category::Config => 1,
+        Category::Admins => plan.admin_positions as u64,
+        Category::Roles => plan.role_positions.iter().map(|len| len as u64).sum(),
+        Category::Tokens | Category::TokenFees => plan.token_positions as u64,
+        Category::Agents => plan.agent_positions as u64,
+        Category::DailyLimits => plan.corridor_count as u64,
+        Category::KycTiers => plan.kyc_tier_count as u64,
+        Category::KycRecords => plan.kyc_positions as u64,
+        Category::Denylist => plan.denylist_positions as u64,
+        Category::TreasuryDestinations => plan.treasury_positions as u64,
+        Category::FeeWithdrawals => plan.withdrawal_counter,
+        Category::PauseScopes => plan.pause_scope_count as u64,
+        Category::VelocityLimits => plan.velocity_limit_count as u64,
+        Category::TransactionLimits => plan.transaction_scope_count as u64,
+        Category::FxRates => plan.fx_pair_count as u64,
+        Category::Remittances | Category::Receipts => plan.remittance_counter,
+        Category::Users => plan.user_positions as u64,
+        Category::ArchivePeriods => plan.archive_pair_counts.len() as u64,
+        Category::ArchiveTotals => plan.archive_pair_counts.iter().map(|len| len as u64).sum(),
+        Category::ArchivedRemittances => plan.archived_id_range,
+    }
+}
+
+fn category_batches(plan: &MigrationPlan, category: Category) -> u32 {
+    match category {
+        Category::Config => 1,
+        _ => category_positions(plan, category).div_ceil(plan.batch_size as u64) as u32,
+    }
+}
+
+/// Resolve a batch number to its category and the `[start, end)` positions it covers.
+fn locate_batch(plan: &MigrationPlan, batch_number: u32) -> (Category, u64, u64) {
+    let mut first = 0u32;
+    for category in CATEGORIES {
+        let batches = category_batches(plan, category);
+        if batch_number < first + batches {
+            let start = (batch_number - first) as u64 * plan.batch_size as u64;
+            let end = (start + plan.batch_size as u64).min(category_positions(plan, category));
+            return (category, start, end);
         }
+        first += batches;
+    }
+    // export_batch rejects batch numbers >= total_batches before locating
+    unreachable!()
+}
+
+fn export_payload(
+    env: &Env,
+    plan: &MigrationPlan,
+    batch_number: u32,
+) -> Result<MigrationPayload, ContractError> {
+    let (category, start, end) = locate_batch(plan, batch_number);
+    let page = (end - start) as u32;
+
+    let payload = match category {
+        Category::Config => MigrationPayload::Config(MigrationConfig {
+            source_contract: env.current_contract_address(),
+            network_passphrase_hash: env.ledger().network_id(),
+            usdc_token: crate::storage::get_usdc_token(env)?,
+            platform_fee_bps: crate::storage::get_platform_fee_bps(env)?,
+            remittance_counter: plan.remittance_counter,
+            accumulated_fees: crate::storage::get_accumulated_fees(env)?,
+            paused: crate::storage::is_paused(env),
+            global_pause: match crate::pause::get_global_record(env) {
+                Some(record) => GlobalPause::Paused(record),
+                None => GlobalPause::Unpaused,
+            },
+            admin_count: crate::storage::get_admin_count(env),
+            rate_limit_cooldown: crate::storage::get_rate_limit_cooldown(env).unwrap_or(0),
+            kyc_required: crate::kyc::is_required(env),
+            screening_contract: crate::sanctions::get_screening_contract(env),
+            withdrawal_timelock: crate::treasury::get_timelock(env),
+            withdrawal_counter: plan.withdrawal_counter,
+            circuit_breaker: crate::rate_limit::get_circuit_breaker_config(env),
+            circuit_breaker_state: match crate::rate_limit::get_circuit_breaker_trip(env) {
+                Some(trip) => MigratedBreakerState::Tripped(trip),
+                None => MigratedBreakerState::Closed,
+            },
+            fx_oracle: crate::fx::get_oracle(env),
+            fx_max_rate_age: crate::fx::get_max_rate_age(env),
+            ttl_policy: crate::ttl::get_policy(env),
+            approval_threshold: crate::multisig::get_threshold(env),
+            recovery_key: crate::admin_transfer::get_recovery_key(env),
+        }),
+        Category::Admins => MigrationPayload::Admins(crate::registry::list(
+            env,
+            RegistryKind::Admin,
+            start as u32,
+            page,
+        )),
+        Category::Roles => MigrationPayload::Roles(role_grants(env, plan, start, end)),
+        Category::Tokens => MigrationPayload::Tokens(crate::registry::list(
+            env,
+            RegistryKind::Token,
+            start as u32,
+            page,
+        )),
+        Category::TokenFees => {
+            MigrationPayload::TokenFees(collect_token_fees(env, start as u32, page)?)
+        }
+        Category::Agents => MigrationPayload::Agents(crate::registry::list(
+            env,
+            RegistryKind::Agent,
+            start as u32,
+            page,
+        )),
+        Category::DailyLimits => {
+            let corridors = crate::storage::get_daily_limit_corridors(env);
+            let mut limits = Vec::new(env);
+            for i in start as u32..end as u32 {
+                let (currency, country) = corridors.get_unchecked(i);
+                if let Some(limit) = crate::storage::get_daily_limit(env, &currency, &country) {
+                    limits.push_back(limit);
+                }
+            }
+            MigrationPayload::DailyLimits(limits)
+        }
+        Category::KycTiers => {
+            let tiers = crate::kyc::get_tiers(env);
+            let mut migrated = Vec::new(env);
+            for i in start as u32..(end as u32).min(tiers.len()) {
+                let tier = tiers.get_unchecked(i);
+                if let Some(limits) = crate::kyc::get_tier_limits(env, tier) {
+                    migrated.push_back(MigratedKycTier { tier, limits });
+                }
+            }
+            MigrationPayload::KycTiers(migrated)
+        }
+        Category::KycRecords => {
+            let accounts = crate::registry::list(env, RegistryKind::Kyc, start as u32, page);
+            let mut records = Vec::new(env);
+            for account in accounts.iter() {
+                if let Some(record) = crate::kyc::get_record(env, &account) {
+                    records.push_back(MigratedKycRecord { account, record });
+                }
+            }
+            MigrationPayload::KycRecords(records)
+        }
+        Category::Denylist => {
+            let accounts =
+                crate::registry::list(env, RegistryKind::Denylist, start as u32, page);
+            let mut entries = Vec::new(env);
+            for account in accounts.iter() {
+                if let Some(entry) = crate::sanctions::get_entry(env, &account) {
+                    entries.push_back(MigratedDenylistEntry { account, entry });
+                }
+            }
+            MigrationPayload::Denylist(entries)
+        }
+        Category::TreasuryDestinations => MigrationPayload::TreasuryDestinations(
+            crate::registry::list(env, RegistryKind::Treasury, start as u32, page),
+        ),
+        Category::FeeWithdrawals => {
+            let mut withdrawals = Vec::new(env);
+            for id in start + 1..=end {
+                if let Ok(withdrawal) = crate::treasury::get_withdrawal(env, id) {
+                    withdrawals.push_back(withdrawal);
+                }
+            }
+            MigrationPayload::FeeWithdrawals(withdrawals)
+        }
+        Category::PauseScopes => {
+            let scopes = crate::pause::active_scopes(env);
+            let mut pauses = Vec::new(env);
+            for i in start as u32..(end as u32).min(scopes.len()) {
+                let scope = scopes.get_unchecked(i);
+                if let Some(record) = crate::pause::get_scope_record(env, &scope) {
+                    pauses.push_back(ScopedPause { scope, record });
+                }
+            }
+            MigrationPayload::PauseScopes(pauses)
+        }
+        Category::VelocityLimits => {
+            let configured = crate::limits::get_velocity_limits(env);
+            let mut limits = Vec::new(env);
+            for i in start as u32..(end as u32).min(configured.len()) {
+                limits.push_back(configured.get_unchecked(i));
+            }
+            MigrationPayload::VelocityLimits(limits)
+        }
+        Category::TransactionLimits => {
+            let scopes = crate::limits::get_transaction_limit_scopes(env);
+            let mut migrated = Vec::new(env);
+            for i in start as u32..(end as u32).min(scopes.len()) {
+                let scope = scopes.get_unchecked(i);
+                if let Some(limits) = crate::limits::get_transaction_limits(env, &scope) {
+                    migrated.push_back(MigratedTransactionLimits { scope, limits });
+                }
+            }
+            MigrationPayload::TransactionLimits(migrated)
+        }
+        Category::FxRates => {
+            let pairs = crate::fx::get_rate_pairs(env);
+            let mut rates = Vec::new(env);
+            for i in start as u32..(end as u32).min(pairs.len()) {
+                let (base_token, quote_token) = pairs.get_unchecked(i);
+                if let Some(rate) = crate::fx::get_admin_rate(env, &base_token, &quote_token) {
+                    rates.push_back(rate);
+                }
+            }
+            MigrationPayload::FxRates(rates)
+        }
+        Category::Remittances => {
+            let mut remittances = Vec::new(env);
+            for id in start + 1..=end {
+                if let Ok(remittance) = crate::storage::get_remittance(env, id) {
+                    remittances.push_back(MigratedRemittance {
+                        remittance,
+                        settled: crate::storage::has_settlement_hash(env, id),
+                        corridor: match crate::storage::get_remittance_corridor(env, id) {
+                            Some((currency, country)) => {
+                                MigratedCorridor::Tagged(currency, country)
+                            }
+                            None => MigratedCorridor::Untagged,
+                        },
+                        closed_at: crate::storage::get_remittance_closed_at(env, id),
+                    });
+                }
+            }
+            MigrationPayload::Remittances(remittances)
+        }
+        Category::Receipts => {
+            let mut receipts = Vec::new(env);
+            for id in start + 1..=end {
+                if let Ok(receipt) = crate::receipts::get_receipt_by_remittance(env, id) {
+                    receipts.push_back(receipt);
+                }
+            }
+            MigrationPayload::Receipts(receipts)
+        }
+        Category::Users => {
+            let users = crate::registry::list(env, RegistryKind::User, start as u32, page);
+            let mut states = Vec::new(env);
+            for i in 0..users.len() {
+                let user = users.get_unchecked(i);
+                states.push_back(MigratedUser {
+                    last_settlement_time: crate::storage::get_last_settlement_time(env, &user),
+                    transfers: crate::storage::get_user_transfers(env, &user),
+                    user,
+                });
+            }
+            MigrationPayload::Users(states)
+        }
+        Category::ArchivePeriods => {
+            let periods = crate::archive::list_periods(env);
+            let mut summaries = Vec::new(env);
+            for i in start as u32..end as u32 {
+                if let Some(summary) = crate::archive::get_summary(env, periods.get_unchecked(i)) {
+                    summaries.push_back(summary);
+                }
+            }
+            MigrationPayload::ArchivePeriods(summaries)
+        }
+        Category::ArchiveTotals => {
+            MigrationPayload::ArchiveTotals(archive_totals(env, plan, start, end))
+        }
+        Category::ArchivedRemittances => {
+            let mut markers = Vec::new(env);
+            for id in start + 1..=end {
+                if let Some(period_id) = crate::archive::get_archived_period(env, id) {
+                    markers.push_back(MigratedArchiveMarker {
+                        remittance_id: id,
+                        period_id,
+                    });
+                }
+            }
+            MigrationPayload::ArchivedRemittances(markers)
+        }
+    };
+
+    Ok(payload)
+}
+
+/// Lists the non-zero fees of the non-USDC tokens among token registry
+/// positions `[start, start + limit)`, including removed tokens.
+fn collect_token_fees(
+    env: &Env,
+    start: u32,
+    limit: u32,
+) -> Result<Vec<MigratedTokenFees>, ContractError> {
+    let usdc_token = crate::storage::get_usdc_token(env)?;
+    let tokens = crate::registry::list_appended(env, RegistryKind::Token, start, limit);
+    let mut migrated = Vec::new(env);
+    for token in tokens.iter() {
+        if token == usdc_token {
+            continue;
+        }
+        let fees = crate::storage::get_token_fees(env, &token)?;
+        if fees != 0 {
+            migrated.push_back(MigratedTokenFees { token, fees });
+        }
+    }
+    Ok(migrated)
+}
+
+fn role_positions(env: &Env) -> Vec<u32> {
+    let mut positions = Vec::new(env);
+    for role in crate::roles::ALL_ROLES {
+        positions.push_back(crate::registry::len(env, RegistryKind::Role(role)));
+    }
+    positions
+}
+
+/// Lists the role holders among positions `[start, end)` of the role
+/// registries laid end to end in `ALL_ROLES` order.
+fn role_grants(env: &Env, plan: &MigrationPlan, start: u64, end: u64) -> Vec<RoleGrant> {
+    let mut grants = Vec::new(env);
+    let mut first = 0u64;
+    for (i, role) in crate::roles::ALL_ROLES.into_iter().enumerate() {
+        let last = first + plan.role_positions.get_unchecked(i as u32) as u64;
+        let from = start.max(first);
+        let to = end.min(last);
+        if from < to {
+            let holders = crate::registry::list(
+                env,
+                RegistryKind::Role(role),
+                (from - first) as u32,
+                (to - from) as u32,
+            );
+            for account in holders.iter() {
+                grants.push_back(RoleGrant { role, account });
+            }
+        }
+        first = last;
+    }
+    grants
+}
+
+fn archive_pair_counts(env: &Env) -> Vec<u32> {
+    let mut counts = Vec::new(env);
+    for period_id in crate::archive::list_periods(env).iter() {
+        if let Some(summary) = crate::archive::get_summary(env, period_id) {
+            counts.push_back(summary.pair_count);
+        }
+    }
+    counts
+}
+
+/// Lists the archive totals among positions `[start, end)` of the periods'
+/// totals laid end to end in `list_periods` order.
+fn archive_totals(
+    env: &Env,
+    plan: &MigrationPlan,
+    start: u64,
+    end: u64,
+) -> Vec<MigratedArchiveTotals> {
+    let periods = crate::archive::list_periods(env);
+    let mut migrated = Vec::new(env);
+    let mut first = 0u64;
+    for i in 0..plan.archive_pair_counts.len() {
+        let period_id = periods.get_unchecked(i);
+        let last = first + plan.archive_pair_counts.get_unchecked(i) as u64;
+        for position in start.max(first)..end.min(last) {
+            let position = (position - first) as u32;
+            if let Some((agent, token)) = crate::archive::get_pair(env, period_id, position) {
+                if let Some(totals) = crate::archive::get_totals(env, period_id, &agent, &token) {
+                    migrated.push_back(MigratedArchiveTotals {
+                        period_id,
+                        position,
+                        totals,
+                    });
+                }
+            }
+        }
+        first = last;
+    }
+    migrated
+}
+
+fn import_config(env: &Env, config: &MigrationConfig) -> Result<(), ContractError> {
+    // Set first, so the entries written by this and later batches get the source's TTLs
+    crate::ttl::set_policy(env, &config.ttl_policy)?;
+    crate::storage::set_usdc_token(env, &config.usdc_token);
+    crate::storage::set_platform_fee_bps(env, config.platform_fee_bps);
+    crate::storage::set_remittance_counter(env, config.remittance_counter);
+    crate::storage::set_accumulated_fees(env, config.accumulated_fees);
+    if let GlobalPause::Paused(record) = &config.global_pause {
+        crate::pause::set_global(env, Some(record.clone()));
+    }
+    crate::storage::set_paused(env, config.paused);
+    crate::storage::set_admin_count(env, config.admin_count);
+    crate::multisig::set_threshold(env, config.approval_threshold)?;
+    crate::admin_transfer::set_recovery_key(env, &config.recovery_key);
+    crate::storage::set_rate_limit_cooldown(env, config.rate_limit_cooldown);
+    crate::kyc::set_required(env, config.kyc_required);
+    crate::sanctions::set_screening_contract(env, &config.screening_contract);
+    crate::treasury::restore_config(env, config.withdrawal_timelock, config.withdrawal_counter);
+    crate::rate_limit::set_circuit_breaker_config(env, &config.circuit_breaker)?;
+    if let MigratedBreakerState::Tripped(trip) = &config.circuit_breaker_state {
+        crate::rate_limit::restore_circuit_breaker_trip(env, trip);
+    }
+    crate::fx::set_oracle(env, &config.fx_oracle);
+    crate::fx::set_max_rate_age(env, config.fx_max_rate_age)?;
+    crate::upgrade::set_schema_version(env, crate::STORAGE_SCHEMA_VERSION);
+    Ok(())
+}
+
+fn import_payload(env: &Env, payload: &MigrationPayload) -> Result<(), ContractError> {
+    match payload {
+        MigrationPayload::Config(_) => {}
+        MigrationPayload::Admins(admins) => {
+            for admin in admins.iter() {
+                crate::storage::set_admin_role(env, &admin, true);
+            }
+        }
+        MigrationPayload::Roles(grants) => {
+            for grant in grants.iter() {
+                crate::roles::set_role(env, grant.role, &grant.account, true);
+            }
+        }
+        MigrationPayload::Tokens(tokens) => {
+            for token in tokens.iter() {
+                crate::storage::set_token_whitelisted(env, &token, true);
+            }
+        }
+        MigrationPayload::TokenFees(token_fees) => {
+            for migrated in token_fees.iter() {
+                crate::storage::add_accumulated_fees(env, &migrated.token, migrated.fees)?;
+            }
+        }
+        MigrationPayload::Agents(agents) => {
+            for agent in agents.iter() {
+                crate::storage::set_agent_registered(env, &agent, true);
+            }
+        }
+        MigrationPayload::DailyLimits(limits) => {
+            for limit in limits.iter() {
+                crate::storage::set_daily_limit(env, &limit.currency, &limit.country, limit.limit);
+            }
+        }
+        MigrationPayload::KycTiers(tiers) => {
+            for migrated in tiers.iter() {
+                crate::kyc::set_tier_limits(env, migrated.tier, &Some(migrated.limits))?;
+            }
+        }
+        MigrationPayload::KycRecords(records) => {
+            for migrated in records.iter() {
+                crate::kyc::restore_record(env, &migrated.account, &migrated.record);
+            }
+        }
+        MigrationPayload::Denylist(entries) => {
+            for migrated in entries.iter() {
+                crate::sanctions::restore_entry(env, &migrated.account, &migrated.entry);
+            }
+        }
+        MigrationPayload::TreasuryDestinations(destinations) => {
+            for destination in destinations.iter() {
+                crate::treasury::set_destination(env, &destination, true);
+            }
+        }
+        MigrationPayload::FeeWithdrawals(withdrawals) => {
+            for withdrawal in withdrawals.iter() {
+                crate::treasury::restore_withdrawal(env, &withdrawal);
+            }
+        }
+        MigrationPayload::PauseScopes(pauses) => {
+            for pause in pauses.iter() {
+                crate::pause::pause_scope(env, &pause.scope, &pause.record);
+            }
+        }
+        MigrationPayload::VelocityLimits(limits) => {
+            for limit in limits.iter() {
+                crate::limits::set_velocity_limit(env, &limit)?;
+            }
+        }
+        MigrationPayload::TransactionLimits(limits) => {
+            for migrated in limits.iter() {
+                crate::limits::set_transaction_limits(env, &migrated.scope, &Some(migrated.limits))?;
+            }
+        }
+        MigrationPayload::FxRates(rates) => {
+            for rate in rates.iter() {
+                crate::fx::restore_rate(env, &rate);
+            }
+        }
+        MigrationPayload::Remittances(remittances) => {
+            for migrated in remittances.iter() {
+                if let Some(closed_at) = migrated.closed_at {
+                    crate::storage::set_remittance_closed_at(env, migrated.remittance.id, closed_at);
+                }
+                crate::storage::set_remittance(env, migrated.remittance.id, &migrated.remittance);
+                if migrated.settled {
+                    crate::storage::set_settlement_hash(env, migrated.remittance.id);
+                }
+                if let MigratedCorridor::Tagged(currency, country) = migrated.corridor {
+                    crate::storage::set_remittance_corridor(
+                        env,
+                        migrated.remittance.id,
+                        &currency,
+                        &country,
+                    );
+                }
+            }
+        }
+        MigrationPayload::Receipts(receipts) => {
+            for receipt in receipts.iter() {
+                crate::receipts::restore_receipt(env, &receipt);
+            }
+        }
+        MigrationPayload::Users(users) => {
+            for state in users.iter() {
+                if let Some(timestamp) = state.last_settlement_time {
+                    crate::storage::set_last_settlement_time(env, &state.user, timestamp);
+                }
+                crate::storage::set_user_transfers(env, &state.user, &state.transfers);
+            }
+        }
+        MigrationPayload::ArchivePeriods(summaries) => {
+            for summary in summaries.iter() {
+                crate::archive::restore_summary(env, &summary);
+            }
+        }
+        MigrationPayload::ArchiveTotals(totals) => {
+            for migrated in totals.iter() {
+                crate::archive::restore_totals(
+                    env,
+                    migrated.period_id,
+                    migrated.position,
+                    &migrated.totals,
+                );
+            }
+        }
+        MigrationPayload::ArchivedRemittances(markers) => {
+            for marker in markers.iter() {
+                crate::archive::restore_archived(env, marker.remittance_id, marker.period_id);
+            }
+        }
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Import Authorization
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// An uninitialized contract would otherwise accept state from anyone. The
+// deployer names the only address allowed to import, and the only contract
+// whose exports it accepts, right after deployment and before publishing the
+// contract address; both import paths then require that address's
+// authorization and data exported by that contract.
+
+/// Sets the address allowed to import state into this contract and the
+/// contract the state must come from.
+///
+/// Can only be called once, on an uninitialized contract.
+pub fn configure_import_admin(
+    env: &Env,
+    import_admin: &Address,
+    source_contract: &Address,
+) -> Result<(), ContractError> {
+    import_admin.require_auth();
+    if crate::storage::has_admin(env) || get_import_admin(env).is_some() {
+        return Err(ContractError::AlreadyInitialized);
+    }
+    let storage = env.storage().instance();
+    storage.set(&MigrationKey::ImportAdmin, import_admin);
+    storage.set(&MigrationKey::ImportSource, source_contract);
+    Ok(())
+}
+
+/// Returns the address allowed to import state, if configured.
+pub fn get_import_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&MigrationKey::ImportAdmin)
+}
+
+/// Returns the contract whose exports can be imported, if configured.
+pub fn get_import_source(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&MigrationKey::ImportSource)
+}
+
+/// Fails unless `source_contract` is the source set with the import admin.
+fn require_import_source(env: &Env, source_contract: &Address) -> Result<(), ContractError> {
+    if get_import_source(env).as_ref() != Some(source_contract) {
+        return Err(ContractError::MigrationSourceMismatch);
+    }
+    Ok(())
+}
+
+/// Fails unless `caller` is the configured import admin and authorized the call.
+fn require_import_admin(env: &Env, caller: &Address) -> Result<(), ContractError> {
+    let import_admin = get_import_admin(env).ok_or(ContractError::Unauthorized)?;
+    caller.require_auth();
+    if *caller != import_admin {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Migration Mode
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// A snapshot only stays accurate if the source stops changing once it is
+// taken. The admin therefore moves the source contract through:
+//
+//   Active → Migrating → Migrated(successor)
+//
+// While migrating, the contract is read-only except for refunds, so exports
+// taken in this window are final. Once marked migrated, every mutating
+// entrypoint fails with `ContractMigrated` and `get_migration_mode` names the
+// successor contract.
+
+/// Lifecycle of a contract being migrated to a successor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MigrationMode {
+    /// Normal operation
+    Active,
+    /// Migration started: read-only except refunds
+    Migrating,
+    /// Migration complete: all state lives in the successor contract
+    Migrated(Address),
+}
+
+/// Returns the migration mode of this contract.
+pub fn get_mode(env: &Env) -> MigrationMode {
+    env.storage()
+        .instance()
+        .get(&MigrationKey::Mode)
+        .unwrap_or(MigrationMode::Active)
+}
+
+/// Enters migration mode.
+pub fn start_migration(env: &Env) -> Result<(), ContractError> {
+    match get_mode(env) {
+        MigrationMode::Active => {}
+        MigrationMode::Migrating => return Err(ContractError::MigrationInProgress),
+        MigrationMode::Migrated(_) => return Err(ContractError::ContractMigrated),
+    }
+    env.storage()
+        .instance()
+        .set(&MigrationKey::Mode, &MigrationMode::Migrating);
+    Ok(())
+}
+
+/// Fails unless the contract is in migration mode. Exports require it so the
+/// exported state cannot change underneath them.
+pub fn require_migrating(env: &Env) -> Result<(), ContractError> {
+    match get_mode(env) {
+        MigrationMode::Migrating => Ok(()),
+        MigrationMode::Active => Err(ContractError::InvalidStatus),
+        MigrationMode::Migrated(_) => Err(ContractError::ContractMigrated),
+    }
+}
+
+/// Marks the contract as migrated to `successor`. Requires migration mode.
+pub fn complete_migration(env: &Env, successor: &Address) -> Result<(), ContractError> {
+    require_migrating(env)?;
+    env.storage()
+        .instance()
+        .set(&MigrationKey::Mode, &MigrationMode::Migrated(successor.clone()));
+    Ok(())
+}
+
+/// Fails unless the contract is active. Guards the batched import itself,
+/// which has to keep running while the imported state is incomplete.
+pub fn require_active(env: &Env) -> Result<(), ContractError> {
+    match get_mode(env) {
+        MigrationMode::Active => Ok(()),
+        MigrationMode::Migrating => Err(ContractError::MigrationInProgress),
+        MigrationMode::Migrated(_) => Err(ContractError::ContractMigrated),
+    }
+}
+
+/// Fails while a batched import has started but is not finalized. Until then
+/// the contract holds part of the source state, so neither writes nor reads of
+/// remittances, fees or configuration can be trusted.
+pub fn require_import_complete(env: &Env) -> Result<(), ContractError> {
+    let status = get_import_status(env);
+    if status.next_batch > 0 && !status.finalized {
+        return Err(ContractError::MigrationInProgress);
+    }
+    Ok(())
+}
+
+/// Fails unless the contract is active and holds no partial import. Guards
+/// every mutating entrypoint except refunds.
+pub fn require_writable(env: &Env) -> Result<(), ContractError> {
+    require_active(env)?;
+    require_import_complete(env)
+}
+
+/// Fails once the contract is migrated, or while a batched import is
+/// incomplete. Refunds remain available while migrating so senders are never
+/// locked out of their funds.
+pub fn require_refundable(env: &Env) -> Result<(), ContractError> {
+    match get_mode(env) {
+        MigrationMode::Migrated(_) => Err(ContractError::ContractMigrated),
+        _ => require_import_complete(env),
     }
-    
-    env.crypto().sha256(&data)
 }
 
 #[cfg(test)]
@@ -522,9 +1899,10 @@ mod tests {
     #[test]
     fn test_snapshot_hash_deterministic() {
         let env = Env::default();
+        let source = Address::generate(&env);
+        let network = env.ledger().network_id();
         
         let instance_data = InstanceData {
-            admin: Address::generate(&env),
             usdc_token: Address::generate(&env),
             platform_fee_bps: 250,
             remittance_counter: 10,
@@ -539,10 +1917,11 @@ mod tests {
             admin_roles: Vec::new(&env),
             settlement_hashes: Vec::new(&env),
             whitelisted_tokens: Vec::new(&env),
+            token_fees: Vec::new(&env),
         };
         
-        let hash1 = compute_snapshot_hash(&env, &instance_data, &persistent_data, 1000, 100);
-        let hash2 = compute_snapshot_hash(&env, &instance_data, &persistent_data, 1000, 100);
+        let hash1 = compute_snapshot_hash(&env, &source, &network, &instance_data, &persistent_data, 1000, 100);
+        let hash2 = compute_snapshot_hash(&env, &source, &network, &instance_data, &persistent_data, 1000, 100);
         
         assert_eq!(hash1, hash2);
     }
@@ -550,9 +1929,10 @@ mod tests {
     #[test]
     fn test_snapshot_hash_changes_with_data() {
         let env = Env::default();
+        let source = Address::generate(&env);
+        let network = env.ledger().network_id();
         
         let instance_data1 = InstanceData {
-            admin: Address::generate(&env),
             usdc_token: Address::generate(&env),
             platform_fee_bps: 250,
             remittance_counter: 10,
@@ -562,7 +1942,6 @@ mod tests {
         };
         
         let instance_data2 = InstanceData {
-            admin: instance_data1.admin.clone(),
             usdc_token: instance_data1.usdc_token.clone(),
             platform_fee_bps: 300, // Different fee
             remittance_counter: 10,
@@ -577,11 +1956,77 @@ mod tests {
             admin_roles: Vec::new(&env),
             settlement_hashes: Vec::new(&env),
             whitelisted_tokens: Vec::new(&env),
+            token_fees: Vec::new(&env),
         };
         
-        let hash1 = compute_snapshot_hash(&env, &instance_data1, &persistent_data, 1000, 100);
-        let hash2 = compute_snapshot_hash(&env, &instance_data2, &persistent_data, 1000, 100);
+        let hash1 = compute_snapshot_hash(&env, &source, &network, &instance_data1, &persistent_data, 1000, 100);
+        let hash2 = compute_snapshot_hash(&env, &source, &network, &instance_data2, &persistent_data, 1000, 100);
         
         assert_ne!(hash1, hash2);
     }
+
+    #[test]
+    fn test_snapshot_hash_binds_source_and_network() {
+        let env = Env::default();
+        let source = Address::generate(&env);
+        let network = env.ledger().network_id();
+
+        let instance_data = InstanceData {
+            usdc_token: Address::generate(&env),
+            platform_fee_bps: 250,
+            remittance_counter: 10,
+            accumulated_fees: 1000,
+            paused: false,
+            admin_count: 1,
+        };
+        let persistent_data = PersistentData {
+            remittances: Vec::new(&env),
+            agents: Vec::new(&env),
+            admin_roles: Vec::new(&env),
+            settlement_hashes: Vec::new(&env),
+            whitelisted_tokens: Vec::new(&env),
+            token_fees: Vec::new(&env),
+        };
+
+        let hash = compute_snapshot_hash(&env, &source, &network, &instance_data, &persistent_data, 1000, 100);
+        let other_source = Address::generate(&env);
+        let other_network = BytesN::from_array(&env, &[7u8; 32]);
+        assert_ne!(
+            hash,
+            compute_snapshot_hash(&env, &other_source, &network, &instance_data, &persistent_data, 1000, 100)
+        );
+        assert_ne!(
+            hash,
+            compute_snapshot_hash(&env, &source, &other_network, &instance_data, &persistent_data, 1000, 100)
+        );
+    }
+
+    #[test]
+    fn test_batch_hash_binds_position_and_payload() {
+        let env = Env::default();
+
+        let mut agents = Vec::new(&env);
+        agents.push_back(Address::generate(&env));
+        let payload = MigrationPayload::Agents(agents.clone());
+
+        let hash = compute_batch_hash(&env, 3, 8, &payload);
+        assert_eq!(hash, compute_batch_hash(&env, 3, 8, &payload));
+        assert_ne!(hash, compute_batch_hash(&env, 4, 8, &payload));
+        assert_ne!(hash, compute_batch_hash(&env, 3, 9, &payload));
+
+        agents.push_back(Address::generate(&env));
+        assert_ne!(hash, compute_batch_hash(&env, 3, 8, &MigrationPayload::Agents(agents)));
+    }
+
+    #[test]
+    fn test_rolling_hash_depends_on_batch_order() {
+        let env = Env::default();
+        let zero = BytesN::from_array(&env, &[0u8; 32]);
+        let a = BytesN::from_array(&env, &[1u8; 32]);
+        let b = BytesN::from_array(&env, &[2u8; 32]);
+
+        let ab = chain_hash(&env, &chain_hash(&env, &zero, &a), &b);
+        let ba = chain_hash(&env, &chain_hash(&env, &zero, &b), &a);
+        assert_ne!(ab, ba);
+    }
 }
@@ -0,0 +1,242 @@
+//! M-of-N admin approvals for sensitive actions.
+//!
+//! Fee changes, pausing and unpausing, lifting scoped pauses, agent removal, migration, admin and role
+//! management, and treasury configuration can be put behind a quorum of
+//! admins. An admin proposes an `AdminAction`, which stores the action with an
+//! ID and an expiry and counts as the proposer's approval.
+//! Other admins approve it, and once the approvals of current admins reach the
+//! approval threshold any admin executes it, replaying the stored action.
+//!
+//! The threshold starts at 1, where the direct entrypoints (`update_fee`,
+//! `pause`, ...) keep working. Raising it above 1 disables them: the actions
+//! can then only run through an approved proposal. The threshold itself is
+//! changed through a proposal, so a single admin cannot lower it.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::ttl::{self, TtlClass};
+use crate::{ContractError, PauseScope, Role};
+
+/// How long a proposal can collect approvals and be executed (7 days).
+pub const PROPOSAL_LIFETIME_SECONDS: u64 = 7 * 86_400;
+
+/// An admin action that can require M-of-N approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    /// Set the platform fee (basis points)
+    UpdateFee(u32),
+    /// Pause the contract, recording the reason code
+    Pause(u32),
+    Unpause,
+    /// Lift the pause of an operation, agent, token or corridor
+    UnpauseScope(PauseScope),
+    /// Remove a payout agent
+    RemoveAgent(Address),
+    /// Put the contract into migration mode
+    StartMigration,
+    /// Mark the contract as migrated to the given successor
+    CompleteMigration(Address),
+    /// Set the number of admin approvals required
+    SetApprovalThreshold(u32),
+    /// Set the fee withdrawal timelock (seconds)
+    SetWithdrawalTimelock(u64),
+    /// Add an address to the treasury allow-list
+    AddTreasuryDestination(Address),
+    /// Grant the admin role to an address
+    AddAdmin(Address),
+    /// Revoke the admin role from an address
+    RemoveAdmin(Address),
+    /// Grant an operational role to an address
+    GrantRole(Role, Address),
+    /// Set or clear the recovery key
+    SetRecoveryKey(Option<Address>),
+}
+
+/// Lifecycle of an admin proposal.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    /// Collecting approvals; executable once approved and before expiry
+    Pending,
+    /// The action has been executed
+    Executed,
+}
+
+/// A proposed admin action and its approvals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposal {
+    pub id: u64,
+    pub action: AdminAction,
+    pub proposer: Address,
+    /// Admins that approved, in approval order (the proposer first)
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    /// Timestamp after which the proposal can no longer be approved or executed
+    pub expires_at: u64,
+    pub status: ProposalStatus,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum MultisigKey {
+    /// Number of admin approvals required (instance storage)
+    Threshold,
+    /// ID of the last proposal (instance storage)
+    ProposalCounter,
+    /// Proposal indexed by ID (persistent storage)
+    Proposal(u64),
+}
+
+/// Returns the number of admin approvals required to execute a proposal.
+pub fn get_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&MultisigKey::Threshold)
+        .unwrap_or(1)
+}
+
+/// Fails when actions require M-of-N approval, so direct entrypoints are disabled.
+///
+/// # Errors
+/// - MultisigRequired: The approval threshold is above 1
+pub fn require_direct(env: &Env) -> Result<(), ContractError> {
+    if get_threshold(env) > 1 {
+        return Err(ContractError::MultisigRequired);
+    }
+    Ok(())
+}
+
+/// Records a proposal, approved by its proposer.
+///
+/// # Errors
+/// - InvalidLimitConfig: A `SetApprovalThreshold` action outside `1..=admin count`,
+///   or a `SetWithdrawalTimelock` action outside the allowed timelock range
+pub fn propose(
+    env: &Env,
+    proposer: &Address,
+    action: AdminAction,
+) -> Result<AdminProposal, ContractError> {
+    match action {
+        AdminAction::SetApprovalThreshold(threshold) => validate_threshold(env, threshold)?,
+        AdminAction::SetWithdrawalTimelock(seconds) => crate::treasury::validate_timelock(seconds)?,
+        _ => {}
+    }
+
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&MultisigKey::ProposalCounter)
+        .unwrap_or(0)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&MultisigKey::ProposalCounter, &id);
+
+    let now = env.ledger().timestamp();
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+    let proposal = AdminProposal {
+        id,
+        action,
+        proposer: proposer.clone(),
+        approvals,
+        created_at: now,
+        expires_at: now.saturating_add(PROPOSAL_LIFETIME_SECONDS),
+        status: ProposalStatus::Pending,
+    };
+    set_proposal(env, &proposal);
+
+    Ok(proposal)
+}
+
+/// Adds an admin's approval to a pending proposal.
+///
+/// # Errors
+/// - ProposalNotFound, InvalidStatus, ProposalExpired
+/// - AlreadyApproved: The admin already approved the proposal
+pub fn approve(env: &Env, admin: &Address, id: u64) -> Result<AdminProposal, ContractError> {
+    let mut proposal = get_open(env, id)?;
+    if proposal.approvals.contains(admin) {
+        return Err(ContractError::AlreadyApproved);
+    }
+
+    proposal.approvals.push_back(admin.clone());
+    set_proposal(env, &proposal);
+
+    Ok(proposal)
+}
+
+/// Marks an approved proposal as executed and returns the action to replay.
+///
+/// Only approvals from addresses that are still admins count.
+///
+/// # Errors
+/// - ProposalNotFound, InvalidStatus, ProposalExpired
+/// - InsufficientApprovals: Fewer current-admin approvals than the threshold
+pub fn take_for_execution(env: &Env, id: u64) -> Result<AdminAction, ContractError> {
+    let mut proposal = get_open(env, id)?;
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|admin| crate::storage::is_admin(env, admin))
+        .count() as u32;
+    if approvals < get_threshold(env) {
+        return Err(ContractError::InsufficientApprovals);
+    }
+
+    proposal.status = ProposalStatus::Executed;
+    set_proposal(env, &proposal);
+
+    Ok(proposal.action)
+}
+
+/// Sets the approval threshold.
+///
+/// # Errors
+/// - InvalidLimitConfig: Zero or more than the number of admins
+pub fn set_threshold(env: &Env, threshold: u32) -> Result<(), ContractError> {
+    validate_threshold(env, threshold)?;
+    env.storage()
+        .instance()
+        .set(&MultisigKey::Threshold, &threshold);
+    Ok(())
+}
+
+/// Retrieves a proposal by ID.
+pub fn get_proposal(env: &Env, id: u64) -> Result<AdminProposal, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&MultisigKey::Proposal(id))
+        .ok_or(ContractError::ProposalNotFound)
+}
+
+fn validate_threshold(env: &Env, threshold: u32) -> Result<(), ContractError> {
+    if threshold == 0 || threshold > crate::storage::get_admin_count(env) {
+        return Err(ContractError::InvalidLimitConfig);
+    }
+    Ok(())
+}
+
+fn get_open(env: &Env, id: u64) -> Result<AdminProposal, ContractError> {
+    let proposal = get_proposal(env, id)?;
+    if proposal.status != ProposalStatus::Pending {
+        return Err(ContractError::InvalidStatus);
+    }
+    if env.ledger().timestamp() > proposal.expires_at {
+        return Err(ContractError::ProposalExpired);
+    }
+    Ok(proposal)
+}
+
+fn set_proposal(env: &Env, proposal: &AdminProposal) {
+    let key = MultisigKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    let class = match proposal.status {
+        ProposalStatus::Pending => TtlClass::Active,
+        ProposalStatus::Executed => TtlClass::Terminal,
+    };
+    ttl::extend_persistent(env, &key, class);
+}
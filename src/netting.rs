@@ -1,21 +1,31 @@
+use core::cmp::Ordering;
+
 use soroban_sdk::{contracttype, Address, Env, Map, Vec};
 
-use crate::{ContractError, Remittance, RemittanceStatus};
+use crate::{compare_addresses, convert, resolve_rate, ContractError, FxRate, Remittance, RemittanceStatus};
+
+/// Maximum number of remittances accepted by a single `batch_settle_with_netting` call.
+pub const MAX_BATCH_SIZE: u32 = 50;
 
 /// Represents a net transfer between two parties after offsetting opposing flows.
 /// This structure ensures deterministic ordering by always placing the party
-/// with the lexicographically smaller address as party_a.
+/// that sorts first under `hashing::compare_addresses` as party_a.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NetTransfer {
-    /// The party with the lexicographically smaller address (deterministic ordering)
+    /// The party whose address sorts first in canonical XDR byte order
     pub party_a: Address,
-    /// The party with the lexicographically larger address (deterministic ordering)
+    /// The party whose address sorts last in canonical XDR byte order
     pub party_b: Address,
+    /// Token the net amount and fees are denominated in
+    pub token: Address,
     /// Net amount to transfer. Positive means A -> B, negative means B -> A
     pub net_amount: i128,
     /// Accumulated fees from all netted remittances
     pub total_fees: i128,
+    /// FX rates applied to convert netted remittances into `token`.
+    /// Empty when every netted remittance was already denominated in `token`.
+    pub fx_rates: Vec<FxRate>,
 }
 
 /// Represents a directional flow between two parties before netting.
@@ -24,6 +34,10 @@ pub struct NetTransfer {
 struct DirectionalFlow {
     from: Address,
     to: Address,
+    /// Token the flow is netted in
+    token: Address,
+    /// Token the remittance was denominated in (differs from `token` once converted)
+    source_token: Address,
     amount: i128,
     fee: i128,
 }
@@ -31,10 +45,13 @@ struct DirectionalFlow {
 /// Computes net settlements by offsetting opposing transfers between the same parties.
 /// 
 /// This function implements a deterministic netting algorithm that:
-/// 1. Groups all pending remittances by party pairs (order-independent)
-/// 2. Calculates net balances for each pair
+/// 1. Groups all pending remittances by party pairs and token (order-independent)
+/// 2. Calculates net balances for each pair within each token
 /// 3. Returns only the net difference that needs to be executed on-chain
 /// 
+/// Remittances in different tokens are never offset against each other; use
+/// `compute_cross_currency_net_settlements` for that.
+/// 
 /// # Algorithm Properties
 /// - Order-independent: Processing remittances in any order yields the same result
 /// - Deterministic: Same input always produces the same output
@@ -55,9 +72,73 @@ struct DirectionalFlow {
 /// # Returns
 /// Vector of NetTransfer structs representing the minimal set of transfers needed
 pub fn compute_net_settlements(env: &Env, remittances: &Vec<Remittance>) -> Vec<NetTransfer> {
+    let flows = extract_flows(env, remittances);
+    net_flows(env, &flows, &Map::new(env))
+}
+
+/// Computes net settlements across tokens by converting every remittance into
+/// a single settlement token before offsetting.
+/// 
+/// Each remittance not already denominated in `settlement_token` is converted
+/// with the rate from `fx::resolve_rate` (admin-posted, else oracle-supplied),
+/// rounding down. A rate is resolved once per source token so every remittance
+/// in the batch uses the same rate, and the rates used for each pair are
+/// reported in `NetTransfer::fx_rates` so reconciliation can reproduce them.
+/// 
+/// # Parameters
+/// - `env`: Environment reference
+/// - `remittances`: Vector of remittances to net
+/// - `settlement_token`: Token every net transfer is denominated in
+/// 
+/// # Returns
+/// Vector of NetTransfer structs, all denominated in `settlement_token`
+/// 
+/// # Errors
+/// - FxRateNotAvailable: No rate could be resolved for one of the source tokens
+/// - Overflow: Arithmetic overflow while converting amounts
+pub fn compute_cross_currency_net_settlements(
+    env: &Env,
+    remittances: &Vec<Remittance>,
+    settlement_token: &Address,
+) -> Result<Vec<NetTransfer>, ContractError> {
+    let flows = extract_flows(env, remittances);
+    let mut rates: Map<Address, FxRate> = Map::new(env);
+    let mut converted: Vec<DirectionalFlow> = Vec::new(env);
+
+    for i in 0..flows.len() {
+        let flow = flows.get_unchecked(i);
+
+        if flow.token == *settlement_token {
+            converted.push_back(flow);
+            continue;
+        }
+
+        let fx_rate = match rates.get(flow.token.clone()) {
+            Some(fx_rate) => fx_rate,
+            None => {
+                let fx_rate = resolve_rate(env, &flow.token, settlement_token)?;
+                rates.set(flow.token.clone(), fx_rate.clone());
+                fx_rate
+            }
+        };
+
+        converted.push_back(DirectionalFlow {
+            from: flow.from,
+            to: flow.to,
+            token: settlement_token.clone(),
+            source_token: flow.source_token,
+            amount: convert(flow.amount, &fx_rate)?,
+            fee: convert(flow.fee, &fx_rate)?,
+        });
+    }
+
+    Ok(net_flows(env, &converted, &rates))
+}
+
+/// Extracts the directional flow of every pending remittance.
+fn extract_flows(env: &Env, remittances: &Vec<Remittance>) -> Vec<DirectionalFlow> {
     let mut flows: Vec<DirectionalFlow> = Vec::new(env);
     
-    // Extract all directional flows from remittances
     for i in 0..remittances.len() {
         let remittance = remittances.get_unchecked(i);
         
@@ -69,19 +150,33 @@ pub fn compute_net_settlements(env: &Env, remittances: &Vec<Remittance>) -> Vec<
         flows.push_back(DirectionalFlow {
             from: remittance.sender.clone(),
             to: remittance.agent.clone(),
+            token: remittance.token.clone(),
+            source_token: remittance.token.clone(),
             amount: remittance.amount,
             fee: remittance.fee,
         });
     }
     
-    // Group flows by party pairs and compute net balances
-    let mut net_map: Map<(Address, Address), (i128, i128)> = Map::new();
+    flows
+}
+
+/// Offsets flows per (party pair, token) and returns the net transfers. A pair
+/// that offsets completely is kept with a zero amount so its fees are still booked.
+/// `rates` maps each converted source token to the rate that was applied to it.
+fn net_flows(
+    env: &Env,
+    flows: &Vec<DirectionalFlow>,
+    rates: &Map<Address, FxRate>,
+) -> Vec<NetTransfer> {
+    // Group flows by party pairs and token, and compute net balances
+    let mut net_map: Map<(Address, Address, Address), (i128, i128)> = Map::new(env);
+    let mut rate_map: Map<(Address, Address, Address), Vec<FxRate>> = Map::new(env);
     
     for i in 0..flows.len() {
         let flow = flows.get_unchecked(i);
         let (party_a, party_b, direction) = normalize_pair(&flow.from, &flow.to);
         
-        let key = (party_a.clone(), party_b.clone());
+        let key = (party_a.clone(), party_b.clone(), flow.token.clone());
         let (current_net, current_fees) = net_map.get(key.clone()).unwrap_or((0, 0));
         
         // Apply the flow in the normalized direction
@@ -90,7 +185,17 @@ pub fn compute_net_settlements(env: &Env, remittances: &Vec<Remittance>) -> Vec<
         let new_net = current_net + (flow.amount * direction);
         let new_fees = current_fees + flow.fee;
         
-        net_map.set(key, (new_net, new_fees));
+        net_map.set(key.clone(), (new_net, new_fees));
+
+        // Record each distinct rate that fed into this pair
+        if flow.source_token != flow.token {
+            let fx_rate = rates.get_unchecked(flow.source_token.clone());
+            let mut used = rate_map.get(key.clone()).unwrap_or(Vec::new(env));
+            if !used.contains(&fx_rate) {
+                used.push_back(fx_rate);
+            }
+            rate_map.set(key, used);
+        }
     }
     
     // Convert map to vector of NetTransfer structs
@@ -101,13 +206,15 @@ pub fn compute_net_settlements(env: &Env, remittances: &Vec<Remittance>) -> Vec<
         let key = keys.get_unchecked(i);
         let (net_amount, total_fees) = net_map.get(key.clone()).unwrap();
         
-        // Only include non-zero net transfers
-        if net_amount != 0 {
+        // Skip pairs that offset completely and carry no fees
+        if net_amount != 0 || total_fees != 0 {
             result.push_back(NetTransfer {
                 party_a: key.0.clone(),
                 party_b: key.1.clone(),
+                token: key.2.clone(),
                 net_amount,
                 total_fees,
+                fx_rates: rate_map.get(key.clone()).unwrap_or(Vec::new(env)),
             });
         }
     }
@@ -119,8 +226,8 @@ pub fn compute_net_settlements(env: &Env, remittances: &Vec<Remittance>) -> Vec<
 /// Returns (smaller_address, larger_address, direction_multiplier)
 /// where direction_multiplier is 1 if from < to, else -1.
 fn normalize_pair(from: &Address, to: &Address) -> (Address, Address, i128) {
-    // Compare addresses lexicographically
-    if compare_addresses(from, to) < 0 {
+    // Canonical ordering shared with the settlement ID hashing standard
+    if compare_addresses(from, to) == Ordering::Less {
         // from < to, so from is party_a, to is party_b
         // Flow is A -> B, direction = +1
         (from.clone(), to.clone(), 1)
@@ -131,40 +238,6 @@ fn normalize_pair(from: &Address, to: &Address) -> (Address, Address, i128) {
     }
 }
 
-/// Compares two addresses lexicographically.
-/// Returns: -1 if a < b, 0 if a == b, 1 if a > b
-fn compare_addresses(a: &Address, b: &Address) -> i32 {
-    // Soroban SDK doesn't provide direct comparison, so we use a workaround
-    // We serialize both addresses and compare their byte representations
-    let a_bytes = a.to_string();
-    let b_bytes = b.to_string();
-    
-    // Compare character by character
-    let a_len = a_bytes.len();
-    let b_len = b_bytes.len();
-    let min_len = if a_len < b_len { a_len } else { b_len };
-    
-    for i in 0..min_len {
-        let a_char = a_bytes.get(i).unwrap();
-        let b_char = b_bytes.get(i).unwrap();
-        
-        if a_char < b_char {
-            return -1;
-        } else if a_char > b_char {
-            return 1;
-        }
-    }
-    
-    // If all compared characters are equal, compare lengths
-    if a_len < b_len {
-        -1
-    } else if a_len > b_len {
-        1
-    } else {
-        0
-    }
-}
-
 /// Validates that net settlement calculations are mathematically correct.
 /// 
 /// Verifies:
@@ -241,6 +314,7 @@ mod tests {
         let env = Env::default();
         let addr_a = Address::generate(&env);
         let addr_b = Address::generate(&env);
+        let token = Address::generate(&env);
         
         let mut remittances = Vec::new(&env);
         
@@ -251,6 +325,7 @@ mod tests {
             agent: addr_b.clone(),
             amount: 100,
             fee: 2,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
@@ -262,23 +337,24 @@ mod tests {
             agent: addr_a.clone(),
             amount: 90,
             fee: 1,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
         
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances);
         
         assert_eq!(net_transfers.len(), 1);
         let transfer = net_transfers.get_unchecked(0);
         
         // Net should be 10 (100 - 90)
-        let expected_net = if compare_addresses(&addr_a, &addr_b) < 0 {
+        let expected_net = if compare_addresses(&addr_a, &addr_b) == Ordering::Less {
             10 // A -> B
         } else {
             -10 // B -> A
         };
         
-        assert_eq!(transfer.net_amount.abs(), 10);
+        assert_eq!(transfer.net_amount, expected_net);
         assert_eq!(transfer.total_fees, 3); // 2 + 1
     }
 
@@ -287,6 +363,7 @@ mod tests {
         let env = Env::default();
         let addr_a = Address::generate(&env);
         let addr_b = Address::generate(&env);
+        let token = Address::generate(&env);
         
         let mut remittances = Vec::new(&env);
         
@@ -297,6 +374,7 @@ mod tests {
             agent: addr_b.clone(),
             amount: 100,
             fee: 2,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
@@ -308,14 +386,18 @@ mod tests {
             agent: addr_a.clone(),
             amount: 100,
             fee: 2,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
         
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances);
         
-        // Complete offset should result in no transfers
-        assert_eq!(net_transfers.len(), 0);
+        // Complete offset moves no funds but still carries both fees
+        assert_eq!(net_transfers.len(), 1);
+        let transfer = net_transfers.get_unchecked(0);
+        assert_eq!(transfer.net_amount, 0);
+        assert_eq!(transfer.total_fees, 4);
     }
 
     #[test]
@@ -324,6 +406,7 @@ mod tests {
         let addr_a = Address::generate(&env);
         let addr_b = Address::generate(&env);
         let addr_c = Address::generate(&env);
+        let token = Address::generate(&env);
         
         let mut remittances = Vec::new(&env);
         
@@ -334,6 +417,7 @@ mod tests {
             agent: addr_b.clone(),
             amount: 100,
             fee: 2,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
@@ -345,6 +429,7 @@ mod tests {
             agent: addr_c.clone(),
             amount: 50,
             fee: 1,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
@@ -356,11 +441,12 @@ mod tests {
             agent: addr_a.clone(),
             amount: 30,
             fee: 1,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
         
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances);
         
         // Should have 3 net transfers (one for each pair)
         assert_eq!(net_transfers.len(), 3);
@@ -378,6 +464,7 @@ mod tests {
         let env = Env::default();
         let addr_a = Address::generate(&env);
         let addr_b = Address::generate(&env);
+        let token = Address::generate(&env);
         
         let mut remittances = Vec::new(&env);
         
@@ -387,6 +474,7 @@ mod tests {
             agent: addr_b.clone(),
             amount: 100,
             fee: 2,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
@@ -397,11 +485,12 @@ mod tests {
             agent: addr_a.clone(),
             amount: 90,
             fee: 1,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
         
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances);
         
         assert!(validate_net_settlement(&remittances, &net_transfers).is_ok());
     }
@@ -411,6 +500,7 @@ mod tests {
         let env = Env::default();
         let addr_a = Address::generate(&env);
         let addr_b = Address::generate(&env);
+        let token = Address::generate(&env);
         
         // First ordering
         let mut remittances1 = Vec::new(&env);
@@ -420,6 +510,7 @@ mod tests {
             agent: addr_b.clone(),
             amount: 100,
             fee: 2,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
@@ -429,6 +520,7 @@ mod tests {
             agent: addr_a.clone(),
             amount: 90,
             fee: 1,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
@@ -441,6 +533,7 @@ mod tests {
             agent: addr_a.clone(),
             amount: 90,
             fee: 1,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
@@ -450,20 +543,246 @@ mod tests {
             agent: addr_b.clone(),
             amount: 100,
             fee: 2,
+            token: token.clone(),
             status: RemittanceStatus::Pending,
             expiry: None,
         });
         
-        let net1 = compute_net_settlements(&remittances1);
-        let net2 = compute_net_settlements(&remittances2);
+        let net1 = compute_net_settlements(&env, &remittances1);
+        let net2 = compute_net_settlements(&env, &remittances2);
         
         // Results should be identical regardless of input order
         assert_eq!(net1.len(), net2.len());
-        if net1.len() > 0 {
+        if !net1.is_empty() {
             let t1 = net1.get_unchecked(0);
             let t2 = net2.get_unchecked(0);
             assert_eq!(t1.net_amount, t2.net_amount);
             assert_eq!(t1.total_fees, t2.total_fees);
         }
     }
+    /// The string-based comparison netting used before switching to XDR bytes,
+    /// kept here only as the baseline for the budget comparison below.
+    fn compare_addresses_by_strkey(a: &Address, b: &Address) -> Ordering {
+        let a_str = a.to_string();
+        let b_str = b.to_string();
+        let mut a_buf = [0u8; 56];
+        let mut b_buf = [0u8; 56];
+        a_str.copy_into_slice(&mut a_buf[..a_str.len() as usize]);
+        b_str.copy_into_slice(&mut b_buf[..b_str.len() as usize]);
+
+        for i in 0..a_str.len().min(b_str.len()) as usize {
+            if a_buf[i] != b_buf[i] {
+                return a_buf[i].cmp(&b_buf[i]);
+            }
+        }
+        a_str.len().cmp(&b_str.len())
+    }
+
+    #[test]
+    fn test_compare_addresses_is_total_order() {
+        let env = Env::default();
+        let addr_a = Address::generate(&env);
+        let addr_b = Address::generate(&env);
+
+        assert_eq!(compare_addresses(&addr_a, &addr_a), Ordering::Equal);
+        assert_eq!(
+            compare_addresses(&addr_a, &addr_b),
+            compare_addresses(&addr_b, &addr_a).reverse()
+        );
+
+        let (party_a, party_b, direction) = normalize_pair(&addr_a, &addr_b);
+        let (party_a2, party_b2, direction2) = normalize_pair(&addr_b, &addr_a);
+        assert_eq!(party_a, party_a2);
+        assert_eq!(party_b, party_b2);
+        assert_eq!(direction, -direction2);
+    }
+
+    #[test]
+    fn test_address_ordering_budget_full_batch() {
+        // Per-transaction CPU instruction limit the default test budget uses
+        const NETWORK_CPU_LIMIT: u64 = 100_000_000;
+
+        let env = Env::default();
+        let token = Address::generate(&env);
+        let mut parties = Vec::new(&env);
+        for _ in 0..=MAX_BATCH_SIZE {
+            parties.push_back(Address::generate(&env));
+        }
+
+        // One comparison per remittance in a full batch, before and after
+        env.budget().reset_unlimited();
+        for i in 0..MAX_BATCH_SIZE {
+            compare_addresses_by_strkey(&parties.get_unchecked(i), &parties.get_unchecked(i + 1));
+        }
+        let strkey_cpu = env.budget().cpu_instruction_cost();
+        let strkey_mem = env.budget().memory_bytes_cost();
+
+        env.budget().reset_unlimited();
+        for i in 0..MAX_BATCH_SIZE {
+            compare_addresses(&parties.get_unchecked(i), &parties.get_unchecked(i + 1));
+        }
+        let canonical_cpu = env.budget().cpu_instruction_cost();
+        let canonical_mem = env.budget().memory_bytes_cost();
+
+        // Full netting pass over a MAX_BATCH_SIZE batch with the new ordering
+        let mut remittances = Vec::new(&env);
+        for i in 0..MAX_BATCH_SIZE {
+            remittances.push_back(Remittance {
+                id: i as u64 + 1,
+                sender: parties.get_unchecked(i),
+                agent: parties.get_unchecked(i + 1),
+                amount: 100,
+                fee: 2,
+                token: token.clone(),
+                status: RemittanceStatus::Pending,
+                expiry: None,
+            });
+        }
+
+        // The netting pass must fit in the default budget, which mirrors the
+        // network's per-transaction limits; exceeding it panics here
+        env.budget().reset_default();
+        let net_transfers = compute_net_settlements(&env, &remittances);
+        let batch_cpu = env.budget().cpu_instruction_cost();
+        assert_eq!(net_transfers.len(), MAX_BATCH_SIZE);
+
+        // Canonical ordering compares in place and costs less than half as much
+        assert_eq!(canonical_mem, 0);
+        assert!(strkey_mem > 0);
+        assert!(canonical_cpu * 2 < strkey_cpu);
+
+        // Ordering is a small share of a full netting pass, which itself stays
+        // well under a tenth of the network CPU limit
+        assert!(canonical_cpu * 20 < batch_cpu);
+        assert!(batch_cpu < NETWORK_CPU_LIMIT / 10);
+    }
+
+    #[test]
+    fn test_netting_never_offsets_across_tokens() {
+        let env = Env::default();
+        let addr_a = Address::generate(&env);
+        let addr_b = Address::generate(&env);
+        let usdc = Address::generate(&env);
+        let eurc = Address::generate(&env);
+
+        let mut remittances = Vec::new(&env);
+        remittances.push_back(Remittance {
+            id: 1,
+            sender: addr_a.clone(),
+            agent: addr_b.clone(),
+            amount: 100,
+            fee: 2,
+            token: usdc.clone(),
+            status: RemittanceStatus::Pending,
+            expiry: None,
+        });
+        remittances.push_back(Remittance {
+            id: 2,
+            sender: addr_b.clone(),
+            agent: addr_a.clone(),
+            amount: 90,
+            fee: 1,
+            token: eurc.clone(),
+            status: RemittanceStatus::Pending,
+            expiry: None,
+        });
+
+        let net_transfers = compute_net_settlements(&env, &remittances);
+
+        // One transfer per token, each carrying its full gross amount
+        assert_eq!(net_transfers.len(), 2);
+        for i in 0..net_transfers.len() {
+            let transfer = net_transfers.get_unchecked(i);
+            assert!(transfer.fx_rates.is_empty());
+            if transfer.token == usdc {
+                assert_eq!(transfer.net_amount.abs(), 100);
+            } else {
+                assert_eq!(transfer.token, eurc);
+                assert_eq!(transfer.net_amount.abs(), 90);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cross_currency_netting_reports_rate() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, crate::SwiftRemitContract);
+        let addr_a = Address::generate(&env);
+        let addr_b = Address::generate(&env);
+        let usdc = Address::generate(&env);
+        let eurc = Address::generate(&env);
+
+        let mut remittances = Vec::new(&env);
+        // A -> B: 100 USDC
+        remittances.push_back(Remittance {
+            id: 1,
+            sender: addr_a.clone(),
+            agent: addr_b.clone(),
+            amount: 100,
+            fee: 2,
+            token: usdc.clone(),
+            status: RemittanceStatus::Pending,
+            expiry: None,
+        });
+        // B -> A: 100 EURC, worth 110 USDC
+        remittances.push_back(Remittance {
+            id: 2,
+            sender: addr_b.clone(),
+            agent: addr_a.clone(),
+            amount: 100,
+            fee: 2,
+            token: eurc.clone(),
+            status: RemittanceStatus::Pending,
+            expiry: None,
+        });
+
+        env.as_contract(&contract_id, || {
+            crate::fx::set_admin_rate(&env, &eurc, &usdc, 11_000_000).unwrap();
+
+            let net_transfers =
+                compute_cross_currency_net_settlements(&env, &remittances, &usdc).unwrap();
+
+            assert_eq!(net_transfers.len(), 1);
+            let transfer = net_transfers.get_unchecked(0);
+            assert_eq!(transfer.token, usdc);
+            assert_eq!(transfer.net_amount.abs(), 10); // 110 - 100
+            assert_eq!(transfer.total_fees, 4); // 2 + floor(2.2)
+            assert_eq!(transfer.fx_rates.len(), 1);
+
+            let fx_rate = transfer.fx_rates.get_unchecked(0);
+            assert_eq!(fx_rate.base_token, eurc);
+            assert_eq!(fx_rate.quote_token, usdc);
+            assert_eq!(fx_rate.rate, 11_000_000);
+            assert_eq!(fx_rate.source, crate::FxRateSource::Admin);
+        });
+    }
+
+    #[test]
+    fn test_cross_currency_netting_requires_rate() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, crate::SwiftRemitContract);
+        let addr_a = Address::generate(&env);
+        let addr_b = Address::generate(&env);
+        let usdc = Address::generate(&env);
+        let eurc = Address::generate(&env);
+
+        let mut remittances = Vec::new(&env);
+        remittances.push_back(Remittance {
+            id: 1,
+            sender: addr_a.clone(),
+            agent: addr_b.clone(),
+            amount: 100,
+            fee: 2,
+            token: eurc.clone(),
+            status: RemittanceStatus::Pending,
+            expiry: None,
+        });
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                compute_cross_currency_net_settlements(&env, &remittances, &usdc),
+                Err(ContractError::FxRateNotAvailable)
+            );
+        });
+    }
 }
@@ -0,0 +1,237 @@
+//! Granular pause controls.
+//!
+//! Besides the global pause (`pause`/`unpause`), which halts every payout
+//! path, a `Pauser` can pause a single operation, agent, token or corridor.
+//! Each pause records a reason code, the pauser and the time, and every
+//! active pause is listed by `get_pause_state`.
+//!
+//! Scoped pauses are checked where the operation runs:
+//! - Creation: the creation operation, the agent, the token and the corridor
+//! - Payout and batch settlement: the operation, and the agent, token and
+//!   corridor of every remittance settled
+//! - Cancellation: the operation and the token (refunds to senders are not
+//!   blocked by agent or corridor pauses)
+//! - Withdrawal: the operation and the withdrawn token
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::ttl::{self, TtlClass};
+use crate::{ContractError, Remittance};
+
+/// Reason code recorded when no specific reason applies.
+pub const PAUSE_REASON_UNSPECIFIED: u32 = 0;
+
+/// An operation that can be paused on its own.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PausableOperation {
+    /// `create_remittance` and `create_remittance_with_token`
+    Creation,
+    /// `confirm_payout` and `queue_for_clearing`
+    Payout,
+    /// `cancel_remittance`
+    Cancellation,
+    /// Netted batch settlement and clearing cycle closes
+    BatchSettlement,
+    /// `execute_fee_withdrawal`
+    Withdrawal,
+}
+
+/// What a scoped pause applies to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    Operation(PausableOperation),
+    /// Every remittance paid out by an agent
+    Agent(Address),
+    /// Every remittance, refund and withdrawal in a token
+    Token(Address),
+    /// Every remittance tagged with a (currency, country) corridor
+    Corridor(String, String),
+}
+
+/// Who paused something, when and why.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseRecord {
+    /// Operator-defined reason code (0 = unspecified)
+    pub reason: u32,
+    pub paused_by: Address,
+    pub paused_at: u64,
+}
+
+/// An active scoped pause.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScopedPause {
+    pub scope: PauseScope,
+    pub record: PauseRecord,
+}
+
+/// State of the global pause.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GlobalPause {
+    Unpaused,
+    /// The contract is paused, with the record of who paused it
+    Paused(PauseRecord),
+}
+
+/// Every active pause.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseState {
+    /// The global pause
+    pub global: GlobalPause,
+    /// Active scoped pauses, in the order they were paused
+    pub scoped: Vec<ScopedPause>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum PauseKey {
+    /// Record of the global pause (instance storage)
+    Global,
+    /// Record of a scoped pause (persistent storage)
+    Scope(PauseScope),
+    /// Scopes currently paused (instance storage)
+    ActiveScopes,
+}
+
+/// Sets the global pause flag, recording or clearing its pause record.
+pub fn set_global(env: &Env, record: Option<PauseRecord>) {
+    crate::storage::set_paused(env, record.is_some());
+    match record {
+        Some(record) => env.storage().instance().set(&PauseKey::Global, &record),
+        None => env.storage().instance().remove(&PauseKey::Global),
+    }
+}
+
+/// Pauses a scope, replacing the record if it is already paused.
+pub fn pause_scope(env: &Env, scope: &PauseScope, record: &PauseRecord) {
+    let key = PauseKey::Scope(scope.clone());
+    if !env.storage().persistent().has(&key) {
+        let mut active = active_scopes(env);
+        active.push_back(scope.clone());
+        env.storage().instance().set(&PauseKey::ActiveScopes, &active);
+    }
+    env.storage().persistent().set(&key, record);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+}
+
+/// Lifts a scoped pause.
+///
+/// # Errors
+/// - InvalidStatus: The scope is not paused
+pub fn unpause_scope(env: &Env, scope: &PauseScope) -> Result<PauseRecord, ContractError> {
+    let record = get_scope_record(env, scope).ok_or(ContractError::InvalidStatus)?;
+
+    env.storage()
+        .persistent()
+        .remove(&PauseKey::Scope(scope.clone()));
+    let mut active = active_scopes(env);
+    if let Some(index) = active.first_index_of(scope) {
+        active.remove(index);
+    }
+    env.storage().instance().set(&PauseKey::ActiveScopes, &active);
+
+    Ok(record)
+}
+
+/// Returns the record of a scoped pause, if the scope is paused.
+pub fn get_scope_record(env: &Env, scope: &PauseScope) -> Option<PauseRecord> {
+    env.storage()
+        .persistent()
+        .get(&PauseKey::Scope(scope.clone()))
+}
+
+/// Returns the paused scopes, in the order they were paused.
+pub fn active_scopes(env: &Env) -> Vec<PauseScope> {
+    env.storage()
+        .instance()
+        .get(&PauseKey::ActiveScopes)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the record of the global pause, if the contract is paused.
+pub fn get_global_record(env: &Env) -> Option<PauseRecord> {
+    if crate::storage::is_paused(env) {
+        env.storage().instance().get(&PauseKey::Global)
+    } else {
+        None
+    }
+}
+
+/// Returns the global pause and every active scoped pause.
+pub fn get_state(env: &Env) -> PauseState {
+    let global = match get_global_record(env) {
+        Some(record) => GlobalPause::Paused(record),
+        None => GlobalPause::Unpaused,
+    };
+
+    let mut scoped = Vec::new(env);
+    for scope in active_scopes(env).iter() {
+        if let Some(record) = get_scope_record(env, &scope) {
+            scoped.push_back(ScopedPause { scope, record });
+        }
+    }
+
+    PauseState { global, scoped }
+}
+
+/// Fails when a scope is paused.
+///
+/// # Errors
+/// - ContractPaused: The scope is paused
+pub fn require_not_paused(env: &Env, scope: PauseScope) -> Result<(), ContractError> {
+    if env.storage().persistent().has(&PauseKey::Scope(scope)) {
+        return Err(ContractError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Fails when an operation is paused.
+///
+/// # Errors
+/// - ContractPaused: The operation is paused
+pub fn require_operation_not_paused(
+    env: &Env,
+    operation: PausableOperation,
+) -> Result<(), ContractError> {
+    require_not_paused(env, PauseScope::Operation(operation))
+}
+
+/// Fails when the agent, token or corridor a remittance is created or
+/// settled in is paused.
+///
+/// # Errors
+/// - ContractPaused: One of the scopes is paused
+pub fn require_remittance_not_paused(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+    corridor: Option<(String, String)>,
+) -> Result<(), ContractError> {
+    require_not_paused(env, PauseScope::Agent(agent.clone()))?;
+    require_not_paused(env, PauseScope::Token(token.clone()))?;
+    if let Some((currency, country)) = corridor {
+        require_not_paused(env, PauseScope::Corridor(currency, country))?;
+    }
+    Ok(())
+}
+
+/// Runs `require_remittance_not_paused` for a stored remittance.
+///
+/// # Errors
+/// - ContractPaused: The remittance's agent, token or corridor is paused
+pub fn require_stored_remittance_not_paused(
+    env: &Env,
+    remittance: &Remittance,
+) -> Result<(), ContractError> {
+    require_remittance_not_paused(
+        env,
+        &remittance.agent,
+        &remittance.token,
+        crate::storage::get_remittance_corridor(env, remittance.id),
+    )
+}
@@ -24,6 +24,55 @@ struct RateLimitEntry {
     window_start: u64,
 }
 
+/// Circuit breaker configuration stored in instance storage.
+///
+/// Payout volumes are tracked per token, so each ceiling applies to every
+/// token separately, in that token's base units.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// Maximum total payout volume per window (0 = no ceiling)
+    pub max_window_volume: i128,
+    /// Maximum payout volume of a single agent per window (0 = no ceiling)
+    pub max_agent_window_volume: i128,
+    /// Time window in seconds
+    pub window_seconds: u64,
+    /// Whether the circuit breaker is enabled
+    pub enabled: bool,
+}
+
+/// Which ceiling tripped the circuit breaker.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CircuitBreakerCause {
+    /// Total payout volume of the token reached `max_window_volume`
+    TotalVolume(Address),
+    /// The agent's payout volume of the token reached `max_agent_window_volume`
+    AgentVolume(Address, Address),
+}
+
+/// State of a tripped circuit breaker, kept until an admin resets it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerTrip {
+    pub cause: CircuitBreakerCause,
+    /// Payout volume of the window when the breaker tripped
+    pub window_volume: i128,
+    /// The ceiling that was reached
+    pub ceiling: i128,
+    pub tripped_at: u64,
+}
+
+/// Payout volume tracking for one window
+#[contracttype]
+#[derive(Clone, Debug)]
+struct VolumeWindow {
+    /// Payout volume in current window
+    volume: i128,
+    /// Window start timestamp
+    window_start: u64,
+}
+
 #[contracttype]
 #[derive(Clone)]
 enum RateLimitKey {
@@ -31,6 +80,14 @@ enum RateLimitKey {
     Config,
     /// Per-address rate limit tracking
     Entry(Address),
+    /// Circuit breaker configuration
+    BreakerConfig,
+    /// Total payout volume tracking per token
+    TotalVolume(Address),
+    /// Per-agent payout volume tracking per token
+    AgentVolume(Address, Address),
+    /// Set while the circuit breaker is tripped
+    BreakerTrip,
 }
 
 /// Initialize rate limiting with default configuration
@@ -66,7 +123,7 @@ pub fn set_rate_limit_config(env: &Env, config: RateLimitConfig) {
 
 /// Check and update rate limit for an address
 /// Returns Ok(()) if within limits, Err(ContractError::RateLimitExceeded) if exceeded
-pub fn check_rate_limit(env: &Env, address: &Address) -> Result<(), ContractError> {
+pub fn check_request_rate_limit(env: &Env, address: &Address) -> Result<(), ContractError> {
     let config = get_rate_limit_config(env);
 
     // If rate limiting is disabled, allow all requests
@@ -137,3 +194,237 @@ pub fn get_rate_limit_status(env: &Env, address: &Address) -> (u32, u32, u64) {
         (entry.request_count, config.max_requests, config.window_seconds)
     }
 }
+
+/// Get the circuit breaker configuration (disabled until configured)
+pub fn get_circuit_breaker_config(env: &Env) -> CircuitBreakerConfig {
+    env.storage()
+        .instance()
+        .get(&RateLimitKey::BreakerConfig)
+        .unwrap_or(CircuitBreakerConfig {
+            max_window_volume: 0,
+            max_agent_window_volume: 0,
+            window_seconds: 3600,
+            enabled: false,
+        })
+}
+
+/// Update the circuit breaker configuration (admin only)
+///
+/// # Errors
+/// - InvalidLimitConfig: A ceiling is negative or the window is zero
+pub fn set_circuit_breaker_config(
+    env: &Env,
+    config: &CircuitBreakerConfig,
+) -> Result<(), ContractError> {
+    if config.max_window_volume < 0
+        || config.max_agent_window_volume < 0
+        || config.window_seconds == 0
+    {
+        return Err(ContractError::InvalidLimitConfig);
+    }
+    env.storage()
+        .instance()
+        .set(&RateLimitKey::BreakerConfig, config);
+    Ok(())
+}
+
+/// Get the circuit breaker trip, if the breaker is tripped
+pub fn get_circuit_breaker_trip(env: &Env) -> Option<CircuitBreakerTrip> {
+    env.storage().instance().get(&RateLimitKey::BreakerTrip)
+}
+
+/// Stores a circuit breaker trip as is (used by migration import)
+pub fn restore_circuit_breaker_trip(env: &Env, trip: &CircuitBreakerTrip) {
+    env.storage()
+        .instance()
+        .set(&RateLimitKey::BreakerTrip, trip);
+}
+
+/// Fails while the circuit breaker is tripped
+///
+/// # Errors
+/// - CircuitBreakerTripped: The breaker tripped and has not been reset
+pub fn require_circuit_closed(env: &Env) -> Result<(), ContractError> {
+    if env.storage().instance().has(&RateLimitKey::BreakerTrip) {
+        return Err(ContractError::CircuitBreakerTripped);
+    }
+    Ok(())
+}
+
+/// Checks that a payout fits under the total and per-agent ceilings of its
+/// token without counting it.
+///
+/// # Errors
+/// - CircuitBreakerTripped: The payout would push a volume window above its ceiling
+pub fn check_payout_volume(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let config = get_circuit_breaker_config(env);
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let windows = [
+        (
+            RateLimitKey::TotalVolume(token.clone()),
+            config.max_window_volume,
+        ),
+        (
+            RateLimitKey::AgentVolume(agent.clone(), token.clone()),
+            config.max_agent_window_volume,
+        ),
+    ];
+    for (key, ceiling) in windows {
+        if ceiling == 0 {
+            continue;
+        }
+        let volume = window_volume(env, &key, config.window_seconds)
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        if volume > ceiling {
+            return Err(ContractError::CircuitBreakerTripped);
+        }
+    }
+    Ok(())
+}
+
+/// Checks a payout against the ceilings of its token and adds it to the total
+/// and per-agent volume windows, tripping the circuit breaker when a window
+/// reaches its ceiling.
+///
+/// Payouts are recorded before their tokens move, so a payout that would
+/// exceed a ceiling fails without settling. A failed call also rolls back any
+/// trip, so the breaker trips on the payout that fills a window instead and
+/// blocks every payout after it until an admin resets it.
+///
+/// # Errors
+/// - CircuitBreakerTripped: The payout would push a volume window above its ceiling
+pub fn record_payout_volume(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let config = get_circuit_breaker_config(env);
+
+    // If the breaker is disabled or already tripped there is nothing to track
+    if !config.enabled || env.storage().instance().has(&RateLimitKey::BreakerTrip) {
+        return Ok(());
+    }
+    check_payout_volume(env, agent, token, amount)?;
+
+    let total = add_to_window(
+        env,
+        &RateLimitKey::TotalVolume(token.clone()),
+        amount,
+        config.window_seconds,
+    );
+    let agent_volume = add_to_window(
+        env,
+        &RateLimitKey::AgentVolume(agent.clone(), token.clone()),
+        amount,
+        config.window_seconds,
+    );
+
+    let tripped = if config.max_window_volume > 0 && total >= config.max_window_volume {
+        Some((
+            CircuitBreakerCause::TotalVolume(token.clone()),
+            total,
+            config.max_window_volume,
+        ))
+    } else if config.max_agent_window_volume > 0 && agent_volume >= config.max_agent_window_volume {
+        Some((
+            CircuitBreakerCause::AgentVolume(agent.clone(), token.clone()),
+            agent_volume,
+            config.max_agent_window_volume,
+        ))
+    } else {
+        None
+    };
+
+    if let Some((cause, window_volume, ceiling)) = tripped {
+        let trip = CircuitBreakerTrip {
+            cause,
+            window_volume,
+            ceiling,
+            tripped_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .instance()
+            .set(&RateLimitKey::BreakerTrip, &trip);
+        crate::events::emit_circuit_breaker_tripped(env, trip);
+    }
+    Ok(())
+}
+
+/// Reset a tripped circuit breaker, clearing the window that tripped it
+///
+/// # Errors
+/// - InvalidStatus: The breaker is not tripped
+pub fn reset_circuit_breaker(env: &Env) -> Result<CircuitBreakerTrip, ContractError> {
+    let trip = get_circuit_breaker_trip(env).ok_or(ContractError::InvalidStatus)?;
+
+    env.storage().instance().remove(&RateLimitKey::BreakerTrip);
+    match &trip.cause {
+        CircuitBreakerCause::TotalVolume(token) => env
+            .storage()
+            .temporary()
+            .remove(&RateLimitKey::TotalVolume(token.clone())),
+        CircuitBreakerCause::AgentVolume(agent, token) => env
+            .storage()
+            .temporary()
+            .remove(&RateLimitKey::AgentVolume(agent.clone(), token.clone())),
+    }
+
+    Ok(trip)
+}
+
+/// Volume of a window, or 0 when it has elapsed.
+fn window_volume(env: &Env, key: &RateLimitKey, window_seconds: u64) -> i128 {
+    match env.storage().temporary().get::<_, VolumeWindow>(key) {
+        Some(window)
+            if env.ledger().timestamp().saturating_sub(window.window_start) < window_seconds =>
+        {
+            window.volume
+        }
+        _ => 0,
+    }
+}
+
+/// Add an amount to a volume window, starting a new window when the current
+/// one has elapsed. Returns the window's volume including the amount.
+fn add_to_window(env: &Env, key: &RateLimitKey, amount: i128, window_seconds: u64) -> i128 {
+    let current_time = env.ledger().timestamp();
+
+    let mut window: VolumeWindow = env
+        .storage()
+        .temporary()
+        .get(key)
+        .unwrap_or(VolumeWindow {
+            volume: 0,
+            window_start: current_time,
+        });
+
+    // Check if we're in a new window
+    let window_elapsed = current_time.saturating_sub(window.window_start);
+    if window_elapsed >= window_seconds {
+        window.volume = amount;
+        window.window_start = current_time;
+    } else {
+        window.volume = window.volume.saturating_add(amount);
+    }
+
+    // Store updated window with TTL
+    let ttl = window_seconds.saturating_add(3600);
+    env.storage()
+        .temporary()
+        .set(key, &window);
+    env.storage()
+        .temporary()
+        .extend_ttl(key, ttl as u32, ttl as u32);
+
+    window.volume
+}
@@ -0,0 +1,133 @@
+//! Persisted settlement receipts for the SwiftRemit contract.
+//!
+//! Every settlement path (single payout, netted batch, clearing cycle) writes a
+//! receipt keyed by the canonical settlement ID from `hashing`, plus an index
+//! from the remittance ID. Banks and anchors that computed the settlement ID
+//! off-chain can look a payment up directly by that hash.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use crate::ttl::{self, TtlClass};
+use crate::{compute_settlement_id_from_remittance, ContractError, Remittance};
+
+/// Record of an executed settlement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementReceipt {
+    /// Canonical settlement ID (see `hashing::compute_settlement_id`)
+    pub settlement_id: BytesN<32>,
+    /// ID of the settled remittance
+    pub remittance_id: u64,
+    /// Amount owed to the agent for this remittance (amount - fee)
+    pub payout_amount: i128,
+    /// Platform fee withheld
+    pub fee: i128,
+    /// Token the remittance is denominated in
+    pub token: Address,
+    /// Ledger sequence the settlement executed in
+    pub ledger_sequence: u32,
+    /// Ledger timestamp the settlement executed at
+    pub timestamp: u64,
+    /// Netting batch the remittance was settled in (None for individual payouts)
+    pub batch_id: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum ReceiptKey {
+    /// Receipt by settlement ID (persistent storage)
+    Receipt(BytesN<32>),
+    /// Settlement ID of a settled remittance (persistent storage)
+    RemittanceSettlement(u64),
+    /// Last netting batch ID issued (instance storage)
+    BatchCounter,
+}
+
+/// Allocates the ID of a new netting batch (batch netting or a clearing cycle close).
+pub fn next_batch_id(env: &Env) -> Result<u64, ContractError> {
+    let last: u64 = env
+        .storage()
+        .instance()
+        .get(&ReceiptKey::BatchCounter)
+        .unwrap_or(0);
+    let batch_id = last.checked_add(1).ok_or(ContractError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&ReceiptKey::BatchCounter, &batch_id);
+    Ok(batch_id)
+}
+
+/// Records a settlement: marks the remittance settled for duplicate detection
+/// and persists its receipt under both the settlement ID and the remittance ID.
+///
+/// # Returns
+///
+/// The stored receipt
+pub fn record_settlement(
+    env: &Env,
+    remittance: &Remittance,
+    payout_amount: i128,
+    batch_id: Option<u64>,
+) -> SettlementReceipt {
+    let receipt = SettlementReceipt {
+        settlement_id: compute_settlement_id_from_remittance(env, remittance),
+        remittance_id: remittance.id,
+        payout_amount,
+        fee: remittance.fee,
+        token: remittance.token.clone(),
+        ledger_sequence: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+        batch_id,
+    };
+
+    crate::storage::set_settlement_hash(env, remittance.id);
+    restore_receipt(env, &receipt);
+
+    receipt
+}
+
+/// Writes an existing receipt under both lookup keys (used by migration import).
+pub fn restore_receipt(env: &Env, receipt: &SettlementReceipt) {
+    let receipt_key = ReceiptKey::Receipt(receipt.settlement_id.clone());
+    let lookup_key = ReceiptKey::RemittanceSettlement(receipt.remittance_id);
+    env.storage().persistent().set(&receipt_key, receipt);
+    env.storage()
+        .persistent()
+        .set(&lookup_key, &receipt.settlement_id);
+    ttl::extend_persistent(env, &receipt_key, TtlClass::Terminal);
+    ttl::extend_persistent(env, &lookup_key, TtlClass::Terminal);
+}
+
+/// Extends the TTL of a remittance's settlement receipt, if it has one.
+pub fn extend_receipt_ttl(env: &Env, remittance_id: u64) {
+    let lookup_key = ReceiptKey::RemittanceSettlement(remittance_id);
+    let settlement_id: Option<BytesN<32>> = env.storage().persistent().get(&lookup_key);
+    if let Some(settlement_id) = settlement_id {
+        ttl::extend_persistent(env, &lookup_key, TtlClass::Terminal);
+        ttl::extend_persistent(env, &ReceiptKey::Receipt(settlement_id), TtlClass::Terminal);
+    }
+}
+
+/// Retrieves a settlement receipt by its settlement ID.
+pub fn get_receipt(
+    env: &Env,
+    settlement_id: &BytesN<32>,
+) -> Result<SettlementReceipt, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&ReceiptKey::Receipt(settlement_id.clone()))
+        .ok_or(ContractError::SettlementReceiptNotFound)
+}
+
+/// Retrieves the settlement receipt of a remittance.
+pub fn get_receipt_by_remittance(
+    env: &Env,
+    remittance_id: u64,
+) -> Result<SettlementReceipt, ContractError> {
+    let settlement_id: BytesN<32> = env
+        .storage()
+        .persistent()
+        .get(&ReceiptKey::RemittanceSettlement(remittance_id))
+        .ok_or(ContractError::SettlementReceiptNotFound)?;
+    get_receipt(env, &settlement_id)
+}
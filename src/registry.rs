@@ -0,0 +1,155 @@
+//! Enumerable registries of agents, admins, whitelisted tokens, treasury
+//! destinations, role holders, KYC records, denylisted addresses and users.
+//!
+//! Membership itself lives in the `storage` flags (`AgentRegistered`,
+//! `AdminRole`, `TokenWhitelisted`), the treasury allow-list and the role
+//! grants. Those are keyed lookups that cannot be iterated, so every address
+//! is also appended, once, to an indexed list the first time its flag is
+//! written. The list is append-only: removing a member
+//! only clears its flag, and listing filters on the flag, so paginated queries
+//! and migration exports see exactly the current members. KYC records and
+//! denylist entries are deleted on removal rather than flagged, so they are
+//! appended through
+//! `track`, which remembers every address it has appended. Users are every
+//! address with per-user state (last settlement time, transfer history) and
+//! are never removed.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::ttl::{self, TtlClass};
+use crate::Role;
+
+/// Maximum number of list positions scanned by one paginated query.
+pub const MAX_REGISTRY_PAGE_SIZE: u32 = 100;
+
+/// Which registry an address belongs to.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RegistryKind {
+    /// Registered payout agents
+    Agent,
+    /// Addresses holding the admin role
+    Admin,
+    /// Whitelisted tokens
+    Token,
+    /// Addresses with per-user rate limit or transfer history state
+    User,
+    /// Allowed fee withdrawal destinations
+    Treasury,
+    /// Holders of an operational role
+    Role(Role),
+    /// Addresses with a KYC record
+    Kyc,
+    /// Denylisted addresses
+    Denylist,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum RegistryKey {
+    /// Number of addresses ever appended to a registry (persistent storage)
+    Len(RegistryKind),
+    /// Address at a list position (persistent storage)
+    Entry(RegistryKind, u32),
+    /// Whether `track` has appended an address (persistent storage)
+    Tracked(RegistryKind, Address),
+}
+
+/// Appends an address to a registry's list.
+///
+/// Callers only append the first time an address's membership flag is written,
+/// which keeps each address in the list at most once.
+pub fn append(env: &Env, kind: RegistryKind, address: &Address) {
+    let len = len(env, kind);
+    let entry_key = RegistryKey::Entry(kind, len);
+    env.storage().persistent().set(&entry_key, address);
+    env.storage()
+        .persistent()
+        .set(&RegistryKey::Len(kind), &(len + 1));
+    ttl::extend_persistent(env, &entry_key, TtlClass::Active);
+    ttl::extend_persistent(env, &RegistryKey::Len(kind), TtlClass::Active);
+}
+
+/// Appends an address to a registry's list unless `track` appended it before.
+///
+/// For registries whose membership records are deleted on removal, so the
+/// record itself cannot tell whether the address is already listed.
+pub fn track(env: &Env, kind: RegistryKind, address: &Address) {
+    let key = RegistryKey::Tracked(kind, address.clone());
+    if !env.storage().persistent().has(&key) {
+        append(env, kind, address);
+        env.storage().persistent().set(&key, &true);
+    }
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+}
+
+/// Number of addresses ever appended to a registry, including removed members.
+pub fn len(env: &Env, kind: RegistryKind) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&RegistryKey::Len(kind))
+        .unwrap_or(0)
+}
+
+/// Lists the current members among list positions `[start, start + limit)`.
+///
+/// Pages are over list positions rather than members, so a page may hold fewer
+/// than `limit` entries when some addresses were removed; callers advance
+/// `start` by `limit` until it reaches `len`. `limit` is capped at
+/// `MAX_REGISTRY_PAGE_SIZE`.
+pub fn list(env: &Env, kind: RegistryKind, start: u32, limit: u32) -> Vec<Address> {
+    let end = start
+        .saturating_add(limit.min(MAX_REGISTRY_PAGE_SIZE))
+        .min(len(env, kind));
+    collect(env, kind, start, end)
+}
+
+/// Lists every current member of a registry (used by migration export).
+pub fn list_all(env: &Env, kind: RegistryKind) -> Vec<Address> {
+    collect(env, kind, 0, len(env, kind))
+}
+
+/// Lists every address appended among list positions `[start, start + limit)`,
+/// including removed members (used by migration export for state that outlives
+/// membership, such as fees collected in a token that is no longer whitelisted).
+pub fn list_appended(env: &Env, kind: RegistryKind, start: u32, limit: u32) -> Vec<Address> {
+    let end = start
+        .saturating_add(limit.min(MAX_REGISTRY_PAGE_SIZE))
+        .min(len(env, kind));
+    let mut addresses = Vec::new(env);
+    for i in start..end {
+        addresses.push_back(entry(env, kind, i));
+    }
+    addresses
+}
+
+fn entry(env: &Env, kind: RegistryKind, position: u32) -> Address {
+    env.storage()
+        .persistent()
+        .get(&RegistryKey::Entry(kind, position))
+        .unwrap()
+}
+
+fn collect(env: &Env, kind: RegistryKind, start: u32, end: u32) -> Vec<Address> {
+    let mut members = Vec::new(env);
+    for i in start..end {
+        let address = entry(env, kind, i);
+        if is_member(env, kind, &address) {
+            members.push_back(address);
+        }
+    }
+    members
+}
+
+fn is_member(env: &Env, kind: RegistryKind, address: &Address) -> bool {
+    match kind {
+        RegistryKind::Agent => crate::storage::is_agent_registered(env, address),
+        RegistryKind::Admin => crate::storage::is_admin(env, address),
+        RegistryKind::Token => crate::storage::is_token_whitelisted(env, address),
+        RegistryKind::Treasury => crate::treasury::is_destination(env, address),
+        RegistryKind::Role(role) => crate::roles::has_role(env, role, address),
+        RegistryKind::Kyc => crate::kyc::get_record(env, address).is_some(),
+        RegistryKind::Denylist => crate::sanctions::get_entry(env, address).is_some(),
+        RegistryKind::User => true,
+    }
+}
@@ -0,0 +1,101 @@
+//! Fine-grained operational roles.
+//!
+//! Each role covers one group of privileged entrypoints and can be granted to
+//! any address, so day-to-day operators do not need full admin rights. Admins
+//! grant and revoke roles and implicitly hold every role: `require_role`
+//! accepts either a holder of the role or an admin.
+//!
+//! Role holders are enumerable through the registry (`RegistryKind::Role`).
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::ttl::{self, TtlClass};
+use crate::{ContractError, RegistryKind};
+
+/// An operational role.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Updates the platform fee
+    FeeManager,
+    /// Registers and removes payout agents
+    AgentManager,
+    /// Pauses and unpauses the contract
+    Pauser,
+    /// Proposes, vetoes and executes fee withdrawals
+    Treasurer,
+    /// Manages send limits and compliance controls
+    ComplianceOfficer,
+    /// Runs storage schema migrations and contract-to-contract migrations
+    Migrator,
+    /// Finalizes remittances and closes clearing cycles
+    SettlementOperator,
+}
+
+/// Every role, in declaration order.
+pub const ALL_ROLES: [Role; 7] = [
+    Role::FeeManager,
+    Role::AgentManager,
+    Role::Pauser,
+    Role::Treasurer,
+    Role::ComplianceOfficer,
+    Role::Migrator,
+    Role::SettlementOperator,
+];
+
+#[contracttype]
+#[derive(Clone)]
+enum RoleKey {
+    /// Whether an address holds a role (persistent storage)
+    Holder(Role, Address),
+}
+
+/// Checks whether an address was granted a role. Does not consider admins.
+pub fn has_role(env: &Env, role: Role, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::Holder(role, address.clone()))
+        .unwrap_or(false)
+}
+
+/// Grants a role to, or revokes it from, an address.
+pub fn set_role(env: &Env, role: Role, address: &Address, granted: bool) {
+    let key = RoleKey::Holder(role, address.clone());
+    if !env.storage().persistent().has(&key) {
+        crate::registry::append(env, RegistryKind::Role(role), address);
+    }
+    env.storage().persistent().set(&key, &granted);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+}
+
+/// Moves every role `from` holds to `to` and returns the moved roles.
+///
+/// Roles `to` already holds are only revoked from `from`.
+pub fn move_roles(env: &Env, from: &Address, to: &Address) -> Vec<Role> {
+    let mut moved = Vec::new(env);
+    for role in ALL_ROLES {
+        if !has_role(env, role, from) {
+            continue;
+        }
+        set_role(env, role, from, false);
+        if !has_role(env, role, to) {
+            set_role(env, role, to, true);
+        }
+        moved.push_back(role);
+    }
+    moved
+}
+
+/// Requires authentication from an address holding `role`, or from an admin.
+///
+/// # Errors
+/// - Unauthorized: The address neither holds the role nor is an admin
+pub fn require_role(env: &Env, address: &Address, role: Role) -> Result<(), ContractError> {
+    address.require_auth();
+
+    if !has_role(env, role, address) && !crate::storage::is_admin(env, address) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+//! Sanctions screening.
+//!
+//! Admins keep a denylist of sanctioned addresses, each with a reason code
+//! and the time it was listed. An external screening contract implementing
+//! `SanctionsScreening` can also be configured; it is asked about every
+//! address the denylist does not already block.
+//!
+//! Screening applies to:
+//! - `create_remittance`: the sender and the agent
+//! - `confirm_payout`, `queue_for_clearing` and batch settlement: the sender
+//!   and the agent of every remittance settled
+//! - `cancel_remittance`: the sender receiving the refund
+//!
+//! Screening fails closed: if the screening contract cannot be called or
+//! errors, the operation is rejected.
+//!
+//! Denylisted addresses are enumerable through the registry
+//! (`RegistryKind::Denylist`), so batched migration exports carry the
+//! denylist along with the screening contract.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+use crate::ttl::{self, TtlClass};
+use crate::{ContractError, RegistryKind};
+
+/// Interface an external sanctions screening contract must implement.
+#[contractclient(name = "SanctionsScreeningClient")]
+pub trait SanctionsScreening {
+    /// Returns whether `account` is sanctioned and must not send, receive or
+    /// pay out funds.
+    fn is_sanctioned(env: Env, account: Address) -> bool;
+}
+
+/// Why and when an address was denylisted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DenylistEntry {
+    /// Operator-defined reason code, e.g. the sanctions program
+    pub reason: u32,
+    pub listed_by: Address,
+    pub listed_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum SanctionsKey {
+    /// Denylist entry of an address (persistent storage)
+    Denied(Address),
+    /// Address of the screening contract (instance storage)
+    Screening,
+}
+
+/// Denylists an address, replacing its entry if it is already listed.
+pub fn deny(env: &Env, account: &Address, reason: u32, listed_by: &Address) -> DenylistEntry {
+    let entry = DenylistEntry {
+        reason,
+        listed_by: listed_by.clone(),
+        listed_at: env.ledger().timestamp(),
+    };
+    restore_entry(env, account, &entry);
+    entry
+}
+
+/// Stores a denylist entry as is (used by migration import).
+pub fn restore_entry(env: &Env, account: &Address, entry: &DenylistEntry) {
+    let key = SanctionsKey::Denied(account.clone());
+    env.storage().persistent().set(&key, entry);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+    crate::registry::track(env, RegistryKind::Denylist, account);
+}
+
+/// Removes an address from the denylist. Returns its entry, if it was listed.
+pub fn allow(env: &Env, account: &Address) -> Option<DenylistEntry> {
+    let entry = get_entry(env, account)?;
+    env.storage()
+        .persistent()
+        .remove(&SanctionsKey::Denied(account.clone()));
+    Some(entry)
+}
+
+/// Returns an address's denylist entry, if it is listed.
+pub fn get_entry(env: &Env, account: &Address) -> Option<DenylistEntry> {
+    env.storage()
+        .persistent()
+        .get(&SanctionsKey::Denied(account.clone()))
+}
+
+/// Sets (or clears, with `None`) the screening contract address.
+pub fn set_screening_contract(env: &Env, screening: &Option<Address>) {
+    match screening {
+        Some(address) => env
+            .storage()
+            .instance()
+            .set(&SanctionsKey::Screening, address),
+        None => env.storage().instance().remove(&SanctionsKey::Screening),
+    }
+}
+
+/// Retrieves the configured screening contract address.
+pub fn get_screening_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&SanctionsKey::Screening)
+}
+
+/// Fails when an address is denylisted or flagged by the screening contract.
+///
+/// # Errors
+/// - AddressSanctioned: The address is denylisted or the screening contract flags it
+/// - ScreeningFailed: The screening contract could not be called or returned an error
+pub fn require_not_sanctioned(env: &Env, account: &Address) -> Result<(), ContractError> {
+    if env
+        .storage()
+        .persistent()
+        .has(&SanctionsKey::Denied(account.clone()))
+    {
+        return Err(ContractError::AddressSanctioned);
+    }
+
+    let screening = match get_screening_contract(env) {
+        Some(screening) => screening,
+        None => return Ok(()),
+    };
+    let client = SanctionsScreeningClient::new(env, &screening);
+    match client.try_is_sanctioned(account) {
+        Ok(Ok(false)) => Ok(()),
+        Ok(Ok(true)) => Err(ContractError::AddressSanctioned),
+        _ => Err(ContractError::ScreeningFailed),
+    }
+}
+
+/// Runs `require_not_sanctioned` for the sender and the agent of a remittance.
+///
+/// # Errors
+/// - AddressSanctioned: The sender or the agent is sanctioned
+/// - ScreeningFailed: The screening contract could not be called or returned an error
+pub fn require_parties_not_sanctioned(
+    env: &Env,
+    sender: &Address,
+    agent: &Address,
+) -> Result<(), ContractError> {
+    require_not_sanctioned(env, sender)?;
+    require_not_sanctioned(env, agent)
+}
@@ -5,15 +5,16 @@
 //! Uses both instance storage (contract-level config) and persistent storage
 //! (per-entity data).
 
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, String, Val, Vec};
 
-use crate::{ContractError, Remittance, TransferRecord, DailyLimit};
+use crate::ttl::{self, TtlClass};
+use crate::{ContractError, DailyLimit, RegistryKind, Remittance, TransferRecord};
 
 /// Storage keys for the SwiftRemit contract.
 ///
 /// Storage Layout:
-/// - Instance storage: Contract-level configuration and state (Admin, UsdcToken, PlatformFeeBps,
-///   RemittanceCounter, AccumulatedFees)
+/// - Instance storage: Contract-level configuration and state (AdminCount, UsdcToken,
+///   PlatformFeeBps, RemittanceCounter, AccumulatedFees)
 /// - Persistent storage: Per-entity data that needs long-term retention (Remittance records,
 ///   AgentRegistered status)
 #[contracttype]
@@ -21,9 +22,6 @@ use crate::{ContractError, Remittance, TransferRecord, DailyLimit};
 enum DataKey {
     // === Contract Configuration ===
     // Core contract settings stored in instance storage
-    /// Contract administrator address with privileged access (deprecated - use AdminRole)
-    Admin,
-
     /// Admin role status indexed by address (persistent storage)
     AdminRole(Address),
 
@@ -44,6 +42,12 @@ enum DataKey {
     /// Individual remittance record indexed by ID (persistent storage)
     Remittance(u64),
 
+    /// Timestamp a remittance first reached a terminal status (persistent storage)
+    RemittanceClosedAt(u64),
+
+    /// (currency, country) corridor a remittance was created in (persistent storage)
+    RemittanceCorridor(u64),
+
     // === Agent Management ===
     // Keys for tracking registered agents
     /// Agent registration status indexed by agent address (persistent storage)
@@ -54,6 +58,9 @@ enum DataKey {
     /// Total accumulated platform fees awaiting withdrawal
     AccumulatedFees,
 
+    /// Accumulated platform fees collected in a non-USDC token (persistent storage)
+    TokenFees(Address),
+
     /// Contract pause status for emergency halts
     Paused,
 
@@ -69,33 +76,26 @@ enum DataKey {
     
     /// Last settlement timestamp for a sender address (persistent storage)
     LastSettlementTime(Address),
-}
 
-/// Checks if the contract has an admin configured.
-///
-/// # Arguments
-///
-/// * `env` - The contract execution environment
-///
-/// # Returns
-///
-/// * `true` - Admin is configured
-/// * `false` - Admin is not configured (contract not initialized)
-pub fn has_admin(env: &Env) -> bool {
-    env.storage().instance().has(&DataKey::Admin)
-}
+    // === Transfer Limits ===
+    /// Daily transfer limit for a (currency, country) corridor (persistent storage)
+    DailyLimit(String, String),
 
-/// Sets the contract administrator address.
-///
-/// # Arguments
-///
-/// * `env` - The contract execution environment
-/// * `admin` - Address to set as admin
-pub fn set_admin(env: &Env, admin: &Address) {
-    env.storage().instance().set(&DataKey::Admin, admin);
+    /// Transfer history of a user for daily limit accounting (persistent storage)
+    UserTransfers(Address),
+
+    /// Every (currency, country) corridor a daily limit was ever set for (persistent storage)
+    DailyLimitCorridors,
+
+    // === Token Whitelist ===
+    /// Whitelist status indexed by token address (persistent storage)
+    TokenWhitelisted(Address),
 }
 
-/// Retrieves the contract administrator address.
+/// Checks if the contract has admins configured.
+///
+/// The admin count is written by `initialize` and by migration imports, so
+/// this doubles as the contract's initialization check.
 ///
 /// # Arguments
 ///
@@ -103,13 +103,10 @@ pub fn set_admin(env: &Env, admin: &Address) {
 ///
 /// # Returns
 ///
-/// * `Ok(Address)` - The admin address
-/// * `Err(ContractError::NotInitialized)` - Contract not initialized
-pub fn get_admin(env: &Env) -> Result<Address, ContractError> {
-    env.storage()
-        .instance()
-        .get(&DataKey::Admin)
-        .ok_or(ContractError::NotInitialized)
+/// * `true` - Admins are configured
+/// * `false` - Admins are not configured (contract not initialized)
+pub fn has_admin(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::AdminCount)
 }
 
 /// Sets the USDC token contract address.
@@ -197,7 +194,11 @@ pub fn get_remittance_counter(env: &Env) -> Result<u64, ContractError> {
         .ok_or(ContractError::NotInitialized)
 }
 
-/// Stores a remittance record.
+/// Stores a remittance record and extends its TTL.
+///
+/// Pending remittances get the active TTL; completed and cancelled ones the
+/// terminal TTL. The first write in a terminal status records the close time
+/// used by archival.
 ///
 /// # Arguments
 ///
@@ -205,9 +206,81 @@ pub fn get_remittance_counter(env: &Env) -> Result<u64, ContractError> {
 /// * `id` - Remittance ID
 /// * `remittance` - Remittance record to store
 pub fn set_remittance(env: &Env, id: u64, remittance: &Remittance) {
+    let key = DataKey::Remittance(id);
+    env.storage().persistent().set(&key, remittance);
+    let class = TtlClass::for_status(&remittance.status);
+    ttl::extend_persistent(env, &key, class);
+    extend_remittance_corridor_ttl(env, id, class);
+
+    if class == TtlClass::Terminal && get_remittance_closed_at(env, id).is_none() {
+        set_remittance_closed_at(env, id, env.ledger().timestamp());
+    }
+}
+
+/// Retrieves the timestamp a remittance first reached a terminal status.
+pub fn get_remittance_closed_at(env: &Env, id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RemittanceClosedAt(id))
+}
+
+/// Records the timestamp a remittance reached a terminal status.
+pub fn set_remittance_closed_at(env: &Env, id: u64, timestamp: u64) {
+    let key = DataKey::RemittanceClosedAt(id);
+    env.storage().persistent().set(&key, &timestamp);
+    ttl::extend_persistent(env, &key, TtlClass::Terminal);
+}
+
+/// Deletes a remittance record with its close time, corridor tag and settlement flag (used by archival).
+pub fn remove_remittance(env: &Env, id: u64) {
+    let storage = env.storage().persistent();
+    storage.remove(&DataKey::Remittance(id));
+    storage.remove(&DataKey::RemittanceClosedAt(id));
+    storage.remove(&DataKey::RemittanceCorridor(id));
+    storage.remove(&DataKey::SettlementHash(id));
+}
+
+/// Tags a remittance with the (currency, country) corridor it was created in.
+pub fn set_remittance_corridor(env: &Env, id: u64, currency: &String, country: &String) {
+    let key = DataKey::RemittanceCorridor(id);
     env.storage()
         .persistent()
-        .set(&DataKey::Remittance(id), remittance);
+        .set(&key, &(currency.clone(), country.clone()));
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+}
+
+/// Retrieves the (currency, country) corridor of a remittance, if it was tagged with one.
+pub fn get_remittance_corridor(env: &Env, id: u64) -> Option<(String, String)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RemittanceCorridor(id))
+}
+
+fn extend_remittance_corridor_ttl(env: &Env, id: u64, class: TtlClass) {
+    let key = DataKey::RemittanceCorridor(id);
+    if env.storage().persistent().has(&key) {
+        ttl::extend_persistent(env, &key, class);
+    }
+}
+
+/// Extends the TTL of a stored remittance and its settlement flag.
+///
+/// # Returns
+///
+/// * `true` - The remittance exists and was extended
+/// * `false` - No remittance is stored under `id`
+pub fn extend_remittance_ttl(env: &Env, id: u64) -> bool {
+    let remittance = match get_remittance(env, id) {
+        Ok(remittance) => remittance,
+        Err(_) => return false,
+    };
+    let class = TtlClass::for_status(&remittance.status);
+    ttl::extend_persistent(env, &DataKey::Remittance(id), class);
+    extend_remittance_corridor_ttl(env, id, class);
+    if has_settlement_hash(env, id) {
+        ttl::extend_persistent(env, &DataKey::SettlementHash(id), TtlClass::Terminal);
+    }
+    true
 }
 
 /// Retrieves a remittance record by ID.
@@ -220,12 +293,38 @@ pub fn set_remittance(env: &Env, id: u64, remittance: &Remittance) {
 /// # Returns
 ///
 /// * `Ok(Remittance)` - The remittance record
+/// * `Err(ContractError::RemittanceArchived)` - Remittance was archived; see `archive::get_archived_period`
 /// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
 pub fn get_remittance(env: &Env, id: u64) -> Result<Remittance, ContractError> {
-    env.storage()
-        .persistent()
-        .get(&DataKey::Remittance(id))
-        .ok_or(ContractError::RemittanceNotFound)
+    match get_remittance_raw(env, id) {
+        Some(raw) => Ok(crate::upgrade::decode_remittance(env, raw)),
+        None if crate::archive::get_archived_period(env, id).is_some() => {
+            Err(ContractError::RemittanceArchived)
+        }
+        None => Err(ContractError::RemittanceNotFound),
+    }
+}
+
+/// Retrieves a stored remittance without decoding it, for schema migrations.
+///
+/// Records written under an older storage schema may not decode as the current
+/// `Remittance`; see `upgrade::decode_remittance`.
+pub fn get_remittance_raw(env: &Env, id: u64) -> Option<Val> {
+    env.storage().persistent().get(&DataKey::Remittance(id))
+}
+
+/// Stores a remittance in an arbitrary layout, to simulate records written by
+/// older code.
+#[cfg(test)]
+pub fn set_remittance_raw(env: &Env, id: u64, raw: &Val) {
+    env.storage().persistent().set(&DataKey::Remittance(id), raw);
+}
+
+/// Remaining TTL, in ledgers, of a stored remittance.
+#[cfg(test)]
+pub fn get_remittance_ttl(env: &Env, id: u64) -> u32 {
+    use soroban_sdk::testutils::storage::Persistent;
+    env.storage().persistent().get_ttl(&DataKey::Remittance(id))
 }
 
 /// Sets an agent's registration status.
@@ -236,9 +335,12 @@ pub fn get_remittance(env: &Env, id: u64) -> Result<Remittance, ContractError> {
 /// * `agent` - Agent address
 /// * `registered` - Registration status (true = registered, false = removed)
 pub fn set_agent_registered(env: &Env, agent: &Address, registered: bool) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::AgentRegistered(agent.clone()), &registered);
+    let key = DataKey::AgentRegistered(agent.clone());
+    if !env.storage().persistent().has(&key) {
+        crate::registry::append(env, RegistryKind::Agent, agent);
+    }
+    env.storage().persistent().set(&key, &registered);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
 }
 
 /// Checks if an address is registered as an agent.
@@ -288,6 +390,93 @@ pub fn get_accumulated_fees(env: &Env) -> Result<i128, ContractError> {
         .ok_or(ContractError::NotInitialized)
 }
 
+/// Adds collected platform fees denominated in `token`.
+///
+/// Fees in the primary USDC token accrue to the accumulated platform fees;
+/// fees in any other whitelisted token are tracked separately per token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token the fees were collected in
+/// * `amount` - Fee amount to add
+///
+/// # Returns
+///
+/// * `Ok(())` - Fees recorded
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+/// * `Err(ContractError::Overflow)` - Fee total overflowed
+pub fn add_accumulated_fees(env: &Env, token: &Address, amount: i128) -> Result<(), ContractError> {
+    let current_fees = get_token_fees(env, token)?;
+    let new_fees = current_fees
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+
+    if *token == get_usdc_token(env)? {
+        set_accumulated_fees(env, new_fees);
+    } else {
+        let key = DataKey::TokenFees(token.clone());
+        env.storage().persistent().set(&key, &new_fees);
+        ttl::extend_persistent(env, &key, TtlClass::Active);
+    }
+
+    Ok(())
+}
+
+/// Deducts withdrawn platform fees denominated in `token`.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token the fees are withdrawn in
+/// * `amount` - Fee amount to deduct
+///
+/// # Returns
+///
+/// * `Ok(())` - Fees deducted
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+/// * `Err(ContractError::NoFeesToWithdraw)` - Accumulated fees are below `amount`
+pub fn deduct_accumulated_fees(env: &Env, token: &Address, amount: i128) -> Result<(), ContractError> {
+    let current_fees = get_token_fees(env, token)?;
+    if current_fees < amount {
+        return Err(ContractError::NoFeesToWithdraw);
+    }
+    let new_fees = current_fees - amount;
+
+    if *token == get_usdc_token(env)? {
+        set_accumulated_fees(env, new_fees);
+    } else {
+        let key = DataKey::TokenFees(token.clone());
+        env.storage().persistent().set(&key, &new_fees);
+        ttl::extend_persistent(env, &key, TtlClass::Active);
+    }
+
+    Ok(())
+}
+
+/// Retrieves the accumulated platform fees collected in `token`.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token to query
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Accumulated fees in that token (0 if none collected)
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+pub fn get_token_fees(env: &Env, token: &Address) -> Result<i128, ContractError> {
+    if *token == get_usdc_token(env)? {
+        return get_accumulated_fees(env);
+    }
+
+    Ok(env
+        .storage()
+        .persistent()
+        .get(&DataKey::TokenFees(token.clone()))
+        .unwrap_or(0))
+}
+
 /// Checks if a settlement hash exists for duplicate detection.
 ///
 /// # Arguments
@@ -312,9 +501,9 @@ pub fn has_settlement_hash(env: &Env, remittance_id: u64) -> bool {
 /// * `env` - The contract execution environment
 /// * `remittance_id` - Remittance ID to mark as settled
 pub fn set_settlement_hash(env: &Env, remittance_id: u64) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::SettlementHash(remittance_id), &true);
+    let key = DataKey::SettlementHash(remittance_id);
+    env.storage().persistent().set(&key, &true);
+    ttl::extend_persistent(env, &key, TtlClass::Terminal);
 }
 
 pub fn is_paused(env: &Env) -> bool {
@@ -342,9 +531,10 @@ pub fn get_rate_limit_cooldown(env: &Env) -> Result<u64, ContractError> {
 }
 
 pub fn set_last_settlement_time(env: &Env, sender: &Address, timestamp: u64) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::LastSettlementTime(sender.clone()), &timestamp);
+    track_user(env, sender);
+    let key = DataKey::LastSettlementTime(sender.clone());
+    env.storage().persistent().set(&key, &timestamp);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
 }
 
 pub fn get_last_settlement_time(env: &Env, sender: &Address) -> Option<u64> {
@@ -368,15 +558,39 @@ pub fn check_rate_limit(env: &Env, sender: &Address) -> Result<(), ContractError
         if elapsed < cooldown {
             return Err(ContractError::RateLimitExceeded);
         }
+    }
+
+    Ok(())
+}
+
+/// Length of the rolling window daily send limits are enforced over (24 hours).
+pub const DAILY_LIMIT_WINDOW_SECONDS: u64 = 86_400;
+
 pub fn set_daily_limit(env: &Env, currency: &String, country: &String, limit: i128) {
     let daily_limit = DailyLimit {
         currency: currency.clone(),
         country: country.clone(),
         limit,
     };
+    let key = DataKey::DailyLimit(currency.clone(), country.clone());
+    if !env.storage().persistent().has(&key) {
+        let mut corridors = get_daily_limit_corridors(env);
+        corridors.push_back((currency.clone(), country.clone()));
+        env.storage()
+            .persistent()
+            .set(&DataKey::DailyLimitCorridors, &corridors);
+    }
+    ttl::extend_persistent(env, &DataKey::DailyLimitCorridors, TtlClass::Active);
+    env.storage().persistent().set(&key, &daily_limit);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+}
+
+/// Lists every (currency, country) corridor a daily limit has been set for.
+pub fn get_daily_limit_corridors(env: &Env) -> Vec<(String, String)> {
     env.storage()
         .persistent()
-        .set(&DataKey::DailyLimit(currency.clone(), country.clone()), &daily_limit);
+        .get(&DataKey::DailyLimitCorridors)
+        .unwrap_or(Vec::new(env))
 }
 
 pub fn get_daily_limit(env: &Env, currency: &String, country: &String) -> Option<DailyLimit> {
@@ -393,9 +607,94 @@ pub fn get_user_transfers(env: &Env, user: &Address) -> Vec<TransferRecord> {
 }
 
 pub fn set_user_transfers(env: &Env, user: &Address, transfers: &Vec<TransferRecord>) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::UserTransfers(user.clone()), transfers);
+    track_user(env, user);
+    let key = DataKey::UserTransfers(user.clone());
+    env.storage().persistent().set(&key, transfers);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+}
+
+/// Returns a user's transfers within the rolling daily limit window.
+///
+/// Records older than `DAILY_LIMIT_WINDOW_SECONDS` are left out. Nothing is
+/// written; `record_user_transfer` and `release_user_transfer` prune storage.
+pub fn get_recent_user_transfers(env: &Env, user: &Address) -> Vec<TransferRecord> {
+    let now = env.ledger().timestamp();
+    let mut recent = Vec::new(env);
+    for record in get_user_transfers(env, user).iter() {
+        if record.timestamp.saturating_add(DAILY_LIMIT_WINDOW_SECONDS) > now {
+            recent.push_back(record);
+        }
+    }
+    recent
+}
+
+/// Sums a user's transfers in a (currency, country) corridor within the rolling daily window.
+pub fn get_daily_corridor_total(
+    env: &Env,
+    user: &Address,
+    currency: &String,
+    country: &String,
+) -> Result<i128, ContractError> {
+    let mut total: i128 = 0;
+    for record in get_recent_user_transfers(env, user).iter() {
+        if &record.currency == currency && &record.country == country {
+            total = total
+                .checked_add(record.amount)
+                .ok_or(ContractError::Overflow)?;
+        }
+    }
+    Ok(total)
+}
+
+/// Sums a user's transfers in every corridor within the rolling daily window.
+pub fn get_daily_total(env: &Env, user: &Address) -> Result<i128, ContractError> {
+    let mut total: i128 = 0;
+    for record in get_recent_user_transfers(env, user).iter() {
+        total = total
+            .checked_add(record.amount)
+            .ok_or(ContractError::Overflow)?;
+    }
+    Ok(total)
+}
+
+/// Records a transfer against a user's daily send limit, pruning records that
+/// fell out of the rolling window.
+pub fn record_user_transfer(env: &Env, user: &Address, record: &TransferRecord) {
+    let mut transfers = get_recent_user_transfers(env, user);
+    transfers.push_back(record.clone());
+    set_user_transfers(env, user, &transfers);
+}
+
+/// Releases the transfer recorded for a remittance, so a cancelled remittance
+/// no longer counts against its sender's daily send limit. Also prunes records
+/// that fell out of the rolling window.
+pub fn release_user_transfer(env: &Env, user: &Address, remittance_id: u64) {
+    let stored = get_user_transfers(env, user);
+    if stored.is_empty() {
+        return;
+    }
+
+    let mut transfers = Vec::new(env);
+    for record in get_recent_user_transfers(env, user).iter() {
+        if record.remittance_id != remittance_id {
+            transfers.push_back(record);
+        }
+    }
+    if transfers != stored {
+        set_user_transfers(env, user, &transfers);
+    }
+}
+
+/// Appends a user to the user registry the first time any per-user state is written.
+fn track_user(env: &Env, user: &Address) {
+    let storage = env.storage().persistent();
+    if !storage.has(&DataKey::LastSettlementTime(user.clone()))
+        && !storage.has(&DataKey::UserTransfers(user.clone()))
+    {
+        crate::registry::append(env, RegistryKind::User, user);
+    }
+}
+
 // === Admin Role Management ===
 
 pub fn is_admin(env: &Env, address: &Address) -> bool {
@@ -406,9 +705,12 @@ pub fn is_admin(env: &Env, address: &Address) -> bool {
 }
 
 pub fn set_admin_role(env: &Env, address: &Address, is_admin: bool) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::AdminRole(address.clone()), &is_admin);
+    let key = DataKey::AdminRole(address.clone());
+    if !env.storage().persistent().has(&key) {
+        crate::registry::append(env, RegistryKind::Admin, address);
+    }
+    env.storage().persistent().set(&key, &is_admin);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
 }
 
 pub fn get_admin_count(env: &Env) -> u32 {
@@ -442,8 +744,10 @@ pub fn is_token_whitelisted(env: &Env, token: &Address) -> bool {
 }
 
 pub fn set_token_whitelisted(env: &Env, token: &Address, whitelisted: bool) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::TokenWhitelisted(token.clone()), &whitelisted);
->>>>>> origin/main
+    let key = DataKey::TokenWhitelisted(token.clone());
+    if !env.storage().persistent().has(&key) {
+        crate::registry::append(env, RegistryKind::Token, token);
+    }
+    env.storage().persistent().set(&key, &whitelisted);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
 }
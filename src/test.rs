@@ -1,13 +1,11 @@
 #![cfg(test)]
 extern crate alloc;
+extern crate std;
 
 use crate::{SwiftRemitContract, SwiftRemitContractClient};
-use soroban_sdk::token::Client as TokenClient;
-use soroban_sdk::token::StellarAssetClient;
-use soroban_sdk::testutils::Ledger;
 use soroban_sdk::{
     symbol_short, testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger},
-    token, Address, Env, IntoVal,
+    token, Address, Env, IntoVal, String, Symbol, Vec,
 };
 
 fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
@@ -23,6 +21,48 @@ fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
     SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
 }
 
+/// Withdraws all accumulated fees in `token` through the timelocked withdrawal flow,
+/// executed by a separate treasurer.
+fn withdraw_all_fees(
+    env: &Env,
+    contract: &SwiftRemitContractClient,
+    admin: &Address,
+    token: &Address,
+    to: &Address,
+) {
+    let treasurer = Address::generate(env);
+    contract.grant_role(admin, &crate::Role::Treasurer, &treasurer);
+    contract.add_treasury_destination(admin, to);
+    let amount = contract.get_token_fees(token);
+    let withdrawal = contract.propose_fee_withdrawal(admin, token, &amount, to);
+    env.ledger().with_mut(|li| li.timestamp = withdrawal.executable_at);
+    contract.execute_fee_withdrawal(&treasurer, &withdrawal.id);
+}
+
+/// Exports `source` in batches and imports them into a fresh contract, with
+/// `admin` as the import admin.
+fn migrate_in_batches<'a>(
+    env: &Env,
+    source: &SwiftRemitContractClient,
+    admin: &Address,
+) -> SwiftRemitContractClient<'a> {
+    source.start_migration(admin);
+    let first = source.export_migration_batch(admin, &0, &5);
+    let mut batches = std::vec![first.clone()];
+    for n in 1..first.total_batches {
+        batches.push(source.export_migration_batch(admin, &n, &5));
+    }
+    let digest = source.get_migration_export_digest();
+
+    let destination = create_swiftremit_contract(env);
+    destination.configure_import_admin(admin, &source.address);
+    for batch in batches {
+        destination.import_migration_batch(admin, &batch);
+    }
+    destination.finalize_migration_import(admin, &digest);
+    destination
+}
+
 fn default_currency(env: &Env) -> String {
     String::from_str(env, "USD")
 }
@@ -42,7 +82,7 @@ fn test_initialize() {
 
     let contract = create_swiftremit_contract(&env);
 
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     assert_eq!(contract.get_platform_fee_bps(), 250);
 }
@@ -59,8 +99,8 @@ fn test_initialize_twice() {
 
     let contract = create_swiftremit_contract(&env);
 
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 }
 
 #[test]
@@ -75,7 +115,7 @@ fn test_initialize_invalid_fee() {
 
     let contract = create_swiftremit_contract(&env);
 
-    contract.initialize(&admin, &token.address, &10001, &0);
+    contract.initialize(&admin, &token.address, &10001, &0, &None);
 }
 
 #[test]
@@ -89,9 +129,9 @@ fn test_register_agent() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-        contract.register_agent(&agent);
+        contract.register_agent(&admin, &agent);
 
     assert_eq!(
         env.auths(),
@@ -100,8 +140,8 @@ fn test_register_agent() {
             AuthorizedInvocation {
                 function: AuthorizedFunction::Contract((
                     contract.address.clone(),
-                    symbol_short!("reg_agent"),
-                    (&agent,).into_val(&env)
+                    Symbol::new(&env, "register_agent"),
+                    (&admin, &agent).into_val(&env)
                 )),
                 sub_invocations: alloc::vec![]
             }
@@ -122,15 +162,48 @@ fn test_remove_agent() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    contract.register_agent(&agent);
+    contract.register_agent(&admin, &agent);
     assert!(contract.is_agent_registered(&agent));
 
-    contract.remove_agent(&agent);
+    contract.remove_agent(&admin, &agent);
     assert!(!contract.is_agent_registered(&agent));
 }
 
+#[test]
+fn test_remove_unregistered_agent_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    assert_eq!(
+        contract.try_remove_agent(&admin, &agent),
+        Err(Ok(crate::ContractError::AgentNotRegistered))
+    );
+
+    contract.register_agent(&admin, &agent);
+    contract.remove_agent(&admin, &agent);
+    assert_eq!(
+        contract.try_remove_agent(&admin, &agent),
+        Err(Ok(crate::ContractError::AgentNotRegistered))
+    );
+
+    // Proposals replay the same check
+    let id = contract.propose_admin_action(&admin, &crate::AdminAction::RemoveAgent(agent.clone()));
+    assert_eq!(
+        contract.try_execute_admin_action(&admin, &id),
+        Err(Ok(crate::ContractError::AgentNotRegistered))
+    );
+}
+
 #[test]
 fn test_update_fee() {
     let env = Env::default();
@@ -141,9 +214,9 @@ fn test_update_fee() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    contract.update_fee(&500);
+    contract.update_fee(&admin, &500);
     assert_eq!(contract.get_platform_fee_bps(), 500);
 }
 
@@ -158,9 +231,9 @@ fn test_update_fee_invalid() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    contract.update_fee(&10001);
+    contract.update_fee(&admin, &10001);
 }
 
 #[test]
@@ -178,8 +251,8 @@ fn test_create_remittance() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
@@ -208,8 +281,8 @@ fn test_create_remittance_invalid_amount() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     contract.create_remittance(&sender, &agent, &0, &default_currency(&env), &default_country(&env), &None);
 }
@@ -230,7 +303,7 @@ fn test_create_remittance_unregistered_agent() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 }
@@ -250,16 +323,15 @@ fn test_confirm_payout() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Settled);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
 
     assert_eq!(get_token_balance(&token, &agent), 975);
     assert_eq!(contract.get_accumulated_fees(), 25);
@@ -267,7 +339,7 @@ fn test_confirm_payout() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #18)")]
+#[should_panic(expected = "Error(Contract, #7)")]
 fn test_confirm_payout_twice() {
     let env = Env::default();
     env.mock_all_auths();
@@ -282,12 +354,11 @@ fn test_confirm_payout_twice() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
     contract.confirm_payout(&remittance_id);
 }
@@ -307,22 +378,22 @@ fn test_cancel_remittance() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
     contract.cancel_remittance(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Cancelled);
 
     assert_eq!(get_token_balance(&token, &sender), 10000);
     assert_eq!(get_token_balance(&token, &contract.address), 0);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #18)")]
+#[should_panic(expected = "Error(Contract, #7)")]
 fn test_cancel_remittance_already_completed() {
     let env = Env::default();
     env.mock_all_auths();
@@ -337,11 +408,10 @@ fn test_cancel_remittance_already_completed() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     contract.cancel_remittance(&remittance_id);
@@ -367,31 +437,30 @@ fn test_cancel_remittance_full_refund() {
     token.mint(&sender, &initial_balance);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250); // 2.5% fee
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None); // 2.5% fee
+    contract.register_agent(&admin, &agent);
 
     // Create remittance with 1000 tokens
     let remittance_amount = 1000i128;
-    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &default_currency(&env), &default_country(&env), &None);
 
-    let token_client = token::Client::new(&env, &token.address);
     // Verify sender balance decreased by full amount
     assert_eq!(
-        token_client.balance(&sender),
+        get_token_balance(&token, &sender),
         initial_balance - remittance_amount
     );
-    assert_eq!(token_client.balance(&contract.address), remittance_amount);
+    assert_eq!(get_token_balance(&token, &contract.address), remittance_amount);
 
     // Cancel the remittance
     contract.cancel_remittance(&remittance_id);
 
     // Verify full refund (entire amount including fee portion)
-    assert_eq!(token_client.balance(&sender), initial_balance);
-    assert_eq!(token_client.balance(&contract.address), 0);
+    assert_eq!(get_token_balance(&token, &sender), initial_balance);
+    assert_eq!(get_token_balance(&token, &contract.address), 0);
 
     // Verify remittance status is Cancelled
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Cancelled);
 }
 
 #[test]
@@ -409,8 +478,8 @@ fn test_cancel_remittance_sender_authorization() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
@@ -448,11 +517,11 @@ fn test_cancel_remittance_event_emission() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_amount = 1000i128;
-    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &default_currency(&env), &default_country(&env), &None);
 
     // Cancel the remittance
     contract.cancel_remittance(&remittance_id);
@@ -462,8 +531,8 @@ fn test_cancel_remittance_event_emission() {
     let event = events.last().unwrap();
 
     assert_eq!(event.0, contract.address);
-    assert_eq!(Symbol::from_val(&env, &event.1.get(0).unwrap()), symbol_short!("remit"));
-    assert_eq!(Symbol::from_val(&env, &event.1.get(1).unwrap()), symbol_short!("cancel"));
+    assert_eq!(<Symbol as soroban_sdk::FromVal<Env, soroban_sdk::Val>>::from_val(&env, &event.1.get(0).unwrap()), symbol_short!("remit"));
+    assert_eq!(<Symbol as soroban_sdk::FromVal<Env, soroban_sdk::Val>>::from_val(&env, &event.1.get(1).unwrap()), symbol_short!("cancel"));
 
     let event_data: soroban_sdk::Vec<soroban_sdk::Val> =
         soroban_sdk::FromVal::from_val(&env, &event.2);
@@ -489,14 +558,14 @@ fn test_cancel_remittance_not_found() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     // Try to cancel non-existent remittance
     contract.cancel_remittance(&999);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #18)")]
+#[should_panic(expected = "Error(Contract, #7)")]
 fn test_cancel_remittance_already_cancelled() {
     let env = Env::default();
     env.mock_all_auths();
@@ -511,8 +580,8 @@ fn test_cancel_remittance_already_cancelled() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
@@ -537,35 +606,34 @@ fn test_cancel_remittance_multiple_remittances() {
     token.mint(&sender, &20000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     // Create multiple remittances
     let remittance_id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     let remittance_id2 = contract.create_remittance(&sender, &agent, &2000, &default_currency(&env), &default_country(&env), &None);
     let remittance_id3 = contract.create_remittance(&sender, &agent, &3000, &default_currency(&env), &default_country(&env), &None);
 
-    let token_client = token::Client::new(&env, &token.address);
     // Sender should have 14000 left (20000 - 1000 - 2000 - 3000)
-    assert_eq!(token_client.balance(&sender), 14000);
-    assert_eq!(token_client.balance(&contract.address), 6000);
+    assert_eq!(get_token_balance(&token, &sender), 14000);
+    assert_eq!(get_token_balance(&token, &contract.address), 6000);
 
     // Cancel first and third remittances
     contract.cancel_remittance(&remittance_id1);
     contract.cancel_remittance(&remittance_id3);
 
     // Verify partial refunds
-    assert_eq!(token_client.balance(&sender), 18000); // 14000 + 1000 + 3000
-    assert_eq!(token_client.balance(&contract.address), 2000); // Only remittance_id2 remains
+    assert_eq!(get_token_balance(&token, &sender), 18000); // 14000 + 1000 + 3000
+    assert_eq!(get_token_balance(&token, &contract.address), 2000); // Only remittance_id2 remains
 
     // Verify statuses
     let r1 = contract.get_remittance(&remittance_id1);
     let r2 = contract.get_remittance(&remittance_id2);
     let r3 = contract.get_remittance(&remittance_id3);
 
-    assert_eq!(r1.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(r1.status, crate::types::RemittanceStatus::Cancelled);
     assert_eq!(r2.status, crate::types::RemittanceStatus::Pending);
-    assert_eq!(r3.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(r3.status, crate::types::RemittanceStatus::Cancelled);
 }
 
 #[test]
@@ -583,8 +651,8 @@ fn test_cancel_remittance_no_fee_accumulation() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     // Create and cancel remittance
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
@@ -609,11 +677,11 @@ fn test_cancel_remittance_preserves_remittance_data() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_amount = 1000i128;
-    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &remittance_amount, &default_currency(&env), &default_country(&env), &None);
 
     // Get original remittance data
     let original = contract.get_remittance(&remittance_id);
@@ -631,7 +699,7 @@ fn test_cancel_remittance_preserves_remittance_data() {
     assert_eq!(cancelled.amount, original.amount);
     assert_eq!(cancelled.fee, original.fee);
     assert_eq!(cancelled.expiry, original.expiry);
-    assert_eq!(cancelled.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(cancelled.status, crate::types::RemittanceStatus::Cancelled);
     assert_eq!(original.status, crate::types::RemittanceStatus::Pending);
 }
 
@@ -651,14 +719,13 @@ fn test_withdraw_fees() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
-    contract.withdraw_fees(&fee_recipient);
+    withdraw_all_fees(&env, &contract, &admin, &token.address, &fee_recipient);
 
     assert_eq!(get_token_balance(&token, &fee_recipient), 25);
     assert_eq!(contract.get_accumulated_fees(), 0);
@@ -677,9 +744,10 @@ fn test_withdraw_fees_no_fees() {
     let fee_recipient = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.add_treasury_destination(&admin, &fee_recipient);
 
-    contract.withdraw_fees(&fee_recipient);
+    contract.propose_fee_withdrawal(&admin, &token.address, &1, &fee_recipient);
 }
 
 #[test]
@@ -696,15 +764,14 @@ fn test_fee_calculation() {
     token.mint(&sender, &100000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &500, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &500, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
 
     let remittance = contract.get_remittance(&remittance_id);
     assert_eq!(remittance.fee, 500);
 
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
     assert_eq!(get_token_balance(&token, &agent), 9500);
     assert_eq!(contract.get_accumulated_fees(), 500);
@@ -726,8 +793,8 @@ fn test_multiple_remittances() {
     token.mint(&sender2, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id1 = contract.create_remittance(&sender1, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     let remittance_id2 = contract.create_remittance(&sender2, &agent, &2000, &default_currency(&env), &default_country(&env), &None);
@@ -735,8 +802,6 @@ fn test_multiple_remittances() {
     assert_eq!(remittance_id1, 1);
     assert_eq!(remittance_id2, 2);
 
-    contract.authorize_remittance(&admin, &remittance_id1);
-    contract.authorize_remittance(&admin, &remittance_id2);
 
     contract.confirm_payout(&remittance_id1);
     contract.confirm_payout(&remittance_id2);
@@ -760,17 +825,16 @@ fn test_events_emitted() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     let initial_events = env.events().all().len();
 
-    contract.register_agent(&agent);
+    contract.register_agent(&admin, &agent);
     assert!(env.events().all().len() > initial_events, "Agent registration should emit event");
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     assert!(env.events().all().len() > initial_events + 1, "Remittance creation should emit event");
 
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
     assert!(env.events().all().len() > initial_events + 2, "Payout confirmation should emit event");
 }
@@ -791,14 +855,13 @@ fn test_authorization_enforcement() {
     let contract = create_swiftremit_contract(&env);
 
     env.mock_all_auths();
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     env.mock_all_auths();
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
     env.mock_all_auths();
-    contract.authorize_remittance(&admin, &remittance_id);
 
     env.mock_all_auths();
     contract.confirm_payout(&remittance_id);
@@ -810,7 +873,7 @@ fn test_authorization_enforcement() {
             AuthorizedInvocation {
                 function: AuthorizedFunction::Contract((
                     contract.address.clone(),
-                    symbol_short!("conf_pay"),
+                    Symbol::new(&env, "confirm_payout"),
                     (remittance_id,).into_val(&env)
                 )),
                 sub_invocations: alloc::vec![]
@@ -835,15 +898,14 @@ fn test_withdraw_fees_valid_address() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     // This should succeed with a valid address
-    contract.withdraw_fees(&fee_recipient);
+    withdraw_all_fees(&env, &contract, &admin, &token.address, &fee_recipient);
 
     assert_eq!(get_token_balance(&token, &fee_recipient), 25);
     assert_eq!(contract.get_accumulated_fees(), 0);
@@ -864,13 +926,12 @@ fn test_confirm_payout_valid_address() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
     // This should succeed with a valid agent address
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
@@ -893,14 +954,13 @@ fn test_address_validation_in_settlement_flow() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     // Create remittance with valid addresses
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
     // Confirm payout - should validate agent address
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     // Verify the settlement completed successfully
@@ -927,17 +987,15 @@ fn test_multiple_settlements_with_address_validation() {
     token.mint(&sender2, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent1);
-    contract.register_agent(&agent2);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent1);
+    contract.register_agent(&admin, &agent2);
 
     // Create and confirm multiple remittances
     let remittance_id1 = contract.create_remittance(&sender1, &agent1, &1000, &default_currency(&env), &default_country(&env), &None);
     let remittance_id2 = contract.create_remittance(&sender2, &agent2, &2000, &default_currency(&env), &default_country(&env), &None);
 
     // Both should succeed with valid addresses
-    contract.authorize_remittance(&admin, &remittance_id1);
-    contract.authorize_remittance(&admin, &remittance_id2);
 
     contract.confirm_payout(&remittance_id1);
     contract.confirm_payout(&remittance_id2);
@@ -962,8 +1020,8 @@ fn test_settlement_with_future_expiry() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     // Set expiry to 1 hour in the future
     env.ledger().set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
@@ -973,7 +1031,6 @@ fn test_settlement_with_future_expiry() {
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &Some(expiry_time));
 
     // Should succeed since expiry is in the future
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
@@ -997,8 +1054,8 @@ fn test_settlement_with_past_expiry() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     // Set expiry to 1 hour in the past
     env.ledger().set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
@@ -1008,7 +1065,6 @@ fn test_settlement_with_past_expiry() {
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &Some(expiry_time));
 
     // Should fail with SettlementExpired error
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 }
 
@@ -1027,14 +1083,13 @@ fn test_settlement_without_expiry() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     // Create remittance without expiry
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
     // Should succeed since there's no expiry
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
@@ -1058,13 +1113,12 @@ fn test_duplicate_settlement_prevention() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
     // First settlement should succeed
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 
     // Verify first settlement completed
@@ -1084,7 +1138,6 @@ fn test_duplicate_settlement_prevention() {
     });
 
     // Second settlement attempt should fail with DuplicateSettlement error
-    contract.authorize_remittance(&admin, &remittance_id);
     contract.confirm_payout(&remittance_id);
 }
 
@@ -1102,16 +1155,14 @@ fn test_different_settlements_allowed() {
     token.mint(&sender, &20000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     // Create two different remittances
     let remittance_id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     let remittance_id2 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
     // Both settlements should succeed as they are different remittances
-    contract.authorize_remittance(&admin, &remittance_id1);
-    contract.authorize_remittance(&admin, &remittance_id2);
 
     contract.confirm_payout(&remittance_id1);
     contract.confirm_payout(&remittance_id2);
@@ -1140,13 +1191,12 @@ fn test_settlement_hash_storage_efficiency() {
     token.mint(&sender, &50000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     // Create and settle multiple remittances
     for _ in 0..5 {
         let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-        contract.authorize_remittance(&admin, &remittance_id);
         contract.confirm_payout(&remittance_id);
     }
 
@@ -1174,8 +1224,8 @@ fn test_duplicate_prevention_with_expiry() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     env.ledger().set(soroban_sdk::testutils::LedgerInfo { timestamp: 10000, ..env.ledger().get() });
     let current_time = env.ledger().timestamp();
@@ -1183,13 +1233,12 @@ fn test_duplicate_prevention_with_expiry() {
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &Some(expiry_time));
 
-    contract.authorize_remittance(&admin, &remittance_id);
 
     // First settlement should succeed
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Settled);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
 
     // Even with valid expiry, duplicate should be prevented
     // (This would require manual status manipulation to test, covered by test_duplicate_settlement_prevention)
@@ -1205,14 +1254,14 @@ fn test_pause_unpause() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     assert!(!contract.is_paused());
 
-    contract.pause();
+    contract.pause(&admin, &0);
     assert!(contract.is_paused());
 
-    contract.unpause();
+    contract.unpause(&admin);
     assert!(!contract.is_paused());
 }
 
@@ -1232,13 +1281,12 @@ fn test_settlement_blocked_when_paused() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
-    contract.authorize_remittance(&admin, &remittance_id);
 
-    contract.pause();
+    contract.pause(&admin, &0);
 
     contract.confirm_payout(&remittance_id);
 }
@@ -1257,13 +1305,13 @@ fn test_settlement_works_after_unpause() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
-    contract.pause();
-    contract.unpause();
+    contract.pause(&admin, &0);
+    contract.unpause(&admin);
 
     contract.confirm_payout(&remittance_id);
 
@@ -1285,13 +1333,13 @@ fn test_get_settlement_valid() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&remittance_id);
 
-    let settlement = contract.get_settlement(&remittance_id);
+    let settlement = contract.get_remittance(&remittance_id);
     assert_eq!(settlement.id, remittance_id);
     assert_eq!(settlement.sender, sender);
     assert_eq!(settlement.agent, agent);
@@ -1301,7 +1349,7 @@ fn test_get_settlement_valid() {
 }
 
 #[test]
-#[should_panic(expected = "RemittanceNotFound")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_get_settlement_invalid_id() {
     let env = Env::default();
     env.mock_all_auths();
@@ -1311,9 +1359,9 @@ fn test_get_settlement_invalid_id() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    contract.get_settlement(&999);
+    contract.get_remittance(&999);
 }
 
 #[test]
@@ -1331,10 +1379,10 @@ fn test_settlement_completed_event_emission() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     
     contract.confirm_payout(&remittance_id);
 
@@ -1358,10 +1406,10 @@ fn test_settlement_completed_event_fields_accuracy() {
     token.mint(&sender, &20000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &500, &0); // 5% fee
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &500, &0, &None); // 5% fee
+    contract.register_agent(&admin, &agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
     
     contract.confirm_payout(&remittance_id);
 
@@ -1389,17 +1437,17 @@ fn test_rate_limit_disabled_by_default() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &0); // 0 = disabled
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None); // 0 = disabled
+    contract.register_agent(&admin, &agent);
 
     // Create and settle multiple remittances immediately
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id1);
 
-    let id2 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id2);
 
-    let id3 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id3 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id3);
 
     // All should succeed when rate limiting is disabled
@@ -1420,11 +1468,11 @@ fn test_rate_limit_enforced() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600); // 1 hour cooldown
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &3600, &None); // 1 hour cooldown
+    contract.register_agent(&admin, &agent);
 
     // First settlement should succeed
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id1);
 
     // Check last settlement time was recorded
@@ -1433,7 +1481,7 @@ fn test_rate_limit_enforced() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #14)")]
+#[should_panic(expected = "Error(Contract, #68)")]
 fn test_rate_limit_blocks_rapid_settlements() {
     let env = Env::default();
     env.mock_all_auths();
@@ -1447,15 +1495,15 @@ fn test_rate_limit_blocks_rapid_settlements() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600); // 1 hour cooldown
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &3600, &None); // 1 hour cooldown
+    contract.register_agent(&admin, &agent);
 
     // First settlement succeeds
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id1);
 
     // Second settlement immediately after should fail
-    let id2 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id2); // Should panic with RateLimitExceeded
 }
 
@@ -1473,20 +1521,20 @@ fn test_rate_limit_allows_after_cooldown() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &60); // 60 second cooldown
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &60, &None); // 60 second cooldown
+    contract.register_agent(&admin, &agent);
 
     // First settlement
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id1);
 
     // Advance time by 61 seconds
     env.ledger().with_mut(|li| {
-        li.timestamp = li.timestamp + 61;
+        li.timestamp += 61;
     });
 
     // Second settlement should now succeed
-    let id2 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id2);
 
     assert_eq!(contract.get_accumulated_fees(), 50);
@@ -1508,15 +1556,15 @@ fn test_rate_limit_per_sender() {
     token.mint(&sender2, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600); // 1 hour cooldown
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &3600, &None); // 1 hour cooldown
+    contract.register_agent(&admin, &agent);
 
     // Sender1 creates and settles
-    let id1 = contract.create_remittance(&sender1, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender1, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id1);
 
     // Sender2 should be able to settle immediately (different sender)
-    let id2 = contract.create_remittance(&sender2, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender2, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id2);
 
     // Both should succeed
@@ -1533,12 +1581,12 @@ fn test_update_rate_limit() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    contract.initialize(&admin, &token.address, &250, &3600, &None);
 
     assert_eq!(contract.get_rate_limit_cooldown(), 3600);
 
     // Admin updates rate limit
-    contract.update_rate_limit(&7200);
+    contract.update_rate_limit_cooldown(&admin, &7200);
 
     assert_eq!(contract.get_rate_limit_cooldown(), 7200);
 }
@@ -1557,18 +1605,18 @@ fn test_admin_can_disable_rate_limit() {
     token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600); // Start with cooldown
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &3600, &None); // Start with cooldown
+    contract.register_agent(&admin, &agent);
 
     // First settlement
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id1);
 
     // Admin disables rate limiting
-    contract.update_rate_limit(&0);
+    contract.update_rate_limit_cooldown(&admin, &0);
 
     // Second settlement should now succeed immediately
-    let id2 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id2);
 
     assert_eq!(contract.get_accumulated_fees(), 50);
@@ -1584,14 +1632,14 @@ fn test_rate_limit_event_emission() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
+    contract.initialize(&admin, &token.address, &250, &3600, &None);
 
-    contract.update_rate_limit(&7200);
+    contract.update_rate_limit_cooldown(&admin, &7200);
 
     assert_eq!(contract.get_rate_limit_cooldown(), 7200);
     
     // Verify event was emitted (events are published)
-    assert!(env.events().all().len() > 0);
+    assert!(!env.events().all().is_empty());
 }
 
 #[test]
@@ -1608,11 +1656,11 @@ fn test_first_settlement_no_rate_limit() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250, &3600);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &3600, &None);
+    contract.register_agent(&admin, &agent);
 
     // First settlement should always succeed (no previous timestamp)
-    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&id1);
 
     let remittance = contract.get_remittance(&id1);
@@ -1634,7 +1682,7 @@ fn test_add_admin() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin1, &token.address, &250);
+    contract.initialize(&admin1, &token.address, &250, &0, &None);
 
     // Initial admin should be registered
     assert!(contract.is_admin(&admin1));
@@ -1661,7 +1709,7 @@ fn test_add_admin_unauthorized() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     // Non-admin trying to add admin should fail
     contract.add_admin(&non_admin, &new_admin);
@@ -1678,7 +1726,7 @@ fn test_add_admin_already_exists() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     // Try to add the same admin again
     contract.add_admin(&admin, &admin);
@@ -1695,7 +1743,7 @@ fn test_remove_admin() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin1, &token.address, &250);
+    contract.initialize(&admin1, &token.address, &250, &0, &None);
 
     // Add second admin
     contract.add_admin(&admin1, &admin2);
@@ -1718,7 +1766,7 @@ fn test_cannot_remove_last_admin() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     // Try to remove the only admin
     contract.remove_admin(&admin, &admin);
@@ -1737,7 +1785,7 @@ fn test_remove_admin_unauthorized() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin1, &token.address, &250);
+    contract.initialize(&admin1, &token.address, &250, &0, &None);
     contract.add_admin(&admin1, &admin2);
 
     // Non-admin trying to remove admin should fail
@@ -1756,7 +1804,7 @@ fn test_remove_admin_not_found() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     // Try to remove an address that is not an admin
     contract.remove_admin(&admin, &non_admin);
@@ -1774,22 +1822,22 @@ fn test_multiple_admins_can_perform_admin_actions() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin1, &token.address, &250);
+    contract.initialize(&admin1, &token.address, &250, &0, &None);
     contract.add_admin(&admin1, &admin2);
 
     // Both admins should be able to register agents
-    contract.register_agent(&agent);
+    contract.register_agent(&admin1, &agent);
     assert!(contract.is_agent_registered(&agent));
 
     // Admin2 should be able to update fee
-    contract.update_fee(&500);
+    contract.update_fee(&admin2, &500);
     assert_eq!(contract.get_platform_fee_bps(), 500);
 
     // Admin2 should be able to pause
-    contract.pause();
+    contract.pause(&admin2, &0);
     assert!(contract.is_paused());
 
-    contract.unpause();
+    contract.unpause(&admin2);
     assert!(!contract.is_paused());
 }
 
@@ -1821,11 +1869,11 @@ fn test_multiple_tokens_different_contracts() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &300);
+    contract1.initialize(&admin, &token1.address, &250, &0, &None);
+    contract2.initialize(&admin, &token2.address, &300, &0, &None);
     
-    contract1.register_agent(&agent);
-    contract2.register_agent(&agent);
+    contract1.register_agent(&admin, &agent);
+    contract2.register_agent(&admin, &agent);
 
     // Create remittances with different tokens
     let remittance_id1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
@@ -1836,14 +1884,14 @@ fn test_multiple_tokens_different_contracts() {
     contract2.confirm_payout(&remittance_id2);
 
     // Verify balances for token1 (250 bps = 2.5% fee)
-    assert_eq!(token1.balance(&agent), 975); // 1000 - 25
+    assert_eq!(get_token_balance(&token1, &agent), 975); // 1000 - 25
     assert_eq!(contract1.get_accumulated_fees(), 25);
-    assert_eq!(token1.balance(&sender), 9000);
+    assert_eq!(get_token_balance(&token1, &sender), 9000);
 
     // Verify balances for token2 (300 bps = 3% fee)
-    assert_eq!(token2.balance(&agent), 1940); // 2000 - 60
+    assert_eq!(get_token_balance(&token2, &agent), 1940); // 2000 - 60
     assert_eq!(contract2.get_accumulated_fees(), 60);
-    assert_eq!(token2.balance(&sender), 18000);
+    assert_eq!(get_token_balance(&token2, &sender), 18000);
 }
 
 #[test]
@@ -1874,14 +1922,14 @@ fn test_multi_token_balance_isolation() {
     let contract2 = create_swiftremit_contract(&env);
     let contract3 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &200);
-    contract2.initialize(&admin, &token2.address, &300);
-    contract3.initialize(&admin, &token3.address, &400);
+    contract1.initialize(&admin, &token1.address, &200, &0, &None);
+    contract2.initialize(&admin, &token2.address, &300, &0, &None);
+    contract3.initialize(&admin, &token3.address, &400, &0, &None);
     
-    contract1.register_agent(&agent1);
-    contract2.register_agent(&agent1);
-    contract2.register_agent(&agent2);
-    contract3.register_agent(&agent2);
+    contract1.register_agent(&admin, &agent1);
+    contract2.register_agent(&admin, &agent1);
+    contract2.register_agent(&admin, &agent2);
+    contract3.register_agent(&admin, &agent2);
 
     // Create multiple remittances across different tokens
     let rem1 = contract1.create_remittance(&sender1, &agent1, &5000, &default_currency(&env), &default_country(&env), &None);
@@ -1896,26 +1944,26 @@ fn test_multi_token_balance_isolation() {
     contract3.confirm_payout(&rem4);
 
     // Verify token1 balances (200 bps = 2%)
-    assert_eq!(token1.balance(&sender1), 45000); // 50000 - 5000
-    assert_eq!(token1.balance(&agent1), 4900); // 5000 - 100
+    assert_eq!(get_token_balance(&token1, &sender1), 45000); // 50000 - 5000
+    assert_eq!(get_token_balance(&token1, &agent1), 4900); // 5000 - 100
     assert_eq!(contract1.get_accumulated_fees(), 100);
 
     // Verify token2 balances (300 bps = 3%)
-    assert_eq!(token2.balance(&sender1), 27000); // 30000 - 3000
-    assert_eq!(token2.balance(&sender2), 36000); // 40000 - 4000
-    assert_eq!(token2.balance(&agent1), 2910); // 3000 - 90
-    assert_eq!(token2.balance(&agent2), 3880); // 4000 - 120
+    assert_eq!(get_token_balance(&token2, &sender1), 27000); // 30000 - 3000
+    assert_eq!(get_token_balance(&token2, &sender2), 36000); // 40000 - 4000
+    assert_eq!(get_token_balance(&token2, &agent1), 2910); // 3000 - 90
+    assert_eq!(get_token_balance(&token2, &agent2), 3880); // 4000 - 120
     assert_eq!(contract2.get_accumulated_fees(), 210); // 90 + 120
 
     // Verify token3 balances (400 bps = 4%)
-    assert_eq!(token3.balance(&sender2), 54000); // 60000 - 6000
-    assert_eq!(token3.balance(&agent2), 5760); // 6000 - 240
+    assert_eq!(get_token_balance(&token3, &sender2), 54000); // 60000 - 6000
+    assert_eq!(get_token_balance(&token3, &agent2), 5760); // 6000 - 240
     assert_eq!(contract3.get_accumulated_fees(), 240);
 
     // Verify no cross-contamination
-    assert_eq!(token1.balance(&agent2), 0);
-    assert_eq!(token2.balance(&sender2), 36000); // Only affected by token2 transactions
-    assert_eq!(token3.balance(&sender1), 0);
+    assert_eq!(get_token_balance(&token1, &agent2), 0);
+    assert_eq!(get_token_balance(&token2, &sender2), 36000); // Only affected by token2 transactions
+    assert_eq!(get_token_balance(&token3, &sender1), 0);
 }
 
 #[test]
@@ -1940,11 +1988,11 @@ fn test_multi_token_fee_withdrawal() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &500);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&admin, &token1.address, &500, &0, &None);
+    contract2.initialize(&admin, &token2.address, &250, &0, &None);
     
-    contract1.register_agent(&agent);
-    contract2.register_agent(&agent);
+    contract1.register_agent(&admin, &agent);
+    contract2.register_agent(&admin, &agent);
 
     // Create and complete multiple remittances
     for _ in 0..3 {
@@ -1962,18 +2010,18 @@ fn test_multi_token_fee_withdrawal() {
     assert_eq!(contract2.get_accumulated_fees(), 100); // 2 * 50
 
     // Withdraw fees to different recipients
-    contract1.withdraw_fees(&fee_recipient1);
-    contract2.withdraw_fees(&fee_recipient2);
+    withdraw_all_fees(&env, &contract1, &admin, &token1.address, &fee_recipient1);
+    withdraw_all_fees(&env, &contract2, &admin, &token2.address, &fee_recipient2);
 
     // Verify fee withdrawals
-    assert_eq!(token1.balance(&fee_recipient1), 150);
-    assert_eq!(token2.balance(&fee_recipient2), 100);
+    assert_eq!(get_token_balance(&token1, &fee_recipient1), 150);
+    assert_eq!(get_token_balance(&token2, &fee_recipient2), 100);
     assert_eq!(contract1.get_accumulated_fees(), 0);
     assert_eq!(contract2.get_accumulated_fees(), 0);
 
     // Verify agent received correct amounts
-    assert_eq!(token1.balance(&agent), 2850); // 3 * 950
-    assert_eq!(token2.balance(&agent), 3900); // 2 * 1950
+    assert_eq!(get_token_balance(&token1, &agent), 2850); // 3 * 950
+    assert_eq!(get_token_balance(&token2, &agent), 3900); // 2 * 1950
 }
 
 #[test]
@@ -1996,11 +2044,11 @@ fn test_multi_token_cancellation_refunds() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &300);
+    contract1.initialize(&admin, &token1.address, &250, &0, &None);
+    contract2.initialize(&admin, &token2.address, &300, &0, &None);
     
-    contract1.register_agent(&agent);
-    contract2.register_agent(&agent);
+    contract1.register_agent(&admin, &agent);
+    contract2.register_agent(&admin, &agent);
 
     // Create remittances
     let rem1 = contract1.create_remittance(&sender, &agent, &2000, &default_currency(&env), &default_country(&env), &None);
@@ -2012,18 +2060,18 @@ fn test_multi_token_cancellation_refunds() {
     contract2.cancel_remittance(&rem2);
 
     // Verify refunds
-    assert_eq!(token1.balance(&sender), 8000); // 10000 - 2000 + 2000 - 1500
-    assert_eq!(token2.balance(&sender), 12000); // 15000 - 3000 + 3000
+    assert_eq!(get_token_balance(&token1, &sender), 8500); // 10000 - 2000 + 2000 - 1500
+    assert_eq!(get_token_balance(&token2, &sender), 15000); // 15000 - 3000 + 3000
 
     // Complete remaining remittance
     contract1.confirm_payout(&rem3);
 
     // Verify final balances
-    assert_eq!(token1.balance(&sender), 8000);
-    assert_eq!(token1.balance(&agent), 1462); // 1500 - 38 (2.5% fee)
-    assert_eq!(contract1.get_accumulated_fees(), 38);
+    assert_eq!(get_token_balance(&token1, &sender), 8500);
+    assert_eq!(get_token_balance(&token1, &agent), 1463); // 1500 - 37 (2.5% fee, rounded down)
+    assert_eq!(contract1.get_accumulated_fees(), 37);
     
-    assert_eq!(token2.balance(&agent), 0);
+    assert_eq!(get_token_balance(&token2, &agent), 0);
     assert_eq!(contract2.get_accumulated_fees(), 0);
 }
 
@@ -2047,11 +2095,11 @@ fn test_multi_token_state_transitions() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&admin, &token1.address, &250, &0, &None);
+    contract2.initialize(&admin, &token2.address, &250, &0, &None);
     
-    contract1.register_agent(&agent);
-    contract2.register_agent(&agent);
+    contract1.register_agent(&admin, &agent);
+    contract2.register_agent(&admin, &agent);
 
     // Create remittances in both tokens
     let rem1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
@@ -2074,10 +2122,10 @@ fn test_multi_token_state_transitions() {
     assert_eq!(remittance2.status, crate::types::RemittanceStatus::Cancelled);
 
     // Verify balances reflect state
-    assert_eq!(token1.balance(&agent), 975);
-    assert_eq!(token2.balance(&agent), 0);
-    assert_eq!(token1.balance(&sender), 9000);
-    assert_eq!(token2.balance(&sender), 10000); // Refunded
+    assert_eq!(get_token_balance(&token1, &agent), 975);
+    assert_eq!(get_token_balance(&token2, &agent), 0);
+    assert_eq!(get_token_balance(&token1, &sender), 9000);
+    assert_eq!(get_token_balance(&token2, &sender), 10000); // Refunded
 }
 
 #[test]
@@ -2104,19 +2152,19 @@ fn test_multi_token_concurrent_operations() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&admin, &token1.address, &250, &0, &None);
+    contract2.initialize(&admin, &token2.address, &250, &0, &None);
     
-    contract1.register_agent(&agent1);
-    contract1.register_agent(&agent2);
-    contract2.register_agent(&agent1);
-    contract2.register_agent(&agent2);
+    contract1.register_agent(&admin, &agent1);
+    contract1.register_agent(&admin, &agent2);
+    contract2.register_agent(&admin, &agent1);
+    contract2.register_agent(&admin, &agent2);
 
     // Create multiple concurrent remittances
     let rem1_1 = contract1.create_remittance(&sender1, &agent1, &1000, &default_currency(&env), &default_country(&env), &None);
     let rem1_2 = contract1.create_remittance(&sender2, &agent2, &2000, &default_currency(&env), &default_country(&env), &None);
-    let rem2_1 = contract2.create_remittance(&sender1, &agent2, &1500, &None);
-    let rem2_2 = contract2.create_remittance(&sender2, &agent1, &2500, &None);
+    let rem2_1 = contract2.create_remittance(&sender1, &agent2, &1500, &default_currency(&env), &default_country(&env), &None);
+    let rem2_2 = contract2.create_remittance(&sender2, &agent1, &2500, &default_currency(&env), &default_country(&env), &None);
 
     // Process in mixed order
     contract1.confirm_payout(&rem1_1);
@@ -2125,13 +2173,13 @@ fn test_multi_token_concurrent_operations() {
     contract2.confirm_payout(&rem2_2);
 
     // Verify all balances are correct
-    assert_eq!(token1.balance(&agent1), 975);
-    assert_eq!(token1.balance(&agent2), 1950);
-    assert_eq!(token2.balance(&agent1), 2437); // 2500 - 63
-    assert_eq!(token2.balance(&agent2), 1462); // 1500 - 38
+    assert_eq!(get_token_balance(&token1, &agent1), 975);
+    assert_eq!(get_token_balance(&token1, &agent2), 1950);
+    assert_eq!(get_token_balance(&token2, &agent1), 2438); // 2500 - 62
+    assert_eq!(get_token_balance(&token2, &agent2), 1463); // 1500 - 37
 
     assert_eq!(contract1.get_accumulated_fees(), 75); // 25 + 50
-    assert_eq!(contract2.get_accumulated_fees(), 101); // 38 + 63
+    assert_eq!(contract2.get_accumulated_fees(), 99); // 37 + 62
 }
 
 #[test]
@@ -2155,11 +2203,11 @@ fn test_multi_token_edge_case_zero_fee() {
     let contract2 = create_swiftremit_contract(&env);
     
     // One with 0% fee, one with normal fee
-    contract1.initialize(&admin, &token1.address, &0);
-    contract2.initialize(&admin, &token2.address, &500);
+    contract1.initialize(&admin, &token1.address, &0, &0, &None);
+    contract2.initialize(&admin, &token2.address, &500, &0, &None);
     
-    contract1.register_agent(&agent);
-    contract2.register_agent(&agent);
+    contract1.register_agent(&admin, &agent);
+    contract2.register_agent(&admin, &agent);
 
     let rem1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     let rem2 = contract2.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
@@ -2168,11 +2216,11 @@ fn test_multi_token_edge_case_zero_fee() {
     contract2.confirm_payout(&rem2);
 
     // Verify zero fee contract
-    assert_eq!(token1.balance(&agent), 1000); // No fee deducted
+    assert_eq!(get_token_balance(&token1, &agent), 1000); // No fee deducted
     assert_eq!(contract1.get_accumulated_fees(), 0);
 
     // Verify normal fee contract
-    assert_eq!(token2.balance(&agent), 950); // 5% fee
+    assert_eq!(get_token_balance(&token2, &agent), 950); // 5% fee
     assert_eq!(contract2.get_accumulated_fees(), 50);
 }
 
@@ -2197,25 +2245,25 @@ fn test_multi_token_large_amounts() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &100);
-    contract2.initialize(&admin, &token2.address, &50);
+    contract1.initialize(&admin, &token1.address, &100, &0, &None);
+    contract2.initialize(&admin, &token2.address, &50, &0, &None);
     
-    contract1.register_agent(&agent);
-    contract2.register_agent(&agent);
+    contract1.register_agent(&admin, &agent);
+    contract2.register_agent(&admin, &agent);
 
     // Large remittances
-    let rem1 = contract1.create_remittance(&sender, &agent, &100_000_000, &None);
-    let rem2 = contract2.create_remittance(&sender, &agent, &500_000_000, &None);
+    let rem1 = contract1.create_remittance(&sender, &agent, &100_000_000, &default_currency(&env), &default_country(&env), &None);
+    let rem2 = contract2.create_remittance(&sender, &agent, &500_000_000, &default_currency(&env), &default_country(&env), &None);
 
     contract1.confirm_payout(&rem1);
     contract2.confirm_payout(&rem2);
 
     // Verify large amount calculations (100 bps = 1%)
-    assert_eq!(token1.balance(&agent), 99_000_000); // 100M - 1M
+    assert_eq!(get_token_balance(&token1, &agent), 99_000_000); // 100M - 1M
     assert_eq!(contract1.get_accumulated_fees(), 1_000_000);
 
     // Verify large amount calculations (50 bps = 0.5%)
-    assert_eq!(token2.balance(&agent), 497_500_000); // 500M - 2.5M
+    assert_eq!(get_token_balance(&token2, &agent), 497_500_000); // 500M - 2.5M
     assert_eq!(contract2.get_accumulated_fees(), 2_500_000);
 }
 
@@ -2239,17 +2287,17 @@ fn test_multi_token_expiry_handling() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&admin, &token1.address, &250, &0, &None);
+    contract2.initialize(&admin, &token2.address, &250, &0, &None);
     
-    contract1.register_agent(&agent);
-    contract2.register_agent(&agent);
+    contract1.register_agent(&admin, &agent);
+    contract2.register_agent(&admin, &agent);
 
     let current_time = env.ledger().timestamp();
     let future_expiry = current_time + 7200;
 
     // Create remittances with expiry
-    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &Some(future_expiry));
+    let rem1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &Some(future_expiry));
     let rem2 = contract2.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
     // Both should succeed
@@ -2285,17 +2333,17 @@ fn test_multi_token_pause_independence() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&admin, &token1.address, &250, &0, &None);
+    contract2.initialize(&admin, &token2.address, &250, &0, &None);
     
-    contract1.register_agent(&agent);
-    contract2.register_agent(&agent);
+    contract1.register_agent(&admin, &agent);
+    contract2.register_agent(&admin, &agent);
 
     let rem1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     let rem2 = contract2.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
     // Pause only contract1
-    contract1.pause();
+    contract1.pause(&admin, &0);
 
     assert!(contract1.is_paused());
     assert!(!contract2.is_paused());
@@ -2305,15 +2353,15 @@ fn test_multi_token_pause_independence() {
     
     let remittance2 = contract2.get_remittance(&rem2);
     assert_eq!(remittance2.status, crate::types::RemittanceStatus::Completed);
-    assert_eq!(token2.balance(&agent), 975);
+    assert_eq!(get_token_balance(&token2, &agent), 975);
 
     // Unpause contract1 and complete
-    contract1.unpause();
+    contract1.unpause(&admin);
     contract1.confirm_payout(&rem1);
     
     let remittance1 = contract1.get_remittance(&rem1);
     assert_eq!(remittance1.status, crate::types::RemittanceStatus::Completed);
-    assert_eq!(token1.balance(&agent), 975);
+    assert_eq!(get_token_balance(&token1, &agent), 975);
 }
 
 #[test]
@@ -2338,20 +2386,20 @@ fn test_multi_token_different_agents() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &200);
-    contract2.initialize(&admin, &token2.address, &300);
+    contract1.initialize(&admin, &token1.address, &200, &0, &None);
+    contract2.initialize(&admin, &token2.address, &300, &0, &None);
     
     // Register different agents for different contracts
-    contract1.register_agent(&agent1);
-    contract1.register_agent(&agent2);
-    contract2.register_agent(&agent2);
-    contract2.register_agent(&agent3);
+    contract1.register_agent(&admin, &agent1);
+    contract1.register_agent(&admin, &agent2);
+    contract2.register_agent(&admin, &agent2);
+    contract2.register_agent(&admin, &agent3);
 
     // Create remittances to different agents
-    let rem1 = contract1.create_remittance(&sender, &agent1, &5000, &None);
-    let rem2 = contract1.create_remittance(&sender, &agent2, &3000, &None);
-    let rem3 = contract2.create_remittance(&sender, &agent2, &4000, &None);
-    let rem4 = contract2.create_remittance(&sender, &agent3, &6000, &None);
+    let rem1 = contract1.create_remittance(&sender, &agent1, &5000, &default_currency(&env), &default_country(&env), &None);
+    let rem2 = contract1.create_remittance(&sender, &agent2, &3000, &default_currency(&env), &default_country(&env), &None);
+    let rem3 = contract2.create_remittance(&sender, &agent2, &4000, &default_currency(&env), &default_country(&env), &None);
+    let rem4 = contract2.create_remittance(&sender, &agent3, &6000, &default_currency(&env), &default_country(&env), &None);
 
     // Complete all
     contract1.confirm_payout(&rem1);
@@ -2360,16 +2408,16 @@ fn test_multi_token_different_agents() {
     contract2.confirm_payout(&rem4);
 
     // Verify agent1 only received from token1
-    assert_eq!(token1.balance(&agent1), 4900); // 5000 - 100 (2%)
-    assert_eq!(token2.balance(&agent1), 0);
+    assert_eq!(get_token_balance(&token1, &agent1), 4900); // 5000 - 100 (2%)
+    assert_eq!(get_token_balance(&token2, &agent1), 0);
 
     // Verify agent2 received from both tokens
-    assert_eq!(token1.balance(&agent2), 2940); // 3000 - 60 (2%)
-    assert_eq!(token2.balance(&agent2), 3880); // 4000 - 120 (3%)
+    assert_eq!(get_token_balance(&token1, &agent2), 2940); // 3000 - 60 (2%)
+    assert_eq!(get_token_balance(&token2, &agent2), 3880); // 4000 - 120 (3%)
 
     // Verify agent3 only received from token2
-    assert_eq!(token1.balance(&agent3), 0);
-    assert_eq!(token2.balance(&agent3), 5820); // 6000 - 180 (3%)
+    assert_eq!(get_token_balance(&token1, &agent3), 0);
+    assert_eq!(get_token_balance(&token2, &agent3), 5820); // 6000 - 180 (3%)
 }
 
 #[test]
@@ -2392,11 +2440,11 @@ fn test_multi_token_mixed_success_failure() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
     
-    contract1.initialize(&admin, &token1.address, &250);
-    contract2.initialize(&admin, &token2.address, &250);
+    contract1.initialize(&admin, &token1.address, &250, &0, &None);
+    contract2.initialize(&admin, &token2.address, &250, &0, &None);
     
-    contract1.register_agent(&agent);
-    contract2.register_agent(&agent);
+    contract1.register_agent(&admin, &agent);
+    contract2.register_agent(&admin, &agent);
 
     // Create remittances
     let rem1 = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
@@ -2409,10 +2457,10 @@ fn test_multi_token_mixed_success_failure() {
     contract2.cancel_remittance(&rem2);
 
     // Verify mixed outcomes
-    assert_eq!(token1.balance(&agent), 975);
-    assert_eq!(token2.balance(&agent), 0);
-    assert_eq!(token1.balance(&sender), 9000);
-    assert_eq!(token2.balance(&sender), 10000); // Refunded
+    assert_eq!(get_token_balance(&token1, &agent), 975);
+    assert_eq!(get_token_balance(&token2, &agent), 0);
+    assert_eq!(get_token_balance(&token1, &sender), 9000);
+    assert_eq!(get_token_balance(&token2, &sender), 10000); // Refunded
 
     let remittance1 = contract1.get_remittance(&rem1);
     let remittance2 = contract2.get_remittance(&rem2);
@@ -2432,9 +2480,11 @@ fn test_whitelist_token() {
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
 
     // Initially token should not be whitelisted
     assert!(!contract.is_token_whitelisted(&token.address));
@@ -2453,9 +2503,11 @@ fn test_remove_whitelisted_token() {
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
 
     // Whitelist the token
     contract.whitelist_token(&admin, &token.address);
@@ -2474,9 +2526,11 @@ fn test_whitelist_token_already_whitelisted() {
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
 
     // Whitelist the token
     contract.whitelist_token(&admin, &token.address);
@@ -2493,32 +2547,18 @@ fn test_remove_token_not_whitelisted() {
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
 
     // Try to remove a token that was never whitelisted - should fail
     contract.remove_whitelisted_token(&admin, &token.address);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #18)")]
-fn test_initialize_with_non_whitelisted_token() {
-    let env = Env::default();
-    env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token_contract(&env, &token_admin);
-
-    let contract = create_swiftremit_contract(&env);
-
-    // Try to initialize with non-whitelisted token - should fail
-    contract.initialize(&admin, &token.address, &250);
-}
-
-#[test]
-fn test_initialize_with_whitelisted_token() {
+fn test_initialize_whitelists_primary_token() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -2527,14 +2567,13 @@ fn test_initialize_with_whitelisted_token() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
+    assert!(!contract.is_token_whitelisted(&token.address));
 
-    // Whitelist the token first
-    contract.whitelist_token(&admin, &token.address);
-
-    // Now initialize should succeed
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     assert_eq!(contract.get_platform_fee_bps(), 250);
+    assert!(contract.is_token_whitelisted(&token.address));
+    assert_eq!(contract.list_whitelisted_tokens(&0, &10).len(), 1);
 }
 
 #[test]
@@ -2544,12 +2583,14 @@ fn test_multiple_tokens_whitelist() {
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
-    
+
+    let usdc = create_token_contract(&env, &token_admin);
     let token1 = create_token_contract(&env, &token_admin);
     let token2 = create_token_contract(&env, &token_admin);
     let token3 = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
 
     // Whitelist multiple tokens
     contract.whitelist_token(&admin, &token1.address);
@@ -2577,9 +2618,11 @@ fn test_whitelist_authorization() {
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
 
     // Whitelist token
     contract.whitelist_token(&admin, &token.address);
@@ -2592,7 +2635,7 @@ fn test_whitelist_authorization() {
             AuthorizedInvocation {
                 function: AuthorizedFunction::Contract((
                     contract.address.clone(),
-                    symbol_short!("whitelist_token"),
+                    Symbol::new(&env, "whitelist_token"),
                     (&admin, &token.address).into_val(&env)
                 )),
                 sub_invocations: std::vec![]
@@ -2608,9 +2651,11 @@ fn test_whitelist_events_emitted() {
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
 
     // Whitelist token
     contract.whitelist_token(&admin, &token.address);
@@ -2619,8 +2664,8 @@ fn test_whitelist_events_emitted() {
     let whitelist_event = events.last().unwrap();
 
     assert_eq!(
-        whitelist_event.topics,
-        (symbol_short!("token"), symbol_short!("whitelist")).into_val(&env)
+        whitelist_event.1,
+        (symbol_short!("token"), symbol_short!("added")).into_val(&env)
     );
 
     // Remove token
@@ -2630,7 +2675,7 @@ fn test_whitelist_events_emitted() {
     let remove_event = events.last().unwrap();
 
     assert_eq!(
-        remove_event.topics,
+        remove_event.1,
         (symbol_short!("token"), symbol_short!("removed")).into_val(&env)
     );
 }
@@ -2643,18 +2688,15 @@ fn test_multi_admin_whitelist_management() {
     let admin1 = Address::generate(&env);
     let admin2 = Address::generate(&env);
     let token_admin = Address::generate(&env);
-    
+
     let token1 = create_token_contract(&env, &token_admin);
     let token2 = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
 
-    // Whitelist first token
-    contract.whitelist_token(&admin1, &token1.address);
-    
-    // Initialize with whitelisted token
-    contract.initialize(&admin1, &token1.address, &250);
-    
+    // Initialize with the first token
+    contract.initialize(&admin1, &token1.address, &250, &0, &None);
+
     // Add second admin
     contract.add_admin(&admin1, &admin2);
 
@@ -2674,24 +2716,27 @@ fn test_whitelist_different_contract_instances() {
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
-    
+
+    let usdc = create_token_contract(&env, &token_admin);
     let token1 = create_token_contract(&env, &token_admin);
     let token2 = create_token_contract(&env, &token_admin);
 
     // Create two separate contract instances
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract2.initialize(&admin, &usdc.address, &250, &0, &None);
 
     // Whitelist token1 in contract1
     contract1.whitelist_token(&admin, &token1.address);
-    
+
     // Whitelist token2 in contract2
     contract2.whitelist_token(&admin, &token2.address);
 
     // Verify isolation - each contract has its own whitelist
     assert!(contract1.is_token_whitelisted(&token1.address));
     assert!(!contract1.is_token_whitelisted(&token2.address));
-    
+
     assert!(!contract2.is_token_whitelisted(&token1.address));
     assert!(contract2.is_token_whitelisted(&token2.address));
 }
@@ -2711,21 +2756,18 @@ fn test_whitelist_and_full_workflow() {
 
     let contract = create_swiftremit_contract(&env);
 
-    // Whitelist token
-    contract.whitelist_token(&admin, &token.address);
-
-    // Initialize with whitelisted token
-    contract.initialize(&admin, &token.address, &250);
+    // Initialize with the token, which whitelists it
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     // Register agent
-    contract.register_agent(&agent);
+    contract.register_agent(&admin, &agent);
 
     // Create and complete remittance
     let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&remittance_id);
 
     // Verify everything worked
-    assert_eq!(token.balance(&agent), 975);
+    assert_eq!(get_token_balance(&token, &agent), 975);
     assert_eq!(contract.get_accumulated_fees(), 25);
 }
 
@@ -2737,7 +2779,7 @@ fn test_whitelist_token_isolation_across_contracts() {
     let admin1 = Address::generate(&env);
     let admin2 = Address::generate(&env);
     let token_admin = Address::generate(&env);
-    
+
     let token1 = create_token_contract(&env, &token_admin);
     let token2 = create_token_contract(&env, &token_admin);
     let token3 = create_token_contract(&env, &token_admin);
@@ -2745,13 +2787,13 @@ fn test_whitelist_token_isolation_across_contracts() {
     let contract1 = create_swiftremit_contract(&env);
     let contract2 = create_swiftremit_contract(&env);
 
-    // Contract1: whitelist token1 and token2
-    contract1.whitelist_token(&admin1, &token1.address);
-    contract1.whitelist_token(&admin1, &token2.address);
+    // Initialize both contracts with different primary tokens
+    contract1.initialize(&admin1, &token1.address, &250, &0, &None);
+    contract2.initialize(&admin2, &token3.address, &300, &0, &None);
 
-    // Contract2: whitelist token2 and token3
+    // Both add token2
+    contract1.whitelist_token(&admin1, &token2.address);
     contract2.whitelist_token(&admin2, &token2.address);
-    contract2.whitelist_token(&admin2, &token3.address);
 
     // Verify contract1 whitelist
     assert!(contract1.is_token_whitelisted(&token1.address));
@@ -2763,10 +2805,6 @@ fn test_whitelist_token_isolation_across_contracts() {
     assert!(contract2.is_token_whitelisted(&token2.address));
     assert!(contract2.is_token_whitelisted(&token3.address));
 
-    // Initialize both contracts with their whitelisted tokens
-    contract1.initialize(&admin1, &token1.address, &250);
-    contract2.initialize(&admin2, &token3.address, &300);
-
     assert_eq!(contract1.get_platform_fee_bps(), 250);
     assert_eq!(contract2.get_platform_fee_bps(), 300);
 }
@@ -2778,26 +2816,26 @@ fn test_cannot_use_removed_token() {
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
     let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
 
-    let contract1 = create_swiftremit_contract(&env);
-    let contract2 = create_swiftremit_contract(&env);
-
-    // Whitelist token
-    contract1.whitelist_token(&admin, &token.address);
-    contract2.whitelist_token(&admin, &token.address);
-
-    // Initialize first contract
-    contract1.initialize(&admin, &token.address, &250);
+    token.mint(&sender, &10000);
 
-    // Remove token from whitelist for contract2
-    contract2.remove_whitelisted_token(&admin, &token.address);
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Verify contract1 still works (already initialized)
-    assert_eq!(contract1.get_platform_fee_bps(), 250);
+    contract.whitelist_token(&admin, &token.address);
+    contract.remove_whitelisted_token(&admin, &token.address);
 
-    // Verify contract2 cannot initialize with removed token
-    assert!(!contract2.is_token_whitelisted(&token.address));
+    // Remittances can no longer be created in the removed token
+    assert!(!contract.is_token_whitelisted(&token.address));
+    assert_eq!(
+        contract.try_create_remittance_with_token(&sender, &agent, &1000, &token.address, &default_currency(&env), &default_country(&env), &None),
+        Err(Ok(crate::ContractError::TokenNotWhitelisted))
+    );
 }
 
 #[test]
@@ -2807,8 +2845,10 @@ fn test_whitelist_edge_case_many_tokens() {
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
 
     // Whitelist many tokens
     let mut tokens = std::vec![];
@@ -2842,27 +2882,15 @@ fn test_whitelist_edge_case_many_tokens() {
 
 
 // ============================================================================
-// Centralized Validation Tests
-// ============================================================================
-
-#[test]
-fn test_validation_prevents_invalid_amount() {
-// ═══════════════════════════════════════════════════════════════════════════
 // Net Settlement Tests
-// ═══════════════════════════════════════════════════════════════════════════
+// ============================================================================
 
 #[test]
 fn test_net_settlement_simple_offset() {
-
-
-#[test]
-fn test_simulate_settlement_success() {
-
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
@@ -2870,45 +2898,67 @@ fn test_simulate_settlement_success() {
     let sender_b = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250); // 2.5% fee
+    contract.initialize(&admin, &token.address, &250, &0, &None); // 2.5% fee
 
     // Register both as agents
-    contract.register_agent(&sender_a);
-    contract.register_agent(&sender_b);
+    contract.register_agent(&admin, &sender_a);
+    contract.register_agent(&admin, &sender_b);
 
-    // Mint tokens
     token.mint(&sender_a, &1000);
     token.mint(&sender_b, &1000);
 
     // Create opposing remittances:
     // A -> B: 100 (fee: 2.5)
-    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &None);
-    
+    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &default_currency(&env), &default_country(&env), &None);
+
     // B -> A: 90 (fee: 2.25)
-    let id2 = contract.create_remittance(&sender_b, &sender_a, &90, &None);
+    let id2 = contract.create_remittance(&sender_b, &sender_a, &90, &default_currency(&env), &default_country(&env), &None);
 
-    // Create batch settlement entries
     let mut entries = Vec::new(&env);
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
 
-    // Execute batch settlement with netting
-    let result = contract.batch_settle_with_netting(&entries);
-
-    assert!(result.is_ok());
-    let settled = result.unwrap();
-    assert_eq!(settled.settled_ids.len(), 2);
+    let result = contract.batch_settle_with_netting(&admin, &entries);
+    assert_eq!(result.settled_ids.len(), 2);
 
     // Verify both remittances are marked as completed
-    let rem1 = contract.get_remittance(&id1);
-    let rem2 = contract.get_remittance(&id2);
-    assert_eq!(rem1.status, crate::RemittanceStatus::Completed);
-    assert_eq!(rem2.status, crate::RemittanceStatus::Completed);
+    assert_eq!(contract.get_remittance(&id1).status, crate::RemittanceStatus::Completed);
+    assert_eq!(contract.get_remittance(&id2).status, crate::RemittanceStatus::Completed);
+
+    // Verify fees accumulated (2.5 + 2.25, each rounded down)
+    assert_eq!(contract.get_accumulated_fees(), 4);
+}
+
+#[test]
+fn test_net_settlement_requires_settlement_operator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let operator = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &1000);
+
+    let id = contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    let mut entries = Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
+
+    assert_eq!(
+        contract.try_batch_settle_with_netting(&operator, &entries),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Pending);
 
-    // Verify fees accumulated (2.5 + 2.25 = 4.75)
-    let fees = contract.get_accumulated_fees();
-    assert_eq!(fees, 4); // Rounded down due to integer division
+    contract.grant_role(&admin, &crate::Role::SettlementOperator, &operator);
+    contract.batch_settle_with_netting(&operator, &entries);
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Completed);
 }
 
 #[test]
@@ -2924,77 +2974,41 @@ fn test_net_settlement_complete_offset() {
     let sender_b = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    contract.register_agent(&sender_a);
-    contract.register_agent(&sender_b);
+    contract.register_agent(&admin, &sender_a);
+    contract.register_agent(&admin, &sender_b);
 
     token.mint(&sender_a, &1000);
     token.mint(&sender_b, &1000);
 
     // Create equal opposing remittances:
     // A -> B: 100
-    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &None);
-    
+    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &default_currency(&env), &default_country(&env), &None);
+
     // B -> A: 100
-    let id2 = contract.create_remittance(&sender_b, &sender_a, &100, &None);
+    let id2 = contract.create_remittance(&sender_b, &sender_a, &100, &default_currency(&env), &default_country(&env), &None);
 
     let mut entries = Vec::new(&env);
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
 
-    let result = contract.batch_settle_with_netting(&entries);
+    contract.batch_settle_with_netting(&admin, &entries);
 
-    assert!(result.is_ok());
-    
     // Both should be marked completed even though net transfer is zero
-    let rem1 = contract.get_remittance(&id1);
-    let rem2 = contract.get_remittance(&id2);
-    assert_eq!(rem1.status, crate::RemittanceStatus::Completed);
-    assert_eq!(rem2.status, crate::RemittanceStatus::Completed);
+    assert_eq!(contract.get_remittance(&id1).status, crate::RemittanceStatus::Completed);
+    assert_eq!(contract.get_remittance(&id2).status, crate::RemittanceStatus::Completed);
 
     // Fees should still be accumulated
-    let fees = contract.get_accumulated_fees();
-    assert!(fees > 0);
+    assert!(contract.get_accumulated_fees() > 0);
 }
 
 #[test]
 fn test_net_settlement_multiple_parties() {
-
-    let sender = Address::generate(&env);
-    let agent = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token_contract(&env, &token_admin);
-
-    let contract = create_swiftremit_contract(&env);
-
-    // Whitelist token
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
-
-    // Mint and create remittance
-    token.mint(&sender, &10000);
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-
-    // Simulate settlement
-    let simulation = contract.simulate_settlement(&remittance_id);
-
-    assert_eq!(simulation.would_succeed, true);
-    assert_eq!(simulation.payout_amount, 9750); // 10000 - 250 (2.5% fee)
-    assert_eq!(simulation.fee, 250);
-    assert_eq!(simulation.error_message, None);
-}
-
-#[test]
-fn test_simulate_settlement_invalid_status() {
-
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
@@ -3003,12 +3017,11 @@ fn test_simulate_settlement_invalid_status() {
     let party_c = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &100); // 1% fee
+    contract.initialize(&admin, &token.address, &100, &0, &None); // 1% fee
 
-    contract.register_agent(&party_a);
-    contract.register_agent(&party_b);
-    contract.register_agent(&party_c);
+    contract.register_agent(&admin, &party_a);
+    contract.register_agent(&admin, &party_b);
+    contract.register_agent(&admin, &party_c);
 
     token.mint(&party_a, &10000);
     token.mint(&party_b, &10000);
@@ -3016,23 +3029,22 @@ fn test_simulate_settlement_invalid_status() {
 
     // Create a triangle of remittances:
     // A -> B: 100
-    let id1 = contract.create_remittance(&party_a, &party_b, &100, &None);
-    
+    let id1 = contract.create_remittance(&party_a, &party_b, &100, &default_currency(&env), &default_country(&env), &None);
+
     // B -> C: 50
-    let id2 = contract.create_remittance(&party_b, &party_c, &50, &None);
-    
+    let id2 = contract.create_remittance(&party_b, &party_c, &50, &default_currency(&env), &default_country(&env), &None);
+
     // C -> A: 30
-    let id3 = contract.create_remittance(&party_c, &party_a, &30, &None);
+    let id3 = contract.create_remittance(&party_c, &party_a, &30, &default_currency(&env), &default_country(&env), &None);
 
     let mut entries = Vec::new(&env);
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id3 });
 
-    let result = contract.batch_settle_with_netting(&entries);
+    let result = contract.batch_settle_with_netting(&admin, &entries);
+    assert_eq!(result.settled_ids.len(), 3);
 
-    assert!(result.is_ok());
-    
     // All should be completed
     assert_eq!(contract.get_remittance(&id1).status, crate::RemittanceStatus::Completed);
     assert_eq!(contract.get_remittance(&id2).status, crate::RemittanceStatus::Completed);
@@ -3052,39 +3064,36 @@ fn test_net_settlement_order_independence() {
     let sender_b = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    contract.register_agent(&sender_a);
-    contract.register_agent(&sender_b);
+    contract.register_agent(&admin, &sender_a);
+    contract.register_agent(&admin, &sender_b);
 
     token.mint(&sender_a, &2000);
     token.mint(&sender_b, &2000);
 
     // First batch: A->B then B->A
-    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &None);
-    let id2 = contract.create_remittance(&sender_b, &sender_a, &90, &None);
+    let id1 = contract.create_remittance(&sender_a, &sender_b, &100, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&sender_b, &sender_a, &90, &default_currency(&env), &default_country(&env), &None);
 
     let mut entries1 = Vec::new(&env);
     entries1.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
     entries1.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
 
     let fees_before = contract.get_accumulated_fees();
-    let result1 = contract.batch_settle_with_netting(&entries1);
-    assert!(result1.is_ok());
+    contract.batch_settle_with_netting(&admin, &entries1);
     let fees_after_batch1 = contract.get_accumulated_fees();
     let fees_batch1 = fees_after_batch1 - fees_before;
 
     // Second batch: B->A then A->B (reversed order)
-    let id3 = contract.create_remittance(&sender_b, &sender_a, &90, &None);
-    let id4 = contract.create_remittance(&sender_a, &sender_b, &100, &None);
+    let id3 = contract.create_remittance(&sender_b, &sender_a, &90, &default_currency(&env), &default_country(&env), &None);
+    let id4 = contract.create_remittance(&sender_a, &sender_b, &100, &default_currency(&env), &default_country(&env), &None);
 
     let mut entries2 = Vec::new(&env);
     entries2.push_back(crate::BatchSettlementEntry { remittance_id: id3 });
     entries2.push_back(crate::BatchSettlementEntry { remittance_id: id4 });
 
-    let result2 = contract.batch_settle_with_netting(&entries2);
-    assert!(result2.is_ok());
+    contract.batch_settle_with_netting(&admin, &entries2);
     let fees_after_batch2 = contract.get_accumulated_fees();
     let fees_batch2 = fees_after_batch2 - fees_after_batch1;
 
@@ -3095,36 +3104,6 @@ fn test_net_settlement_order_independence() {
 #[test]
 #[should_panic(expected = "Error(Contract, #3)")]
 fn test_net_settlement_empty_batch() {
-
-    let sender = Address::generate(&env);
-    let agent = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token_contract(&env, &token_admin);
-
-    let contract = create_swiftremit_contract(&env);
-
-    // Whitelist token
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
-
-    // Mint and create remittance
-    token.mint(&sender, &10000);
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-
-    // Complete the remittance
-    contract.confirm_payout(&remittance_id);
-
-    // Simulate settlement on completed remittance
-    let simulation = contract.simulate_settlement(&remittance_id);
-
-    assert_eq!(simulation.would_succeed, false);
-    assert_eq!(simulation.error_message, Some(7)); // InvalidStatus error code
-}
-
-#[test]
-fn test_simulate_settlement_nonexistent() {
-
     let env = Env::default();
     env.mock_all_auths();
 
@@ -3133,12 +3112,10 @@ fn test_simulate_settlement_nonexistent() {
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
     let entries = Vec::new(&env);
-    contract.batch_settle_with_netting(&entries);
+    contract.batch_settle_with_netting(&admin, &entries);
 }
 
 #[test]
@@ -3155,11 +3132,11 @@ fn test_net_settlement_exceeds_max_batch_size() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     token.mint(&sender, &100000);
+    env.budget().reset_unlimited();
 
     // Create more than MAX_BATCH_SIZE remittances
     let mut entries = Vec::new(&env);
@@ -3168,33 +3145,16 @@ fn test_net_settlement_exceeds_max_batch_size() {
         entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
     }
 
-    contract.batch_settle_with_netting(&entries);
+    contract.batch_settle_with_netting(&admin, &entries);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #12)")]
 fn test_net_settlement_duplicate_ids() {
-
-
-    // Whitelist token
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
-
-    // Simulate non-existent remittance
-    let simulation = contract.simulate_settlement(&999);
-
-    assert_eq!(simulation.would_succeed, false);
-    assert_eq!(simulation.error_message, Some(6)); // RemittanceNotFound error code
-}
-
-#[test]
-fn test_simulate_settlement_when_paused() {
-
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
@@ -3202,9 +3162,8 @@ fn test_simulate_settlement_when_paused() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     token.mint(&sender, &1000);
 
@@ -3214,7 +3173,7 @@ fn test_simulate_settlement_when_paused() {
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id }); // Duplicate
 
-    contract.batch_settle_with_netting(&entries);
+    contract.batch_settle_with_netting(&admin, &entries);
 }
 
 #[test]
@@ -3231,20 +3190,8 @@ fn test_net_settlement_already_completed() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-
-    let sender = Address::generate(&env);
-    let agent = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token_contract(&env, &token_admin);
-
-    let contract = create_swiftremit_contract(&env);
-
-    // Whitelist token
-
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
-
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     token.mint(&sender, &1000);
 
@@ -3257,35 +3204,16 @@ fn test_net_settlement_already_completed() {
     let mut entries = Vec::new(&env);
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
 
-    contract.batch_settle_with_netting(&entries);
+    contract.batch_settle_with_netting(&admin, &entries);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #13)")]
 fn test_net_settlement_when_paused() {
-    // Mint and create remittance
-    token.mint(&sender, &10000);
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-
-    // Pause contract
-    contract.pause();
-
-    // Simulate settlement while paused
-    let simulation = contract.simulate_settlement(&remittance_id);
-
-    assert_eq!(simulation.would_succeed, false);
-    assert_eq!(simulation.error_message, Some(13)); // ContractPaused error code
-}
-
-
-#[test]
-fn test_settlement_id_returned() {
-
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
@@ -3293,58 +3221,28 @@ fn test_settlement_id_returned() {
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-
-    let sender = Address::generate(&env);
-    let agent = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token_contract(&env, &token_admin);
-
-    let contract = create_swiftremit_contract(&env);
-
-
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
-
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     token.mint(&sender, &1000);
 
     let id = contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
 
     // Pause the contract
-    contract.pause(&admin);
+    contract.pause(&admin, &0);
 
     let mut entries = Vec::new(&env);
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
 
-    contract.batch_settle_with_netting(&entries);
+    contract.batch_settle_with_netting(&admin, &entries);
 }
 
 #[test]
 fn test_net_settlement_fee_preservation() {
-
-    token.mint(&sender, &10000);
-    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-
-    // Confirm payout should return the settlement ID
-    let settlement_id = contract.confirm_payout(&remittance_id);
-    
-    assert_eq!(settlement_id, remittance_id);
-    
-    // Should be able to query settlement using the ID
-    let settlement = contract.get_settlement(&settlement_id);
-    assert_eq!(settlement.id, settlement_id);
-    assert_eq!(settlement.status, crate::RemittanceStatus::Completed);
-}
-
-#[test]
-fn test_settlement_ids_sequential() {
-
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
@@ -3352,19 +3250,18 @@ fn test_settlement_ids_sequential() {
     let sender_b = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &500); // 5% fee
+    contract.initialize(&admin, &token.address, &500, &0, &None); // 5% fee
 
-    contract.register_agent(&sender_a);
-    contract.register_agent(&sender_b);
+    contract.register_agent(&admin, &sender_a);
+    contract.register_agent(&admin, &sender_b);
 
     token.mint(&sender_a, &10000);
     token.mint(&sender_b, &10000);
 
     // Create multiple remittances with different amounts
-    let id1 = contract.create_remittance(&sender_a, &sender_b, &1000, &None);
-    let id2 = contract.create_remittance(&sender_b, &sender_a, &800, &None);
-    let id3 = contract.create_remittance(&sender_a, &sender_b, &500, &None);
+    let id1 = contract.create_remittance(&sender_a, &sender_b, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&sender_b, &sender_a, &800, &default_currency(&env), &default_country(&env), &None);
+    let id3 = contract.create_remittance(&sender_a, &sender_b, &500, &default_currency(&env), &default_country(&env), &None);
 
     // Calculate expected fees manually
     let fee1 = 1000 * 500 / 10000; // 50
@@ -3378,11 +3275,8 @@ fn test_settlement_ids_sequential() {
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id3 });
 
     let fees_before = contract.get_accumulated_fees();
-    let result = contract.batch_settle_with_netting(&entries);
-    assert!(result.is_ok());
-
-    let fees_after = contract.get_accumulated_fees();
-    let fees_collected = fees_after - fees_before;
+    contract.batch_settle_with_netting(&admin, &entries);
+    let fees_collected = contract.get_accumulated_fees() - fees_before;
 
     // Verify all fees are preserved
     assert_eq!(fees_collected, expected_total_fees);
@@ -3390,51 +3284,6 @@ fn test_settlement_ids_sequential() {
 
 #[test]
 fn test_net_settlement_large_batch() {
-
-    let sender = Address::generate(&env);
-    let agent = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token_contract(&env, &token_admin);
-
-    let contract = create_swiftremit_contract(&env);
-
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
-
-    token.mint(&sender, &100000);
-
-    // Create multiple remittances and verify IDs are sequential
-    let id1 = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-    let id2 = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-    let id3 = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-
-    assert_eq!(id1, 1);
-    assert_eq!(id2, 2);
-    assert_eq!(id3, 3);
-
-    // Settle and verify settlement IDs match remittance IDs
-    let settlement_id1 = contract.confirm_payout(&id1);
-    let settlement_id2 = contract.confirm_payout(&id2);
-    let settlement_id3 = contract.confirm_payout(&id3);
-
-    assert_eq!(settlement_id1, id1);
-    assert_eq!(settlement_id2, id2);
-    assert_eq!(settlement_id3, id3);
-
-    // Verify all settlements can be queried
-    let s1 = contract.get_settlement(&settlement_id1);
-    let s2 = contract.get_settlement(&settlement_id2);
-    let s3 = contract.get_settlement(&settlement_id3);
-
-    assert_eq!(s1.id, 1);
-    assert_eq!(s2.id, 2);
-    assert_eq!(s3.id, 3);
-}
-
-#[test]
-fn test_settlement_id_uniqueness() {
-
     let env = Env::default();
     env.mock_all_auths();
 
@@ -3442,37 +3291,18 @@ fn test_settlement_id_uniqueness() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let token_admin = Address::generate(&env);
-    let token = create_token_contract(&env, &token_admin);
-
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
-
-    // Test zero amount
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.create_remittance(&sender, &agent, &0, &None);
-    }));
-    assert!(result.is_err());
-
-    // Test negative amount
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.create_remittance(&sender, &agent, &-100, &None);
-    }));
-    assert!(result.is_err());
-}
-
-#[test]
-fn test_validation_prevents_invalid_fee_bps() {
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &100);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &100, &0, &None);
+    contract.register_agent(&admin, &agent);
 
     token.mint(&sender, &1000000);
 
+    // Setup runs without a budget; the settlement itself gets the default one
+    env.budget().reset_unlimited();
+
     // Create maximum allowed batch size
     let mut entries = Vec::new(&env);
     for _ in 0..50 {
@@ -3480,11 +3310,9 @@ fn test_validation_prevents_invalid_fee_bps() {
         entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
     }
 
-    let result = contract.batch_settle_with_netting(&entries);
-    assert!(result.is_ok());
-
-    let settled = result.unwrap();
-    assert_eq!(settled.settled_ids.len(), 50);
+    env.budget().reset_default();
+    let result = contract.batch_settle_with_netting(&admin, &entries);
+    assert_eq!(result.settled_ids.len(), 50);
 }
 
 #[test]
@@ -3496,35 +3324,14 @@ fn test_net_settlement_reduces_transfer_count() {
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    let contract = create_swiftremit_contract(&env);
-
-    // Test fee > 10000 in initialize
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.initialize(&admin, &token.address, &10001);
-    }));
-    assert!(result.is_err());
-
-    // Initialize with valid fee
-    contract.initialize(&admin, &token.address, &250);
-
-    // Test fee > 10000 in update_fee
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.update_fee(&15000);
-    }));
-    assert!(result.is_err());
-}
-
-#[test]
-fn test_validation_prevents_unregistered_agent() {
     let party_a = Address::generate(&env);
     let party_b = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    contract.register_agent(&party_a);
-    contract.register_agent(&party_b);
+    contract.register_agent(&admin, &party_a);
+    contract.register_agent(&admin, &party_b);
 
     token.mint(&party_a, &10000);
     token.mint(&party_b, &10000);
@@ -3533,22 +3340,22 @@ fn test_validation_prevents_unregistered_agent() {
     let mut entries = Vec::new(&env);
     for i in 0..10 {
         let id = if i % 2 == 0 {
-            contract.create_remittance(&party_a, &party_b, &100, &None)
+            contract.create_remittance(&party_a, &party_b, &100, &default_currency(&env), &default_country(&env), &None)
         } else {
-            contract.create_remittance(&party_b, &party_a, &100, &None)
+            contract.create_remittance(&party_b, &party_a, &100, &default_currency(&env), &default_country(&env), &None)
         };
         entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
     }
 
-    let result = contract.batch_settle_with_netting(&entries);
-    assert!(result.is_ok());
-
     // All 10 remittances should be settled
-    let settled = result.unwrap();
-    assert_eq!(settled.settled_ids.len(), 10);
+    let result = contract.batch_settle_with_netting(&admin, &entries);
+    assert_eq!(result.settled_ids.len(), 10);
 
-    // But due to complete offsetting, net transfers should be minimal
-    // (In this case, 5 A->B and 5 B->A should completely offset)
+    // 5 A->B and 5 B->A completely offset, so no net transfer is made but
+    // every remittance's fee is still booked
+    assert_eq!(get_token_balance(&token, &party_a), 10000 - 5 * 100);
+    assert_eq!(get_token_balance(&token, &party_b), 10000 - 5 * 100);
+    assert_eq!(contract.get_accumulated_fees(), 10 * 2);
 }
 
 #[test]
@@ -3564,24 +3371,23 @@ fn test_net_settlement_mathematical_correctness() {
     let party_b = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &200); // 2% fee
+    contract.initialize(&admin, &token.address, &200, &0, &None); // 2% fee
 
-    contract.register_agent(&party_a);
-    contract.register_agent(&party_b);
+    contract.register_agent(&admin, &party_a);
+    contract.register_agent(&admin, &party_b);
 
     token.mint(&party_a, &100000);
     token.mint(&party_b, &100000);
 
     // Create specific amounts to test mathematical correctness
     // A -> B: 1000, 500, 300 = 1800 total
-    let id1 = contract.create_remittance(&party_a, &party_b, &1000, &None);
-    let id2 = contract.create_remittance(&party_a, &party_b, &500, &None);
-    let id3 = contract.create_remittance(&party_a, &party_b, &300, &None);
-    
+    let id1 = contract.create_remittance(&party_a, &party_b, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&party_a, &party_b, &500, &default_currency(&env), &default_country(&env), &None);
+    let id3 = contract.create_remittance(&party_a, &party_b, &300, &default_currency(&env), &default_country(&env), &None);
+
     // B -> A: 800, 400 = 1200 total
-    let id4 = contract.create_remittance(&party_b, &party_a, &800, &None);
-    let id5 = contract.create_remittance(&party_b, &party_a, &400, &None);
+    let id4 = contract.create_remittance(&party_b, &party_a, &800, &default_currency(&env), &default_country(&env), &None);
+    let id5 = contract.create_remittance(&party_b, &party_a, &400, &default_currency(&env), &default_country(&env), &None);
 
     // Net should be: 1800 - 1200 = 600 from A to B
 
@@ -3592,8 +3398,7 @@ fn test_net_settlement_mathematical_correctness() {
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id4 });
     entries.push_back(crate::BatchSettlementEntry { remittance_id: id5 });
 
-    let result = contract.batch_settle_with_netting(&entries);
-    assert!(result.is_ok());
+    contract.batch_settle_with_netting(&admin, &entries);
 
     // Calculate expected fees
     let fee1 = 1000 * 200 / 10000; // 20
@@ -3603,303 +3408,340 @@ fn test_net_settlement_mathematical_correctness() {
     let fee5 = 400 * 200 / 10000;  // 8
     let expected_fees = fee1 + fee2 + fee3 + fee4 + fee5; // 60
 
-    let fees = contract.get_accumulated_fees();
-    assert_eq!(fees, expected_fees);
+    assert_eq!(contract.get_accumulated_fees(), expected_fees);
+}
 
-    let sender1 = Address::generate(&env);
-    let sender2 = Address::generate(&env);
+// ============================================================================
+// Settlement Simulation Tests
+// ============================================================================
+
+#[test]
+fn test_simulate_settlement_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
     let agent = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
-
-    token.mint(&sender1, &50000);
-    token.mint(&sender2, &50000);
-
-    // Create remittances from different senders
-    let id1 = contract.create_remittance(&sender1, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-    let id2 = contract.create_remittance(&sender2, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-    let id3 = contract.create_remittance(&sender1, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
-
-    // All IDs should be unique
-    assert_ne!(id1, id2);
-    assert_ne!(id1, id3);
-    assert_ne!(id2, id3);
-
-    // Settle and verify unique settlement IDs
-    let settlement_id1 = contract.confirm_payout(&id1);
-    let settlement_id2 = contract.confirm_payout(&id2);
-    let settlement_id3 = contract.confirm_payout(&id3);
+    // Mint and create remittance
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
 
-    assert_ne!(settlement_id1, settlement_id2);
-    assert_ne!(settlement_id1, settlement_id3);
-    assert_ne!(settlement_id2, settlement_id3);
+    // Simulate settlement
+    let simulation = contract.simulate_settlement(&remittance_id);
 
+    assert!(simulation.would_succeed);
+    assert_eq!(simulation.payout_amount, 9750); // 10000 - 250 (2.5% fee)
+    assert_eq!(simulation.fee, 250);
+    assert_eq!(simulation.error_message, None);
 }
 
-
-// ═══════════════════════════════════════════════════════════════════════════
-// Migration Tests
-// ═══════════════════════════════════════════════════════════════════════════
-
 #[test]
-fn test_export_migration_state() {
+fn test_simulate_settlement_invalid_status() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Export state
-    let snapshot = contract.export_migration_state(&admin);
-    assert!(snapshot.is_ok());
+    // Mint and create remittance
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
 
-    let snap = snapshot.unwrap();
-    assert_eq!(snap.version, 1);
-    assert_eq!(snap.instance_data.platform_fee_bps, 250);
-    assert_eq!(snap.instance_data.remittance_counter, 0);
-    assert_eq!(snap.instance_data.accumulated_fees, 0);
+    // Complete the remittance
+    contract.confirm_payout(&remittance_id);
+
+    // Simulate settlement on completed remittance
+    let simulation = contract.simulate_settlement(&remittance_id);
+
+    assert!(!simulation.would_succeed);
+    assert_eq!(simulation.error_message, Some(7)); // InvalidStatus error code
 }
 
 #[test]
-fn test_export_import_migration_state() {
+fn test_simulate_settlement_nonexistent() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
-    let sender = Address::generate(&env);
-    let unregistered_agent = Address::generate(&env);
-
-    token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    // Try to create remittance with unregistered agent
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.create_remittance(&sender, &unregistered_agent, &1000, &None);
-    }));
-    assert!(result.is_err());
+    // Simulate non-existent remittance
+    let simulation = contract.simulate_settlement(&999);
+
+    assert!(!simulation.would_succeed);
+    assert_eq!(simulation.error_message, Some(6)); // RemittanceNotFound error code
 }
 
 #[test]
-fn test_validation_prevents_operations_on_nonexistent_remittance() {
-
-    // Create and populate first contract
-    let contract1 = create_swiftremit_contract(&env);
-    contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
+fn test_simulate_settlement_when_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
 
+    let admin = Address::generate(&env);
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
-    contract1.register_agent(&agent);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
 
-    token.mint(&sender, &1000);
-    let id = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Export state
-    let snapshot = contract1.export_migration_state(&admin).unwrap();
+    // Mint and create remittance
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
 
-    // Create new contract and import state
-    let contract2 = create_swiftremit_contract(&env);
-    let result = contract2.import_migration_state(&admin, snapshot);
-    assert!(result.is_ok());
+    // Pause contract
+    contract.pause(&admin, &0);
 
-    // Verify state was imported correctly
-    assert_eq!(contract2.get_platform_fee_bps(), 250);
-    assert_eq!(contract2.get_accumulated_fees(), 0);
+    // Simulate settlement while paused
+    let simulation = contract.simulate_settlement(&remittance_id);
 
-    let remittance = contract2.get_remittance(&id);
-    assert!(remittance.is_ok());
-    assert_eq!(remittance.unwrap().amount, 100);
+    assert!(!simulation.would_succeed);
+    assert_eq!(simulation.error_message, Some(13)); // ContractPaused error code
 }
 
 #[test]
-fn test_verify_migration_snapshot() {
+fn test_simulate_settlement_during_migration() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Export and verify
-    let snapshot = contract.export_migration_state(&admin).unwrap();
-    let verification = contract.verify_migration_snapshot(snapshot);
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
 
-    assert!(verification.valid);
-    assert_eq!(verification.expected_hash, verification.actual_hash);
+    contract.start_migration(&admin);
+
+    // The simulation reports the same error confirm_payout would return
+    let simulation = contract.simulate_settlement(&remittance_id);
+    assert!(!simulation.would_succeed);
+    assert_eq!(simulation.error_message, Some(21)); // MigrationInProgress error code
+    assert_eq!(
+        contract.try_confirm_payout(&remittance_id),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
 }
 
+// ============================================================================
+// Settlement ID Tests
+// ============================================================================
+
 #[test]
-fn test_migration_hash_detects_tampering() {
+fn test_settlement_ids_sequential() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Try to confirm payout for non-existent remittance
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.confirm_payout(&999);
-    }));
-    assert!(result.is_err());
-
-    // Try to cancel non-existent remittance
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.cancel_remittance(&999);
-    }));
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    token.mint(&sender, &100000);
 
-    // Export snapshot
-    let mut snapshot = contract.export_migration_state(&admin).unwrap();
+    // Create multiple remittances and verify IDs are sequential
+    let id1 = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let id3 = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
 
-    // Tamper with data
-    snapshot.instance_data.platform_fee_bps = 500;
+    assert_eq!(id1, 1);
+    assert_eq!(id2, 2);
+    assert_eq!(id3, 3);
 
-    // Verification should fail
-    let verification = contract.verify_migration_snapshot(snapshot.clone());
-    assert!(!verification.valid);
+    // Settle and verify each receipt points back at its remittance
+    contract.confirm_payout(&id1);
+    contract.confirm_payout(&id2);
+    contract.confirm_payout(&id3);
 
-    // Import should fail
-    let contract2 = create_swiftremit_contract(&env);
-    let result = contract2.import_migration_state(&admin, snapshot);
-    assert!(result.is_err());
+    assert_eq!(contract.get_remittance_receipt(&id1).remittance_id, id1);
+    assert_eq!(contract.get_remittance_receipt(&id2).remittance_id, id2);
+    assert_eq!(contract.get_remittance_receipt(&id3).remittance_id, id3);
 }
 
 #[test]
-fn test_validation_prevents_operations_on_completed_remittance() {
-#[should_panic(expected = "Error(Contract, #1)")]
-fn test_import_fails_if_already_initialized() {
+fn test_settlement_id_uniqueness() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
+    let sender1 = Address::generate(&env);
+    let sender2 = Address::generate(&env);
+    let agent = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
 
-    // Create and export from first contract
-    let contract1 = create_swiftremit_contract(&env);
-    contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
-    let snapshot = contract1.export_migration_state(&admin).unwrap();
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Create and initialize second contract
-    let contract2 = create_swiftremit_contract(&env);
-    contract2.whitelist_token(&admin, &token.address);
-    contract2.initialize(&admin, &token.address, &300);
+    token.mint(&sender1, &50000);
+    token.mint(&sender2, &50000);
 
-    // Import should fail because contract2 is already initialized
-    contract2.import_migration_state(&admin, snapshot);
+    // Create remittances from different senders
+    let id1 = contract.create_remittance(&sender1, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&sender2, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+    let id3 = contract.create_remittance(&sender1, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+
+    // All IDs should be unique
+    assert_ne!(id1, id2);
+    assert_ne!(id1, id3);
+    assert_ne!(id2, id3);
+
+    // Settle and verify unique settlement IDs
+    contract.confirm_payout(&id1);
+    contract.confirm_payout(&id2);
+    contract.confirm_payout(&id3);
+
+    let settlement_id1 = contract.get_remittance_receipt(&id1).settlement_id;
+    let settlement_id2 = contract.get_remittance_receipt(&id2).settlement_id;
+    let settlement_id3 = contract.get_remittance_receipt(&id3).settlement_id;
+
+    assert_ne!(settlement_id1, settlement_id2);
+    assert_ne!(settlement_id1, settlement_id3);
+    assert_ne!(settlement_id2, settlement_id3);
 }
 
+// ============================================================================
+// Centralized Validation Tests
+// ============================================================================
+
 #[test]
-fn test_export_migration_batch() {
+fn test_validation_prevents_invalid_amount() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    let sender = Address::generate(&env);
-    let agent = Address::generate(&env);
-    contract.register_agent(&agent);
+    // Test zero amount
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &0, &default_currency(&env), &default_country(&env), &None),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
 
-    token.mint(&sender, &10000);
+    // Test negative amount
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &-100, &default_currency(&env), &default_country(&env), &None),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+}
 
-    // Create 10 remittances
-    for _ in 0..10 {
-        contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
-    }
+#[test]
+fn test_validation_prevents_invalid_fee_bps() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
 
-    // Export in batches of 5
-    let batch0 = contract.export_migration_batch(&admin, 0, 5);
-    assert!(batch0.is_ok());
+    let contract = create_swiftremit_contract(&env);
 
-    let b0 = batch0.unwrap();
-    assert_eq!(b0.batch_number, 0);
-    assert_eq!(b0.total_batches, 2);
-    assert_eq!(b0.remittances.len(), 5);
+    // Test fee > 10000 in initialize
+    assert_eq!(
+        contract.try_initialize(&admin, &token.address, &10001, &0, &None),
+        Err(Ok(crate::ContractError::InvalidFeeBps))
+    );
 
-    let batch1 = contract.export_migration_batch(&admin, 1, 5);
-    assert!(batch1.is_ok());
+    // Initialize with valid fee
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    let b1 = batch1.unwrap();
-    assert_eq!(b1.batch_number, 1);
-    assert_eq!(b1.remittances.len(), 5);
+    // Test fee > 10000 in update_fee
+    assert_eq!(
+        contract.try_update_fee(&admin, &15000),
+        Err(Ok(crate::ContractError::InvalidFeeBps))
+    );
 }
 
 #[test]
-fn test_import_migration_batch() {
+fn test_validation_prevents_unregistered_agent() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
-
-    // Create and populate first contract
-    let contract1 = create_swiftremit_contract(&env);
-    contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
-
     let sender = Address::generate(&env);
-    let agent = Address::generate(&env);
-    contract1.register_agent(&agent);
+    let unregistered_agent = Address::generate(&env);
 
     token.mint(&sender, &10000);
 
-    // Create 5 remittances
-    for _ in 0..5 {
-        contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
-    }
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    // Export batch
-    let batch = contract1.export_migration_batch(&admin, 0, 5).unwrap();
+    // Try to create remittance with unregistered agent
+    assert_eq!(
+        contract.try_create_remittance(&sender, &unregistered_agent, &1000, &default_currency(&env), &default_country(&env), &None),
+        Err(Ok(crate::ContractError::AgentNotRegistered))
+    );
+}
 
-    // Create new contract and import batch
-    let contract2 = create_swiftremit_contract(&env);
-    contract2.whitelist_token(&admin, &token.address);
-    contract2.initialize(&admin, &token.address, &250);
+#[test]
+fn test_validation_prevents_operations_on_nonexistent_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    let result = contract2.import_migration_batch(&admin, batch);
-    assert!(result.is_ok());
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
 
-    // Verify remittances were imported
-    for id in 1..=5 {
-        let remittance = contract2.get_remittance(&id);
-        assert!(remittance.is_ok());
-    }
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // Try to confirm payout for non-existent remittance
+    assert_eq!(
+        contract.try_confirm_payout(&999),
+        Err(Ok(crate::ContractError::RemittanceNotFound))
+    );
+
+    // Try to cancel non-existent remittance
+    assert_eq!(
+        contract.try_cancel_remittance(&999),
+        Err(Ok(crate::ContractError::RemittanceNotFound))
+    );
 }
 
 #[test]
-fn test_migration_batch_hash_verification() {
+fn test_validation_prevents_operations_on_completed_remittance() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -3912,176 +3754,198 @@ fn test_migration_batch_hash_verification() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
     contract.confirm_payout(&remittance_id);
 
     // Try to cancel already completed remittance
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.cancel_remittance(&remittance_id);
-    }));
-    assert!(result.is_err());
+    assert_eq!(
+        contract.try_cancel_remittance(&remittance_id),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
 }
 
 #[test]
 fn test_validation_prevents_withdraw_with_no_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    let contract1 = create_swiftremit_contract(&env);
-    contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let recipient = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.add_treasury_destination(&admin, &recipient);
 
+    // Try to withdraw when no fees accumulated
+    assert_eq!(
+        contract.try_propose_fee_withdrawal(&admin, &token.address, &1, &recipient),
+        Err(Ok(crate::ContractError::NoFeesToWithdraw))
+    );
+}
+
+#[test]
+fn test_validation_prevents_paused_operations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
-    contract1.register_agent(&agent);
 
     token.mint(&sender, &10000);
 
-    // Create remittances
-    for _ in 0..5 {
-        contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
-    }
-
-    // Export batch
-    let mut batch = contract1.export_migration_batch(&admin, 0, 5).unwrap();
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Tamper with batch
-    let mut remittances = batch.remittances.clone();
-    let mut first = remittances.get_unchecked(0);
-    first.amount = 200; // Change amount
-    remittances.set(0, first);
-    batch.remittances = remittances;
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
-    // Import should fail due to hash mismatch
-    let contract2 = create_swiftremit_contract(&env);
-    contract2.whitelist_token(&admin, &token.address);
-    contract2.initialize(&admin, &token.address, &250);
+    // Pause contract
+    contract.pause(&admin, &0);
 
-    let result = contract2.import_migration_batch(&admin, batch);
-    assert!(result.is_err());
+    // Try to confirm payout while paused
+    assert_eq!(
+        contract.try_confirm_payout(&remittance_id),
+        Err(Ok(crate::ContractError::ContractPaused))
+    );
 }
 
 #[test]
-fn test_migration_preserves_all_data() {
+fn test_validation_allows_valid_operations() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
-
-    // Create and populate first contract
-    let contract1 = create_swiftremit_contract(&env);
-    contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
-
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
-    contract1.register_agent(&agent);
 
-    token.mint(&sender, &1000);
+    token.mint(&sender, &10000);
 
-    // Create remittance and complete it
-    let id = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
-    contract1.confirm_payout(&id);
+    let contract = create_swiftremit_contract(&env);
 
-    // Export state
-    let snapshot = contract1.export_migration_state(&admin).unwrap();
+    // Valid initialization
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    // Verify all data is in snapshot
-    assert_eq!(snapshot.instance_data.platform_fee_bps, 250);
-    assert_eq!(snapshot.instance_data.remittance_counter, 1);
-    assert!(snapshot.instance_data.accumulated_fees > 0);
-    assert_eq!(snapshot.persistent_data.remittances.len(), 1);
+    // Valid agent registration
+    contract.register_agent(&admin, &agent);
 
-    // Import to new contract
-    let contract2 = create_swiftremit_contract(&env);
-    contract2.import_migration_state(&admin, snapshot).unwrap();
+    // Valid remittance creation
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    assert_eq!(remittance_id, 1);
 
-    // Verify all data was imported
-    assert_eq!(contract2.get_platform_fee_bps(), 250);
-    assert!(contract2.get_accumulated_fees().unwrap() > 0);
+    // Valid payout confirmation
+    contract.confirm_payout(&remittance_id);
 
-    let remittance = contract2.get_remittance(&id).unwrap();
-    assert_eq!(remittance.status, crate::RemittanceStatus::Completed);
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
 }
 
 #[test]
-fn test_migration_deterministic_hash() {
+fn test_validation_structured_error_for_expired_settlement() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Export twice
-    let snapshot1 = contract.export_migration_state(&admin).unwrap();
-    let snapshot2 = contract.export_migration_state(&admin).unwrap();
+    // Create remittance with an expiry, then move past it
+    env.ledger().with_mut(|li| li.timestamp = 10000);
+    let expiry = env.ledger().timestamp() + 3600;
 
-    // Hashes should be identical (deterministic)
-    // Note: timestamps will differ, so we can't compare full snapshots
-    // but the hash algorithm should be deterministic for same data
-    assert_eq!(snapshot1.instance_data.platform_fee_bps, snapshot2.instance_data.platform_fee_bps);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &Some(expiry));
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+
+    // Validation should prevent expired settlement
+    assert_eq!(
+        contract.try_confirm_payout(&remittance_id),
+        Err(Ok(crate::ContractError::SettlementExpired))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #3)")]
-fn test_export_batch_invalid_size() {
+fn test_validation_prevents_duplicate_settlement() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
-    let recipient = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Try to withdraw when no fees accumulated
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.withdraw_fees(&recipient);
-    }));
-    assert!(result.is_err());
-}
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
-#[test]
-fn test_validation_prevents_paused_operations() {
+    // First settlement succeeds
+    contract.confirm_payout(&remittance_id);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    // Manually reset status to test duplicate prevention
+    let mut remittance = contract.get_remittance(&remittance_id);
+    remittance.status = crate::types::RemittanceStatus::Pending;
+    env.as_contract(&contract.address, || {
+        crate::storage::set_remittance(&env, remittance_id, &remittance);
+    });
 
-    // Try to export with batch size > MAX_MIGRATION_BATCH_SIZE
-    contract.export_migration_batch(&admin, 0, 101);
+    // Second settlement should be prevented by validation
+    assert_eq!(
+        contract.try_confirm_payout(&remittance_id),
+        Err(Ok(crate::ContractError::DuplicateSettlement))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #3)")]
-fn test_export_batch_zero_size() {
+fn test_validation_comprehensive_create_remittance() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.whitelist_token(&admin, &token.address);
-    contract.initialize(&admin, &token.address, &250);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Try to export with zero batch size
-    contract.export_migration_batch(&admin, 0, 0);
+    // Test all validation passes for valid request
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    assert_eq!(remittance_id, 1);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.sender, sender);
+    assert_eq!(remittance.agent, agent);
+    assert_eq!(remittance.amount, 1000);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Pending);
 }
 
 #[test]
-fn test_migration_with_multiple_remittance_statuses() {
+fn test_validation_comprehensive_confirm_payout() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -4094,149 +3958,98 @@ fn test_migration_with_multiple_remittance_statuses() {
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let current_time = env.ledger().timestamp();
+    let future_expiry = current_time + 7200;
 
-    // Pause contract
-    contract.pause();
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &Some(future_expiry));
 
-    // Try to confirm payout while paused
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.confirm_payout(&remittance_id);
-    }));
-    assert!(result.is_err());
+    // All validations should pass
+    contract.confirm_payout(&remittance_id);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
+    assert_eq!(get_token_balance(&token, &agent), 975);
 }
 
 #[test]
-fn test_validation_allows_valid_operations() {
-
-    let contract1 = create_swiftremit_contract(&env);
-    contract1.whitelist_token(&admin, &token.address);
-    contract1.initialize(&admin, &token.address, &250);
+fn test_validation_comprehensive_cancel_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
 
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
-    contract1.register_agent(&agent);
 
     token.mint(&sender, &10000);
 
-    // Create remittances with different statuses
-    let id1 = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None); // Pending
-    let id2 = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
-    contract1.confirm_payout(&id2); // Completed
-    let id3 = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
-    contract1.cancel_remittance(&id3); // Cancelled
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Export and import
-    let snapshot = contract1.export_migration_state(&admin).unwrap();
-    let contract2 = create_swiftremit_contract(&env);
-    contract2.import_migration_state(&admin, snapshot).unwrap();
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
 
-    // Verify all statuses preserved
-    assert_eq!(contract2.get_remittance(&id1).unwrap().status, crate::RemittanceStatus::Pending);
-    assert_eq!(contract2.get_remittance(&id2).unwrap().status, crate::RemittanceStatus::Completed);
-    assert_eq!(contract2.get_remittance(&id3).unwrap().status, crate::RemittanceStatus::Cancelled);
-}
+    // All validations should pass
+    contract.cancel_remittance(&remittance_id);
 
-// ═══════════════════════════════════════════════════════════════════════════
-// Rate Limiting Tests
-// ═══════════════════════════════════════════════════════════════════════════
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Cancelled);
+    assert_eq!(get_token_balance(&token, &sender), 10000); // Refunded
+}
 
 #[test]
-fn test_rate_limit_initialization() {
+fn test_validation_comprehensive_withdraw_fees() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
-    let contract = create_swiftremit_contract(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let recipient = Address::generate(&env);
 
     token.mint(&sender, &10000);
 
     let contract = create_swiftremit_contract(&env);
-    
-    // Valid initialization
-    contract.initialize(&admin, &token.address, &250);
-    
-    // Valid agent registration
-    contract.register_agent(&agent);
-    
-    // Valid remittance creation
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
-    assert_eq!(remittance_id, 1);
-    
-    // Valid payout confirmation
-    contract.confirm_payout(&remittance_id);
-    
-    let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
-}
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-#[test]
-fn test_validation_structured_error_for_expired_settlement() {
-    token.mint(&sender, &20000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.confirm_payout(&remittance_id);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    // All validations should pass
+    withdraw_all_fees(&env, &contract, &admin, &token.address, &recipient);
 
-    // Check default rate limit config
-    let (max_requests, window_seconds, enabled) = contract.get_rate_limit_config();
-    assert_eq!(max_requests, 100);
-    assert_eq!(window_seconds, 60);
-    assert!(enabled);
+    assert_eq!(get_token_balance(&token, &recipient), 25);
+    assert_eq!(contract.get_accumulated_fees(), 0);
 }
 
 #[test]
-fn test_update_rate_limit() {
+fn test_validation_edge_case_boundary_fee() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
-    let sender = Address::generate(&env);
-    let agent = Address::generate(&env);
-
-    token.mint(&sender, &10000);
-    token.mint(&sender, &20000);
-
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
-
-    // Create remittance with past expiry
-    let current_time = env.ledger().timestamp();
-    let past_expiry = current_time.saturating_sub(3600);
-    
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &Some(past_expiry));
-
-    // Validation should prevent expired settlement
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.confirm_payout(&remittance_id);
-    }));
-    assert!(result.is_err());
-}
-
-#[test]
-fn test_validation_prevents_duplicate_settlement() {
-    let currency = String::from_str(&env, "USD");
-    let country = String::from_str(&env, "US");
-
-    // Set daily limit to 10000
-    contract.set_daily_limit(&currency, &country, &10000);
 
-    // First transfer of 6000 should succeed
-    contract.create_remittance(&sender, &agent, &6000, &currency, &country, &None);
+    // Test boundary: 10000 should be valid (100%)
+    contract.initialize(&admin, &token.address, &10000, &0, &None);
+    assert_eq!(contract.get_platform_fee_bps(), 10000);
 
-    // Second transfer of 5000 should fail (total 11000 > 10000)
-    contract.create_remittance(&sender, &agent, &5000, &currency, &country, &None);
+    // Test boundary: 0 should be valid (0%)
+    contract.update_fee(&admin, &0);
+    assert_eq!(contract.get_platform_fee_bps(), 0);
 }
 
 #[test]
-fn test_daily_limit_rolling_window() {
+fn test_validation_edge_case_minimum_amount() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -4247,563 +4060,1578 @@ fn test_daily_limit_rolling_window() {
     let agent = Address::generate(&env);
 
     token.mint(&sender, &10000);
-    token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
-
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
-
-    // First settlement succeeds
-    contract.confirm_payout(&remittance_id);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
 
-    // Manually reset status to test duplicate prevention
-    let mut remittance = contract.get_remittance(&remittance_id);
-    remittance.status = crate::types::RemittanceStatus::Pending;
-    env.as_contract(&contract.address, || {
-        crate::storage::set_remittance(&env, remittance_id, &remittance);
-    });
+    // Minimum valid amount is 1
+    let remittance_id = contract.create_remittance(&sender, &agent, &1, &default_currency(&env), &default_country(&env), &None);
+    assert_eq!(remittance_id, 1);
 
-    // Second settlement should be prevented by validation
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.confirm_payout(&remittance_id);
-    }));
-    assert!(result.is_err());
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.amount, 1);
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// Migration Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
 #[test]
-fn test_validation_comprehensive_create_remittance() {
-    let currency = String::from_str(&env, "USD");
-    let country = String::from_str(&env, "US");
+fn test_export_migration_state() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    // Update rate limit
-    contract.update_rate_limit(&admin, &50, &30, &true);
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
 
-    let (max_requests, window_seconds, enabled) = contract.get_rate_limit_config();
-    assert_eq!(max_requests, 50);
-    assert_eq!(window_seconds, 30);
-    assert!(enabled);
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // Export state
+    contract.start_migration(&admin);
+    let snap = contract.export_migration_state(&admin);
+    assert_eq!(snap.version, 1);
+    assert_eq!(snap.instance_data.platform_fee_bps, 250);
+    assert_eq!(snap.instance_data.remittance_counter, 0);
+    assert_eq!(snap.instance_data.accumulated_fees, 0);
 }
 
 #[test]
-fn test_rate_limit_status() {
+fn test_export_import_migration_state() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
+
+    // Create and populate first contract
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
+    contract1.register_agent(&admin, &agent);
 
-    token.mint(&sender, &10000);
-    token.mint(&sender, &30000);
+    token.mint(&sender, &1000);
+    let id = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    // Export state
+    contract1.start_migration(&admin);
+    let snapshot = contract1.export_migration_state(&admin);
 
-    // Test all validation passes for valid request
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
-    assert_eq!(remittance_id, 1);
+    // Create new contract and import state
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    contract2.import_migration_state(&admin, &snapshot);
 
-    let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.sender, sender);
-    assert_eq!(remittance.agent, agent);
-    assert_eq!(remittance.amount, 1000);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Pending);
+    // Verify state was imported correctly
+    assert_eq!(contract2.get_platform_fee_bps(), 250);
+    assert_eq!(contract2.get_accumulated_fees(), 0);
+    assert_eq!(contract2.get_remittance(&id).amount, 100);
 }
 
 #[test]
-fn test_validation_comprehensive_confirm_payout() {
-    let usd = String::from_str(&env, "USD");
-    let eur = String::from_str(&env, "EUR");
-    let us = String::from_str(&env, "US");
+fn test_verify_migration_snapshot() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    // Set different limits for different currencies
-    contract.set_daily_limit(&usd, &us, &10000);
-    contract.set_daily_limit(&eur, &us, &15000);
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
 
-    // Transfer 9000 in USD should succeed
-    contract.create_remittance(&sender, &agent, &9000, &usd, &us, &None);
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    // Transfer 14000 in EUR should succeed (different currency limit)
-    contract.create_remittance(&sender, &agent, &14000, &eur, &us, &None);
+    // Export and verify
+    contract.start_migration(&admin);
+    let snapshot = contract.export_migration_state(&admin);
+    let verification = contract.verify_migration_snapshot(&snapshot);
 
-    assert_eq!(token.balance(&contract.address), 23000);
+    assert!(verification.valid);
+    assert_eq!(verification.expected_hash, verification.actual_hash);
 }
 
 #[test]
-fn test_daily_limit_different_countries() {
+fn test_migration_hash_detects_tampering() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
-    let sender = Address::generate(&env);
-    let agent = Address::generate(&env);
-
-    token.mint(&sender, &10000);
-    token.mint(&sender, &30000);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    let current_time = env.ledger().timestamp();
-    let future_expiry = current_time + 7200;
+    // Export snapshot
+    contract.start_migration(&admin);
+    let mut snapshot = contract.export_migration_state(&admin);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &Some(future_expiry));
+    // Tamper with data
+    snapshot.instance_data.platform_fee_bps = 500;
 
-    // All validations should pass
-    contract.confirm_payout(&remittance_id);
+    // Verification should fail
+    let verification = contract.verify_migration_snapshot(&snapshot);
+    assert!(!verification.valid);
 
-    let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
-    assert_eq!(token.balance(&agent), 975);
+    // Import should fail
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract.address);
+    assert_eq!(
+        contract2.try_import_migration_state(&admin, &snapshot),
+        Err(Ok(crate::ContractError::InvalidMigrationHash))
+    );
 }
 
 #[test]
-fn test_validation_comprehensive_cancel_remittance() {
-    let usd = String::from_str(&env, "USD");
-    let us = String::from_str(&env, "US");
-    let uk = String::from_str(&env, "UK");
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_import_fails_if_already_initialized() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    // Set different limits for different countries
-    contract.set_daily_limit(&usd, &us, &10000);
-    contract.set_daily_limit(&usd, &uk, &15000);
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
 
-    // Transfer 9000 to US should succeed
-    contract.create_remittance(&sender, &agent, &9000, &usd, &us, &None);
+    // Create and export from first contract
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+    contract1.start_migration(&admin);
+    let snapshot = contract1.export_migration_state(&admin);
 
-    // Transfer 14000 to UK should succeed (different country limit)
-    contract.create_remittance(&sender, &agent, &14000, &usd, &uk, &None);
+    // Create and initialize second contract
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    contract2.initialize(&admin, &token.address, &300, &0, &None);
 
-    assert_eq!(token.balance(&contract.address), 23000);
+    // Import should fail because contract2 is already initialized
+    contract2.import_migration_state(&admin, &snapshot);
 }
 
 #[test]
-fn test_daily_limit_no_limit_configured() {
+fn test_export_migration_batch() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let sender = Address::generate(&env);
-    let agent = Address::generate(&env);
-
-    token.mint(&sender, &10000);
-    token.mint(&sender, &100000);
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
 
     let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    contract.register_agent(&admin, &agent);
 
-    // All validations should pass
-    contract.cancel_remittance(&remittance_id);
+    token.mint(&sender, &10000);
+    // The daily limit makes the contract keep the sender's transfer history
+    contract.set_daily_limit(&admin, &default_currency(&env), &default_country(&env), &1_000_000);
 
-    let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Cancelled);
-    assert_eq!(token.balance(&sender), 10000); // Refunded
-}
+    // Create 10 remittances
+    for _ in 0..10 {
+        contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    }
 
-#[test]
-fn test_validation_comprehensive_withdraw_fees() {
-    let currency = String::from_str(&env, "USD");
-    let country = String::from_str(&env, "US");
+    // Config, admins, tokens, token fees, agents, daily limits, 2 x remittances,
+    // 2 x receipts, users
+    contract.start_migration(&admin);
+    let b0 = contract.export_migration_batch(&admin, &0, &5);
+    assert_eq!(b0.batch_number, 0);
+    assert_eq!(b0.total_batches, 11);
+    assert!(matches!(b0.payload, crate::MigrationPayload::Config(_)));
+
+    let mut remittance_batches = 0;
+    for n in 1..b0.total_batches {
+        let batch = contract.export_migration_batch(&admin, &n, &5);
+        if let crate::MigrationPayload::Remittances(remittances) = batch.payload {
+            assert_eq!(remittances.len(), 5);
+            remittance_batches += 1;
+        }
+    }
+    assert_eq!(remittance_batches, 2);
 
-    // No limit configured, large transfer should succeed
-    let remittance_id = contract.create_remittance(&sender, &agent, &50000, &currency, &country, &None);
-    assert_eq!(remittance_id, 1);
-    assert_eq!(token.balance(&contract.address), 50000);
+    let digest = contract.get_migration_export_digest();
+    assert_eq!(digest.total_batches, 11);
 }
 
 #[test]
-fn test_daily_limit_multiple_users() {
+fn test_import_migration_batch() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
+
+    // Create and populate first contract
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
-    let recipient = Address::generate(&env);
+    contract1.register_agent(&admin, &agent);
+    contract1.set_daily_limit(&admin, &default_currency(&env), &default_country(&env), &5000);
 
     token.mint(&sender, &10000);
-    let sender1 = Address::generate(&env);
-    let sender2 = Address::generate(&env);
-    let agent = Address::generate(&env);
 
-    token.mint(&sender1, &20000);
-    token.mint(&sender2, &20000);
+    // Create 5 remittances and settle one
+    for _ in 0..5 {
+        contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    }
+    contract1.confirm_payout(&1);
 
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract1.start_migration(&admin);
+    let first = contract1.export_migration_batch(&admin, &0, &5);
+    let mut batches = std::vec![first.clone()];
+    for n in 1..first.total_batches {
+        batches.push(contract1.export_migration_batch(&admin, &n, &5));
+    }
+    let digest = contract1.get_migration_export_digest();
 
-    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
-    contract.confirm_payout(&remittance_id);
+    // Import into a fresh contract
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    for batch in batches {
+        contract2.import_migration_batch(&admin, &batch);
+    }
+    contract2.finalize_migration_import(&admin, &digest);
+    assert!(contract2.get_migration_import_status().finalized);
 
-    // All validations should pass
-    contract.withdraw_fees(&recipient);
+    // Every category arrived
+    assert_eq!(contract2.get_platform_fee_bps(), 250);
+    assert!(contract2.is_agent_registered(&agent));
+    assert_eq!(contract2.list_whitelisted_tokens(&0, &10).get_unchecked(0), token.address);
+    assert_eq!(
+        contract2.get_daily_limit(&default_currency(&env), &default_country(&env)).unwrap().limit,
+        5000
+    );
+    for id in 1..=5 {
+        assert_eq!(contract2.get_remittance(&id), contract1.get_remittance(&id));
+    }
+    assert_eq!(
+        contract2.get_remittance_receipt(&1),
+        contract1.get_remittance_receipt(&1)
+    );
+    assert_eq!(
+        contract2.get_last_settlement_time(&sender),
+        contract1.get_last_settlement_time(&sender)
+    );
 
-    assert_eq!(token.balance(&recipient), 25);
-    assert_eq!(contract.get_accumulated_fees(), 0);
+    // The settled flag came across: no second settlement on the new contract
+    assert_eq!(
+        contract2.try_confirm_payout(&1),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
 }
 
 #[test]
-fn test_validation_edge_case_boundary_fee() {
-    let currency = String::from_str(&env, "USD");
-    let country = String::from_str(&env, "US");
+fn test_partially_imported_contract_is_closed_until_finalized() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    // Set daily limit to 10000
-    contract.set_daily_limit(&currency, &country, &10000);
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
 
-    // Each user should have their own limit
-    contract.create_remittance(&sender1, &agent, &9000, &currency, &country, &None);
-    contract.create_remittance(&sender2, &agent, &9000, &currency, &country, &None);
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    contract1.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+    let id = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+
+    contract1.start_migration(&admin);
+    let first = contract1.export_migration_batch(&admin, &0, &5);
+    let mut batches = std::vec![first.clone()];
+    for n in 1..first.total_batches {
+        batches.push(contract1.export_migration_batch(&admin, &n, &5));
+    }
+    let digest = contract1.get_migration_export_digest();
+
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    contract2.import_migration_batch(&admin, &batches[0]);
+
+    // Batch 0 initialized the contract, but the rest of the state is missing
+    assert_eq!(
+        contract2.try_confirm_payout(&id),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
+    assert_eq!(
+        contract2.try_cancel_remittance(&id),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
+    assert_eq!(
+        contract2.try_register_agent(&admin, &Address::generate(&env)),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
+    assert_eq!(
+        contract2.try_get_platform_fee_bps(),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
+    assert_eq!(
+        contract2.try_get_accumulated_fees(),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
+    assert_eq!(
+        contract2.try_get_remittance(&id),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
 
-    assert_eq!(token.balance(&contract.address), 18000);
+    for batch in batches.into_iter().skip(1) {
+        contract2.import_migration_batch(&admin, &batch);
+    }
+    contract2.finalize_migration_import(&admin, &digest);
+
+    assert_eq!(contract2.get_platform_fee_bps(), 250);
+    assert_eq!(contract2.get_remittance(&id), contract1.get_remittance(&id));
 }
 
 #[test]
-fn test_rate_limit_disable() {
+fn test_migration_batches_carry_role_holders() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
-    let contract = create_swiftremit_contract(&env);
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
+    // Enough holders to spread the role registries over several batches
+    let mut treasurers = std::vec::Vec::new();
+    for _ in 0..4 {
+        let treasurer = Address::generate(&env);
+        contract1.grant_role(&admin, &crate::Role::Treasurer, &treasurer);
+        treasurers.push(treasurer);
+    }
+    let migrator = Address::generate(&env);
+    contract1.grant_role(&admin, &crate::Role::Migrator, &migrator);
+    contract1.grant_role(&admin, &crate::Role::Pauser, &migrator);
+    contract1.revoke_role(&admin, &crate::Role::Pauser, &migrator);
 
-    // Test boundary: 10000 should be valid (100%)
-    contract.initialize(&admin, &token.address, &10000);
-    assert_eq!(contract.get_platform_fee_bps(), 10000);
+    let contract2 = migrate_in_batches(&env, &contract1, &admin);
 
-    // Test boundary: 0 should be valid (0%)
-    contract.update_fee(&0);
-    assert_eq!(contract.get_platform_fee_bps(), 0);
+    for treasurer in treasurers.iter() {
+        assert!(contract2.has_role(&crate::Role::Treasurer, treasurer));
+    }
+    assert!(contract2.has_role(&crate::Role::Migrator, &migrator));
+    assert!(!contract2.has_role(&crate::Role::Pauser, &migrator));
+    assert_eq!(contract2.list_role_holders(&crate::Role::Treasurer, &0, &10).len(), 4);
 }
 
 #[test]
-fn test_validation_edge_case_minimum_amount() {
-    contract.initialize(&admin, &token.address, &250);
-
-    let currency = String::from_str(&env, "USD");
-    let country = String::from_str(&env, "US");
+fn test_migration_batches_carry_kyc_state() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    // Negative limit should fail
-    contract.set_daily_limit(&currency, &country, &-1000);
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
+    let limits = crate::KycTierLimits {
+        max_transfer_amount: 500,
+        max_daily_volume: 1_000,
+    };
+    contract1.set_kyc_tier_limits(&admin, &1, &Some(limits.clone()));
+    contract1.set_kyc_tier_limits(&admin, &2, &Some(limits.clone()));
+    contract1.set_kyc_tier_limits(&admin, &2, &None);
+
+    let verified = Address::generate(&env);
+    let revoked = Address::generate(&env);
+    contract1.set_kyc(&admin, &verified, &1, &86_400);
+    contract1.set_kyc(&admin, &revoked, &1, &86_400);
+    contract1.revoke_kyc(&admin, &revoked);
+    // Re-verified after a revocation
+    contract1.revoke_kyc(&admin, &verified);
+    contract1.set_kyc(&admin, &verified, &1, &86_400);
+    contract1.set_kyc_required(&admin, &true);
+
+    let contract2 = migrate_in_batches(&env, &contract1, &admin);
+
+    assert_eq!(contract2.get_kyc(&verified), contract1.get_kyc(&verified));
+    assert_eq!(contract2.get_kyc(&revoked), None);
+    assert_eq!(contract2.get_kyc_tier_limits(&1), Some(limits));
+    assert_eq!(contract2.get_kyc_tier_limits(&2), None);
+
+    // KYC is enforced on the successor
+    let unverified = Address::generate(&env);
+    let agent = Address::generate(&env);
+    contract2.register_agent(&admin, &agent);
+    token.mint(&unverified, &1_000);
+    assert_eq!(
+        contract2.try_create_remittance(&unverified, &agent, &100, &default_currency(&env), &default_country(&env), &None),
+        Err(Ok(crate::ContractError::KycRequired))
+    );
 }
 
 #[test]
-fn test_daily_limit_exact_limit() {
+fn test_migration_batches_carry_sanctions_state() {
     let env = Env::default();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
+    let denied = Address::generate(&env);
+    let allowed = Address::generate(&env);
+    contract1.deny_address(&admin, &denied, &7);
+    contract1.deny_address(&admin, &allowed, &7);
+    contract1.allow_address(&admin, &allowed);
+    let screening = Address::generate(&env);
+    contract1.set_screening_contract(&admin, &Some(screening.clone()));
+
+    let contract2 = migrate_in_batches(&env, &contract1, &admin);
+
+    assert_eq!(
+        contract2.get_denylist_entry(&denied),
+        contract1.get_denylist_entry(&denied)
+    );
+    assert_eq!(contract2.get_denylist_entry(&allowed), None);
+    assert_eq!(contract2.get_screening_contract(), Some(screening));
+}
+
+#[test]
+fn test_migration_batches_carry_fees_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
 
-    token.mint(&sender, &10000);
-    token.mint(&sender, &20000);
-
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract1.whitelist_token(&admin, &eurc.address);
+    contract1.register_agent(&admin, &agent);
 
-    // Minimum valid amount is 1
-    let remittance_id = contract.create_remittance(&sender, &agent, &1, &None);
-    assert_eq!(remittance_id, 1);
+    usdc.mint(&sender, &10000);
+    eurc.mint(&sender, &10000);
+    let usdc_id = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let eurc_id =
+        contract1.create_remittance_with_token(&sender, &agent, &2000, &eurc.address, &default_currency(&env), &default_country(&env), &None);
+    contract1.confirm_payout(&usdc_id);
+    contract1.confirm_payout(&eurc_id);
+    // Fees collected in a token stay withdrawable after it leaves the whitelist
+    contract1.remove_whitelisted_token(&admin, &eurc.address);
 
-    let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.amount, 1);
-    let currency = String::from_str(&env, "USD");
-    let country = String::from_str(&env, "US");
+    let contract2 = migrate_in_batches(&env, &contract1, &admin);
 
-    let (_, _, enabled) = contract.get_rate_limit_config();
-    assert!(!enabled);
+    assert_eq!(contract2.get_token_fees(&usdc.address), 25);
+    assert_eq!(contract2.get_token_fees(&eurc.address), 50);
+    assert!(!contract2.is_token_whitelisted(&eurc.address));
 }
 
+#[test]
+fn test_migration_snapshot_carries_fees_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-// ============================================================================
-// Centralized Error Handling Tests
-// ============================================================================
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract1.whitelist_token(&admin, &eurc.address);
+    contract1.register_agent(&admin, &agent);
+
+    eurc.mint(&sender, &10000);
+    let eurc_id =
+        contract1.create_remittance_with_token(&sender, &agent, &2000, &eurc.address, &default_currency(&env), &default_country(&env), &None);
+    contract1.confirm_payout(&eurc_id);
+    contract1.remove_whitelisted_token(&admin, &eurc.address);
+
+    contract1.start_migration(&admin);
+    let snapshot = contract1.export_migration_state(&admin);
+    assert_eq!(snapshot.persistent_data.token_fees.len(), 1);
+
+    // The fees are covered by the snapshot hash
+    let mut tampered = snapshot.clone();
+    tampered.persistent_data.token_fees = soroban_sdk::Vec::new(&env);
+    assert!(!contract1.verify_migration_snapshot(&tampered).valid);
+
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    contract2.import_migration_state(&admin, &snapshot);
+    assert_eq!(contract2.get_token_fees(&eurc.address), 50);
+}
 
 #[test]
-fn test_error_handler_validation_errors() {
+fn test_migration_batches_carry_fx_config() {
     let env = Env::default();
-    
-    // Test InvalidAmount
-    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::InvalidAmount);
-    assert_eq!(response.code, 3);
-    assert_eq!(response.category, crate::error_handler::ErrorCategory::Validation);
-    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Low);
-    
-    // Test InvalidFeeBps
-    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::InvalidFeeBps);
-    assert_eq!(response.code, 4);
-    assert_eq!(response.category, crate::error_handler::ErrorCategory::Validation);
-    
-    // Test InvalidAddress
-    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::InvalidAddress);
-    assert_eq!(response.code, 10);
-    assert_eq!(response.category, crate::error_handler::ErrorCategory::Validation);
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
+    let oracle = Address::generate(&env);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract1.whitelist_token(&admin, &eurc.address);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let posted = contract1.set_fx_rate(&admin, &eurc.address, &usdc.address, &11_000_000);
+    // A reposted rate replaces the earlier one
+    contract1.set_fx_rate(&admin, &eurc.address, &usdc.address, &10_900_000);
+    contract1.set_fx_rate(&admin, &usdc.address, &eurc.address, &9_100_000);
+    contract1.set_fx_rate_max_age(&admin, &600);
+    contract1.set_fx_oracle(&admin, &Some(oracle.clone()));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_300);
+    let contract2 = migrate_in_batches(&env, &contract1, &admin);
+
+    // Rates keep the time they were posted, so their age carries over
+    let rate = contract2.get_fx_rate(&eurc.address, &usdc.address).unwrap();
+    assert_eq!(rate.rate, 10_900_000);
+    assert_eq!(rate.updated_at, posted.updated_at);
+    assert_eq!(
+        contract2.get_fx_rate(&usdc.address, &eurc.address),
+        contract1.get_fx_rate(&usdc.address, &eurc.address)
+    );
+    assert_eq!(contract2.get_fx_rate_max_age(), 600);
+    assert_eq!(contract2.get_fx_oracle(), Some(oracle));
 }
 
 #[test]
-fn test_error_handler_authorization_errors() {
+fn test_migration_batches_carry_treasury_state() {
     let env = Env::default();
-    
-    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::Unauthorized);
-    assert_eq!(response.code, 14);
-    assert_eq!(response.category, crate::error_handler::ErrorCategory::Authorization);
-    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Medium);
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+    contract1.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+    let remittance_id = contract1.create_remittance(&sender, &agent, &4000, &default_currency(&env), &default_country(&env), &None);
+    contract1.confirm_payout(&remittance_id);
+
+    let treasurer = Address::generate(&env);
+    contract1.grant_role(&admin, &crate::Role::Treasurer, &treasurer);
+    contract1.set_withdrawal_timelock(&admin, &(3 * 86_400));
+    let treasury = Address::generate(&env);
+    let retired = Address::generate(&env);
+    contract1.add_treasury_destination(&admin, &treasury);
+    contract1.add_treasury_destination(&admin, &retired);
+    let vetoed = contract1.propose_fee_withdrawal(&admin, &token.address, &40, &retired);
+    contract1.veto_fee_withdrawal(&treasurer, &vetoed.id);
+    contract1.remove_treasury_destination(&admin, &retired);
+    let pending = contract1.propose_fee_withdrawal(&admin, &token.address, &60, &treasury);
+
+    let contract2 = migrate_in_batches(&env, &contract1, &admin);
+
+    assert_eq!(contract2.get_withdrawal_timelock(), 3 * 86_400);
+    assert!(contract2.is_treasury_destination(&treasury));
+    assert!(!contract2.is_treasury_destination(&retired));
+    assert_eq!(contract2.get_fee_withdrawal(&vetoed.id), contract1.get_fee_withdrawal(&vetoed.id));
+    assert_eq!(contract2.get_fee_withdrawal(&pending.id), pending);
+
+    // The pending proposal can still be vetoed, and new proposals continue the IDs
+    contract2.veto_fee_withdrawal(&treasurer, &pending.id);
+    let next = contract2.propose_fee_withdrawal(&admin, &token.address, &10, &treasury);
+    assert_eq!(next.id, 3);
 }
 
 #[test]
-fn test_error_handler_state_errors() {
+fn test_migration_batches_carry_pause_state() {
     let env = Env::default();
-    
-    // Test ContractPaused
-    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::ContractPaused);
-    assert_eq!(response.code, 13);
-    assert_eq!(response.category, crate::error_handler::ErrorCategory::State);
-    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Low);
-    
-    // Test DuplicateSettlement
-    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::DuplicateSettlement);
-    assert_eq!(response.code, 12);
-    assert_eq!(response.category, crate::error_handler::ErrorCategory::State);
-    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Medium);
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+    contract1.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+    let kes = String::from_str(&env, "KES");
+    let ke = String::from_str(&env, "KE");
+    let remittance_id = contract1.create_remittance(&sender, &agent, &1000, &kes, &ke, &None);
+
+    let corridor = crate::PauseScope::Corridor(kes.clone(), ke.clone());
+    contract1.pause_scope(&admin, &corridor, &3);
+    contract1.pause_scope(&admin, &crate::PauseScope::Operation(crate::PausableOperation::Creation), &4);
+    contract1.pause_scope(&admin, &crate::PauseScope::Token(token.address.clone()), &5);
+    contract1.unpause_scope(&admin, &crate::PauseScope::Token(token.address.clone()));
+    contract1.pause(&admin, &9);
+
+    let contract2 = migrate_in_batches(&env, &contract1, &admin);
+
+    assert_eq!(contract2.get_pause_state(), contract1.get_pause_state());
+    assert!(contract2.is_paused());
+
+    // The remittance kept its corridor, so the corridor pause still holds it
+    contract2.unpause(&admin);
+    assert_eq!(
+        contract2.try_confirm_payout(&remittance_id),
+        Err(Ok(crate::ContractError::ContractPaused))
+    );
+    contract2.unpause_scope(&admin, &corridor);
+    // Funds are moved to the successor separately from its state
+    token.mint(&contract2.address, &1000);
+    contract2.confirm_payout(&remittance_id);
 }
 
 #[test]
-fn test_error_handler_resource_errors() {
+fn test_migration_batches_carry_circuit_breaker_state() {
     let env = Env::default();
-    
-    // Test RemittanceNotFound
-    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::RemittanceNotFound);
-    assert_eq!(response.code, 6);
-    assert_eq!(response.category, crate::error_handler::ErrorCategory::Resource);
-    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Low);
-    
-    // Test AgentNotRegistered
-    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::AgentNotRegistered);
-    assert_eq!(response.code, 5);
-    assert_eq!(response.category, crate::error_handler::ErrorCategory::Resource);
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &0, &0, &None);
+    contract1.register_agent(&admin, &agent);
+
+    let config = crate::CircuitBreakerConfig {
+        max_window_volume: 1000,
+        max_agent_window_volume: 0,
+        window_seconds: 3600,
+        enabled: true,
+    };
+    contract1.set_circuit_breaker(&admin, &config);
+    token.mint(&sender, &10_000);
+    let tripping = contract1.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let held = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    contract1.confirm_payout(&tripping);
+    let trip = contract1.get_circuit_breaker_trip().unwrap();
+
+    let contract2 = migrate_in_batches(&env, &contract1, &admin);
+
+    assert_eq!(contract2.get_circuit_breaker_config(), config);
+    assert_eq!(contract2.get_circuit_breaker_trip(), Some(trip));
+    assert_eq!(
+        contract2.try_confirm_payout(&held),
+        Err(Ok(crate::ContractError::CircuitBreakerTripped))
+    );
+
+    // After the reset, volume windows start empty on the successor
+    contract2.reset_circuit_breaker(&admin);
+    token.mint(&contract2.address, &100);
+    contract2.confirm_payout(&held);
 }
 
 #[test]
-fn test_error_handler_system_errors() {
+fn test_migration_batches_carry_limits_config() {
     let env = Env::default();
-    
-    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::Overflow);
-    assert_eq!(response.code, 8);
-    assert_eq!(response.category, crate::error_handler::ErrorCategory::System);
-    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::High);
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &0, &0, &None);
+    contract1.register_agent(&admin, &agent);
+
+    let corridor = crate::LimitScope::Corridor(default_currency(&env), default_country(&env));
+    for limit in [
+        crate::VelocityLimit { scope: crate::LimitScope::Sender, window: crate::LimitWindow::IsoWeek, max_volume: 5_000 },
+        crate::VelocityLimit { scope: crate::LimitScope::Agent, window: crate::LimitWindow::UtcDay, max_volume: 8_000 },
+        crate::VelocityLimit { scope: crate::LimitScope::Global, window: crate::LimitWindow::CalendarMonth, max_volume: 90_000 },
+    ] {
+        contract1.set_velocity_limit(&admin, &limit);
+    }
+    contract1.remove_velocity_limit(&admin, &crate::LimitScope::Global, &crate::LimitWindow::CalendarMonth);
+    let global_limits = crate::TransactionLimits { min_amount: 10, max_amount: 0 };
+    let corridor_limits = crate::TransactionLimits { min_amount: 0, max_amount: 3_000 };
+    contract1.set_transaction_limits(&admin, &crate::LimitScope::Global, &Some(global_limits.clone()));
+    contract1.set_transaction_limits(&admin, &corridor, &Some(corridor_limits.clone()));
+    contract1.set_transaction_limits(&admin, &corridor, &None);
+    contract1.set_transaction_limits(&admin, &corridor, &Some(corridor_limits.clone()));
+
+    token.mint(&sender, &10_000);
+    contract1.create_remittance(&sender, &agent, &2_000, &default_currency(&env), &default_country(&env), &None);
+
+    let contract2 = migrate_in_batches(&env, &contract1, &admin);
+
+    assert_eq!(contract2.get_velocity_limits(), contract1.get_velocity_limits());
+    assert_eq!(contract2.get_velocity_limits().len(), 2);
+    assert_eq!(contract2.get_transaction_limits(&crate::LimitScope::Global), Some(global_limits));
+    assert_eq!(contract2.get_transaction_limits(&corridor), Some(corridor_limits));
+    assert_eq!(
+        contract2.try_create_remittance(&sender, &agent, &3_001, &default_currency(&env), &default_country(&env), &None),
+        Err(Ok(crate::ContractError::TransactionLimitExceeded))
+    );
+
+    // Volume windows start empty: the sender's 2,000 from the source is not counted
+    contract2.create_remittance(&sender, &agent, &3_000, &default_currency(&env), &default_country(&env), &None);
+    contract2.create_remittance(&sender, &agent, &2_000, &default_currency(&env), &default_country(&env), &None);
 }
 
 #[test]
-fn test_error_handler_all_errors_have_unique_codes() {
+fn test_import_migration_batch_out_of_order() {
     let env = Env::default();
-    
-    let errors = vec![
-        crate::ContractError::AlreadyInitialized,
-        crate::ContractError::NotInitialized,
-        crate::ContractError::InvalidAmount,
-        crate::ContractError::InvalidFeeBps,
-        crate::ContractError::AgentNotRegistered,
-        crate::ContractError::RemittanceNotFound,
-        crate::ContractError::InvalidStatus,
-        crate::ContractError::Overflow,
-        crate::ContractError::NoFeesToWithdraw,
-        crate::ContractError::InvalidAddress,
-        crate::ContractError::SettlementExpired,
-        crate::ContractError::DuplicateSettlement,
-        crate::ContractError::ContractPaused,
-        crate::ContractError::Unauthorized,
-        crate::ContractError::AdminAlreadyExists,
-        crate::ContractError::AdminNotFound,
-        crate::ContractError::CannotRemoveLastAdmin,
-        crate::ContractError::TokenNotWhitelisted,
-        crate::ContractError::TokenAlreadyWhitelisted,
-    ];
-    
-    let mut codes = std::collections::HashSet::new();
-    for error in errors {
-        let response = crate::error_handler::ErrorHandler::handle_error(&env, error);
-        assert!(codes.insert(response.code), "Duplicate error code found: {}", response.code);
-    }
-    
-    assert_eq!(codes.len(), 19, "Expected 19 unique error codes");
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
+    contract1.start_migration(&admin);
+    let b0 = contract1.export_migration_batch(&admin, &0, &5);
+    let b1 = contract1.export_migration_batch(&admin, &1, &5);
+
+    // Exports must also be sequential
+    assert_eq!(
+        contract1.try_export_migration_batch(&admin, &3, &5),
+        Err(Ok(crate::ContractError::InvalidMigrationBatch))
+    );
+
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    assert_eq!(
+        contract2.try_import_migration_batch(&admin, &b1),
+        Err(Ok(crate::ContractError::InvalidMigrationBatch))
+    );
+
+    contract2.import_migration_batch(&admin, &b0);
+    assert_eq!(
+        contract2.try_import_migration_batch(&admin, &b0),
+        Err(Ok(crate::ContractError::InvalidMigrationBatch))
+    );
+    contract2.import_migration_batch(&admin, &b1);
+    assert_eq!(contract2.get_migration_import_status().next_batch, 2);
 }
 
 #[test]
-fn test_error_handler_messages_are_user_friendly() {
+fn test_finalize_migration_import_checks_rolling_hash() {
     let env = Env::default();
-    
-    let errors = vec![
-        crate::ContractError::InvalidAmount,
-        crate::ContractError::AgentNotRegistered,
-        crate::ContractError::Overflow,
-    ];
-    
-    for error in errors {
-        let response = crate::error_handler::ErrorHandler::handle_error(&env, error);
-        let message = response.message.to_string();
-        
-        // Messages should not contain technical jargon
-        assert!(!message.contains("panic"), "Message contains 'panic': {}", message);
-        assert!(!message.contains("stack"), "Message contains 'stack': {}", message);
-        assert!(!message.contains("trace"), "Message contains 'trace': {}", message);
-        assert!(!message.contains("0x"), "Message contains hex address: {}", message);
-        
-        // Messages should be non-empty
-        assert!(!message.is_empty(), "Error message is empty");
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
+    contract1.start_migration(&admin);
+    let first = contract1.export_migration_batch(&admin, &0, &5);
+    let mut batches = std::vec![first.clone()];
+    for n in 1..first.total_batches {
+        batches.push(contract1.export_migration_batch(&admin, &n, &5));
     }
-}
+    let digest = contract1.get_migration_export_digest();
 
-#[test]
-fn test_error_handler_get_error_category() {
-    use crate::error_handler::{ErrorHandler, ErrorCategory};
-    
-    assert_eq!(ErrorHandler::get_error_category(crate::ContractError::InvalidAmount), ErrorCategory::Validation);
-    assert_eq!(ErrorHandler::get_error_category(crate::ContractError::Unauthorized), ErrorCategory::Authorization);
-    assert_eq!(ErrorHandler::get_error_category(crate::ContractError::ContractPaused), ErrorCategory::State);
-    assert_eq!(ErrorHandler::get_error_category(crate::ContractError::RemittanceNotFound), ErrorCategory::Resource);
-    assert_eq!(ErrorHandler::get_error_category(crate::ContractError::Overflow), ErrorCategory::System);
-}
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    contract2.import_migration_batch(&admin, &batches[0]);
 
-#[test]
-fn test_error_handler_get_error_severity() {
-    use crate::error_handler::{ErrorHandler, ErrorSeverity};
-    
-    // Low severity
-    assert_eq!(ErrorHandler::get_error_severity(crate::ContractError::InvalidAmount), ErrorSeverity::Low);
-    assert_eq!(ErrorHandler::get_error_severity(crate::ContractError::AgentNotRegistered), ErrorSeverity::Low);
-    
-    // Medium severity
-    assert_eq!(ErrorHandler::get_error_severity(crate::ContractError::Unauthorized), ErrorSeverity::Medium);
-    assert_eq!(ErrorHandler::get_error_severity(crate::ContractError::DuplicateSettlement), ErrorSeverity::Medium);
-    
-    // High severity
-    assert_eq!(ErrorHandler::get_error_severity(crate::ContractError::Overflow), ErrorSeverity::High);
-}
+    // Missing batches
+    assert_eq!(
+        contract2.try_finalize_migration_import(&admin, &digest),
+        Err(Ok(crate::ContractError::InvalidMigrationBatch))
+    );
 
-#[test]
-fn test_error_handler_is_retryable() {
-    use crate::error_handler::ErrorHandler;
-    
-    // Retryable errors
-    assert!(ErrorHandler::is_retryable(crate::ContractError::ContractPaused));
-    
-    // Non-retryable errors
-    assert!(!ErrorHandler::is_retryable(crate::ContractError::InvalidAmount));
-    assert!(!ErrorHandler::is_retryable(crate::ContractError::RemittanceNotFound));
-    assert!(!ErrorHandler::is_retryable(crate::ContractError::Overflow));
-    assert!(!ErrorHandler::is_retryable(crate::ContractError::Unauthorized));
+    for batch in batches.iter().skip(1) {
+        contract2.import_migration_batch(&admin, batch);
+    }
+
+    let mut forged = digest.clone();
+    forged.rolling_hash = soroban_sdk::BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(
+        contract2.try_finalize_migration_import(&admin, &forged),
+        Err(Ok(crate::ContractError::InvalidMigrationHash))
+    );
+
+    contract2.finalize_migration_import(&admin, &digest);
+    assert_eq!(
+        contract2.try_finalize_migration_import(&admin, &digest),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
 }
 
+
 #[test]
-fn test_error_handler_get_user_message() {
+fn test_migration_batch_hash_verification() {
     let env = Env::default();
-    use crate::error_handler::ErrorHandler;
-    
-    let message = ErrorHandler::get_user_message(&env, crate::ContractError::InvalidAmount);
-    assert_eq!(message.to_string(), "Amount must be greater than zero");
-    
-    let message = ErrorHandler::get_user_message(&env, crate::ContractError::Unauthorized);
-    assert_eq!(message.to_string(), "Unauthorized: admin access required");
-}
+    env.mock_all_auths();
 
-#[test]
-fn test_error_handler_get_error_code() {
-    use crate::error_handler::ErrorHandler;
-    
-    assert_eq!(ErrorHandler::get_error_code(crate::ContractError::InvalidAmount), 3);
-    assert_eq!(ErrorHandler::get_error_code(crate::ContractError::Unauthorized), 14);
-    assert_eq!(ErrorHandler::get_error_code(crate::ContractError::Overflow), 8);
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    contract1.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+
+    // Create remittances
+    for _ in 0..5 {
+        contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    }
+
+    // Export batch
+    contract1.start_migration(&admin);
+    let mut batch = contract1.export_migration_batch(&admin, &0, &5);
+
+    // Tamper with batch
+    if let crate::MigrationPayload::Config(mut config) = batch.payload.clone() {
+        config.platform_fee_bps = 0; // Change fee
+        batch.payload = crate::MigrationPayload::Config(config);
+    }
+
+    // Import should fail due to hash mismatch
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    assert_eq!(
+        contract2.try_import_migration_batch(&admin, &batch),
+        Err(Ok(crate::ContractError::InvalidMigrationHash))
+    );
 }
 
 #[test]
-fn test_error_handler_integration_with_contract() {
+fn test_migration_preserves_all_data() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let admin = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let token = create_token_contract(&env, &token_admin);
+
+    // Create and populate first contract
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
-    
-    let contract = create_swiftremit_contract(&env);
-    contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
-    
-    // Test that errors are properly handled through the system
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        contract.create_remittance(&sender, &agent, &0, &None);
-    }));
-    
-    assert!(result.is_err(), "Should fail with InvalidAmount error");
+    contract1.register_agent(&admin, &agent);
+
+    token.mint(&sender, &1000);
+
+    // Create remittance and complete it
+    let id = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    contract1.confirm_payout(&id);
+
+    // Export state
+    contract1.start_migration(&admin);
+    let snapshot = contract1.export_migration_state(&admin);
+
+    // Verify all data is in snapshot
+    assert_eq!(snapshot.instance_data.platform_fee_bps, 250);
+    assert_eq!(snapshot.instance_data.remittance_counter, 1);
+    assert!(snapshot.instance_data.accumulated_fees > 0);
+    assert_eq!(snapshot.persistent_data.remittances.len(), 1);
+
+    // Import to new contract
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    contract2.import_migration_state(&admin, &snapshot);
+
+    // Verify all data was imported
+    assert_eq!(contract2.get_platform_fee_bps(), 250);
+    assert!(contract2.get_accumulated_fees() > 0);
+
+    let remittance = contract2.get_remittance(&id);
+    assert_eq!(remittance.status, crate::RemittanceStatus::Completed);
 }
 
 #[test]
-fn test_error_handler_no_information_leakage() {
+fn test_migration_deterministic_hash() {
     let env = Env::default();
-    use crate::error_handler::ErrorHandler;
-    
-    // Test that error messages don't leak sensitive information
-    let errors = vec![
-        crate::ContractError::RemittanceNotFound,
-        crate::ContractError::AdminNotFound,
-        crate::ContractError::AgentNotRegistered,
-    ];
-    
-    for error in errors {
-        let response = ErrorHandler::handle_error(&env, error);
-        let message = response.message.to_string();
-        
-        // Should not contain addresses
-        assert!(!message.contains("G"), "Message may contain Stellar address");
-        
-        // Should not contain storage keys
-        assert!(!message.contains("storage"), "Message contains 'storage'");
-        assert!(!message.contains("key"), "Message contains 'key'");
-        
-        // Should not contain internal paths
-        assert!(!message.contains("src/"), "Message contains source path");
-        assert!(!message.contains(".rs"), "Message contains file extension");
-    }
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // Export twice from the same ledger state
+    contract.start_migration(&admin);
+    let snapshot1 = contract.export_migration_state(&admin);
+    let snapshot2 = contract.export_migration_state(&admin);
+
+    // Hashes should be identical (deterministic)
+    assert_eq!(snapshot1.verification_hash, snapshot2.verification_hash);
+    assert_eq!(snapshot1.instance_data.platform_fee_bps, snapshot2.instance_data.platform_fee_bps);
 }
 
 #[test]
-fn test_error_handler_consistency_across_categories() {
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_export_batch_invalid_size() {
     let env = Env::default();
-    use crate::error_handler::{ErrorHandler, ErrorCategory};
-    
-    // All validation errors should be Low severity
-    let validation_errors = vec![
-        crate::ContractError::InvalidAmount,
-        crate::ContractError::InvalidFeeBps,
-        crate::ContractError::InvalidAddress,
-    ];
-    
-    for error in validation_errors {
-        let response = ErrorHandler::handle_error(&env, error);
-        assert_eq!(response.category, ErrorCategory::Validation);
-        assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Low);
-    }
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // Try to export with batch size > MAX_MIGRATION_BATCH_SIZE
+    contract.start_migration(&admin);
+    contract.export_migration_batch(&admin, &0, &101);
 }
 
 #[test]
-fn test_error_handler_high_severity_errors() {
-    let env = Env::default();
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_export_batch_zero_size() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // Try to export with zero batch size
+    contract.start_migration(&admin);
+    contract.export_migration_batch(&admin, &0, &0);
+}
+
+#[test]
+fn test_migration_with_multiple_remittance_statuses() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    contract1.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+
+    // Create remittances with different statuses
+    let id1 = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None); // Pending
+    let id2 = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    contract1.confirm_payout(&id2); // Completed
+    let id3 = contract1.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    contract1.cancel_remittance(&id3); // Cancelled
+
+    // Export and import
+    contract1.start_migration(&admin);
+    let snapshot = contract1.export_migration_state(&admin);
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    contract2.import_migration_state(&admin, &snapshot);
+
+    // Verify all statuses preserved
+    assert_eq!(contract2.get_remittance(&id1).status, crate::RemittanceStatus::Pending);
+    assert_eq!(contract2.get_remittance(&id2).status, crate::RemittanceStatus::Completed);
+    assert_eq!(contract2.get_remittance(&id3).status, crate::RemittanceStatus::Cancelled);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Request Rate Limit Configuration Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_rate_limit_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // Check default rate limit config
+    let (max_requests, window_seconds, enabled) = contract.get_rate_limit_config();
+    assert_eq!(max_requests, 100);
+    assert_eq!(window_seconds, 60);
+    assert!(enabled);
+}
+
+#[test]
+fn test_update_rate_limit_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // Update rate limit
+    contract.update_rate_limit(&admin, &50, &30, &true);
+
+    let (max_requests, window_seconds, enabled) = contract.get_rate_limit_config();
+    assert_eq!(max_requests, 50);
+    assert_eq!(window_seconds, 30);
+    assert!(enabled);
+}
+
+#[test]
+fn test_rate_limit_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let user = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // An address that has made no requests has the full window available
+    let (current_requests, max_requests, window_seconds) = contract.get_rate_limit_status(&user);
+    assert_eq!(current_requests, 0);
+    assert_eq!(max_requests, 100);
+    assert_eq!(window_seconds, 60);
+}
+
+#[test]
+fn test_rate_limit_disable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    contract.update_rate_limit(&admin, &100, &60, &false);
+
+    let (_, _, enabled) = contract.get_rate_limit_config();
+    assert!(!enabled);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Daily Limit Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+fn test_daily_limit_exceeded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    token.mint(&sender, &20000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let currency = String::from_str(&env, "USD");
+    let country = String::from_str(&env, "US");
+
+    // Set daily limit to 10000
+    contract.set_daily_limit(&admin, &currency, &country, &10000);
+
+    // First transfer of 6000 should succeed
+    contract.create_remittance(&sender, &agent, &6000, &currency, &country, &None);
+
+    // Second transfer of 5000 should fail (total 11000 > 10000)
+    contract.create_remittance(&sender, &agent, &5000, &currency, &country, &None);
+}
+
+#[test]
+fn test_daily_limit_different_currencies() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    token.mint(&sender, &30000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let usd = String::from_str(&env, "USD");
+    let eur = String::from_str(&env, "EUR");
+    let us = String::from_str(&env, "US");
+
+    // Set different limits for different currencies
+    contract.set_daily_limit(&admin, &usd, &us, &10000);
+    contract.set_daily_limit(&admin, &eur, &us, &15000);
+
+    // Transfer 9000 in USD should succeed
+    contract.create_remittance(&sender, &agent, &9000, &usd, &us, &None);
+
+    // Transfer 14000 in EUR should succeed (different currency limit)
+    contract.create_remittance(&sender, &agent, &14000, &eur, &us, &None);
+
+    assert_eq!(get_token_balance(&token, &contract.address), 23000);
+}
+
+#[test]
+fn test_daily_limit_different_countries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    token.mint(&sender, &30000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let usd = String::from_str(&env, "USD");
+    let us = String::from_str(&env, "US");
+    let uk = String::from_str(&env, "UK");
+
+    // Set different limits for different countries
+    contract.set_daily_limit(&admin, &usd, &us, &10000);
+    contract.set_daily_limit(&admin, &usd, &uk, &15000);
+
+    // Transfer 9000 to US should succeed
+    contract.create_remittance(&sender, &agent, &9000, &usd, &us, &None);
+
+    // Transfer 14000 to UK should succeed (different country limit)
+    contract.create_remittance(&sender, &agent, &14000, &usd, &uk, &None);
+
+    assert_eq!(get_token_balance(&token, &contract.address), 23000);
+}
+
+#[test]
+fn test_daily_limit_no_limit_configured() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    token.mint(&sender, &100000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let currency = String::from_str(&env, "USD");
+    let country = String::from_str(&env, "US");
+
+    // No limit configured, large transfer should succeed
+    let remittance_id = contract.create_remittance(&sender, &agent, &50000, &currency, &country, &None);
+    assert_eq!(remittance_id, 1);
+    assert_eq!(get_token_balance(&token, &contract.address), 50000);
+}
+
+#[test]
+fn test_daily_limit_multiple_users() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender1 = Address::generate(&env);
+    let sender2 = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    token.mint(&sender1, &20000);
+    token.mint(&sender2, &20000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let currency = String::from_str(&env, "USD");
+    let country = String::from_str(&env, "US");
+
+    // Set daily limit to 10000
+    contract.set_daily_limit(&admin, &currency, &country, &10000);
+
+    // Each user should have their own limit
+    contract.create_remittance(&sender1, &agent, &9000, &currency, &country, &None);
+    contract.create_remittance(&sender2, &agent, &9000, &currency, &country, &None);
+
+    assert_eq!(get_token_balance(&token, &contract.address), 18000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_daily_limit_negative_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    let currency = String::from_str(&env, "USD");
+    let country = String::from_str(&env, "US");
+
+    // Negative limit should fail
+    contract.set_daily_limit(&admin, &currency, &country, &-1000);
+}
+
+#[test]
+fn test_daily_limit_exact_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    token.mint(&sender, &20000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let currency = String::from_str(&env, "USD");
+    let country = String::from_str(&env, "US");
+
+    contract.set_daily_limit(&admin, &currency, &country, &10000);
+
+    // Sending exactly the limit is allowed
+    contract.create_remittance(&sender, &agent, &10000, &currency, &country, &None);
+    assert_eq!(
+        contract.get_remaining_daily_allowance(&sender, &currency, &country),
+        Some(0)
+    );
+}
+
+// ============================================================================
+// Centralized Error Handling Tests
+// ============================================================================
+
+fn to_std_string(message: &String) -> std::string::String {
+    let mut buf = std::vec![0u8; message.len() as usize];
+    message.copy_into_slice(&mut buf);
+    std::string::String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn test_error_handler_validation_errors() {
+    let env = Env::default();
+    
+    // Test InvalidAmount
+    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::InvalidAmount);
+    assert_eq!(response.code, 3);
+    assert_eq!(response.category, crate::error_handler::ErrorCategory::Validation);
+    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Low);
+    
+    // Test InvalidFeeBps
+    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::InvalidFeeBps);
+    assert_eq!(response.code, 4);
+    assert_eq!(response.category, crate::error_handler::ErrorCategory::Validation);
+    
+    // Test InvalidAddress
+    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::InvalidAddress);
+    assert_eq!(response.code, 10);
+    assert_eq!(response.category, crate::error_handler::ErrorCategory::Validation);
+}
+
+#[test]
+fn test_error_handler_authorization_errors() {
+    let env = Env::default();
+    
+    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::Unauthorized);
+    assert_eq!(response.code, 14);
+    assert_eq!(response.category, crate::error_handler::ErrorCategory::Authorization);
+    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Medium);
+}
+
+#[test]
+fn test_error_handler_state_errors() {
+    let env = Env::default();
+    
+    // Test ContractPaused
+    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::ContractPaused);
+    assert_eq!(response.code, 13);
+    assert_eq!(response.category, crate::error_handler::ErrorCategory::State);
+    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Low);
+    
+    // Test DuplicateSettlement
+    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::DuplicateSettlement);
+    assert_eq!(response.code, 12);
+    assert_eq!(response.category, crate::error_handler::ErrorCategory::State);
+    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Medium);
+}
+
+#[test]
+fn test_error_handler_resource_errors() {
+    let env = Env::default();
+    
+    // Test RemittanceNotFound
+    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::RemittanceNotFound);
+    assert_eq!(response.code, 6);
+    assert_eq!(response.category, crate::error_handler::ErrorCategory::Resource);
+    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Low);
+    
+    // Test AgentNotRegistered
+    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::AgentNotRegistered);
+    assert_eq!(response.code, 5);
+    assert_eq!(response.category, crate::error_handler::ErrorCategory::Resource);
+}
+
+#[test]
+fn test_error_handler_system_errors() {
+    let env = Env::default();
+    
+    let response = crate::error_handler::ErrorHandler::handle_error(&env, crate::ContractError::Overflow);
+    assert_eq!(response.code, 8);
+    assert_eq!(response.category, crate::error_handler::ErrorCategory::System);
+    assert_eq!(response.severity, crate::error_handler::ErrorSeverity::High);
+}
+
+#[test]
+fn test_error_handler_all_errors_have_unique_codes() {
+    let env = Env::default();
+    
+    let errors = std::vec![
+        crate::ContractError::AlreadyInitialized,
+        crate::ContractError::NotInitialized,
+        crate::ContractError::InvalidAmount,
+        crate::ContractError::InvalidFeeBps,
+        crate::ContractError::AgentNotRegistered,
+        crate::ContractError::RemittanceNotFound,
+        crate::ContractError::InvalidStatus,
+        crate::ContractError::Overflow,
+        crate::ContractError::NoFeesToWithdraw,
+        crate::ContractError::InvalidAddress,
+        crate::ContractError::SettlementExpired,
+        crate::ContractError::DuplicateSettlement,
+        crate::ContractError::ContractPaused,
+        crate::ContractError::Unauthorized,
+        crate::ContractError::AdminAlreadyExists,
+        crate::ContractError::AdminNotFound,
+        crate::ContractError::CannotRemoveLastAdmin,
+        crate::ContractError::TokenNotWhitelisted,
+        crate::ContractError::TokenAlreadyWhitelisted,
+    ];
+    
+    let mut codes = std::collections::HashSet::new();
+    for error in errors {
+        let response = crate::error_handler::ErrorHandler::handle_error(&env, error);
+        assert!(codes.insert(response.code), "Duplicate error code found: {}", response.code);
+    }
+    
+    assert_eq!(codes.len(), 19, "Expected 19 unique error codes");
+}
+
+#[test]
+fn test_error_handler_messages_are_user_friendly() {
+    let env = Env::default();
+    
+    let errors = std::vec![
+        crate::ContractError::InvalidAmount,
+        crate::ContractError::AgentNotRegistered,
+        crate::ContractError::Overflow,
+    ];
+    
+    for error in errors {
+        let response = crate::error_handler::ErrorHandler::handle_error(&env, error);
+        let message = to_std_string(&response.message);
+        
+        // Messages should not contain technical jargon
+        assert!(!message.contains("panic"), "Message contains 'panic': {}", message);
+        assert!(!message.contains("stack"), "Message contains 'stack': {}", message);
+        assert!(!message.contains("trace"), "Message contains 'trace': {}", message);
+        assert!(!message.contains("0x"), "Message contains hex address: {}", message);
+        
+        // Messages should be non-empty
+        assert!(!message.is_empty(), "Error message is empty");
+    }
+}
+
+#[test]
+fn test_error_handler_get_error_category() {
+    use crate::error_handler::{ErrorHandler, ErrorCategory};
+    
+    assert_eq!(ErrorHandler::get_error_category(crate::ContractError::InvalidAmount), ErrorCategory::Validation);
+    assert_eq!(ErrorHandler::get_error_category(crate::ContractError::Unauthorized), ErrorCategory::Authorization);
+    assert_eq!(ErrorHandler::get_error_category(crate::ContractError::ContractPaused), ErrorCategory::State);
+    assert_eq!(ErrorHandler::get_error_category(crate::ContractError::RemittanceNotFound), ErrorCategory::Resource);
+    assert_eq!(ErrorHandler::get_error_category(crate::ContractError::Overflow), ErrorCategory::System);
+}
+
+#[test]
+fn test_error_handler_get_error_severity() {
+    use crate::error_handler::{ErrorHandler, ErrorSeverity};
+    
+    // Low severity
+    assert_eq!(ErrorHandler::get_error_severity(crate::ContractError::InvalidAmount), ErrorSeverity::Low);
+    assert_eq!(ErrorHandler::get_error_severity(crate::ContractError::AgentNotRegistered), ErrorSeverity::Low);
+    
+    // Medium severity
+    assert_eq!(ErrorHandler::get_error_severity(crate::ContractError::Unauthorized), ErrorSeverity::Medium);
+    assert_eq!(ErrorHandler::get_error_severity(crate::ContractError::DuplicateSettlement), ErrorSeverity::Medium);
+    
+    // High severity
+    assert_eq!(ErrorHandler::get_error_severity(crate::ContractError::Overflow), ErrorSeverity::High);
+}
+
+#[test]
+fn test_error_handler_is_retryable() {
+    use crate::error_handler::ErrorHandler;
+    
+    // Retryable errors
+    assert!(ErrorHandler::is_retryable(crate::ContractError::ContractPaused));
+    
+    // Non-retryable errors
+    assert!(!ErrorHandler::is_retryable(crate::ContractError::InvalidAmount));
+    assert!(!ErrorHandler::is_retryable(crate::ContractError::RemittanceNotFound));
+    assert!(!ErrorHandler::is_retryable(crate::ContractError::Overflow));
+    assert!(!ErrorHandler::is_retryable(crate::ContractError::Unauthorized));
+}
+
+#[test]
+fn test_error_handler_get_user_message() {
+    let env = Env::default();
+    use crate::error_handler::ErrorHandler;
+    
+    let message = ErrorHandler::get_user_message(&env, crate::ContractError::InvalidAmount);
+    assert_eq!(to_std_string(&message), "Amount must be greater than zero");
+    
+    let message = ErrorHandler::get_user_message(&env, crate::ContractError::Unauthorized);
+    assert_eq!(to_std_string(&message), "Unauthorized: admin access required");
+}
+
+#[test]
+fn test_error_handler_get_error_code() {
+    use crate::error_handler::ErrorHandler;
+    
+    assert_eq!(ErrorHandler::get_error_code(crate::ContractError::InvalidAmount), 3);
+    assert_eq!(ErrorHandler::get_error_code(crate::ContractError::Unauthorized), 14);
+    assert_eq!(ErrorHandler::get_error_code(crate::ContractError::Overflow), 8);
+}
+
+#[test]
+fn test_error_handler_integration_with_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+    
+    // Test that errors are properly handled through the system
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        contract.create_remittance(&sender, &agent, &0, &default_currency(&env), &default_country(&env), &None);
+    }));
+    
+    assert!(result.is_err(), "Should fail with InvalidAmount error");
+}
+
+#[test]
+fn test_error_handler_no_information_leakage() {
+    let env = Env::default();
+    use crate::error_handler::ErrorHandler;
+    
+    // Test that error messages don't leak sensitive information
+    let errors = std::vec![
+        crate::ContractError::RemittanceNotFound,
+        crate::ContractError::AdminNotFound,
+        crate::ContractError::AgentNotRegistered,
+    ];
+    
+    for error in errors {
+        let response = ErrorHandler::handle_error(&env, error);
+        let message = to_std_string(&response.message);
+        
+        // Should not contain addresses
+        assert!(!message.contains("G"), "Message may contain Stellar address");
+        
+        // Should not contain storage keys
+        assert!(!message.contains("storage"), "Message contains 'storage'");
+        assert!(!message.contains("key"), "Message contains 'key'");
+        
+        // Should not contain internal paths
+        assert!(!message.contains("src/"), "Message contains source path");
+        assert!(!message.contains(".rs"), "Message contains file extension");
+    }
+}
+
+#[test]
+fn test_error_handler_consistency_across_categories() {
+    let env = Env::default();
+    use crate::error_handler::{ErrorHandler, ErrorCategory};
+    
+    // All validation errors should be Low severity
+    let validation_errors = std::vec![
+        crate::ContractError::InvalidAmount,
+        crate::ContractError::InvalidFeeBps,
+        crate::ContractError::InvalidAddress,
+    ];
+    
+    for error in validation_errors {
+        let response = ErrorHandler::handle_error(&env, error);
+        assert_eq!(response.category, ErrorCategory::Validation);
+        assert_eq!(response.severity, crate::error_handler::ErrorSeverity::Low);
+    }
+}
+
+#[test]
+fn test_error_handler_high_severity_errors() {
+    let env = Env::default();
     use crate::error_handler::{ErrorHandler, ErrorSeverity};
     
     // Only Overflow should be High severity
@@ -4811,7 +5639,7 @@ fn test_error_handler_high_severity_errors() {
     assert_eq!(response.severity, ErrorSeverity::High);
     
     // Verify it's the only High severity error
-    let all_errors = vec![
+    let all_errors = std::vec![
         crate::ContractError::AlreadyInitialized,
         crate::ContractError::NotInitialized,
         crate::ContractError::InvalidAmount,
@@ -4837,3 +5665,3537 @@ fn test_error_handler_high_severity_errors() {
         assert_ne!(response.severity, ErrorSeverity::High, "Unexpected High severity for {:?}", error);
     }
 }
+
+// ============================================================================
+// Settlement Simulation Tests
+// ============================================================================
+
+#[test]
+fn test_simulate_settlement_does_not_mutate_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &None);
+
+    let simulation = contract.simulate_settlement(&remittance_id);
+    assert!(simulation.would_succeed);
+
+    // Nothing moved and the remittance is still pending
+    assert_eq!(get_token_balance(&token, &agent), 0);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+    assert_eq!(
+        contract.get_remittance(&remittance_id).status,
+        crate::RemittanceStatus::Pending
+    );
+    assert_eq!(contract.get_last_settlement_time(&sender), None);
+
+    // The real payout still goes through afterwards
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(get_token_balance(&token, &agent), simulation.payout_amount);
+}
+
+#[test]
+fn test_simulate_settlement_expired() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &10000, &default_currency(&env), &default_country(&env), &Some(2000));
+
+    env.ledger().with_mut(|li| li.timestamp = 2001);
+    let simulation = contract.simulate_settlement(&remittance_id);
+
+    assert!(!simulation.would_succeed);
+    assert_eq!(simulation.error_message, Some(11)); // SettlementExpired
+    assert_eq!(simulation.payout_amount, 9750);
+    assert_eq!(simulation.fee, 250);
+}
+
+#[test]
+fn test_simulate_settlement_duplicate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.confirm_payout(&remittance_id);
+
+    let simulation = contract.simulate_settlement(&remittance_id);
+
+    // Status is checked before the settlement hash, matching confirm_payout
+    assert!(!simulation.would_succeed);
+    assert_eq!(simulation.error_message, Some(7)); // InvalidStatus
+}
+
+#[test]
+fn test_simulate_settlement_rate_limited() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &3600, &None);
+    contract.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.confirm_payout(&id1);
+
+    let simulation = contract.simulate_settlement(&id2);
+    assert!(!simulation.would_succeed);
+    assert_eq!(simulation.error_message, Some(68)); // RateLimitExceeded
+
+    // Once the cooldown has elapsed the simulation flips to success
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    let simulation = contract.simulate_settlement(&id2);
+    assert!(simulation.would_succeed);
+}
+
+// ============================================================================
+// Clearing Cycle Tests
+// ============================================================================
+
+#[test]
+fn test_clearing_cycle_one_transfer_per_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent_a = Address::generate(&env);
+    let agent_b = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent_a);
+    contract.register_agent(&admin, &agent_b);
+
+    token.mint(&sender, &10000);
+    let id1 = contract.create_remittance(&sender, &agent_a, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&sender, &agent_a, &2000, &default_currency(&env), &default_country(&env), &None);
+    let id3 = contract.create_remittance(&sender, &agent_b, &4000, &default_currency(&env), &default_country(&env), &None);
+
+    assert_eq!(contract.queue_for_clearing(&id1), 1);
+    assert_eq!(contract.queue_for_clearing(&id2), 1);
+    assert_eq!(contract.queue_for_clearing(&id3), 1);
+
+    // Nothing is paid out until the cycle closes
+    assert_eq!(get_token_balance(&token, &agent_a), 0);
+    assert_eq!(contract.get_remittance(&id1).status, crate::RemittanceStatus::Pending);
+
+    let cycle = contract.close_clearing_cycle(&admin);
+    assert_eq!(cycle.id, 1);
+    assert_eq!(cycle.status, crate::ClearingCycleStatus::Settled);
+    assert_eq!(cycle.remittance_count, 3);
+    assert_eq!(cycle.total_amount, 7000);
+    assert_eq!(cycle.total_fees, 175);
+    assert_eq!(cycle.total_payout, 6825);
+
+    assert_eq!(get_token_balance(&token, &agent_a), 2925);
+    assert_eq!(get_token_balance(&token, &agent_b), 3900);
+    assert_eq!(contract.get_accumulated_fees(), 175);
+    assert_eq!(contract.get_remittance(&id3).status, crate::RemittanceStatus::Completed);
+
+    let report = contract.get_clearing_cycle_report(&1);
+    assert_eq!(report.remittance_ids.len(), 3);
+    assert_eq!(report.positions.len(), 2);
+
+    // A fresh cycle is open for the next window
+    let next = contract.get_current_clearing_cycle();
+    assert_eq!(next.id, 2);
+    assert_eq!(next.status, crate::ClearingCycleStatus::Open);
+    assert_eq!(next.remittance_count, 0);
+}
+
+#[test]
+fn test_clearing_queued_remittance_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.queue_for_clearing(&remittance_id);
+
+    assert_eq!(contract.get_remittance_clearing_cycle(&remittance_id), Some(1));
+    assert_eq!(
+        contract.try_queue_for_clearing(&remittance_id),
+        Err(Ok(crate::ContractError::DuplicateSettlement))
+    );
+    assert_eq!(
+        contract.try_confirm_payout(&remittance_id),
+        Err(Ok(crate::ContractError::DuplicateSettlement))
+    );
+    assert_eq!(
+        contract.try_cancel_remittance(&remittance_id),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_close_empty_clearing_cycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    contract.close_clearing_cycle(&admin);
+}
+
+#[test]
+fn test_close_full_clearing_cycle_within_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // Worst case: every remittance goes to a different agent, so closing the
+    // cycle makes one transfer per remittance
+    token.mint(&sender, &(1000 * (crate::MAX_CLEARING_CYCLE_SIZE as i128 + 1)));
+    let mut agent = Address::generate(&env);
+    for _ in 0..crate::MAX_CLEARING_CYCLE_SIZE {
+        agent = Address::generate(&env);
+        contract.register_agent(&admin, &agent);
+        let id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+        contract.queue_for_clearing(&id);
+    }
+
+    // The cycle is full
+    let extra = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    assert_eq!(
+        contract.try_queue_for_clearing(&extra),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+
+    // Closing it fits in the default budget, which mirrors the network's
+    // per-transaction limits; exceeding it panics here
+    env.budget().reset_default();
+    let cycle = contract.close_clearing_cycle(&admin);
+    assert_eq!(cycle.remittance_count, crate::MAX_CLEARING_CYCLE_SIZE);
+    assert_eq!(
+        contract.get_clearing_cycle_report(&cycle.id).positions.len(),
+        crate::MAX_CLEARING_CYCLE_SIZE
+    );
+}
+
+// ============================================================================
+// Cross-Currency Settlement Tests
+// ============================================================================
+
+#[test]
+fn test_remittance_in_secondary_token_pays_out_in_that_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract.whitelist_token(&admin, &eurc.address);
+    contract.register_agent(&admin, &agent);
+
+    eurc.mint(&sender, &10000);
+    let remittance_id =
+        contract.create_remittance_with_token(&sender, &agent, &1000, &eurc.address, &default_currency(&env), &default_country(&env), &None);
+    assert_eq!(contract.get_remittance(&remittance_id).token, eurc.address);
+
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(get_token_balance(&eurc, &agent), 975);
+    assert_eq!(get_token_balance(&usdc, &agent), 0);
+    assert_eq!(contract.get_token_fees(&eurc.address), 25);
+    assert_eq!(contract.get_token_fees(&usdc.address), 0);
+}
+
+#[test]
+fn test_create_remittance_with_unlisted_token_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let other = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    assert_eq!(
+        contract.try_create_remittance_with_token(&sender, &agent, &1000, &other.address, &default_currency(&env), &default_country(&env), &None),
+        Err(Ok(crate::ContractError::TokenNotWhitelisted))
+    );
+}
+
+#[test]
+fn test_set_fx_rate_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
+
+    assert_eq!(
+        contract.try_set_fx_rate(&admin, &eurc.address, &usdc.address, &0),
+        Err(Ok(crate::ContractError::InvalidFxRate))
+    );
+    assert_eq!(
+        contract.try_set_fx_rate(&admin, &usdc.address, &usdc.address, &crate::FX_RATE_SCALE),
+        Err(Ok(crate::ContractError::InvalidFxRate))
+    );
+
+    let fx_rate = contract.set_fx_rate(&admin, &eurc.address, &usdc.address, &11_000_000);
+    assert_eq!(fx_rate.source, crate::FxRateSource::Admin);
+    assert_eq!(
+        contract.get_fx_rate(&eurc.address, &usdc.address),
+        Some(fx_rate)
+    );
+    assert_eq!(contract.get_fx_rate(&usdc.address, &eurc.address), None);
+}
+
+#[test]
+fn test_batch_settle_with_fx_netting() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
+    let party_a = Address::generate(&env);
+    let party_b = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &0, &0, &None);
+    contract.whitelist_token(&admin, &eurc.address);
+    contract.register_agent(&admin, &party_a);
+    contract.register_agent(&admin, &party_b);
+
+    // 1 EURC = 1.1 USDC
+    contract.set_fx_rate(&admin, &eurc.address, &usdc.address, &11_000_000);
+
+    usdc.mint(&party_a, &10000);
+    eurc.mint(&party_b, &10000);
+
+    // A -> B: 100 USDC; B -> A: 100 EURC (= 110 USDC)
+    let id1 = contract.create_remittance(&party_a, &party_b, &100, &default_currency(&env), &default_country(&env), &None);
+    let id2 =
+        contract.create_remittance_with_token(&party_b, &party_a, &100, &eurc.address, &default_currency(&env), &default_country(&env), &None);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
+
+    let result = contract.batch_settle_with_fx_netting(&admin, &entries, &usdc.address);
+    assert_eq!(result.settled_ids.len(), 2);
+
+    // Only the 10 USDC difference moves, paid from B's escrowed EURC
+    // (10 USDC = 9.09 EURC, rounded down)
+    assert_eq!(get_token_balance(&usdc, &party_a), 9900);
+    assert_eq!(get_token_balance(&eurc, &party_a), 9);
+    assert_eq!(get_token_balance(&eurc, &party_b), 9900);
+    assert_eq!(get_token_balance(&usdc, &contract.address), 100);
+    assert_eq!(get_token_balance(&eurc, &contract.address), 91);
+    assert_eq!(
+        contract.get_remittance(&id2).status,
+        crate::RemittanceStatus::Completed
+    );
+}
+
+#[test]
+fn test_batch_settle_with_fx_netting_books_fees_in_source_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
+    let party_a = Address::generate(&env);
+    let party_b = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract.whitelist_token(&admin, &eurc.address);
+    contract.register_agent(&admin, &party_a);
+    contract.register_agent(&admin, &party_b);
+
+    // 1 EURC = 1.1 USDC
+    contract.set_fx_rate(&admin, &eurc.address, &usdc.address, &11_000_000);
+
+    usdc.mint(&party_a, &10000);
+    eurc.mint(&party_b, &10000);
+
+    // A -> B: 400 USDC (fee 10); B -> A: 1000 EURC (fee 25) = 1100 USDC (fee 27)
+    let id1 = contract.create_remittance(&party_a, &party_b, &400, &default_currency(&env), &default_country(&env), &None);
+    let id2 =
+        contract.create_remittance_with_token(&party_b, &party_a, &1000, &eurc.address, &default_currency(&env), &default_country(&env), &None);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
+
+    let operator = Address::generate(&env);
+    contract.grant_role(&admin, &crate::Role::SettlementOperator, &operator);
+    contract.batch_settle_with_fx_netting(&operator, &entries, &usdc.address);
+
+    // Net 700 USDC - 37 USDC of fees = 663 USDC, paid as 602 EURC
+    assert_eq!(get_token_balance(&eurc, &party_a), 602);
+    assert_eq!(get_token_balance(&usdc, &party_b), 0);
+
+    // Each fee is booked in the token it was escrowed in
+    assert_eq!(contract.get_token_fees(&usdc.address), 10);
+    assert_eq!(contract.get_token_fees(&eurc.address), 25);
+}
+
+#[test]
+fn test_batch_settle_with_fx_netting_requires_operator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    usdc.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id });
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        contract.try_batch_settle_with_fx_netting(&stranger, &entries, &usdc.address),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(
+        contract.get_remittance(&remittance_id).status,
+        crate::RemittanceStatus::Pending
+    );
+}
+
+#[test]
+fn test_batch_settle_with_fx_netting_requires_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract.whitelist_token(&admin, &eurc.address);
+    contract.register_agent(&admin, &agent);
+
+    eurc.mint(&sender, &10000);
+    let remittance_id =
+        contract.create_remittance_with_token(&sender, &agent, &1000, &eurc.address, &default_currency(&env), &default_country(&env), &None);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id });
+
+    assert_eq!(
+        contract.try_batch_settle_with_fx_netting(&admin, &entries, &usdc.address),
+        Err(Ok(crate::ContractError::FxRateNotAvailable))
+    );
+}
+
+#[test]
+fn test_batch_settle_with_fx_netting_rejects_stale_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract.whitelist_token(&admin, &eurc.address);
+    contract.register_agent(&admin, &agent);
+    contract.set_fx_rate(&admin, &eurc.address, &usdc.address, &11_000_000);
+    assert_eq!(contract.get_fx_rate_max_age(), crate::DEFAULT_MAX_FX_RATE_AGE);
+
+    eurc.mint(&sender, &10000);
+    let remittance_id =
+        contract.create_remittance_with_token(&sender, &agent, &1000, &eurc.address, &default_currency(&env), &default_country(&env), &None);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id });
+
+    // The posted rate outlives the maximum age
+    env.ledger().with_mut(|li| li.timestamp += crate::DEFAULT_MAX_FX_RATE_AGE + 1);
+    assert_eq!(
+        contract.try_batch_settle_with_fx_netting(&admin, &entries, &usdc.address),
+        Err(Ok(crate::ContractError::FxRateNotAvailable))
+    );
+
+    // Widening the maximum age makes the rate usable again
+    assert_eq!(
+        contract.try_set_fx_rate_max_age(&admin, &0),
+        Err(Ok(crate::ContractError::InvalidFxRate))
+    );
+    contract.set_fx_rate_max_age(&admin, &(2 * crate::DEFAULT_MAX_FX_RATE_AGE));
+    contract.batch_settle_with_fx_netting(&admin, &entries, &usdc.address);
+    assert_eq!(
+        contract.get_remittance(&remittance_id).status,
+        crate::RemittanceStatus::Completed
+    );
+}
+
+// ============================================================================
+// Settlement Receipt Tests
+// ============================================================================
+
+#[test]
+fn test_confirm_payout_records_receipt() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.confirm_payout(&remittance_id);
+
+    // The ID a bank computes off-chain from the remittance fields
+    let settlement_id =
+        crate::compute_settlement_id(&env, remittance_id, &sender, &agent, 1000, 25, None);
+
+    let receipt = contract.get_settlement_receipt(&settlement_id);
+    assert_eq!(receipt.remittance_id, remittance_id);
+    assert_eq!(receipt.payout_amount, 975);
+    assert_eq!(receipt.fee, 25);
+    assert_eq!(receipt.token, token.address);
+    assert_eq!(receipt.ledger_sequence, env.ledger().sequence());
+    assert_eq!(receipt.timestamp, env.ledger().timestamp());
+    assert_eq!(receipt.batch_id, None);
+
+    assert_eq!(contract.get_remittance_receipt(&remittance_id), receipt);
+}
+
+#[test]
+fn test_netted_batch_receipts_share_batch_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let party_a = Address::generate(&env);
+    let party_b = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &party_a);
+    contract.register_agent(&admin, &party_b);
+
+    token.mint(&party_a, &10000);
+    token.mint(&party_b, &10000);
+    let id1 = contract.create_remittance(&party_a, &party_b, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&party_b, &party_a, &400, &default_currency(&env), &default_country(&env), &None);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
+    let result = contract.batch_settle_with_netting(&admin, &entries);
+
+    let receipt1 = contract.get_remittance_receipt(&id1);
+    let receipt2 = contract.get_remittance_receipt(&id2);
+    assert_eq!(receipt1.batch_id, Some(result.batch_id));
+    assert_eq!(receipt2.batch_id, Some(result.batch_id));
+    assert_eq!(receipt1.payout_amount, 975);
+    assert_eq!(receipt2.payout_amount, 390);
+    assert_ne!(receipt1.settlement_id, receipt2.settlement_id);
+}
+
+#[test]
+fn test_receipt_not_found_before_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+
+    assert_eq!(
+        contract.try_get_remittance_receipt(&remittance_id),
+        Err(Ok(crate::ContractError::SettlementReceiptNotFound))
+    );
+    assert_eq!(
+        contract.try_get_settlement_receipt(&soroban_sdk::BytesN::from_array(&env, &[0u8; 32])),
+        Err(Ok(crate::ContractError::SettlementReceiptNotFound))
+    );
+}
+
+// ============================================================================
+// Registry Tests
+// ============================================================================
+
+#[test]
+fn test_list_agents_reflects_registration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let agent1 = Address::generate(&env);
+    let agent2 = Address::generate(&env);
+    let agent3 = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    contract.register_agent(&admin, &agent1);
+    contract.register_agent(&admin, &agent2);
+    contract.register_agent(&admin, &agent3);
+    contract.remove_agent(&admin, &agent2);
+
+    // Re-registering does not append a second list entry
+    contract.register_agent(&admin, &agent1);
+
+    assert_eq!(contract.get_agent_registry_len(), 3);
+    let agents = contract.list_agents(&0, &10);
+    assert_eq!(agents.len(), 2);
+    assert_eq!(agents.get_unchecked(0), agent1);
+    assert_eq!(agents.get_unchecked(1), agent3);
+
+    // Pages are over registry positions
+    assert_eq!(contract.list_agents(&0, &1).len(), 1);
+    assert_eq!(contract.list_agents(&1, &1).len(), 0);
+    assert_eq!(contract.list_agents(&2, &1).get_unchecked(0), agent3);
+    assert_eq!(contract.list_agents(&3, &10).len(), 0);
+
+    // A removed agent re-registered reappears at its original position
+    contract.register_agent(&admin, &agent2);
+    assert_eq!(contract.list_agents(&0, &10).len(), 3);
+    assert_eq!(contract.get_agent_registry_len(), 3);
+}
+
+#[test]
+fn test_list_admins_and_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let usdc = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &usdc.address, &250, &0, &None);
+    contract.whitelist_token(&admin, &eurc.address);
+    contract.remove_whitelisted_token(&admin, &eurc.address);
+
+    let admins = contract.list_admins(&0, &10);
+    assert_eq!(admins.len(), 1);
+    assert_eq!(admins.get_unchecked(0), admin);
+
+    let tokens = contract.list_whitelisted_tokens(&0, &10);
+    assert_eq!(contract.get_token_registry_len(), 2);
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens.get_unchecked(0), usdc.address);
+}
+
+#[test]
+fn test_migration_export_includes_registries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let agent = Address::generate(&env);
+    let removed_agent = Address::generate(&env);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+    contract1.register_agent(&admin, &agent);
+    contract1.register_agent(&admin, &removed_agent);
+    contract1.remove_agent(&admin, &removed_agent);
+
+    contract1.start_migration(&admin);
+    let snapshot = contract1.export_migration_state(&admin);
+    assert_eq!(snapshot.persistent_data.agents.len(), 1);
+    assert_eq!(snapshot.persistent_data.admin_roles.len(), 1);
+    assert_eq!(snapshot.persistent_data.whitelisted_tokens.len(), 1);
+
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    contract2.import_migration_state(&admin, &snapshot);
+
+    assert!(contract2.is_agent_registered(&agent));
+    assert!(!contract2.is_agent_registered(&removed_agent));
+    assert_eq!(contract2.list_agents(&0, &10).len(), 1);
+    assert_eq!(contract2.list_admins(&0, &10).get_unchecked(0), admin);
+    assert_eq!(
+        contract2.list_whitelisted_tokens(&0, &10).get_unchecked(0),
+        token.address
+    );
+}
+
+// ============================================================================
+// Migration Merkle Proof Tests
+// ============================================================================
+
+#[test]
+fn test_verify_migration_proof_for_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    token.mint(&sender, &10000);
+    for _ in 0..5 {
+        contract.create_remittance(&sender, &agent, &100, &default_currency(&env), &default_country(&env), &None);
+    }
+
+    contract.start_migration(&admin);
+    let snapshot = contract.export_migration_state(&admin);
+    let remittances = snapshot.persistent_data.remittances.clone();
+
+    // An auditor holding only the root checks remittance #3
+    let mut leaves = soroban_sdk::Vec::new(&env);
+    for remittance in remittances.iter() {
+        leaves.push_back(crate::remittance_leaf(&env, &remittance));
+    }
+    let proof = crate::build_proof(&env, &leaves, 2);
+    let record = crate::MigrationRecord::Remittance(remittances.get_unchecked(2));
+    assert!(contract.verify_migration_proof(&record, &proof, &snapshot.merkle_root));
+
+    // A tampered record does not verify
+    let mut tampered = remittances.get_unchecked(2);
+    tampered.amount = 1_000_000;
+    assert!(!contract.verify_migration_proof(
+        &crate::MigrationRecord::Remittance(tampered),
+        &proof,
+        &snapshot.merkle_root
+    ));
+}
+
+#[test]
+fn test_verify_migration_proof_for_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    contract.start_migration(&admin);
+    let first = contract.export_migration_batch(&admin, &0, &5);
+    let mut batches = std::vec![first.clone()];
+    for n in 1..first.total_batches {
+        batches.push(contract.export_migration_batch(&admin, &n, &5));
+    }
+    let digest = contract.get_migration_export_digest();
+
+    let mut leaves = soroban_sdk::Vec::new(&env);
+    for batch in batches.iter() {
+        leaves.push_back(crate::batch_leaf(&env, &batch.batch_hash));
+    }
+    let proof = crate::build_proof(&env, &leaves, 1);
+    let record = crate::MigrationRecord::Batch(batches[1].clone());
+    assert!(contract.verify_migration_proof(&record, &proof, &digest.merkle_root));
+
+    // The same proof does not hold for a different batch
+    let other = crate::MigrationRecord::Batch(batches[0].clone());
+    assert!(!contract.verify_migration_proof(&other, &proof, &digest.merkle_root));
+}
+
+#[test]
+fn test_import_rejects_snapshot_with_wrong_merkle_root() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+
+    contract1.start_migration(&admin);
+    let mut snapshot = contract1.export_migration_state(&admin);
+    snapshot.merkle_root = soroban_sdk::BytesN::from_array(&env, &[9u8; 32]);
+    assert!(!contract1.verify_migration_snapshot(&snapshot).valid);
+
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    assert_eq!(
+        contract2.try_import_migration_state(&admin, &snapshot),
+        Err(Ok(crate::ContractError::InvalidMigrationHash))
+    );
+}
+
+// ============================================================================
+// Upgrade and Storage Schema Migration Tests
+// ============================================================================
+
+/// Rewrites a stored remittance in the schema version 1 layout (no `token`).
+fn store_v1_remittance(env: &Env, contract: &Address, remittance: &crate::Remittance) {
+    use soroban_sdk::{IntoVal, Map, Symbol, Val};
+
+    env.as_contract(contract, || {
+        let mut fields: Map<Symbol, Val> = Map::new(env);
+        fields.set(Symbol::new(env, "id"), remittance.id.into_val(env));
+        fields.set(Symbol::new(env, "sender"), remittance.sender.into_val(env));
+        fields.set(Symbol::new(env, "agent"), remittance.agent.into_val(env));
+        fields.set(Symbol::new(env, "amount"), remittance.amount.into_val(env));
+        fields.set(Symbol::new(env, "fee"), remittance.fee.into_val(env));
+        fields.set(Symbol::new(env, "status"), remittance.status.into_val(env));
+        fields.set(Symbol::new(env, "expiry"), remittance.expiry.into_val(env));
+        crate::storage::set_remittance_raw(env, remittance.id, &fields.to_val());
+        crate::upgrade::set_schema_version(env, 1);
+    });
+}
+
+#[test]
+fn test_get_version_reports_code_and_schema_versions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    let version = contract.get_version();
+    assert_eq!(
+        version.code_version,
+        soroban_sdk::String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    );
+    assert_eq!(version.code_schema_version, crate::STORAGE_SCHEMA_VERSION);
+    assert_eq!(version.storage_schema_version, crate::STORAGE_SCHEMA_VERSION);
+    assert!(contract.get_schema_migration_status().complete);
+}
+
+#[test]
+fn test_legacy_remittances_read_lazily_and_migrate_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+
+    let mut remittances = std::vec![];
+    for _ in 0..3 {
+        let id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+        remittances.push(contract.get_remittance(&id));
+    }
+    for remittance in remittances.iter() {
+        store_v1_remittance(&env, &contract.address, remittance);
+    }
+    assert_eq!(contract.get_version().storage_schema_version, 1);
+
+    // Legacy records decode with the USDC token before any migration runs
+    assert_eq!(contract.get_remittance(&2), remittances[1]);
+
+    let status = contract.migrate_storage_schema(&admin, &2);
+    assert!(!status.complete);
+    assert_eq!(status.next_remittance_id, 3);
+    assert_eq!(status.last_remittance_id, 3);
+
+    // Legacy payouts still settle mid-migration
+    contract.confirm_payout(&3);
+
+    let status = contract.migrate_storage_schema(&admin, &2);
+    assert!(status.complete);
+    assert_eq!(status.storage_schema_version, crate::STORAGE_SCHEMA_VERSION);
+    assert_eq!(contract.get_version().storage_schema_version, crate::STORAGE_SCHEMA_VERSION);
+    assert_eq!(contract.get_remittance(&1), remittances[0]);
+    assert_eq!(contract.get_remittance(&3).status, crate::RemittanceStatus::Completed);
+
+    // Further calls are no-ops
+    assert_eq!(contract.migrate_storage_schema(&admin, &2), status);
+}
+
+#[test]
+fn test_schema_migration_rejects_invalid_batch_size() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    assert_eq!(
+        contract.try_migrate_storage_schema(&admin, &0),
+        Err(Ok(crate::ContractError::InvalidMigrationBatch))
+    );
+}
+
+#[test]
+fn test_upgrade_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let stranger = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    let wasm_hash = soroban_sdk::BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(
+        contract.try_upgrade(&stranger, &wasm_hash),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(
+        contract.try_migrate_storage_schema(&stranger, &10),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+}
+
+// ============================================================================
+// Migration Mode Tests
+// ============================================================================
+
+#[test]
+fn test_migration_mode_blocks_writes_except_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let successor = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+    let first = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let second = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+
+    contract.start_migration(&admin);
+    assert_eq!(contract.get_migration_mode(), crate::MigrationMode::Migrating);
+
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
+    assert_eq!(
+        contract.try_confirm_payout(&first),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
+
+    // Refunds and exports still work while migrating
+    contract.cancel_remittance(&first);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+    let snapshot = contract.export_migration_state(&admin);
+    assert_eq!(snapshot.persistent_data.remittances.len(), 2);
+
+    contract.complete_migration(&admin, &successor);
+    assert_eq!(
+        contract.get_migration_mode(),
+        crate::MigrationMode::Migrated(successor)
+    );
+
+    assert_eq!(
+        contract.try_cancel_remittance(&second),
+        Err(Ok(crate::ContractError::ContractMigrated))
+    );
+    assert_eq!(
+        contract.try_register_agent(&admin, &Address::generate(&env)),
+        Err(Ok(crate::ContractError::ContractMigrated))
+    );
+
+    // Reads keep working
+    assert_eq!(contract.get_remittance(&second).status, crate::RemittanceStatus::Pending);
+}
+
+#[test]
+fn test_migration_mode_transitions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let successor = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    assert_eq!(contract.get_migration_mode(), crate::MigrationMode::Active);
+
+    assert_eq!(
+        contract.try_complete_migration(&admin, &successor),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+
+    contract.start_migration(&admin);
+    assert_eq!(
+        contract.try_start_migration(&admin),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
+
+    contract.complete_migration(&admin, &successor);
+    assert_eq!(
+        contract.try_start_migration(&admin),
+        Err(Ok(crate::ContractError::ContractMigrated))
+    );
+}
+
+#[test]
+fn test_start_migration_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let stranger = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    assert_eq!(
+        contract.try_start_migration(&stranger),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(contract.get_migration_mode(), crate::MigrationMode::Active);
+}
+
+#[test]
+fn test_export_requires_migration_mode() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let successor = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // A writable contract could change underneath the export
+    assert_eq!(
+        contract.try_export_migration_state(&admin).err(),
+        Some(Ok(crate::ContractError::InvalidStatus))
+    );
+    assert_eq!(
+        contract.try_export_migration_batch(&admin, &0, &5),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+
+    contract.start_migration(&admin);
+    contract.export_migration_state(&admin);
+    contract.export_migration_batch(&admin, &0, &5);
+
+    contract.complete_migration(&admin, &successor);
+    assert_eq!(
+        contract.try_export_migration_state(&admin).err(),
+        Some(Ok(crate::ContractError::ContractMigrated))
+    );
+    assert_eq!(
+        contract.try_export_migration_batch(&admin, &0, &5),
+        Err(Ok(crate::ContractError::ContractMigrated))
+    );
+}
+
+// ============================================================================
+// Import Binding Tests
+// ============================================================================
+
+#[test]
+fn test_import_requires_configured_import_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let attacker = Address::generate(&env);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+    contract1.start_migration(&admin);
+    let snapshot = contract1.export_migration_state(&admin);
+    assert_eq!(snapshot.source_contract, contract1.address);
+    assert_eq!(snapshot.network_passphrase_hash, env.ledger().network_id());
+
+    // Unconfigured contracts accept no imports
+    let contract2 = create_swiftremit_contract(&env);
+    assert_eq!(
+        contract2.try_import_migration_state(&attacker, &snapshot),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+
+    contract2.configure_import_admin(&admin, &contract1.address);
+    assert_eq!(contract2.get_import_admin(), Some(admin.clone()));
+    assert_eq!(
+        contract2.try_configure_import_admin(&attacker, &contract1.address),
+        Err(Ok(crate::ContractError::AlreadyInitialized))
+    );
+    assert_eq!(
+        contract2.try_import_migration_state(&attacker, &snapshot),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+
+    contract2.import_migration_state(&admin, &snapshot);
+    assert_eq!(contract2.get_platform_fee_bps(), 250);
+}
+
+#[test]
+fn test_import_rejects_snapshot_from_other_network() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+    contract1.start_migration(&admin);
+    let mut snapshot = contract1.export_migration_state(&admin);
+
+    // Changing the network also breaks the hash
+    snapshot.network_passphrase_hash = soroban_sdk::BytesN::from_array(&env, &[3u8; 32]);
+    assert!(!contract1.verify_migration_snapshot(&snapshot).valid);
+
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    assert_eq!(
+        contract2.try_import_migration_state(&admin, &snapshot),
+        Err(Ok(crate::ContractError::InvalidMigrationHash))
+    );
+}
+
+#[test]
+fn test_import_rejects_snapshot_with_rewritten_source() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+    contract1.start_migration(&admin);
+    let mut snapshot = contract1.export_migration_state(&admin);
+    snapshot.source_contract = Address::generate(&env);
+
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    assert_eq!(
+        contract2.try_import_migration_state(&admin, &snapshot),
+        Err(Ok(crate::ContractError::InvalidMigrationHash))
+    );
+}
+
+#[test]
+fn test_batch_import_requires_configured_import_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let operator = Address::generate(&env);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+    contract1.start_migration(&admin);
+    let first = contract1.export_migration_batch(&admin, &0, &5);
+
+    let contract2 = create_swiftremit_contract(&env);
+    assert_eq!(
+        contract2.try_import_migration_batch(&operator, &first),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+
+    // The import admin need not be the exported admin
+    contract2.configure_import_admin(&operator, &contract1.address);
+    assert_eq!(
+        contract2.try_import_migration_batch(&admin, &first),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    contract2.import_migration_batch(&operator, &first);
+    assert_eq!(contract2.get_migration_import_status().next_batch, 1);
+}
+
+#[test]
+fn test_import_rejects_data_from_other_source_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let expected = create_swiftremit_contract(&env);
+    expected.initialize(&admin, &token.address, &250, &0, &None);
+
+    // Validly hashed exports of a contract the destination was not bound to
+    let other = create_swiftremit_contract(&env);
+    other.initialize(&admin, &token.address, &100, &0, &None);
+    other.start_migration(&admin);
+    let snapshot = other.export_migration_state(&admin);
+    let first = other.export_migration_batch(&admin, &0, &5);
+    assert!(other.verify_migration_snapshot(&snapshot).valid);
+
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &expected.address);
+    assert_eq!(contract2.get_import_source(), Some(expected.address.clone()));
+    assert_eq!(
+        contract2.try_import_migration_state(&admin, &snapshot),
+        Err(Ok(crate::ContractError::MigrationSourceMismatch))
+    );
+    assert_eq!(
+        contract2.try_import_migration_batch(&admin, &first),
+        Err(Ok(crate::ContractError::MigrationSourceMismatch))
+    );
+    assert_eq!(contract2.get_migration_import_status().next_batch, 0);
+}
+
+#[test]
+fn test_import_admin_and_source_cannot_be_reconfigured() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let source = Address::generate(&env);
+    let other_source = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.configure_import_admin(&admin, &source);
+
+    // Not even the import admin can repoint the source
+    assert_eq!(
+        contract.try_configure_import_admin(&admin, &other_source),
+        Err(Ok(crate::ContractError::AlreadyInitialized))
+    );
+    assert_eq!(contract.get_import_admin(), Some(admin));
+    assert_eq!(contract.get_import_source(), Some(source));
+}
+
+#[test]
+fn test_snapshot_can_only_be_imported_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract1 = create_swiftremit_contract(&env);
+    contract1.initialize(&admin, &token.address, &250, &0, &None);
+    contract1.start_migration(&admin);
+    let snapshot = contract1.export_migration_state(&admin);
+
+    let contract2 = create_swiftremit_contract(&env);
+    contract2.configure_import_admin(&admin, &contract1.address);
+    contract2.import_migration_state(&admin, &snapshot);
+    assert_eq!(
+        contract2.try_import_migration_state(&admin, &snapshot),
+        Err(Ok(crate::ContractError::MigrationInProgress))
+    );
+}
+
+// ============================================================================
+// Storage TTL Tests
+// ============================================================================
+
+fn ttl_test_policy() -> crate::TtlPolicy {
+    crate::TtlPolicy {
+        instance_threshold: 100_000,
+        instance_extend_to: 500_000,
+        active_threshold: 40_000,
+        active_extend_to: 50_000,
+        terminal_threshold: 150_000,
+        terminal_extend_to: 200_000,
+    }
+}
+
+fn remittance_ttl(env: &Env, contract: &SwiftRemitContractClient, remittance_id: u64) -> u32 {
+    env.as_contract(&contract.address, || {
+        crate::storage::get_remittance_ttl(env, remittance_id)
+    })
+}
+
+#[test]
+fn test_contract_usable_past_default_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+
+    // Far beyond the network's minimum TTL for new entries
+    let min_ttl = env.ledger().get().min_persistent_entry_ttl;
+    env.ledger().with_mut(|li| li.sequence_number += min_ttl * 10);
+
+    assert_eq!(contract.get_remittance(&remittance_id).status, crate::RemittanceStatus::Pending);
+    assert!(contract.is_agent_registered(&agent));
+    contract.cancel_remittance(&remittance_id);
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+}
+
+#[test]
+fn test_remittance_ttl_follows_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.set_ttl_policy(&admin, &ttl_test_policy());
+    assert_eq!(contract.get_ttl_policy(), ttl_test_policy());
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    assert_eq!(remittance_ttl(&env, &contract, remittance_id), 50_000);
+
+    contract.cancel_remittance(&remittance_id);
+    assert_eq!(remittance_ttl(&env, &contract, remittance_id), 200_000);
+}
+
+#[test]
+fn test_migration_batches_carry_ttl_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.set_ttl_policy(&admin, &ttl_test_policy());
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+    let pending = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.cancel_remittance(&cancelled);
+
+    let destination = migrate_in_batches(&env, &contract, &admin);
+
+    assert_eq!(destination.get_ttl_policy(), ttl_test_policy());
+    // Imported entries already follow the migrated policy
+    assert_eq!(remittance_ttl(&env, &destination, pending), 50_000);
+    assert_eq!(remittance_ttl(&env, &destination, cancelled), 200_000);
+}
+
+#[test]
+fn test_bump_remittance_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.set_ttl_policy(&admin, &ttl_test_policy());
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+
+    // Above the threshold the bump leaves the TTL alone
+    env.ledger().with_mut(|li| li.sequence_number += 5_000);
+    assert_eq!(contract.bump_remittance_ttl(&soroban_sdk::vec![&env, remittance_id]), 1);
+    assert_eq!(remittance_ttl(&env, &contract, remittance_id), 45_000);
+
+    // Below the threshold it is extended again; unknown IDs are skipped
+    env.ledger().with_mut(|li| li.sequence_number += 30_000);
+    assert_eq!(
+        contract.bump_remittance_ttl(&soroban_sdk::vec![&env, remittance_id, 999]),
+        1
+    );
+    assert_eq!(remittance_ttl(&env, &contract, remittance_id), 50_000);
+
+    // Past the original expiry the remittance is still readable
+    env.ledger().with_mut(|li| li.sequence_number += 30_000);
+    assert_eq!(contract.get_remittance(&remittance_id).amount, 1000);
+
+    let mut too_many = soroban_sdk::Vec::new(&env);
+    for id in 0..=crate::MAX_TTL_BUMP_IDS as u64 {
+        too_many.push_back(id);
+    }
+    assert_eq!(
+        contract.try_bump_remittance_ttl(&too_many),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_terminal_remittance_archived_after_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.set_ttl_policy(&admin, &ttl_test_policy());
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.cancel_remittance(&remittance_id);
+
+    // Nobody bumps the record, so it is archived once the terminal TTL runs out
+    env.ledger().with_mut(|li| li.sequence_number += 200_001);
+    contract.get_remittance(&remittance_id);
+}
+
+#[test]
+fn test_set_ttl_policy_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    assert_eq!(contract.get_ttl_policy(), crate::TtlPolicy::default_policy());
+
+    let mut threshold_above_extension = ttl_test_policy();
+    threshold_above_extension.active_threshold = 60_000;
+    assert_eq!(
+        contract.try_set_ttl_policy(&admin, &threshold_above_extension),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+
+    let mut beyond_max_ttl = ttl_test_policy();
+    beyond_max_ttl.terminal_extend_to = env.ledger().get().max_entry_ttl + 1;
+    assert_eq!(
+        contract.try_set_ttl_policy(&admin, &beyond_max_ttl),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+
+    assert_eq!(
+        contract.try_set_ttl_policy(&Address::generate(&env), &ttl_test_policy()),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(contract.get_ttl_policy(), crate::TtlPolicy::default_policy());
+}
+
+// ============================================================================
+// Archival Tests
+// ============================================================================
+
+#[test]
+fn test_archive_terminal_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+
+    env.ledger().with_mut(|li| li.timestamp = 10 * crate::ARCHIVE_PERIOD_SECONDS + 100);
+    let completed = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &2000, &default_currency(&env), &default_country(&env), &None);
+    let pending = contract.create_remittance(&sender, &agent, &3000, &default_currency(&env), &default_country(&env), &None);
+    contract.confirm_payout(&completed);
+    contract.cancel_remittance(&cancelled);
+
+    let ids = soroban_sdk::vec![&env, completed, cancelled, pending, 999];
+    assert_eq!(contract.archive_remittances(&admin, &ids, &30), 0);
+
+    let completed_record = contract.get_remittance(&completed);
+    let cancelled_record = contract.get_remittance(&cancelled);
+    env.ledger().with_mut(|li| li.timestamp += 31 * 86_400);
+    assert_eq!(contract.archive_remittances(&admin, &ids, &30), 2);
+    assert_eq!(contract.archive_remittances(&admin, &ids, &30), 0);
+
+    assert_eq!(
+        contract.try_get_remittance(&completed),
+        Err(Ok(crate::ContractError::RemittanceArchived))
+    );
+    assert_eq!(
+        contract.try_get_remittance(&999),
+        Err(Ok(crate::ContractError::RemittanceNotFound))
+    );
+    assert_eq!(contract.get_remittance(&pending).status, crate::RemittanceStatus::Pending);
+    assert_eq!(contract.get_archived_period(&completed), Some(10));
+    assert_eq!(contract.get_archived_period(&pending), None);
+    assert_eq!(contract.list_archive_periods(), soroban_sdk::vec![&env, 10u64]);
+
+    let summary = contract.get_archive_summary(&10).unwrap();
+    assert_eq!(summary.period_start, 10 * crate::ARCHIVE_PERIOD_SECONDS);
+    assert_eq!(summary.period_end, 11 * crate::ARCHIVE_PERIOD_SECONDS);
+    assert_eq!(summary.remittance_count, 2);
+    assert_eq!(summary.pair_count, 1);
+    let totals = contract.get_archive_totals(&10, &agent, &token.address).unwrap();
+    assert_eq!(contract.list_archive_totals(&10, &0, &10), soroban_sdk::vec![&env, totals.clone()]);
+    assert_eq!(totals.agent, agent);
+    assert_eq!(totals.token, token.address);
+    assert_eq!(totals.completed_count, 1);
+    assert_eq!(totals.completed_volume, 1000);
+    assert_eq!(totals.fees, 25);
+    assert_eq!(totals.cancelled_count, 1);
+    assert_eq!(totals.cancelled_volume, 2000);
+
+    // The commitment replays from the pruned records
+    let zero = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
+    let expected = crate::chain_commitment(
+        &env,
+        &crate::chain_commitment(&env, &zero, &completed_record),
+        &cancelled_record,
+    );
+    assert_eq!(summary.commitment, expected);
+}
+
+#[test]
+fn test_migration_batches_carry_archive_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent1 = Address::generate(&env);
+    let agent2 = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent1);
+    contract.register_agent(&admin, &agent2);
+    token.mint(&sender, &10000);
+
+    env.ledger().with_mut(|li| li.timestamp = 10 * crate::ARCHIVE_PERIOD_SECONDS + 100);
+    let first = contract.create_remittance(&sender, &agent1, &1000, &default_currency(&env), &default_country(&env), &None);
+    let second = contract.create_remittance(&sender, &agent2, &2000, &default_currency(&env), &default_country(&env), &None);
+    let unarchived = contract.create_remittance(&sender, &agent1, &500, &default_currency(&env), &default_country(&env), &None);
+    contract.confirm_payout(&first);
+    contract.cancel_remittance(&second);
+    contract.confirm_payout(&unarchived);
+
+    env.ledger().with_mut(|li| li.timestamp += 31 * 86_400);
+    assert_eq!(contract.archive_remittances(&admin, &soroban_sdk::vec![&env, first, second], &30), 2);
+
+    let destination = migrate_in_batches(&env, &contract, &admin);
+
+    // The snapshot does not carry archive state, so it refuses archived contracts
+    assert_eq!(
+        contract.try_export_migration_state(&admin).err(),
+        Some(Ok(crate::ContractError::InvalidStatus))
+    );
+
+    assert_eq!(destination.list_archive_periods(), soroban_sdk::vec![&env, 10u64]);
+    assert_eq!(destination.get_archive_summary(&10), contract.get_archive_summary(&10));
+    assert_eq!(destination.get_archive_summary(&10).unwrap().pair_count, 2);
+    assert_eq!(
+        destination.list_archive_totals(&10, &0, &10),
+        contract.list_archive_totals(&10, &0, &10)
+    );
+    assert_eq!(
+        destination.get_archive_totals(&10, &agent2, &token.address),
+        contract.get_archive_totals(&10, &agent2, &token.address)
+    );
+    assert_eq!(destination.get_archived_period(&first), Some(10));
+    assert_eq!(destination.get_archived_period(&second), Some(10));
+    assert_eq!(
+        destination.try_get_remittance(&first),
+        Err(Ok(crate::ContractError::RemittanceArchived))
+    );
+
+    // Close times are carried, so the remaining remittance archives into its original period
+    assert_eq!(
+        destination.archive_remittances(&admin, &soroban_sdk::vec![&env, unarchived], &30),
+        1
+    );
+    assert_eq!(destination.get_archived_period(&unarchived), Some(10));
+    assert_eq!(destination.get_archive_summary(&10).unwrap().remittance_count, 3);
+    assert_eq!(
+        destination.get_archive_totals(&10, &agent1, &token.address).unwrap().completed_count,
+        2
+    );
+}
+
+#[test]
+fn test_archive_remittances_requires_admin_and_bounded_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    assert_eq!(
+        contract.try_archive_remittances(&Address::generate(&env), &soroban_sdk::vec![&env, 1u64], &30),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+
+    let mut too_many = soroban_sdk::Vec::new(&env);
+    for id in 0..=crate::MAX_ARCHIVE_BATCH_SIZE as u64 {
+        too_many.push_back(id);
+    }
+    assert_eq!(
+        contract.try_archive_remittances(&admin, &too_many, &30),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+}
+
+// ============================================================================
+// Timelocked Fee Withdrawal Tests
+// ============================================================================
+
+#[test]
+fn test_fee_withdrawal_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.grant_role(&admin, &crate::Role::Treasurer, &treasurer);
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(contract.get_withdrawal_timelock(), crate::DEFAULT_WITHDRAWAL_TIMELOCK);
+    assert_eq!(
+        contract.try_set_withdrawal_timelock(&admin, &0),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+    assert_eq!(
+        contract.try_set_withdrawal_timelock(&admin, &(crate::MIN_WITHDRAWAL_TIMELOCK - 1)),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+    contract.set_withdrawal_timelock(&admin, &crate::MIN_WITHDRAWAL_TIMELOCK);
+    contract.add_treasury_destination(&admin, &treasury);
+    assert!(contract.is_treasury_destination(&treasury));
+    assert_eq!(contract.list_treasury_destinations(&0, &10), soroban_sdk::vec![&env, treasury.clone()]);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let withdrawal = contract.propose_fee_withdrawal(&admin, &token.address, &20, &treasury);
+    let executable_at = 1000 + crate::MIN_WITHDRAWAL_TIMELOCK;
+    assert_eq!(withdrawal.executable_at, executable_at);
+    assert_eq!(withdrawal.status, crate::FeeWithdrawalStatus::Pending);
+
+    env.ledger().with_mut(|li| li.timestamp = executable_at - 1);
+    assert_eq!(
+        contract.try_execute_fee_withdrawal(&treasurer, &withdrawal.id),
+        Err(Ok(crate::ContractError::TimelockNotElapsed))
+    );
+    assert_eq!(contract.get_accumulated_fees(), 25);
+
+    // The proposer cannot execute their own withdrawal
+    env.ledger().with_mut(|li| li.timestamp = executable_at);
+    assert_eq!(
+        contract.try_execute_fee_withdrawal(&admin, &withdrawal.id),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    contract.execute_fee_withdrawal(&treasurer, &withdrawal.id);
+    assert_eq!(get_token_balance(&token, &treasury), 20);
+    assert_eq!(contract.get_accumulated_fees(), 5);
+
+    let executed = contract.get_fee_withdrawal(&withdrawal.id);
+    assert_eq!(executed.status, crate::FeeWithdrawalStatus::Executed);
+    assert_eq!(executed.resolved_by, Some(treasurer.clone()));
+    assert_eq!(
+        contract.try_execute_fee_withdrawal(&treasurer, &withdrawal.id),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+
+    // Proposals do not reserve fees; execution re-checks the balance
+    let first = contract.propose_fee_withdrawal(&admin, &token.address, &5, &treasury);
+    let second = contract.propose_fee_withdrawal(&admin, &token.address, &5, &treasury);
+    env.ledger().with_mut(|li| li.timestamp += crate::MIN_WITHDRAWAL_TIMELOCK);
+    contract.execute_fee_withdrawal(&treasurer, &first.id);
+    assert_eq!(
+        contract.try_execute_fee_withdrawal(&treasurer, &second.id),
+        Err(Ok(crate::ContractError::NoFeesToWithdraw))
+    );
+}
+
+#[test]
+fn test_fee_withdrawal_veto() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.add_admin(&admin, &admin2);
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.confirm_payout(&remittance_id);
+    contract.add_treasury_destination(&admin, &treasury);
+
+    let withdrawal = contract.propose_fee_withdrawal(&admin, &token.address, &25, &treasury);
+
+    // The proposer cannot veto their own withdrawal
+    assert_eq!(
+        contract.try_veto_fee_withdrawal(&admin, &withdrawal.id),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    contract.veto_fee_withdrawal(&admin2, &withdrawal.id);
+
+    let vetoed = contract.get_fee_withdrawal(&withdrawal.id);
+    assert_eq!(vetoed.status, crate::FeeWithdrawalStatus::Vetoed);
+    assert_eq!(vetoed.resolved_by, Some(admin2));
+
+    env.ledger().with_mut(|li| li.timestamp = withdrawal.executable_at);
+    assert_eq!(
+        contract.try_execute_fee_withdrawal(&admin, &withdrawal.id),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(
+        contract.try_get_fee_withdrawal(&99),
+        Err(Ok(crate::ContractError::WithdrawalNotFound))
+    );
+}
+
+#[test]
+fn test_fee_withdrawal_destination_allow_list() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.add_admin(&admin, &admin2);
+    contract.register_agent(&admin, &agent);
+    token.mint(&sender, &10000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(
+        contract.try_propose_fee_withdrawal(&admin, &token.address, &25, &treasury),
+        Err(Ok(crate::ContractError::DestinationNotAllowed))
+    );
+
+    contract.add_treasury_destination(&admin, &treasury);
+    assert_eq!(
+        contract.try_propose_fee_withdrawal(&admin, &token.address, &26, &treasury),
+        Err(Ok(crate::ContractError::NoFeesToWithdraw))
+    );
+    assert_eq!(
+        contract.try_propose_fee_withdrawal(&Address::generate(&env), &token.address, &25, &treasury),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    let withdrawal = contract.propose_fee_withdrawal(&admin, &token.address, &25, &treasury);
+
+    // Removing the destination blocks execution of pending withdrawals
+    contract.remove_treasury_destination(&admin, &treasury);
+    assert_eq!(contract.list_treasury_destinations(&0, &10).len(), 0);
+    env.ledger().with_mut(|li| li.timestamp = withdrawal.executable_at);
+    assert_eq!(
+        contract.try_execute_fee_withdrawal(&admin2, &withdrawal.id),
+        Err(Ok(crate::ContractError::DestinationNotAllowed))
+    );
+
+    assert_eq!(
+        contract.try_set_withdrawal_timelock(&admin, &(crate::MAX_WITHDRAWAL_TIMELOCK + 1)),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+}
+
+#[test]
+fn test_treasury_config_requires_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, admin2, _) = setup_multisig_contract(&env);
+    let treasury = Address::generate(&env);
+
+    assert_eq!(
+        contract.try_set_withdrawal_timelock(&admin, &crate::MAX_WITHDRAWAL_TIMELOCK),
+        Err(Ok(crate::ContractError::MultisigRequired))
+    );
+    assert_eq!(
+        contract.try_add_treasury_destination(&admin, &treasury),
+        Err(Ok(crate::ContractError::MultisigRequired))
+    );
+    assert_eq!(
+        contract.try_propose_admin_action(&admin, &crate::AdminAction::SetWithdrawalTimelock(0)),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+
+    let timelock = contract.propose_admin_action(
+        &admin,
+        &crate::AdminAction::SetWithdrawalTimelock(crate::MAX_WITHDRAWAL_TIMELOCK),
+    );
+    contract.approve_admin_action(&admin2, &timelock);
+    contract.execute_admin_action(&admin, &timelock);
+    assert_eq!(contract.get_withdrawal_timelock(), crate::MAX_WITHDRAWAL_TIMELOCK);
+
+    let destination = contract.propose_admin_action(
+        &admin,
+        &crate::AdminAction::AddTreasuryDestination(treasury.clone()),
+    );
+    contract.approve_admin_action(&admin2, &destination);
+    contract.execute_admin_action(&admin, &destination);
+    assert!(contract.is_treasury_destination(&treasury));
+
+    // Removing a destination only restricts withdrawals and stays direct
+    contract.remove_treasury_destination(&admin, &treasury);
+    assert!(!contract.is_treasury_destination(&treasury));
+}
+
+// ============================================================================
+// Multisig Admin Action Tests
+// ============================================================================
+
+fn setup_multisig_contract<'a>(
+    env: &Env,
+) -> (SwiftRemitContractClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let admin2 = Address::generate(env);
+    let admin3 = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = create_token_contract(env, &token_admin);
+
+    let contract = create_swiftremit_contract(env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.add_admin(&admin, &admin2);
+    contract.add_admin(&admin, &admin3);
+
+    // With a threshold of 1 the proposer's own approval is enough
+    let id = contract.propose_admin_action(&admin, &crate::AdminAction::SetApprovalThreshold(2));
+    contract.execute_admin_action(&admin, &id);
+    assert_eq!(contract.get_approval_threshold(), 2);
+
+    (contract, admin, admin2, admin3)
+}
+
+#[test]
+fn test_multisig_fee_change_requires_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, admin2, admin3) = setup_multisig_contract(&env);
+
+    assert_eq!(
+        contract.try_update_fee(&admin, &500),
+        Err(Ok(crate::ContractError::MultisigRequired))
+    );
+    assert_eq!(contract.try_pause(&admin, &0), Err(Ok(crate::ContractError::MultisigRequired)));
+
+    let id = contract.propose_admin_action(&admin, &crate::AdminAction::UpdateFee(500));
+    let proposal = contract.get_admin_proposal(&id);
+    assert_eq!(proposal.proposer, admin);
+    assert_eq!(proposal.approvals, soroban_sdk::vec![&env, admin.clone()]);
+    assert_eq!(proposal.status, crate::ProposalStatus::Pending);
+
+    assert_eq!(
+        contract.try_execute_admin_action(&admin, &id),
+        Err(Ok(crate::ContractError::InsufficientApprovals))
+    );
+    assert_eq!(
+        contract.try_approve_admin_action(&admin, &id),
+        Err(Ok(crate::ContractError::AlreadyApproved))
+    );
+
+    contract.approve_admin_action(&admin2, &id);
+    contract.execute_admin_action(&admin3, &id);
+    assert_eq!(contract.get_platform_fee_bps(), 500);
+    assert_eq!(contract.get_admin_proposal(&id).status, crate::ProposalStatus::Executed);
+
+    assert_eq!(
+        contract.try_execute_admin_action(&admin, &id),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+    assert_eq!(
+        contract.try_get_admin_proposal(&99),
+        Err(Ok(crate::ContractError::ProposalNotFound))
+    );
+}
+
+#[test]
+fn test_multisig_replays_pause_and_agent_removal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, admin2, _) = setup_multisig_contract(&env);
+    let agent = Address::generate(&env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_agent_registered(&env, &agent, true)
+    });
+
+    let pause = contract.propose_admin_action(&admin, &crate::AdminAction::Pause(0));
+    contract.approve_admin_action(&admin2, &pause);
+    contract.execute_admin_action(&admin, &pause);
+    assert!(contract.is_paused());
+
+    let remove = contract.propose_admin_action(&admin2, &crate::AdminAction::RemoveAgent(agent.clone()));
+    contract.approve_admin_action(&admin, &remove);
+    contract.execute_admin_action(&admin2, &remove);
+    assert!(!contract.is_agent_registered(&agent));
+}
+
+#[test]
+fn test_multisig_scoped_unpause_requires_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, admin2, _) = setup_multisig_contract(&env);
+    let scope = crate::PauseScope::Operation(crate::PausableOperation::Creation);
+
+    // Pausing a scope stays available to a single pauser
+    contract.pause_scope(&admin, &scope, &1);
+    assert!(contract.is_scope_paused(&scope));
+    assert_eq!(
+        contract.try_unpause_scope(&admin, &scope),
+        Err(Ok(crate::ContractError::MultisigRequired))
+    );
+
+    let id = contract.propose_admin_action(&admin, &crate::AdminAction::UnpauseScope(scope.clone()));
+    contract.approve_admin_action(&admin2, &id);
+    contract.execute_admin_action(&admin, &id);
+    assert!(!contract.is_scope_paused(&scope));
+}
+
+#[test]
+fn test_multisig_expiry_and_revoked_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, admin2, admin3) = setup_multisig_contract(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let expiring = contract.propose_admin_action(&admin, &crate::AdminAction::Unpause);
+    assert_eq!(
+        contract.get_admin_proposal(&expiring).expires_at,
+        1000 + crate::PROPOSAL_LIFETIME_SECONDS
+    );
+
+    // Approvals of addresses that lost the admin role do not count
+    let revoked = contract.propose_admin_action(&admin, &crate::AdminAction::UpdateFee(100));
+    contract.approve_admin_action(&admin2, &revoked);
+    let removal = contract.propose_admin_action(&admin, &crate::AdminAction::RemoveAdmin(admin2.clone()));
+    contract.approve_admin_action(&admin3, &removal);
+    contract.execute_admin_action(&admin, &removal);
+    assert!(!contract.is_admin(&admin2));
+    assert_eq!(
+        contract.try_execute_admin_action(&admin, &revoked),
+        Err(Ok(crate::ContractError::InsufficientApprovals))
+    );
+    contract.approve_admin_action(&admin3, &revoked);
+    contract.execute_admin_action(&admin, &revoked);
+    assert_eq!(contract.get_platform_fee_bps(), 100);
+
+    env.ledger().with_mut(|li| li.timestamp = 1001 + crate::PROPOSAL_LIFETIME_SECONDS);
+    assert_eq!(
+        contract.try_approve_admin_action(&admin3, &expiring),
+        Err(Ok(crate::ContractError::ProposalExpired))
+    );
+}
+
+#[test]
+fn test_multisig_admin_and_role_management() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, admin2, _) = setup_multisig_contract(&env);
+    let new_admin = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    let recovery_key = Address::generate(&env);
+
+    assert_eq!(
+        contract.try_add_admin(&admin, &new_admin),
+        Err(Ok(crate::ContractError::MultisigRequired))
+    );
+    assert_eq!(
+        contract.try_grant_role(&admin, &crate::Role::Treasurer, &treasurer),
+        Err(Ok(crate::ContractError::MultisigRequired))
+    );
+    assert_eq!(
+        contract.try_set_recovery_key(&admin, &Some(recovery_key.clone())),
+        Err(Ok(crate::ContractError::MultisigRequired))
+    );
+
+    let add = contract.propose_admin_action(&admin, &crate::AdminAction::AddAdmin(new_admin.clone()));
+    contract.approve_admin_action(&admin2, &add);
+    contract.execute_admin_action(&admin, &add);
+    assert!(contract.is_admin(&new_admin));
+
+    let grant = contract.propose_admin_action(
+        &admin,
+        &crate::AdminAction::GrantRole(crate::Role::Treasurer, treasurer.clone()),
+    );
+    contract.approve_admin_action(&admin2, &grant);
+    contract.execute_admin_action(&admin, &grant);
+    assert!(contract.has_role(&crate::Role::Treasurer, &treasurer));
+
+    // Actions are validated again when they are executed
+    let regrant = contract.propose_admin_action(
+        &admin,
+        &crate::AdminAction::GrantRole(crate::Role::Treasurer, treasurer.clone()),
+    );
+    contract.approve_admin_action(&admin2, &regrant);
+    assert_eq!(
+        contract.try_execute_admin_action(&admin, &regrant),
+        Err(Ok(crate::ContractError::RoleAlreadyGranted))
+    );
+
+    let key = contract.propose_admin_action(
+        &admin,
+        &crate::AdminAction::SetRecoveryKey(Some(recovery_key.clone())),
+    );
+    contract.approve_admin_action(&admin2, &key);
+    contract.execute_admin_action(&admin, &key);
+    assert_eq!(contract.get_recovery_key(), Some(recovery_key));
+
+    // Revoking a role only restricts access and stays direct
+    contract.revoke_role(&admin, &crate::Role::Treasurer, &treasurer);
+    assert!(!contract.has_role(&crate::Role::Treasurer, &treasurer));
+}
+
+#[test]
+fn test_migration_batches_carry_approval_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, admin2, _) = setup_multisig_contract(&env);
+
+    let pending = contract.propose_admin_action(&admin, &crate::AdminAction::UpdateFee(500));
+    let start = contract.propose_admin_action(&admin, &crate::AdminAction::StartMigration);
+    contract.approve_admin_action(&admin2, &start);
+    contract.execute_admin_action(&admin, &start);
+
+    let first = contract.export_migration_batch(&admin, &0, &5);
+    let mut batches = std::vec![first.clone()];
+    for n in 1..first.total_batches {
+        batches.push(contract.export_migration_batch(&admin, &n, &5));
+    }
+    let destination = create_swiftremit_contract(&env);
+    destination.configure_import_admin(&admin, &contract.address);
+    for batch in batches {
+        destination.import_migration_batch(&admin, &batch);
+    }
+    destination.finalize_migration_import(&admin, &contract.get_migration_export_digest());
+
+    assert_eq!(destination.get_approval_threshold(), 2);
+    assert_eq!(
+        destination.try_update_fee(&admin, &500),
+        Err(Ok(crate::ContractError::MultisigRequired))
+    );
+    // Pending proposals are dropped
+    assert_eq!(
+        destination.try_get_admin_proposal(&pending),
+        Err(Ok(crate::ContractError::ProposalNotFound))
+    );
+}
+
+#[test]
+fn test_multisig_threshold_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, _, _) = setup_multisig_contract(&env);
+
+    assert_eq!(
+        contract.try_propose_admin_action(&admin, &crate::AdminAction::SetApprovalThreshold(0)),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+    assert_eq!(
+        contract.try_propose_admin_action(&admin, &crate::AdminAction::SetApprovalThreshold(4)),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+    assert_eq!(
+        contract.try_propose_admin_action(&Address::generate(&env), &crate::AdminAction::Pause(0)),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+}
+
+// ============================================================================
+// Role Tests
+// ============================================================================
+
+#[test]
+fn test_role_holders_can_only_use_their_entrypoints() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let fee_manager = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    contract.grant_role(&admin, &crate::Role::FeeManager, &fee_manager);
+    contract.grant_role(&admin, &crate::Role::Pauser, &pauser);
+    assert!(contract.has_role(&crate::Role::FeeManager, &fee_manager));
+    assert!(!contract.has_role(&crate::Role::Pauser, &fee_manager));
+
+    contract.update_fee(&fee_manager, &300);
+    assert_eq!(contract.get_platform_fee_bps(), 300);
+    assert_eq!(
+        contract.try_pause(&fee_manager, &0),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(
+        contract.try_register_agent(&fee_manager, &agent),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+
+    contract.pause(&pauser, &0);
+    assert!(contract.is_paused());
+    assert_eq!(
+        contract.try_update_fee(&pauser, &400),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+
+    // Admins implicitly hold every role
+    contract.unpause(&admin);
+    contract.register_agent(&admin, &agent);
+
+    assert_eq!(
+        contract.list_role_holders(&crate::Role::FeeManager, &0, &10),
+        soroban_sdk::vec![&env, fee_manager.clone()]
+    );
+
+    contract.revoke_role(&admin, &crate::Role::FeeManager, &fee_manager);
+    assert!(!contract.has_role(&crate::Role::FeeManager, &fee_manager));
+    assert_eq!(
+        contract.try_update_fee(&fee_manager, &400),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(contract.list_role_holders(&crate::Role::FeeManager, &0, &10).len(), 0);
+    assert_eq!(contract.get_role_registry_len(&crate::Role::FeeManager), 1);
+}
+
+#[test]
+fn test_grant_and_revoke_role_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    contract.grant_role(&admin, &crate::Role::Treasurer, &treasurer);
+    assert_eq!(
+        contract.try_grant_role(&admin, &crate::Role::Treasurer, &treasurer),
+        Err(Ok(crate::ContractError::RoleAlreadyGranted))
+    );
+    assert_eq!(
+        contract.try_revoke_role(&admin, &crate::Role::Migrator, &treasurer),
+        Err(Ok(crate::ContractError::RoleNotGranted))
+    );
+
+    // Role holders cannot manage roles
+    assert_eq!(
+        contract.try_grant_role(&treasurer, &crate::Role::Migrator, &treasurer),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(
+        contract.try_revoke_role(&treasurer, &crate::Role::Treasurer, &treasurer),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+}
+
+// ============================================================================
+// Admin Management Tests
+// ============================================================================
+
+#[test]
+fn test_admin_management_lists_and_respects_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.add_admin(&admin, &admin2);
+    assert_eq!(
+        contract.list_admins(&0, &10),
+        soroban_sdk::vec![&env, admin.clone(), admin2.clone()]
+    );
+
+    let id = contract.propose_admin_action(&admin, &crate::AdminAction::SetApprovalThreshold(2));
+    contract.execute_admin_action(&admin, &id);
+
+    // Removing either admin would leave fewer admins than the threshold
+    assert_eq!(
+        contract.try_remove_admin(&admin, &admin2),
+        Err(Ok(crate::ContractError::MultisigRequired))
+    );
+    let id = contract.propose_admin_action(&admin, &crate::AdminAction::RemoveAdmin(admin2.clone()));
+    contract.approve_admin_action(&admin2, &id);
+    assert_eq!(
+        contract.try_execute_admin_action(&admin, &id),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+
+    let id = contract.propose_admin_action(&admin, &crate::AdminAction::SetApprovalThreshold(1));
+    contract.approve_admin_action(&admin2, &id);
+    contract.execute_admin_action(&admin, &id);
+
+    // Admins may remove themselves while another admin remains
+    contract.remove_admin(&admin, &admin);
+    assert!(!contract.is_admin(&admin));
+    assert_eq!(contract.list_admins(&0, &10), soroban_sdk::vec![&env, admin2.clone()]);
+    assert_eq!(
+        contract.try_register_agent(&admin, &Address::generate(&env)),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(
+        contract.try_remove_admin(&admin2, &admin2),
+        Err(Ok(crate::ContractError::CannotRemoveLastAdmin))
+    );
+}
+
+// ============================================================================
+// Admin Transfer and Recovery Tests
+// ============================================================================
+
+#[test]
+fn test_admin_transfer_requires_acceptance_before_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let typo = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    // An unaccepted transfer changes nothing and expires
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let transfer = contract.propose_admin_transfer(&admin, &typo);
+    assert_eq!(transfer.expires_at, 1000 + crate::ADMIN_TRANSFER_LIFETIME_SECONDS);
+    assert!(contract.is_admin(&admin));
+    assert!(!contract.is_admin(&typo));
+    env.ledger().with_mut(|li| li.timestamp = 1001 + crate::ADMIN_TRANSFER_LIFETIME_SECONDS);
+    assert_eq!(
+        contract.try_accept_admin_transfer(&typo),
+        Err(Ok(crate::ContractError::ProposalExpired))
+    );
+
+    contract.propose_admin_transfer(&admin, &new_admin);
+    assert_eq!(contract.get_admin_transfer(&new_admin).unwrap().from, admin);
+    contract.accept_admin_transfer(&new_admin);
+    assert!(contract.is_admin(&new_admin));
+    assert!(!contract.is_admin(&admin));
+    assert_eq!(contract.list_admins(&0, &10), soroban_sdk::vec![&env, new_admin.clone()]);
+    assert_eq!(contract.get_admin_transfer(&new_admin), None);
+
+    assert_eq!(
+        contract.try_accept_admin_transfer(&new_admin),
+        Err(Ok(crate::ContractError::ProposalNotFound))
+    );
+    assert_eq!(
+        contract.try_propose_admin_transfer(&admin, &typo),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_admin_transfer_moves_roles() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.add_admin(&admin, &admin2);
+    contract.grant_role(&admin2, &crate::Role::Treasurer, &admin);
+    contract.grant_role(&admin2, &crate::Role::Pauser, &admin);
+    contract.grant_role(&admin2, &crate::Role::Pauser, &new_admin);
+
+    contract.propose_admin_transfer(&admin, &new_admin);
+    contract.accept_admin_transfer(&new_admin);
+
+    for role in [crate::Role::Treasurer, crate::Role::Pauser] {
+        assert!(!contract.has_role(&role, &admin));
+        assert!(contract.has_role(&role, &new_admin));
+    }
+    assert_eq!(
+        contract.list_role_holders(&crate::Role::Treasurer, &0, &10),
+        soroban_sdk::vec![&env, new_admin.clone()]
+    );
+
+    // The old key keeps no privileges
+    assert_eq!(
+        contract.try_pause(&admin, &0),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    let treasury = Address::generate(&env);
+    contract.add_treasury_destination(&admin2, &treasury);
+    assert_eq!(
+        contract.try_propose_fee_withdrawal(&admin, &token.address, &1, &treasury),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_admin_recovery_after_delay_unless_cancelled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let recovery_key = Address::generate(&env);
+    let rescuer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &Some(recovery_key.clone()));
+    assert_eq!(contract.get_recovery_key(), Some(recovery_key.clone()));
+
+    contract.add_admin(&admin, &admin2);
+    let id = contract.propose_admin_action(&admin, &crate::AdminAction::SetApprovalThreshold(2));
+    contract.execute_admin_action(&admin, &id);
+
+    // Admins can cancel a recovery during the delay
+    contract.start_admin_recovery(&rescuer);
+    contract.cancel_admin_recovery(&admin2);
+    assert_eq!(contract.get_pending_recovery(), None);
+    assert_eq!(
+        contract.try_complete_admin_recovery(),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 5000);
+    let recovery = contract.start_admin_recovery(&rescuer);
+    assert_eq!(recovery.executable_at, 5000 + crate::RECOVERY_DELAY_SECONDS);
+    assert_eq!(
+        contract.try_complete_admin_recovery(),
+        Err(Ok(crate::ContractError::TimelockNotElapsed))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 5000 + crate::RECOVERY_DELAY_SECONDS);
+    contract.complete_admin_recovery();
+    assert!(contract.is_admin(&rescuer));
+    assert_eq!(contract.get_approval_threshold(), 1);
+
+    // The recovered admin can act alone
+    contract.update_fee(&rescuer, &300);
+    assert_eq!(contract.get_platform_fee_bps(), 300);
+}
+
+#[test]
+fn test_migration_batches_carry_recovery_key() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let recovery_key = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &Some(recovery_key.clone()));
+    contract.start_admin_recovery(&Address::generate(&env));
+
+    let destination = migrate_in_batches(&env, &contract, &admin);
+
+    assert_eq!(destination.get_recovery_key(), Some(recovery_key));
+    // A recovery in progress is not carried
+    assert_eq!(destination.get_pending_recovery(), None);
+}
+
+#[test]
+fn test_recovery_key_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    assert_eq!(
+        contract.try_initialize(&admin, &token.address, &250, &0, &Some(admin.clone())),
+        Err(Ok(crate::ContractError::InvalidAddress))
+    );
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+
+    assert_eq!(
+        contract.try_start_admin_recovery(&Address::generate(&env)),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    assert_eq!(
+        contract.try_set_recovery_key(&admin, &Some(admin.clone())),
+        Err(Ok(crate::ContractError::InvalidAddress))
+    );
+
+    let recovery_key = Address::generate(&env);
+    contract.set_recovery_key(&admin, &Some(recovery_key.clone()));
+    assert_eq!(contract.get_recovery_key(), Some(recovery_key));
+    assert_eq!(
+        contract.try_start_admin_recovery(&admin),
+        Err(Ok(crate::ContractError::AdminAlreadyExists))
+    );
+}
+
+// ============================================================================
+// Granular Pause Tests
+// ============================================================================
+
+#[test]
+fn test_scoped_pauses_block_only_their_scope() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+    contract.register_agent(&admin, &other_agent);
+    contract.grant_role(&admin, &crate::Role::Pauser, &pauser);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+
+    // Pausing payouts leaves creation and cancellation available
+    let payouts = crate::PauseScope::Operation(crate::PausableOperation::Payout);
+    contract.pause_scope(&pauser, &payouts, &1);
+    assert!(contract.is_scope_paused(&payouts));
+    assert!(!contract.is_paused());
+    assert_eq!(contract.try_confirm_payout(&id), Err(Ok(crate::ContractError::ContractPaused)));
+    assert_eq!(
+        contract.simulate_settlement(&id).error_message,
+        Some(crate::ContractError::ContractPaused as u32)
+    );
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.cancel_remittance(&id2);
+    contract.unpause_scope(&pauser, &payouts);
+
+    // An agent pause blocks that agent's creations and payouts, but not refunds
+    let agent_scope = crate::PauseScope::Agent(agent.clone());
+    contract.pause_scope(&pauser, &agent_scope, &2);
+    assert_eq!(contract.try_confirm_payout(&id), Err(Ok(crate::ContractError::ContractPaused)));
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None),
+        Err(Ok(crate::ContractError::ContractPaused))
+    );
+    contract.create_remittance(&sender, &other_agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.unpause_scope(&pauser, &agent_scope);
+
+    // A corridor pause applies to remittances created in that corridor only
+    let ngn = String::from_str(&env, "NGN");
+    let ng = String::from_str(&env, "NG");
+    let id3 = contract.create_remittance(&sender, &agent, &1000, &ngn, &ng, &None);
+    contract.pause_scope(&pauser, &crate::PauseScope::Corridor(ngn.clone(), ng.clone()), &3);
+    assert_eq!(contract.try_confirm_payout(&id3), Err(Ok(crate::ContractError::ContractPaused)));
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &ngn, &ng, &None),
+        Err(Ok(crate::ContractError::ContractPaused))
+    );
+    contract.confirm_payout(&id);
+    contract.cancel_remittance(&id3);
+
+    // A token pause also blocks refunds in that token
+    let id4 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.pause_scope(&pauser, &crate::PauseScope::Token(token.address.clone()), &4);
+    assert_eq!(contract.try_cancel_remittance(&id4), Err(Ok(crate::ContractError::ContractPaused)));
+}
+
+#[test]
+fn test_pause_state_records_reason_and_pauser() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.grant_role(&admin, &crate::Role::Pauser, &pauser);
+
+    let state = contract.get_pause_state();
+    assert_eq!(state.global, crate::GlobalPause::Unpaused);
+    assert_eq!(state.scoped.len(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    contract.pause(&admin, &9);
+    let withdrawals = crate::PauseScope::Operation(crate::PausableOperation::Withdrawal);
+    contract.pause_scope(&pauser, &withdrawals, &5);
+    contract.pause_scope(&pauser, &crate::PauseScope::Agent(agent.clone()), &6);
+
+    let state = contract.get_pause_state();
+    assert_eq!(
+        state.global,
+        crate::GlobalPause::Paused(crate::PauseRecord { reason: 9, paused_by: admin.clone(), paused_at: 1000 })
+    );
+    assert_eq!(state.scoped.len(), 2);
+    let first = state.scoped.get(0).unwrap();
+    assert_eq!(first.scope, withdrawals);
+    assert_eq!(first.record, crate::PauseRecord { reason: 5, paused_by: pauser.clone(), paused_at: 1000 });
+
+    // Re-pausing a scope replaces its record without listing it twice
+    contract.pause_scope(&admin, &withdrawals, &8);
+    let state = contract.get_pause_state();
+    assert_eq!(state.scoped.len(), 2);
+    assert_eq!(state.scoped.get(0).unwrap().record.reason, 8);
+
+    contract.unpause(&admin);
+    contract.unpause_scope(&pauser, &withdrawals);
+    let state = contract.get_pause_state();
+    assert_eq!(state.global, crate::GlobalPause::Unpaused);
+    assert_eq!(state.scoped.len(), 1);
+    assert_eq!(
+        contract.try_unpause_scope(&pauser, &withdrawals),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+    assert_eq!(
+        contract.try_pause_scope(&Address::generate(&env), &withdrawals, &1),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+}
+
+// ============================================================================
+// Circuit Breaker Tests
+// ============================================================================
+
+#[test]
+fn test_circuit_breaker_trips_on_total_volume_until_admin_reset() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &0, &0, &None);
+    contract.register_agent(&admin, &agent);
+    contract.grant_role(&admin, &crate::Role::Pauser, &pauser);
+
+    assert_eq!(
+        contract.try_set_circuit_breaker(
+            &admin,
+            &crate::CircuitBreakerConfig {
+                max_window_volume: 1500,
+                max_agent_window_volume: 0,
+                window_seconds: 0,
+                enabled: true,
+            }
+        ),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+    contract.set_circuit_breaker(
+        &admin,
+        &crate::CircuitBreakerConfig {
+            max_window_volume: 1500,
+            max_agent_window_volume: 0,
+            window_seconds: 3600,
+            enabled: true,
+        },
+    );
+
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id3 = contract.create_remittance(&sender, &agent, &500, &default_currency(&env), &default_country(&env), &None);
+
+    contract.confirm_payout(&id1);
+    assert_eq!(contract.get_circuit_breaker_trip(), None);
+
+    // A payout that would exceed the ceiling is rejected before any tokens move
+    assert_eq!(contract.simulate_settlement(&id2).error_message, Some(58));
+    assert_eq!(contract.try_confirm_payout(&id2), Err(Ok(crate::ContractError::CircuitBreakerTripped)));
+    assert_eq!(get_token_balance(&token, &agent), 1000);
+    assert_eq!(contract.get_remittance(&id2).status, crate::RemittanceStatus::Pending);
+    assert_eq!(contract.get_circuit_breaker_trip(), None);
+
+    // The payout that fills the window trips the breaker
+    contract.confirm_payout(&id3);
+    let trip = contract.get_circuit_breaker_trip().unwrap();
+    assert_eq!(trip.cause, crate::CircuitBreakerCause::TotalVolume(token.address.clone()));
+    assert_eq!(trip.window_volume, 1500);
+    assert_eq!(trip.ceiling, 1500);
+
+    assert_eq!(contract.try_confirm_payout(&id2), Err(Ok(crate::ContractError::CircuitBreakerTripped)));
+    // Refunds stay available while payouts are halted
+    let id4 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    contract.cancel_remittance(&id4);
+
+    // Only an admin can reset the breaker
+    assert_eq!(
+        contract.try_reset_circuit_breaker(&pauser),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+    contract.reset_circuit_breaker(&admin);
+    assert_eq!(contract.get_circuit_breaker_trip(), None);
+    assert_eq!(
+        contract.try_reset_circuit_breaker(&admin),
+        Err(Ok(crate::ContractError::InvalidStatus))
+    );
+
+    // The tripping window was cleared, so payouts resume
+    contract.confirm_payout(&id2);
+    assert_eq!(contract.get_circuit_breaker_trip(), None);
+}
+
+#[test]
+fn test_circuit_breaker_trips_on_agent_volume_per_window_and_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    let eurc = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &10_000);
+    eurc.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &0, &0, &None);
+    contract.whitelist_token(&admin, &eurc.address);
+    contract.register_agent(&admin, &agent);
+    contract.register_agent(&admin, &other_agent);
+    contract.set_circuit_breaker(
+        &admin,
+        &crate::CircuitBreakerConfig {
+            max_window_volume: 0,
+            max_agent_window_volume: 1500,
+            window_seconds: 3600,
+            enabled: true,
+        },
+    );
+
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&sender, &other_agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id3 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id4 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id5 = contract.create_remittance(&sender, &agent, &500, &default_currency(&env), &default_country(&env), &None);
+    let eurc_id =
+        contract.create_remittance_with_token(&sender, &agent, &1000, &eurc.address, &default_currency(&env), &default_country(&env), &None);
+
+    contract.confirm_payout(&id1);
+    contract.confirm_payout(&id2);
+
+    // A new window starts once the previous one has elapsed
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    contract.confirm_payout(&id3);
+
+    // Volumes in different tokens are tracked separately
+    contract.confirm_payout(&eurc_id);
+    assert_eq!(contract.get_circuit_breaker_trip(), None);
+
+    assert_eq!(contract.try_confirm_payout(&id4), Err(Ok(crate::ContractError::CircuitBreakerTripped)));
+    contract.confirm_payout(&id5);
+    let trip = contract.get_circuit_breaker_trip().unwrap();
+    assert_eq!(
+        trip.cause,
+        crate::CircuitBreakerCause::AgentVolume(agent.clone(), token.address.clone())
+    );
+    assert_eq!(trip.window_volume, 1500);
+}
+
+#[test]
+fn test_circuit_breaker_checks_batch_volume_before_transfers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &0, &0, &None);
+    contract.register_agent(&admin, &agent);
+    contract.set_circuit_breaker(
+        &admin,
+        &crate::CircuitBreakerConfig {
+            max_window_volume: 1500,
+            max_agent_window_volume: 0,
+            window_seconds: 3600,
+            enabled: true,
+        },
+    );
+
+    // Each payout fits under the ceiling, but the batch as a whole does not
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &default_currency(&env), &default_country(&env), &None);
+    let mut entries = Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
+
+    assert_eq!(
+        contract.try_batch_settle_with_netting(&admin, &entries),
+        Err(Ok(crate::ContractError::CircuitBreakerTripped))
+    );
+    assert_eq!(get_token_balance(&token, &agent), 0);
+    assert_eq!(contract.get_remittance(&id1).status, crate::RemittanceStatus::Pending);
+    assert_eq!(contract.get_circuit_breaker_trip(), None);
+}
+
+// ============================================================================
+// Daily Send Limit Enforcement Tests
+// ============================================================================
+
+#[test]
+fn test_daily_limit_enforced_per_sender_per_corridor_over_rolling_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let other_sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &100_000);
+    token.mint(&other_sender, &100_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let usd = default_currency(&env);
+    let us = default_country(&env);
+    let ng = String::from_str(&env, "NG");
+    assert_eq!(contract.get_remaining_daily_allowance(&sender, &usd, &us), None);
+    contract.set_daily_limit(&admin, &usd, &us, &10_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    contract.create_remittance(&sender, &agent, &6000, &usd, &us, &None);
+    assert_eq!(contract.get_remaining_daily_allowance(&sender, &usd, &us), Some(4000));
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &5000, &usd, &us, &None),
+        Err(Ok(crate::ContractError::DailySendLimitExceeded))
+    );
+
+    // Other senders and other corridors are counted separately
+    contract.create_remittance(&other_sender, &agent, &9000, &usd, &us, &None);
+    contract.create_remittance(&sender, &agent, &50_000, &usd, &ng, &None);
+
+    // Exactly reaching the limit is allowed
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 43_200);
+    contract.create_remittance(&sender, &agent, &4000, &usd, &us, &None);
+    assert_eq!(contract.get_remaining_daily_allowance(&sender, &usd, &us), Some(0));
+
+    // The first transfer leaves the window 24 hours after it was created
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 86_400);
+    assert_eq!(contract.get_remaining_daily_allowance(&sender, &usd, &us), Some(6000));
+    contract.create_remittance(&sender, &agent, &6000, &usd, &us, &None);
+
+    // Expired records are pruned when a new transfer is recorded
+    let transfers = env.as_contract(&contract.address, || {
+        crate::storage::get_user_transfers(&env, &sender)
+    });
+    assert_eq!(transfers.len(), 2);
+}
+
+#[test]
+fn test_transfers_recorded_only_when_a_daily_limit_applies() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let usd = default_currency(&env);
+    let us = default_country(&env);
+    let ng = String::from_str(&env, "NG");
+    let user_transfers = || {
+        env.as_contract(&contract.address, || {
+            crate::storage::get_user_transfers(&env, &sender).len()
+        })
+    };
+
+    // Without a limit nothing reads the history, so nothing is stored
+    contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+    assert_eq!(user_transfers(), 0);
+
+    contract.set_daily_limit(&admin, &usd, &us, &10_000);
+    contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+    contract.create_remittance(&sender, &agent, &1000, &usd, &ng, &None);
+    assert_eq!(user_transfers(), 1);
+
+    // A KYC tier with a daily volume cap counts every corridor
+    contract.set_kyc_tier_limits(
+        &admin,
+        &1,
+        &Some(crate::KycTierLimits { max_transfer_amount: 0, max_daily_volume: 50_000 }),
+    );
+    contract.set_kyc(&admin, &sender, &1, &(30 * 86_400));
+    contract.set_kyc_required(&admin, &true);
+    contract.create_remittance(&sender, &agent, &1000, &usd, &ng, &None);
+    assert_eq!(user_transfers(), 2);
+}
+
+#[test]
+fn test_cancelled_remittance_releases_daily_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let usd = default_currency(&env);
+    let us = default_country(&env);
+    contract.set_daily_limit(&admin, &usd, &us, &10_000);
+
+    let settled = contract.create_remittance(&sender, &agent, &4000, &usd, &us, &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &6000, &usd, &us, &None);
+    assert_eq!(contract.get_remaining_daily_allowance(&sender, &usd, &us), Some(0));
+
+    // Settled remittances keep counting, refunded ones do not
+    contract.confirm_payout(&settled);
+    contract.cancel_remittance(&cancelled);
+    assert_eq!(contract.get_remaining_daily_allowance(&sender, &usd, &us), Some(6000));
+    contract.create_remittance(&sender, &agent, &6000, &usd, &us, &None);
+}
+
+// ============================================================================
+// Limits Engine Tests
+// ============================================================================
+
+#[test]
+fn test_transaction_and_velocity_limits_on_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let other_sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &100_000);
+    token.mint(&other_sender, &100_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let usd = default_currency(&env);
+    let us = default_country(&env);
+    let corridor = crate::LimitScope::Corridor(usd.clone(), us.clone());
+
+    // Transaction limits only apply to the global and corridor scopes
+    assert_eq!(
+        contract.try_set_transaction_limits(
+            &admin,
+            &crate::LimitScope::Sender,
+            &Some(crate::TransactionLimits { min_amount: 1, max_amount: 0 }),
+        ),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+    contract.set_transaction_limits(
+        &admin,
+        &crate::LimitScope::Global,
+        &Some(crate::TransactionLimits { min_amount: 100, max_amount: 0 }),
+    );
+    contract.set_transaction_limits(
+        &admin,
+        &corridor,
+        &Some(crate::TransactionLimits { min_amount: 0, max_amount: 5000 }),
+    );
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &99, &usd, &us, &None),
+        Err(Ok(crate::ContractError::TransactionLimitExceeded))
+    );
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &5001, &usd, &us, &None),
+        Err(Ok(crate::ContractError::TransactionLimitExceeded))
+    );
+
+    // Weekly cap per sender, monthly cap for the corridor
+    contract.set_velocity_limit(
+        &admin,
+        &crate::VelocityLimit {
+            scope: crate::LimitScope::Sender,
+            window: crate::LimitWindow::IsoWeek,
+            max_volume: 8000,
+        },
+    );
+    contract.set_velocity_limit(
+        &admin,
+        &crate::VelocityLimit {
+            scope: corridor.clone(),
+            window: crate::LimitWindow::CalendarMonth,
+            max_volume: 15_000,
+        },
+    );
+    assert_eq!(contract.get_velocity_limits().len(), 2);
+
+    let (week_start, week_end) = crate::LimitWindow::IsoWeek.bucket(1_700_000_000);
+    env.ledger().with_mut(|li| li.timestamp = week_start);
+    contract.create_remittance(&sender, &agent, &5000, &usd, &us, &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &3000, &usd, &us, &None);
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &100, &usd, &us, &None),
+        Err(Ok(crate::ContractError::VelocityLimitExceeded))
+    );
+
+    let usage = contract
+        .get_limit_usage(&crate::LimitSubject::Sender(sender.clone()), &crate::LimitWindow::IsoWeek)
+        .unwrap();
+    assert_eq!(usage.used, 8000);
+    assert_eq!(usage.remaining, 0);
+    assert_eq!(usage.window_start, week_start);
+    assert_eq!(usage.window_end, week_end);
+
+    // A cancelled remittance releases its volume
+    contract.cancel_remittance(&cancelled);
+    contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+
+    // The corridor cap counts every sender
+    contract.create_remittance(&other_sender, &agent, &5000, &usd, &us, &None);
+    assert_eq!(
+        contract.try_create_remittance(&other_sender, &agent, &4500, &usd, &us, &None),
+        Err(Ok(crate::ContractError::VelocityLimitExceeded))
+    );
+
+    // The sender's weekly volume resets in the next window
+    env.ledger().with_mut(|li| li.timestamp = week_end);
+    let usage = contract
+        .get_limit_usage(&crate::LimitSubject::Sender(sender.clone()), &crate::LimitWindow::IsoWeek)
+        .unwrap();
+    assert_eq!(usage.used, 0);
+
+    // Removing a limit stops enforcing it
+    contract.remove_velocity_limit(&admin, &corridor, &crate::LimitWindow::CalendarMonth);
+    assert_eq!(
+        contract.get_limit_usage(
+            &crate::LimitSubject::Corridor(usd.clone(), us.clone()),
+            &crate::LimitWindow::CalendarMonth,
+        ),
+        None
+    );
+    contract.create_remittance(&other_sender, &agent, &4500, &usd, &us, &None);
+}
+
+#[test]
+fn test_velocity_windows_are_utc_calendar_buckets() {
+    // Thursday 2024-02-29 12:00 UTC, in a leap year
+    let now = 1_709_208_000;
+    assert_eq!(crate::LimitWindow::UtcDay.bucket(now), (1_709_164_800, 1_709_251_200));
+    // Monday 2024-02-26 to Monday 2024-03-04
+    assert_eq!(crate::LimitWindow::IsoWeek.bucket(now), (1_708_905_600, 1_709_510_400));
+    // 2024-02-01 to 2024-03-01
+    assert_eq!(crate::LimitWindow::CalendarMonth.bucket(now), (1_706_745_600, 1_709_251_200));
+
+    // The last second of a year is still in December
+    assert_eq!(
+        crate::LimitWindow::CalendarMonth.bucket(1_704_067_199),
+        (1_701_388_800, 1_704_067_200)
+    );
+    assert_eq!(
+        crate::LimitWindow::IsoWeek.bucket(1_704_067_199),
+        (1_703_462_400, 1_704_067_200)
+    );
+}
+
+#[test]
+fn test_agent_daily_payout_ceiling() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    assert_eq!(
+        contract.try_set_velocity_limit(
+            &admin,
+            &crate::VelocityLimit {
+                scope: crate::LimitScope::Agent,
+                window: crate::LimitWindow::UtcDay,
+                max_volume: -1,
+            },
+        ),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+    contract.set_velocity_limit(
+        &admin,
+        &crate::VelocityLimit {
+            scope: crate::LimitScope::Agent,
+            window: crate::LimitWindow::UtcDay,
+            max_volume: 2000,
+        },
+    );
+
+    let usd = default_currency(&env);
+    let us = default_country(&env);
+    env.ledger().with_mut(|li| li.timestamp = 86_400);
+    let first = contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+    let second = contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+    let third = contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+
+    // Payouts are counted net of fees: 975 each
+    contract.confirm_payout(&first);
+    contract.confirm_payout(&second);
+    assert_eq!(
+        contract.try_confirm_payout(&third),
+        Err(Ok(crate::ContractError::VelocityLimitExceeded))
+    );
+    let simulation = contract.simulate_settlement(&third);
+    assert!(!simulation.would_succeed);
+    assert_eq!(
+        simulation.error_message,
+        Some(crate::ContractError::VelocityLimitExceeded as u32)
+    );
+
+    let usage = contract
+        .get_limit_usage(&crate::LimitSubject::Agent(agent.clone()), &crate::LimitWindow::UtcDay)
+        .unwrap();
+    assert_eq!(usage.used, 1950);
+    assert_eq!(usage.remaining, 50);
+
+    // The ceiling resets the next day
+    env.ledger().with_mut(|li| li.timestamp = 2 * 86_400);
+    contract.confirm_payout(&third);
+}
+
+#[test]
+fn test_queued_payouts_count_against_agent_velocity_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+    contract.set_velocity_limit(
+        &admin,
+        &crate::VelocityLimit {
+            scope: crate::LimitScope::Agent,
+            window: crate::LimitWindow::UtcDay,
+            max_volume: 2000,
+        },
+    );
+
+    let usd = default_currency(&env);
+    let us = default_country(&env);
+    env.ledger().with_mut(|li| li.timestamp = 86_400);
+    let first = contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+    let second = contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+    let third = contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+
+    // Queued payouts reserve their volume, so the third cannot be queued
+    // behind them and later block the cycle from closing
+    contract.queue_for_clearing(&first);
+    contract.queue_for_clearing(&second);
+    assert_eq!(
+        contract.try_queue_for_clearing(&third),
+        Err(Ok(crate::ContractError::VelocityLimitExceeded))
+    );
+    assert_eq!(
+        contract.try_confirm_payout(&third),
+        Err(Ok(crate::ContractError::VelocityLimitExceeded))
+    );
+
+    // Closing the cycle settles both without counting them again
+    let cycle = contract.close_clearing_cycle(&admin);
+    assert_eq!(cycle.remittance_count, 2);
+    let usage = contract
+        .get_limit_usage(&crate::LimitSubject::Agent(agent.clone()), &crate::LimitWindow::UtcDay)
+        .unwrap();
+    assert_eq!(usage.used, 1950);
+}
+
+// ============================================================================
+// KYC Tier Tests
+// ============================================================================
+
+#[test]
+fn test_kyc_required_blocks_missing_and_expired_kyc() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let officer = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+    contract.grant_role(&admin, &crate::Role::ComplianceOfficer, &officer);
+
+    let usd = default_currency(&env);
+    let us = default_country(&env);
+
+    // KYC is not enforced until required
+    contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+
+    contract.set_kyc_required(&officer, &true);
+    assert!(contract.is_kyc_required());
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &usd, &us, &None),
+        Err(Ok(crate::ContractError::KycRequired))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    assert_eq!(
+        contract.try_set_kyc(&officer, &sender, &1, &1000),
+        Err(Ok(crate::ContractError::KycExpired))
+    );
+    contract.set_kyc(&officer, &sender, &1, &5000);
+    let record = contract.get_kyc(&sender).unwrap();
+    assert_eq!(record.tier, 1);
+    assert_eq!(record.verified_by, officer);
+    assert_eq!(record.verified_at, 1000);
+    contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 5000);
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &usd, &us, &None),
+        Err(Ok(crate::ContractError::KycExpired))
+    );
+
+    contract.revoke_kyc(&officer, &sender);
+    assert_eq!(contract.get_kyc(&sender), None);
+    assert_eq!(
+        contract.try_revoke_kyc(&officer, &sender),
+        Err(Ok(crate::ContractError::KycRequired))
+    );
+
+    // Only compliance officers and admins manage KYC
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        contract.try_set_kyc(&outsider, &sender, &1, &10_000),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_kyc_tier_limits_cap_transfer_size_and_daily_volume() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let basic = Address::generate(&env);
+    let premium = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&basic, &100_000);
+    token.mint(&premium, &100_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    assert_eq!(
+        contract.try_set_kyc_tier_limits(
+            &admin,
+            &1,
+            &Some(crate::KycTierLimits { max_transfer_amount: -1, max_daily_volume: 0 }),
+        ),
+        Err(Ok(crate::ContractError::InvalidLimitConfig))
+    );
+    contract.set_kyc_tier_limits(
+        &admin,
+        &1,
+        &Some(crate::KycTierLimits { max_transfer_amount: 1000, max_daily_volume: 2500 }),
+    );
+    contract.set_kyc_required(&admin, &true);
+    contract.set_kyc(&admin, &basic, &1, &(30 * 86_400));
+    contract.set_kyc(&admin, &premium, &2, &(30 * 86_400));
+
+    let usd = default_currency(&env);
+    let us = default_country(&env);
+    let ng = String::from_str(&env, "NG");
+
+    assert_eq!(
+        contract.try_create_remittance(&basic, &agent, &1001, &usd, &us, &None),
+        Err(Ok(crate::ContractError::KycTierLimitExceeded))
+    );
+
+    // The daily volume counts every corridor, and cancelled remittances are released
+    contract.create_remittance(&basic, &agent, &1000, &usd, &us, &None);
+    let cancelled = contract.create_remittance(&basic, &agent, &1000, &usd, &ng, &None);
+    assert_eq!(
+        contract.try_create_remittance(&basic, &agent, &600, &usd, &us, &None),
+        Err(Ok(crate::ContractError::KycTierLimitExceeded))
+    );
+    contract.cancel_remittance(&cancelled);
+    contract.create_remittance(&basic, &agent, &600, &usd, &us, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 86_400);
+    contract.create_remittance(&basic, &agent, &1000, &usd, &us, &None);
+
+    // Tiers without limits are not capped
+    contract.create_remittance(&premium, &agent, &50_000, &usd, &us, &None);
+}
+
+// ============================================================================
+// Sanctions Screening Tests
+// ============================================================================
+
+/// Screening contract that flags the addresses passed to `flag`.
+#[soroban_sdk::contract]
+pub struct MockScreening;
+
+#[soroban_sdk::contractimpl]
+impl MockScreening {
+    pub fn flag(env: Env, account: Address) {
+        env.storage().instance().set(&account, &true);
+    }
+
+    pub fn is_sanctioned(env: Env, account: Address) -> bool {
+        env.storage().instance().has(&account)
+    }
+}
+
+#[test]
+fn test_denylist_blocks_creation_payout_and_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let other_sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &100_000);
+    token.mint(&other_sender, &100_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let usd = default_currency(&env);
+    let us = default_country(&env);
+    let to_settle = contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+    let to_refund = contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    contract.deny_address(&admin, &sender, &7);
+    let entry = contract.get_denylist_entry(&sender).unwrap();
+    assert_eq!(entry.reason, 7);
+    assert_eq!(entry.listed_by, admin);
+    assert_eq!(entry.listed_at, 1000);
+
+    // A denylisted sender cannot send, be paid out for or be refunded
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &usd, &us, &None),
+        Err(Ok(crate::ContractError::AddressSanctioned))
+    );
+    assert_eq!(
+        contract.try_confirm_payout(&to_settle),
+        Err(Ok(crate::ContractError::AddressSanctioned))
+    );
+    assert_eq!(
+        contract.try_cancel_remittance(&to_refund),
+        Err(Ok(crate::ContractError::AddressSanctioned))
+    );
+    assert_eq!(
+        contract.try_screen_address(&sender),
+        Err(Ok(crate::ContractError::AddressSanctioned))
+    );
+
+    contract.allow_address(&admin, &sender);
+    assert_eq!(contract.get_denylist_entry(&sender), None);
+    contract.cancel_remittance(&to_refund);
+
+    // A denylisted agent cannot receive remittances or payouts
+    contract.deny_address(&admin, &agent, &7);
+    assert_eq!(
+        contract.try_create_remittance(&other_sender, &agent, &1000, &usd, &us, &None),
+        Err(Ok(crate::ContractError::AddressSanctioned))
+    );
+    assert_eq!(
+        contract.try_confirm_payout(&to_settle),
+        Err(Ok(crate::ContractError::AddressSanctioned))
+    );
+    contract.allow_address(&admin, &agent);
+    contract.confirm_payout(&to_settle);
+
+    // Only admins manage the denylist
+    assert_eq!(
+        contract.try_deny_address(&other_sender, &sender, &7),
+        Err(Ok(crate::ContractError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_screening_contract_flags_addresses_and_fails_closed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let flagged = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token_contract(&env, &token_admin);
+    token.mint(&sender, &100_000);
+    token.mint(&flagged, &100_000);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0, &None);
+    contract.register_agent(&admin, &agent);
+
+    let screening_id = env.register_contract(None, MockScreening);
+    let screening = MockScreeningClient::new(&env, &screening_id);
+    screening.flag(&flagged);
+    contract.set_screening_contract(&admin, &Some(screening_id.clone()));
+    assert_eq!(contract.get_screening_contract(), Some(screening_id));
+
+    let usd = default_currency(&env);
+    let us = default_country(&env);
+    contract.create_remittance(&sender, &agent, &1000, &usd, &us, &None);
+    assert_eq!(
+        contract.try_create_remittance(&flagged, &agent, &1000, &usd, &us, &None),
+        Err(Ok(crate::ContractError::AddressSanctioned))
+    );
+
+    // A screening contract that cannot be called blocks everything
+    contract.set_screening_contract(&admin, &Some(Address::generate(&env)));
+    assert_eq!(
+        contract.try_create_remittance(&sender, &agent, &1000, &usd, &us, &None),
+        Err(Ok(crate::ContractError::ScreeningFailed))
+    );
+
+    contract.set_screening_contract(&admin, &None);
+    contract.create_remittance(&flagged, &agent, &1000, &usd, &us, &None);
+}
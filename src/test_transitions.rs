@@ -27,7 +27,7 @@ fn setup_contract(env: &Env) -> (SwiftRemitContractClient, token::StellarAssetCl
     env.mock_all_auths();
     contract.whitelist_token(&admin, &token.address);
     contract.initialize(&admin, &token.address, &250);
-    contract.register_agent(&agent);
+    contract.register_agent(&admin, &agent);
 
     token.mint(&sender, &10000);
 
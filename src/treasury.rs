@@ -0,0 +1,270 @@
+//! Timelocked platform fee withdrawals.
+//!
+//! Fees leave the contract in two phases. An admin proposes a withdrawal of an
+//! amount of one token to a treasury destination; the proposal becomes
+//! executable once the withdrawal timelock has elapsed, and only by someone
+//! other than the proposer. Until it is executed, any other admin can veto it.
+//! Destinations must be on the treasury allow-list both when the withdrawal is
+//! proposed and when it is executed. Changing the timelock or adding a
+//! destination is an `AdminAction`, so it needs an approved proposal once the
+//! approval threshold is above 1.
+//!
+//! Proposals do not reserve fees: the amount is checked against the
+//! accumulated fees again at execution, so competing proposals cannot
+//! withdraw more than was collected.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::ttl::{self, TtlClass};
+use crate::{ContractError, RegistryKind};
+
+/// Default delay between proposing and executing a withdrawal (48 hours).
+pub const DEFAULT_WITHDRAWAL_TIMELOCK: u64 = 48 * 3600;
+
+/// Shortest configurable withdrawal timelock (24 hours), so other admins
+/// always have a window to veto a withdrawal.
+pub const MIN_WITHDRAWAL_TIMELOCK: u64 = 24 * 3600;
+
+/// Longest configurable withdrawal timelock (30 days).
+pub const MAX_WITHDRAWAL_TIMELOCK: u64 = 30 * 86_400;
+
+/// Lifecycle of a fee withdrawal proposal.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeeWithdrawalStatus {
+    /// Waiting for the timelock to elapse, or for execution
+    Pending,
+    /// Fees transferred to the destination
+    Executed,
+    /// Blocked by another admin
+    Vetoed,
+}
+
+/// A proposed fee withdrawal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeWithdrawal {
+    pub id: u64,
+    /// Admin that proposed the withdrawal
+    pub proposer: Address,
+    /// Token the fees are withdrawn in
+    pub token: Address,
+    pub amount: i128,
+    /// Treasury destination receiving the fees
+    pub to: Address,
+    pub proposed_at: u64,
+    /// Earliest timestamp the withdrawal can be executed at
+    pub executable_at: u64,
+    pub status: FeeWithdrawalStatus,
+    /// Admin that executed or vetoed the withdrawal
+    pub resolved_by: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum TreasuryKey {
+    /// Withdrawal timelock in seconds (instance storage)
+    Timelock,
+    /// ID of the last proposed withdrawal (instance storage)
+    WithdrawalCounter,
+    /// Withdrawal proposal indexed by ID (persistent storage)
+    Withdrawal(u64),
+    /// Allow-list status indexed by destination address (persistent storage)
+    Destination(Address),
+}
+
+/// Returns the withdrawal timelock in seconds.
+pub fn get_timelock(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&TreasuryKey::Timelock)
+        .unwrap_or(DEFAULT_WITHDRAWAL_TIMELOCK)
+}
+
+/// Checks that a timelock lies within `MIN_WITHDRAWAL_TIMELOCK..=MAX_WITHDRAWAL_TIMELOCK`.
+///
+/// # Errors
+/// - InvalidLimitConfig: Shorter than the minimum or longer than the maximum
+pub fn validate_timelock(seconds: u64) -> Result<(), ContractError> {
+    if !(MIN_WITHDRAWAL_TIMELOCK..=MAX_WITHDRAWAL_TIMELOCK).contains(&seconds) {
+        return Err(ContractError::InvalidLimitConfig);
+    }
+    Ok(())
+}
+
+/// Sets the withdrawal timelock. Applies to withdrawals proposed afterwards.
+///
+/// # Errors
+/// - InvalidLimitConfig: Outside `MIN_WITHDRAWAL_TIMELOCK..=MAX_WITHDRAWAL_TIMELOCK`
+pub fn set_timelock(env: &Env, seconds: u64) -> Result<(), ContractError> {
+    validate_timelock(seconds)?;
+    env.storage()
+        .instance()
+        .set(&TreasuryKey::Timelock, &seconds);
+    Ok(())
+}
+
+/// Checks whether an address is on the treasury allow-list.
+pub fn is_destination(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&TreasuryKey::Destination(address.clone()))
+        .unwrap_or(false)
+}
+
+/// Adds an address to, or removes it from, the treasury allow-list.
+pub fn set_destination(env: &Env, address: &Address, allowed: bool) {
+    let key = TreasuryKey::Destination(address.clone());
+    if !env.storage().persistent().has(&key) {
+        crate::registry::append(env, RegistryKind::Treasury, address);
+    }
+    env.storage().persistent().set(&key, &allowed);
+    ttl::extend_persistent(env, &key, TtlClass::Active);
+}
+
+/// Records a withdrawal proposal.
+///
+/// # Errors
+/// - InvalidAmount: Amount is zero or negative
+/// - DestinationNotAllowed: `to` is not on the treasury allow-list
+/// - NoFeesToWithdraw: Accumulated fees in `token` are below `amount`
+pub fn propose_withdrawal(
+    env: &Env,
+    proposer: &Address,
+    token: &Address,
+    amount: i128,
+    to: &Address,
+) -> Result<FeeWithdrawal, ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if !is_destination(env, to) {
+        return Err(ContractError::DestinationNotAllowed);
+    }
+    if crate::storage::get_token_fees(env, token)? < amount {
+        return Err(ContractError::NoFeesToWithdraw);
+    }
+
+    let id = get_withdrawal_counter(env)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&TreasuryKey::WithdrawalCounter, &id);
+
+    let now = env.ledger().timestamp();
+    let withdrawal = FeeWithdrawal {
+        id,
+        proposer: proposer.clone(),
+        token: token.clone(),
+        amount,
+        to: to.clone(),
+        proposed_at: now,
+        executable_at: now.saturating_add(get_timelock(env)),
+        status: FeeWithdrawalStatus::Pending,
+        resolved_by: None,
+    };
+    set_withdrawal(env, &withdrawal);
+
+    Ok(withdrawal)
+}
+
+/// Vetoes a pending withdrawal.
+///
+/// # Errors
+/// - WithdrawalNotFound: Unknown withdrawal ID
+/// - InvalidStatus: Already executed or vetoed
+/// - Unauthorized: `admin` proposed the withdrawal
+pub fn veto(env: &Env, admin: &Address, id: u64) -> Result<FeeWithdrawal, ContractError> {
+    let mut withdrawal = get_pending(env, id)?;
+    if withdrawal.proposer == *admin {
+        return Err(ContractError::Unauthorized);
+    }
+
+    withdrawal.status = FeeWithdrawalStatus::Vetoed;
+    withdrawal.resolved_by = Some(admin.clone());
+    set_withdrawal(env, &withdrawal);
+
+    Ok(withdrawal)
+}
+
+/// Executes a pending withdrawal whose timelock has elapsed.
+///
+/// # Errors
+/// - WithdrawalNotFound: Unknown withdrawal ID
+/// - InvalidStatus: Already executed or vetoed
+/// - Unauthorized: `admin` proposed the withdrawal
+/// - TimelockNotElapsed: Called before `executable_at`
+/// - DestinationNotAllowed: Destination was removed from the allow-list
+/// - NoFeesToWithdraw: Accumulated fees fell below the amount
+pub fn execute(env: &Env, admin: &Address, id: u64) -> Result<FeeWithdrawal, ContractError> {
+    let mut withdrawal = get_pending(env, id)?;
+    if withdrawal.proposer == *admin {
+        return Err(ContractError::Unauthorized);
+    }
+    if env.ledger().timestamp() < withdrawal.executable_at {
+        return Err(ContractError::TimelockNotElapsed);
+    }
+    if !is_destination(env, &withdrawal.to) {
+        return Err(ContractError::DestinationNotAllowed);
+    }
+
+    crate::storage::deduct_accumulated_fees(env, &withdrawal.token, withdrawal.amount)?;
+    soroban_sdk::token::Client::new(env, &withdrawal.token).transfer(
+        &env.current_contract_address(),
+        &withdrawal.to,
+        &withdrawal.amount,
+    );
+
+    withdrawal.status = FeeWithdrawalStatus::Executed;
+    withdrawal.resolved_by = Some(admin.clone());
+    set_withdrawal(env, &withdrawal);
+
+    Ok(withdrawal)
+}
+
+/// Returns the ID of the last proposed withdrawal (0 if none).
+pub fn get_withdrawal_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&TreasuryKey::WithdrawalCounter)
+        .unwrap_or(0)
+}
+
+/// Stores the timelock and withdrawal counter as is (used by migration import).
+pub fn restore_config(env: &Env, timelock: u64, withdrawal_counter: u64) {
+    let storage = env.storage().instance();
+    storage.set(&TreasuryKey::Timelock, &timelock);
+    storage.set(&TreasuryKey::WithdrawalCounter, &withdrawal_counter);
+}
+
+/// Stores a withdrawal proposal as is (used by migration import).
+pub fn restore_withdrawal(env: &Env, withdrawal: &FeeWithdrawal) {
+    set_withdrawal(env, withdrawal);
+}
+
+/// Retrieves a withdrawal proposal by ID.
+pub fn get_withdrawal(env: &Env, id: u64) -> Result<FeeWithdrawal, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&TreasuryKey::Withdrawal(id))
+        .ok_or(ContractError::WithdrawalNotFound)
+}
+
+fn get_pending(env: &Env, id: u64) -> Result<FeeWithdrawal, ContractError> {
+    let withdrawal = get_withdrawal(env, id)?;
+    if withdrawal.status != FeeWithdrawalStatus::Pending {
+        return Err(ContractError::InvalidStatus);
+    }
+    Ok(withdrawal)
+}
+
+fn set_withdrawal(env: &Env, withdrawal: &FeeWithdrawal) {
+    let key = TreasuryKey::Withdrawal(withdrawal.id);
+    env.storage().persistent().set(&key, withdrawal);
+    let class = match withdrawal.status {
+        FeeWithdrawalStatus::Pending => TtlClass::Active,
+        FeeWithdrawalStatus::Executed | FeeWithdrawalStatus::Vetoed => TtlClass::Terminal,
+    };
+    ttl::extend_persistent(env, &key, class);
+}
@@ -0,0 +1,150 @@
+//! Storage TTL policy for the SwiftRemit contract.
+//!
+//! Soroban archives persistent entries whose TTL runs out, after which reads
+//! fail until the entry is restored. Every write of a persistent entry
+//! therefore extends its TTL according to one of two classes:
+//!
+//! - Active: pending remittances and long-lived configuration (agent, admin
+//!   and token flags, daily limits, per-user state, registries)
+//! - Terminal: completed or cancelled remittances, settlement flags and receipts
+//!
+//! The contract instance is extended on every call. Anyone can keep specific
+//! remittances alive with `bump_remittance_ttl`. All thresholds and extensions
+//! are in ledgers and configurable by an admin.
+
+use soroban_sdk::{contracttype, Env, IntoVal, Val, Vec};
+
+use crate::{ContractError, RemittanceStatus};
+
+/// Ledgers per day at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Maximum number of remittances extended by one `bump_remittance_ttl` call.
+pub const MAX_TTL_BUMP_IDS: u32 = 100;
+
+/// TTL thresholds and extensions, in ledgers.
+///
+/// An entry is extended to `*_extend_to` once its remaining TTL falls below
+/// `*_threshold`, so frequently touched entries are not rewritten every call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlPolicy {
+    /// Contract instance (configuration and counters)
+    pub instance_threshold: u32,
+    pub instance_extend_to: u32,
+    /// Pending remittances and long-lived configuration entries
+    pub active_threshold: u32,
+    pub active_extend_to: u32,
+    /// Completed or cancelled remittances, settlement flags and receipts
+    pub terminal_threshold: u32,
+    pub terminal_extend_to: u32,
+}
+
+impl TtlPolicy {
+    /// Default policy: 30 days for the instance, 90 days for active entries
+    /// and 30 days for terminal entries, each renewed one week before expiry.
+    pub fn default_policy() -> Self {
+        TtlPolicy {
+            instance_threshold: 23 * DAY_IN_LEDGERS,
+            instance_extend_to: 30 * DAY_IN_LEDGERS,
+            active_threshold: 83 * DAY_IN_LEDGERS,
+            active_extend_to: 90 * DAY_IN_LEDGERS,
+            terminal_threshold: 23 * DAY_IN_LEDGERS,
+            terminal_extend_to: 30 * DAY_IN_LEDGERS,
+        }
+    }
+}
+
+/// TTL class of a persistent entry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TtlClass {
+    Active,
+    Terminal,
+}
+
+impl TtlClass {
+    /// Class of a remittance record in the given status.
+    pub fn for_status(status: &RemittanceStatus) -> Self {
+        match status {
+            RemittanceStatus::Pending => TtlClass::Active,
+            _ => TtlClass::Terminal,
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum TtlKey {
+    /// Configured TTL policy (instance storage)
+    Policy,
+}
+
+/// Returns the configured TTL policy, or the default policy.
+pub fn get_policy(env: &Env) -> TtlPolicy {
+    env.storage()
+        .instance()
+        .get(&TtlKey::Policy)
+        .unwrap_or_else(TtlPolicy::default_policy)
+}
+
+/// Validates and stores a TTL policy.
+///
+/// # Errors
+/// - InvalidLimitConfig: A threshold exceeds its extension, an extension is zero,
+///   or an extension exceeds the network's maximum entry TTL
+pub fn set_policy(env: &Env, policy: &TtlPolicy) -> Result<(), ContractError> {
+    let max_ttl = env.storage().max_ttl();
+    let pairs = [
+        (policy.instance_threshold, policy.instance_extend_to),
+        (policy.active_threshold, policy.active_extend_to),
+        (policy.terminal_threshold, policy.terminal_extend_to),
+    ];
+    for (threshold, extend_to) in pairs {
+        if extend_to == 0 || threshold > extend_to || extend_to > max_ttl {
+            return Err(ContractError::InvalidLimitConfig);
+        }
+    }
+    env.storage().instance().set(&TtlKey::Policy, policy);
+    Ok(())
+}
+
+/// Extends the contract instance TTL. Called at the start of every entrypoint.
+pub fn bump_instance(env: &Env) {
+    let policy = get_policy(env);
+    env.storage()
+        .instance()
+        .extend_ttl(policy.instance_threshold, policy.instance_extend_to);
+}
+
+/// Extends the TTL of an existing persistent entry according to its class.
+pub fn extend_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K, class: TtlClass) {
+    let policy = get_policy(env);
+    let (threshold, extend_to) = match class {
+        TtlClass::Active => (policy.active_threshold, policy.active_extend_to),
+        TtlClass::Terminal => (policy.terminal_threshold, policy.terminal_extend_to),
+    };
+    env.storage()
+        .persistent()
+        .extend_ttl(key, threshold, extend_to);
+}
+
+/// Extends the TTL of the given remittances and their settlement records.
+///
+/// Unknown IDs are skipped. Returns the number of remittances extended.
+///
+/// # Errors
+/// - InvalidAmount: More than `MAX_TTL_BUMP_IDS` IDs
+pub fn bump_remittances(env: &Env, ids: &Vec<u64>) -> Result<u32, ContractError> {
+    if ids.len() > MAX_TTL_BUMP_IDS {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let mut bumped = 0;
+    for id in ids.iter() {
+        if crate::storage::extend_remittance_ttl(env, id) {
+            crate::receipts::extend_receipt_ttl(env, id);
+            bumped += 1;
+        }
+    }
+    Ok(bumped)
+}
@@ -3,7 +3,7 @@
 //! This module defines the core data structures used throughout the contract,
 //! including remittance records and status enums.
 
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, String, Vec};
 
 /// Status of a remittance transaction.
 ///
@@ -39,6 +39,8 @@ pub struct Remittance {
     pub amount: i128,
     /// Platform fee deducted from the amount (in USDC)
     pub fee: i128,
+    /// Token contract the amount and fee are denominated in
+    pub token: Address,
     /// Current status of the remittance
     pub status: RemittanceStatus,
     /// Optional expiry timestamp (seconds since epoch) for settlement
@@ -57,8 +59,10 @@ pub struct BatchSettlementEntry {
 /// Result of a batch settlement operation.
 /// Contains the IDs of successfully settled remittances.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BatchSettlementResult {
+    /// ID of the netting batch, recorded in each settlement receipt
+    pub batch_id: u64,
     /// List of successfully settled remittance IDs
     pub settled_ids: Vec<u64>,
 }
@@ -86,9 +90,16 @@ pub struct DailyLimit {
     pub limit: i128,
 }
 
+/// A remittance counted against its sender's daily send limit.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TransferRecord {
+    /// Remittance the transfer was created as, used to release it on cancellation
+    pub remittance_id: u64,
+    /// Corridor currency code
+    pub currency: String,
+    /// Corridor country code
+    pub country: String,
     pub timestamp: u64,
     pub amount: i128,
 }
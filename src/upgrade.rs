@@ -0,0 +1,207 @@
+//! In-place contract upgrades and storage schema migrations.
+//!
+//! `upgrade` swaps the contract WASM while keeping the contract address and all
+//! stored state. New code may expect a different storage layout, so the layout
+//! is versioned: `STORAGE_SCHEMA_VERSION` is the version this code writes and the
+//! stored schema version is the version existing records were written with.
+//!
+//! Each registered `SchemaMigration` upgrades records from one version to the
+//! next. Migrations run in two ways:
+//!
+//! - Lazily: reads apply pending migrations to a record's fields in memory, so
+//!   the contract stays fully usable right after an upgrade. Records are stored
+//!   in the current layout the next time they are written.
+//! - In batches: the admin calls `migrate_storage_schema` to rewrite remittance
+//!   records in ID order. Once every record is rewritten the stored schema
+//!   version is raised to `STORAGE_SCHEMA_VERSION`.
+//!
+//! Contracts deployed before schema versioning have no stored version and are
+//! treated as version 1.
+
+use soroban_sdk::{contracttype, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val};
+
+use crate::{ContractError, Remittance, MAX_MIGRATION_BATCH_SIZE};
+
+/// Storage schema version written by this code.
+///
+/// - 1: initial layout
+/// - 2: `Remittance` gained `token`
+pub const STORAGE_SCHEMA_VERSION: u32 = 2;
+
+/// Code and storage versions reported by `get_version`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractVersion {
+    /// Crate version of the running WASM
+    pub code_version: String,
+    /// Storage schema version the running code writes
+    pub code_schema_version: u32,
+    /// Storage schema version of the stored records
+    pub storage_schema_version: u32,
+}
+
+/// Progress of the batched storage schema migration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrationStatus {
+    /// Storage schema version of the stored records
+    pub storage_schema_version: u32,
+    /// Storage schema version the migration upgrades to
+    pub target_schema_version: u32,
+    /// Next remittance ID the migration will rewrite
+    pub next_remittance_id: u64,
+    /// Highest remittance ID to rewrite
+    pub last_remittance_id: u64,
+    /// Whether stored records are at the target version
+    pub complete: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum UpgradeKey {
+    /// Storage schema version of the stored records (instance storage)
+    SchemaVersion,
+    /// Next remittance ID to rewrite in the batched migration (instance storage)
+    MigrationCursor,
+}
+
+/// A transformation of stored records from `from_version` to `from_version + 1`.
+///
+/// Transformations operate on the raw field map of a record and must be
+/// idempotent: while a batched migration is in progress, stored records are a
+/// mix of old and already-rewritten layouts.
+struct SchemaMigration {
+    from_version: u32,
+    /// Upgrades the fields of a stored `Remittance`
+    remittance: fn(&Env, Map<Symbol, Val>) -> Map<Symbol, Val>,
+}
+
+/// Registered migrations, in version order.
+const MIGRATIONS: [SchemaMigration; 1] = [SchemaMigration {
+    from_version: 1,
+    remittance: remittance_add_token,
+}];
+
+/// v1 → v2: remittances created before multi-token support were all in USDC.
+fn remittance_add_token(env: &Env, mut fields: Map<Symbol, Val>) -> Map<Symbol, Val> {
+    let key = Symbol::new(env, "token");
+    if !fields.contains_key(key.clone()) {
+        let usdc = crate::storage::get_usdc_token(env)
+            .expect("USDC token must be set before migrating remittances");
+        fields.set(key, usdc.into_val(env));
+    }
+    fields
+}
+
+/// Storage schema version of the stored records.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&UpgradeKey::SchemaVersion)
+        .unwrap_or(1)
+}
+
+/// Records the storage schema version (on initialize, import and migration completion).
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&UpgradeKey::SchemaVersion, &version);
+}
+
+/// Reports the code and storage schema versions.
+pub fn get_version(env: &Env) -> ContractVersion {
+    ContractVersion {
+        code_version: String::from_str(env, env!("CARGO_PKG_VERSION")),
+        code_schema_version: STORAGE_SCHEMA_VERSION,
+        storage_schema_version: get_schema_version(env),
+    }
+}
+
+/// Replaces the contract WASM. The new code takes effect from the next invocation.
+pub fn upgrade_wasm(env: &Env, new_wasm_hash: BytesN<32>) {
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+}
+
+/// Decodes a stored remittance, upgrading it from an older layout if needed.
+///
+/// When the stored schema is current the record is decoded directly; otherwise
+/// every migration from the stored version onwards is applied to its fields.
+pub fn decode_remittance(env: &Env, raw: Val) -> Remittance {
+    let from_version = get_schema_version(env);
+    if from_version >= STORAGE_SCHEMA_VERSION {
+        return Remittance::try_from_val(env, &raw).unwrap();
+    }
+
+    let mut fields = Map::<Symbol, Val>::try_from_val(env, &raw).unwrap();
+    for migration in MIGRATIONS.iter() {
+        if migration.from_version >= from_version {
+            fields = (migration.remittance)(env, fields);
+        }
+    }
+    Remittance::try_from_val(env, &fields.to_val()).unwrap()
+}
+
+/// Rewrites up to `batch_size` remittances in the current layout.
+///
+/// Completes the migration, raising the stored schema version, once the cursor
+/// passes the last remittance ID. Calling it with nothing pending is a no-op.
+pub fn migrate_batch(env: &Env, batch_size: u32) -> Result<SchemaMigrationStatus, ContractError> {
+    if batch_size == 0 || batch_size > MAX_MIGRATION_BATCH_SIZE {
+        return Err(ContractError::InvalidMigrationBatch);
+    }
+
+    let from_version = get_schema_version(env);
+    if from_version >= STORAGE_SCHEMA_VERSION {
+        return get_migration_status(env);
+    }
+    if !MIGRATIONS.iter().any(|m| m.from_version == from_version) {
+        return Err(ContractError::InvalidMigrationBatch);
+    }
+
+    let last_id = crate::storage::get_remittance_counter(env)?;
+    let mut id = cursor(env);
+    let end = id.saturating_add(batch_size as u64);
+    while id < end && id <= last_id {
+        if let Some(raw) = crate::storage::get_remittance_raw(env, id) {
+            crate::storage::set_remittance(env, id, &decode_remittance(env, raw));
+        }
+        id += 1;
+    }
+
+    if id > last_id {
+        set_schema_version(env, STORAGE_SCHEMA_VERSION);
+        env.storage().instance().remove(&UpgradeKey::MigrationCursor);
+        crate::events::emit_schema_migrated(env, from_version, STORAGE_SCHEMA_VERSION);
+    } else {
+        env.storage()
+            .instance()
+            .set(&UpgradeKey::MigrationCursor, &id);
+    }
+
+    get_migration_status(env)
+}
+
+/// Returns the progress of the batched storage schema migration.
+pub fn get_migration_status(env: &Env) -> Result<SchemaMigrationStatus, ContractError> {
+    let storage_schema_version = get_schema_version(env);
+    let complete = storage_schema_version >= STORAGE_SCHEMA_VERSION;
+    let last_remittance_id = crate::storage::get_remittance_counter(env)?;
+    Ok(SchemaMigrationStatus {
+        storage_schema_version,
+        target_schema_version: STORAGE_SCHEMA_VERSION,
+        next_remittance_id: if complete {
+            last_remittance_id + 1
+        } else {
+            cursor(env)
+        },
+        last_remittance_id,
+        complete,
+    })
+}
+
+fn cursor(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&UpgradeKey::MigrationCursor)
+        .unwrap_or(1)
+}
@@ -2,15 +2,15 @@
 //!
 //! This module provides validation functions for Stellar addresses used in
 //! contract operations.
+//!
+//! Centralized validation module for all API requests.
+//! Validates required fields before controller logic to prevent invalid data
+//! from reaching business logic.
 
-use soroban_sdk::Address;
+use soroban_sdk::{Address, Env, String};
 
 use crate::{ContractError, is_agent_registered, is_paused, get_remittance, RemittanceStatus};
 
-/// Centralized validation module for all API requests.
-/// Validates required fields before controller logic to prevent invalid data
-/// from reaching business logic.
-
 /// Validates that an address is properly formatted and not empty.
 ///
 /// Stellar addresses in Soroban are represented by the Address type,
@@ -30,7 +30,7 @@ use crate::{ContractError, is_agent_registered, is_paused, get_remittance, Remit
 /// The Address type in Soroban SDK is guaranteed to be valid by the runtime.
 /// This function primarily serves as a placeholder for future validation logic
 /// and to make the code more explicit about validation requirements.
-pub fn validate_address(address: &Address) -> Result<(), ContractError> {
+pub fn validate_address(_address: &Address) -> Result<(), ContractError> {
     // The Address type in Soroban SDK is already validated by the runtime.
     // However, we can add additional checks if needed.
     // For now, we ensure the address is not a zero/empty address by checking
@@ -107,6 +107,14 @@ pub fn validate_no_duplicate_settlement(env: &Env, remittance_id: u64) -> Result
     Ok(())
 }
 
+/// Validates that a remittance has not already been queued into a clearing cycle.
+pub fn validate_not_queued_for_clearing(env: &Env, remittance_id: u64) -> Result<(), ContractError> {
+    if crate::is_queued_for_clearing(env, remittance_id) {
+        return Err(ContractError::DuplicateSettlement);
+    }
+    Ok(())
+}
+
 /// Validates that there are fees available to withdraw.
 pub fn validate_fees_available(fees: i128) -> Result<(), ContractError> {
     if fees <= 0 {
@@ -131,11 +139,6 @@ pub fn validate_initialize_request(
         return Err(ContractError::AlreadyInitialized);
     }
     
-    // Check if token is whitelisted
-    if !crate::is_token_whitelisted(env, token) {
-        return Err(ContractError::TokenNotWhitelisted);
-    }
-    
     Ok(())
 }
 
@@ -153,28 +156,98 @@ pub fn validate_create_remittance_request(
     Ok(())
 }
 
+/// Validates that a transfer keeps the sender within the daily send limit of
+/// its (currency, country) corridor.
+///
+/// Sums the sender's transfers in the corridor over the rolling 24-hour
+/// window. Corridors without a configured limit are unrestricted.
+///
+/// # Returns
+///
+/// * `Ok(())` - No limit is configured, or the transfer fits within it
+/// * `Err(ContractError::DailySendLimitExceeded)` - The transfer would exceed the limit
+pub fn validate_daily_send_limit(
+    env: &Env,
+    sender: &Address,
+    currency: &String,
+    country: &String,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let daily_limit = match crate::storage::get_daily_limit(env, currency, country) {
+        Some(daily_limit) => daily_limit,
+        None => return Ok(()),
+    };
+
+    let total = crate::storage::get_daily_corridor_total(env, sender, currency, country)?
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    if total > daily_limit.limit {
+        return Err(ContractError::DailySendLimitExceeded);
+    }
+    Ok(())
+}
+
 /// Comprehensive validation for confirm_payout request.
 pub fn validate_confirm_payout_request(
     env: &Env,
     remittance_id: u64,
 ) -> Result<crate::Remittance, ContractError> {
     validate_not_paused(env)?;
+    crate::rate_limit::require_circuit_closed(env)?;
+    crate::pause::require_operation_not_paused(env, crate::PausableOperation::Payout)?;
     let remittance = validate_remittance_exists(env, remittance_id)?;
     validate_remittance_pending(&remittance)?;
     validate_no_duplicate_settlement(env, remittance_id)?;
+    validate_not_queued_for_clearing(env, remittance_id)?;
     validate_settlement_not_expired(env, remittance.expiry)?;
     validate_address(&remittance.agent)?;
+    crate::pause::require_stored_remittance_not_paused(env, &remittance)?;
+    crate::sanctions::require_parties_not_sanctioned(env, &remittance.sender, &remittance.agent)?;
     Ok(remittance)
 }
 
+/// Runs every precondition `confirm_payout` enforces before moving funds.
+///
+/// Shared by `confirm_payout`, `queue_for_clearing` and `simulate_settlement`
+/// so the simulation can never drift from the real settlement path, including
+/// the migration-mode check. Performs no storage writes.
+///
+/// # Returns
+///
+/// * `Ok((remittance, payout_amount))` - Settlement would succeed
+/// * `Err(ContractError)` - The exact error `confirm_payout` would return
+pub fn validate_settlement(
+    env: &Env,
+    remittance_id: u64,
+) -> Result<(crate::Remittance, i128), ContractError> {
+    crate::migration::require_writable(env)?;
+    let remittance = validate_confirm_payout_request(env, remittance_id)?;
+    crate::storage::check_rate_limit(env, &remittance.sender)?;
+    let payout_amount = remittance
+        .amount
+        .checked_sub(remittance.fee)
+        .ok_or(ContractError::Overflow)?;
+    crate::limits::check_payout(env, &remittance.agent, payout_amount)?;
+    crate::rate_limit::check_payout_volume(env, &remittance.agent, &remittance.token, payout_amount)?;
+    Ok((remittance, payout_amount))
+}
+
 /// Comprehensive validation for cancel_remittance request.
 pub fn validate_cancel_remittance_request(
     env: &Env,
     remittance_id: u64,
 ) -> Result<crate::Remittance, ContractError> {
+    crate::pause::require_operation_not_paused(env, crate::PausableOperation::Cancellation)?;
     let remittance = validate_remittance_exists(env, remittance_id)?;
     validate_remittance_pending(&remittance)?;
+    crate::pause::require_not_paused(env, crate::PauseScope::Token(remittance.token.clone()))?;
+    // Queued remittances are committed to a clearing cycle and can no longer be refunded
+    if crate::is_queued_for_clearing(env, remittance_id) {
+        return Err(ContractError::InvalidStatus);
+    }
     validate_address(&remittance.sender)?;
+    // Refunds to sanctioned senders stay frozen in escrow
+    crate::sanctions::require_not_sanctioned(env, &remittance.sender)?;
     Ok(remittance)
 }
 
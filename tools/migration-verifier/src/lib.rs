@@ -0,0 +1,634 @@
+//! Offline verification of SwiftRemit migration exports.
+//!
+//! Operators save the `MigrationSnapshot` returned by `export_migration_state`,
+//! or the `MigrationBatch` values returned by `export_migration_batch`, as XDR
+//! files (raw `ScVal` bytes or base64 text). This crate decodes those files and
+//! checks them with the contract's own hashing code (`compute_snapshot_hash`,
+//! `compute_batch_hash`, `chain_hash` and the Merkle helpers), so the off-chain
+//! checks cannot drift from what the contract computes on-chain.
+//!
+//! Every check returns a serializable report; the `swiftremit-migration`
+//! binary prints them as JSON.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use serde::Serialize;
+use soroban_sdk::xdr::{Limits, ReadXdr, ScVal, ToXdr};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, TryFromVal, Val, Vec};
+use swiftremit::{MigrationBatch, MigrationPayload, MigrationSnapshot, Remittance};
+
+/// Errors raised while loading export files.
+#[derive(Debug)]
+pub enum VerifierError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is not valid XDR (raw or base64) for an `ScVal`
+    InvalidXdr(String),
+    /// The XDR does not decode as the expected contract type
+    UnexpectedType(&'static str),
+}
+
+impl fmt::Display for VerifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifierError::Io(err) => write!(f, "failed to read file: {err}"),
+            VerifierError::InvalidXdr(err) => write!(f, "invalid XDR: {err}"),
+            VerifierError::UnexpectedType(expected) => {
+                write!(f, "XDR does not decode as a {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VerifierError {}
+
+impl From<std::io::Error> for VerifierError {
+    fn from(err: std::io::Error) -> Self {
+        VerifierError::Io(err)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Decoding
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Decodes an `ScVal` from raw XDR bytes or base64 text.
+fn decode_scval(data: &[u8]) -> Result<ScVal, VerifierError> {
+    match <ScVal as ReadXdr>::from_xdr(data, Limits::none()) {
+        Ok(scval) => Ok(scval),
+        Err(raw_err) => {
+            let text = std::str::from_utf8(data)
+                .map_err(|_| VerifierError::InvalidXdr(raw_err.to_string()))?;
+            ScVal::from_xdr_base64(text.trim(), Limits::none())
+                .map_err(|err| VerifierError::InvalidXdr(err.to_string()))
+        }
+    }
+}
+
+/// Converts `data` into a contract type after checking that it is a struct
+/// map carrying `field`. The host aborts, rather than returning an error, when
+/// a map of the wrong shape is unpacked into a struct, so the shape is checked
+/// on the XDR first.
+fn decode_struct<T: TryFromVal<Env, Val>>(
+    env: &Env,
+    data: &[u8],
+    field: &str,
+    expected: &'static str,
+) -> Result<T, VerifierError> {
+    let scval = decode_scval(data)?;
+    let has_field = match &scval {
+        ScVal::Map(Some(map)) => map
+            .iter()
+            .any(|entry| matches!(&entry.key, ScVal::Symbol(key) if key.to_utf8_string_lossy() == field)),
+        _ => false,
+    };
+    if !has_field {
+        return Err(VerifierError::UnexpectedType(expected));
+    }
+    let val = Val::try_from_val(env, &scval)
+        .map_err(|err| VerifierError::InvalidXdr(format!("{err:?}")))?;
+    T::try_from_val(env, &val).map_err(|_| VerifierError::UnexpectedType(expected))
+}
+
+/// Decodes a `MigrationSnapshot` from raw XDR bytes or base64 text.
+pub fn decode_snapshot(env: &Env, data: &[u8]) -> Result<MigrationSnapshot, VerifierError> {
+    decode_struct(env, data, "instance_data", "MigrationSnapshot")
+}
+
+/// Decodes a `MigrationBatch` from raw XDR bytes or base64 text.
+pub fn decode_batch(env: &Env, data: &[u8]) -> Result<MigrationBatch, VerifierError> {
+    decode_struct(env, data, "batch_hash", "MigrationBatch")
+}
+
+/// Reads and decodes a snapshot file.
+pub fn read_snapshot(env: &Env, path: &Path) -> Result<MigrationSnapshot, VerifierError> {
+    decode_snapshot(env, &std::fs::read(path)?)
+}
+
+/// Reads and decodes a batch file.
+pub fn read_batch(env: &Env, path: &Path) -> Result<MigrationBatch, VerifierError> {
+    decode_batch(env, &std::fs::read(path)?)
+}
+
+/// Encodes a contract value as raw `ScVal` XDR, the format accepted by the
+/// decoders.
+pub fn encode_xdr<T: IntoVal<Env, Val> + Clone>(env: &Env, value: &T) -> std::vec::Vec<u8> {
+    value.clone().to_xdr(env).iter().collect()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Snapshot Verification
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Result of recomputing a snapshot's hash and Merkle root.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct SnapshotReport {
+    pub version: u32,
+    pub source_contract: String,
+    pub network_passphrase_hash: String,
+    pub timestamp: u64,
+    pub ledger_sequence: u32,
+    pub remittance_count: u32,
+    pub expected_hash: String,
+    pub computed_hash: String,
+    pub hash_valid: bool,
+    pub expected_merkle_root: String,
+    pub computed_merkle_root: String,
+    pub merkle_root_valid: bool,
+    pub valid: bool,
+}
+
+/// Recomputes a snapshot's verification hash and Merkle root.
+pub fn verify_snapshot(env: &Env, snapshot: &MigrationSnapshot) -> SnapshotReport {
+    let computed_hash = swiftremit::compute_snapshot_hash(
+        env,
+        &snapshot.source_contract,
+        &snapshot.network_passphrase_hash,
+        &snapshot.instance_data,
+        &snapshot.persistent_data,
+        snapshot.timestamp,
+        snapshot.ledger_sequence,
+    );
+    let computed_root =
+        swiftremit::compute_remittance_root(env, &snapshot.persistent_data.remittances);
+
+    let hash_valid = computed_hash == snapshot.verification_hash;
+    let merkle_root_valid = computed_root == snapshot.merkle_root;
+
+    SnapshotReport {
+        version: snapshot.version,
+        source_contract: address_string(&snapshot.source_contract),
+        network_passphrase_hash: hex(&snapshot.network_passphrase_hash),
+        timestamp: snapshot.timestamp,
+        ledger_sequence: snapshot.ledger_sequence,
+        remittance_count: snapshot.persistent_data.remittances.len(),
+        expected_hash: hex(&snapshot.verification_hash),
+        computed_hash: hex(&computed_hash),
+        hash_valid,
+        expected_merkle_root: hex(&snapshot.merkle_root),
+        computed_merkle_root: hex(&computed_root),
+        merkle_root_valid,
+        valid: hash_valid && merkle_root_valid,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Batch Verification
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Verification result of one batch.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct BatchEntryReport {
+    pub batch_number: u32,
+    pub total_batches: u32,
+    pub category: &'static str,
+    pub expected_hash: String,
+    pub computed_hash: String,
+    pub valid: bool,
+}
+
+/// Result of verifying a full batch sequence.
+///
+/// `rolling_hash` and `merkle_root` are the values the destination contract
+/// and `get_migration_export_digest` must report for the same sequence.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct BatchSequenceReport {
+    pub batches: std::vec::Vec<BatchEntryReport>,
+    pub total_batches: u32,
+    pub rolling_hash: String,
+    pub merkle_root: String,
+    pub errors: std::vec::Vec<String>,
+    pub valid: bool,
+}
+
+/// Verifies a batch sequence: every batch hash, the numbering, and that the
+/// sequence is complete. Batches must be given in export order.
+pub fn verify_batches(env: &Env, batches: &[MigrationBatch]) -> BatchSequenceReport {
+    let mut errors = std::vec::Vec::new();
+    let mut entries = std::vec::Vec::new();
+    let mut rolling_hash = BytesN::from_array(env, &[0u8; 32]);
+    let mut leaves = Vec::new(env);
+
+    let total_batches = batches.first().map(|b| b.total_batches).unwrap_or(0);
+    if batches.is_empty() {
+        errors.push("no batches given".to_string());
+    }
+
+    for (position, batch) in batches.iter().enumerate() {
+        let computed_hash = swiftremit::compute_batch_hash(
+            env,
+            batch.batch_number,
+            batch.total_batches,
+            &batch.payload,
+        );
+        let valid = computed_hash == batch.batch_hash;
+        if !valid {
+            errors.push(format!("batch {}: hash mismatch", batch.batch_number));
+        }
+        if batch.batch_number as usize != position {
+            errors.push(format!(
+                "batch {} found at position {position}",
+                batch.batch_number
+            ));
+        }
+        if batch.total_batches != total_batches {
+            errors.push(format!(
+                "batch {}: total_batches {} differs from {total_batches}",
+                batch.batch_number, batch.total_batches
+            ));
+        }
+
+        rolling_hash = swiftremit::chain_hash(env, &rolling_hash, &computed_hash);
+        leaves.push_back(swiftremit::batch_leaf(env, &computed_hash));
+        entries.push(BatchEntryReport {
+            batch_number: batch.batch_number,
+            total_batches: batch.total_batches,
+            category: payload_category(&batch.payload),
+            expected_hash: hex(&batch.batch_hash),
+            computed_hash: hex(&computed_hash),
+            valid,
+        });
+    }
+
+    if !batches.is_empty() && batches.len() != total_batches as usize {
+        errors.push(format!(
+            "expected {total_batches} batches, got {}",
+            batches.len()
+        ));
+    }
+
+    BatchSequenceReport {
+        batches: entries,
+        total_batches,
+        rolling_hash: hex(&rolling_hash),
+        merkle_root: hex(&swiftremit::compute_root(env, &leaves)),
+        valid: errors.is_empty(),
+        errors,
+    }
+}
+
+fn payload_category(payload: &MigrationPayload) -> &'static str {
+    match payload {
+        MigrationPayload::Config(_) => "config",
+        MigrationPayload::Admins(_) => "admins",
+        MigrationPayload::Roles(_) => "roles",
+        MigrationPayload::Tokens(_) => "tokens",
+        MigrationPayload::TokenFees(_) => "token_fees",
+        MigrationPayload::Agents(_) => "agents",
+        MigrationPayload::DailyLimits(_) => "daily_limits",
+        MigrationPayload::KycTiers(_) => "kyc_tiers",
+        MigrationPayload::KycRecords(_) => "kyc_records",
+        MigrationPayload::Denylist(_) => "denylist",
+        MigrationPayload::TreasuryDestinations(_) => "treasury_destinations",
+        MigrationPayload::FeeWithdrawals(_) => "fee_withdrawals",
+        MigrationPayload::PauseScopes(_) => "pause_scopes",
+        MigrationPayload::VelocityLimits(_) => "velocity_limits",
+        MigrationPayload::TransactionLimits(_) => "transaction_limits",
+        MigrationPayload::FxRates(_) => "fx_rates",
+        MigrationPayload::Remittances(_) => "remittances",
+        MigrationPayload::Receipts(_) => "receipts",
+        MigrationPayload::Users(_) => "users",
+        MigrationPayload::ArchivePeriods(_) => "archive_periods",
+        MigrationPayload::ArchiveTotals(_) => "archive_totals",
+        MigrationPayload::ArchivedRemittances(_) => "archived_remittances",
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Snapshot Diff
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// A changed configuration field.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+/// Items added to and removed from a set.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct SetDiff<T> {
+    pub added: std::vec::Vec<T>,
+    pub removed: std::vec::Vec<T>,
+}
+
+impl<T> SetDiff<T> {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Differences in contract state between two snapshots, from `before` to `after`.
+///
+/// The source contracts are reported but not compared, so a snapshot of the
+/// destination taken after an import is `identical` to the imported one.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    pub before_source_contract: String,
+    pub after_source_contract: String,
+    pub config_changes: std::vec::Vec<FieldChange>,
+    pub remittances: SetDiff<u64>,
+    /// Remittances present in both snapshots whose contents differ
+    pub remittances_changed: std::vec::Vec<u64>,
+    pub agents: SetDiff<String>,
+    pub admins: SetDiff<String>,
+    pub tokens: SetDiff<String>,
+    pub settled_remittances: SetDiff<u64>,
+    pub identical: bool,
+}
+
+/// Compares two snapshots field by field.
+pub fn diff_snapshots(before: &MigrationSnapshot, after: &MigrationSnapshot) -> SnapshotDiff {
+    let (a, b) = (&before.instance_data, &after.instance_data);
+    let mut config_changes = std::vec::Vec::new();
+    let mut field = |name: &'static str, before: String, after: String| {
+        if before != after {
+            config_changes.push(FieldChange { field: name, before, after });
+        }
+    };
+    field("network_passphrase_hash", hex(&before.network_passphrase_hash), hex(&after.network_passphrase_hash));
+    field("usdc_token", address_string(&a.usdc_token), address_string(&b.usdc_token));
+    field("platform_fee_bps", a.platform_fee_bps.to_string(), b.platform_fee_bps.to_string());
+    field("remittance_counter", a.remittance_counter.to_string(), b.remittance_counter.to_string());
+    field("accumulated_fees", a.accumulated_fees.to_string(), b.accumulated_fees.to_string());
+    field("paused", a.paused.to_string(), b.paused.to_string());
+    field("admin_count", a.admin_count.to_string(), b.admin_count.to_string());
+
+    let before_remittances = remittances_by_id(&before.persistent_data.remittances);
+    let after_remittances = remittances_by_id(&after.persistent_data.remittances);
+    let remittances = set_diff(
+        before_remittances.keys().copied(),
+        after_remittances.keys().copied(),
+    );
+    let remittances_changed: std::vec::Vec<u64> = before_remittances
+        .iter()
+        .filter(|(id, remittance)| {
+            after_remittances
+                .get(*id)
+                .is_some_and(|other| other != *remittance)
+        })
+        .map(|(id, _)| *id)
+        .collect();
+
+    let (p, q) = (&before.persistent_data, &after.persistent_data);
+    let agents = set_diff(addresses(&p.agents), addresses(&q.agents));
+    let admins = set_diff(addresses(&p.admin_roles), addresses(&q.admin_roles));
+    let tokens = set_diff(addresses(&p.whitelisted_tokens), addresses(&q.whitelisted_tokens));
+    let settled_remittances = set_diff(p.settlement_hashes.iter(), q.settlement_hashes.iter());
+
+    let identical = config_changes.is_empty()
+        && remittances.is_empty()
+        && remittances_changed.is_empty()
+        && agents.is_empty()
+        && admins.is_empty()
+        && tokens.is_empty()
+        && settled_remittances.is_empty();
+
+    SnapshotDiff {
+        before_source_contract: address_string(&before.source_contract),
+        after_source_contract: address_string(&after.source_contract),
+        config_changes,
+        remittances,
+        remittances_changed,
+        agents,
+        admins,
+        tokens,
+        settled_remittances,
+        identical,
+    }
+}
+
+fn remittances_by_id(remittances: &Vec<Remittance>) -> BTreeMap<u64, Remittance> {
+    remittances.iter().map(|r| (r.id, r)).collect()
+}
+
+fn addresses(list: &Vec<Address>) -> impl Iterator<Item = String> + '_ {
+    list.iter().map(|address| address_string(&address))
+}
+
+fn set_diff<T: Ord + Clone>(
+    before: impl Iterator<Item = T>,
+    after: impl Iterator<Item = T>,
+) -> SetDiff<T> {
+    let before: std::collections::BTreeSet<T> = before.collect();
+    let after: std::collections::BTreeSet<T> = after.collect();
+    SetDiff {
+        added: after.difference(&before).cloned().collect(),
+        removed: before.difference(&after).cloned().collect(),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Formatting
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Lowercase hex encoding of a 32-byte hash.
+pub fn hex(bytes: &BytesN<32>) -> String {
+    bytes.to_array().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Strkey form of an address (`G...` or `C...`).
+pub fn address_string(address: &Address) -> String {
+    let strkey = address.to_string();
+    let mut buf = std::vec![0u8; strkey.len() as usize];
+    strkey.copy_into_slice(&mut buf);
+    String::from_utf8(buf).expect("strkeys are ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::token::StellarAssetClient;
+    use soroban_sdk::String;
+    use swiftremit::{SwiftRemitContract, SwiftRemitContractClient};
+
+    struct Fixture<'a> {
+        env: Env,
+        admin: Address,
+        token: Address,
+        sender: Address,
+        agent: Address,
+        contract: SwiftRemitContractClient<'a>,
+    }
+
+    /// Deploys a contract holding one pending remittance. It is left active,
+    /// so tests call `start_migration` before exporting.
+    fn setup<'a>() -> Fixture<'a> {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let token_admin = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(token_admin).address();
+        let sender = Address::generate(&env);
+        let agent = Address::generate(&env);
+
+        StellarAssetClient::new(&env, &token).mint(&sender, &10_000);
+        let contract = deploy(&env, &admin, &token, &sender, &agent);
+
+        Fixture { env, admin, token, sender, agent, contract }
+    }
+
+    fn deploy<'a>(
+        env: &Env,
+        admin: &Address,
+        token: &Address,
+        sender: &Address,
+        agent: &Address,
+    ) -> SwiftRemitContractClient<'a> {
+        let contract = SwiftRemitContractClient::new(
+            env,
+            &env.register_contract(None, SwiftRemitContract {}),
+        );
+        contract.initialize(admin, token, &250, &0, &None);
+        contract.register_agent(admin, agent);
+        contract.create_remittance(
+            sender,
+            agent,
+            &1_000,
+            &String::from_str(env, "USD"),
+            &String::from_str(env, "US"),
+            &None,
+        );
+        contract
+    }
+
+    fn temp_file(name: &str, data: &[u8]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "swiftremit-migration-{}-{name}",
+            std::process::id()
+        ));
+        std::fs::write(&path, data).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_snapshot_file_round_trip_verifies() {
+        let f = setup();
+        f.contract.start_migration(&f.admin);
+        let snapshot = f.contract.export_migration_state(&f.admin);
+
+        let raw = temp_file("snapshot.xdr", &encode_xdr(&f.env, &snapshot));
+        let report = verify_snapshot(&f.env, &read_snapshot(&f.env, &raw).unwrap());
+        assert!(report.valid);
+        assert_eq!(report.remittance_count, 1);
+        assert_eq!(report.computed_hash, hex(&snapshot.verification_hash));
+        assert_eq!(report.source_contract, address_string(&f.contract.address));
+
+        // Base64 text decodes to the same snapshot
+        let scval = <ScVal as ReadXdr>::from_xdr(encode_xdr(&f.env, &snapshot), Limits::none()).unwrap();
+        let text = soroban_sdk::xdr::WriteXdr::to_xdr_base64(&scval, Limits::none()).unwrap();
+        let b64 = temp_file("snapshot.b64", text.as_bytes());
+        assert_eq!(verify_snapshot(&f.env, &read_snapshot(&f.env, &b64).unwrap()), report);
+    }
+
+    #[test]
+    fn test_tampered_snapshot_fails_verification() {
+        let f = setup();
+        f.contract.start_migration(&f.admin);
+        let mut snapshot = f.contract.export_migration_state(&f.admin);
+        snapshot.instance_data.accumulated_fees += 1;
+
+        let report = verify_snapshot(&f.env, &snapshot);
+        assert!(!report.hash_valid);
+        assert!(report.merkle_root_valid);
+        assert!(!report.valid);
+    }
+
+    #[test]
+    fn test_decode_rejects_wrong_type() {
+        let f = setup();
+        f.contract.start_migration(&f.admin);
+        let batch = f.contract.export_migration_batch(&f.admin, &0, &5);
+        assert!(matches!(
+            decode_snapshot(&f.env, &encode_xdr(&f.env, &batch)),
+            Err(VerifierError::UnexpectedType("MigrationSnapshot"))
+        ));
+        assert!(matches!(
+            decode_snapshot(&f.env, b"not xdr"),
+            Err(VerifierError::InvalidXdr(_))
+        ));
+    }
+
+    #[test]
+    fn test_batch_sequence_matches_export_digest() {
+        let f = setup();
+        f.contract.start_migration(&f.admin);
+        let first = f.contract.export_migration_batch(&f.admin, &0, &5);
+        let mut batches = std::vec![first.clone()];
+        for n in 1..first.total_batches {
+            batches.push(f.contract.export_migration_batch(&f.admin, &n, &5));
+        }
+        let digest = f.contract.get_migration_export_digest();
+
+        let files: std::vec::Vec<_> = batches
+            .iter()
+            .map(|b| temp_file(&format!("batch-{}.xdr", b.batch_number), &encode_xdr(&f.env, b)))
+            .collect();
+        let decoded: std::vec::Vec<_> = files.iter().map(|p| read_batch(&f.env, p).unwrap()).collect();
+
+        let report = verify_batches(&f.env, &decoded);
+        assert!(report.valid, "{:?}", report.errors);
+        assert_eq!(report.total_batches, digest.total_batches);
+        assert_eq!(report.rolling_hash, hex(&digest.rolling_hash));
+        assert_eq!(report.merkle_root, hex(&digest.merkle_root));
+        assert_eq!(report.batches[0].category, "config");
+    }
+
+    #[test]
+    fn test_batch_sequence_detects_reorder_and_gaps() {
+        let f = setup();
+        f.contract.start_migration(&f.admin);
+        let first = f.contract.export_migration_batch(&f.admin, &0, &5);
+        let second = f.contract.export_migration_batch(&f.admin, &1, &5);
+
+        let report = verify_batches(&f.env, &[second.clone(), first.clone()]);
+        assert!(!report.valid);
+
+        let report = verify_batches(&f.env, &[first]);
+        assert!(!report.valid);
+        assert!(report.errors.iter().any(|e| e.starts_with("expected")));
+    }
+
+    #[test]
+    fn test_diff_snapshots_reports_changes() {
+        let f = setup();
+        f.contract.start_migration(&f.admin);
+        let before = f.contract.export_migration_state(&f.admin);
+        assert!(diff_snapshots(&before, &before).identical);
+
+        // A contract with the same history that kept operating
+        let other = deploy(&f.env, &f.admin, &f.token, &f.sender, &f.agent);
+        other.confirm_payout(&1);
+        other.create_remittance(
+            &f.sender,
+            &f.agent,
+            &500,
+            &String::from_str(&f.env, "USD"),
+            &String::from_str(&f.env, "US"),
+            &None,
+        );
+        let new_agent = Address::generate(&f.env);
+        other.register_agent(&f.admin, &new_agent);
+        other.start_migration(&f.admin);
+        let after = other.export_migration_state(&f.admin);
+
+        let diff = diff_snapshots(&before, &after);
+        assert!(!diff.identical);
+        assert_eq!(diff.remittances.added, std::vec![2]);
+        assert_eq!(diff.remittances_changed, std::vec![1]);
+        assert_eq!(diff.settled_remittances.added, std::vec![1]);
+        assert_eq!(diff.agents.added, std::vec![address_string(&new_agent)]);
+        assert!(diff
+            .config_changes
+            .iter()
+            .any(|c| c.field == "remittance_counter" && c.before == "1" && c.after == "2"));
+
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(json["remittances"]["added"][0], 2);
+    }
+}
@@ -0,0 +1,79 @@
+//! `swiftremit-migration`: offline checks for SwiftRemit migration exports.
+//!
+//! ```text
+//! swiftremit-migration verify-snapshot <snapshot.xdr>
+//! swiftremit-migration verify-batches <batch-0.xdr> [<batch-1.xdr> ...]
+//! swiftremit-migration diff <before.xdr> <after.xdr>
+//! ```
+//!
+//! Files hold raw `ScVal` XDR or its base64 text. Reports are printed to stdout
+//! as JSON. The exit code is 0 when verification passes (or the snapshots are
+//! identical, for `diff`), 1 when it fails, and 2 on usage or decoding errors.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use serde::Serialize;
+use soroban_sdk::Env;
+use swiftremit_migration_verifier::{
+    diff_snapshots, read_batch, read_snapshot, verify_batches, verify_snapshot, VerifierError,
+};
+
+const USAGE: &str = "usage:
+  swiftremit-migration verify-snapshot <snapshot.xdr>
+  swiftremit-migration verify-batches <batch-0.xdr> [<batch-1.xdr> ...]
+  swiftremit-migration diff <before.xdr> <after.xdr>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Runs a command, returning whether the check passed.
+fn run(args: &[String]) -> Result<bool, String> {
+    let env = Env::default();
+    let (command, paths) = args.split_first().ok_or(USAGE)?;
+    let paths: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
+
+    match (command.as_str(), paths.as_slice()) {
+        ("verify-snapshot", [path]) => {
+            let report = verify_snapshot(&env, &read_snapshot(&env, path).map_err(describe(path))?);
+            print_json(&report)?;
+            Ok(report.valid)
+        }
+        ("verify-batches", paths) if !paths.is_empty() => {
+            let batches = paths
+                .iter()
+                .map(|path| read_batch(&env, path).map_err(describe(path)))
+                .collect::<Result<Vec<_>, _>>()?;
+            let report = verify_batches(&env, &batches);
+            print_json(&report)?;
+            Ok(report.valid)
+        }
+        ("diff", [before, after]) => {
+            let before_snapshot = read_snapshot(&env, before).map_err(describe(before))?;
+            let after_snapshot = read_snapshot(&env, after).map_err(describe(after))?;
+            let diff = diff_snapshots(&before_snapshot, &after_snapshot);
+            print_json(&diff)?;
+            Ok(diff.identical)
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn describe(path: &std::path::Path) -> impl Fn(VerifierError) -> String + '_ {
+    move |err| format!("{}: {err}", path.display())
+}
+
+fn print_json<T: Serialize>(report: &T) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(report).map_err(|err| err.to_string())?;
+    println!("{json}");
+    Ok(())
+}